@@ -0,0 +1,39 @@
+use chrono::{Local, NaiveDate};
+
+#[cfg(test)]
+use std::cell::Cell;
+
+#[cfg(test)]
+thread_local! {
+    static TODAY_OVERRIDE: Cell<Option<NaiveDate>> = const { Cell::new(None) };
+}
+
+/// Today's date in the local timezone. Every CLI command and the TUI derive
+/// "today" from here so they agree on which day it is.
+pub fn today_local() -> NaiveDate {
+    #[cfg(test)]
+    if let Some(date) = TODAY_OVERRIDE.with(Cell::get) {
+        return date;
+    }
+    Local::now().date_naive()
+}
+
+/// Pins [`today_local`] for the current test thread; `None` restores the clock.
+#[cfg(test)]
+pub fn set_today_override(date: Option<NaiveDate>) {
+    TODAY_OVERRIDE.with(|cell| cell.set(date));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_override_pins_today_local() {
+        let pinned = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+        set_today_override(Some(pinned));
+        assert_eq!(today_local(), pinned);
+        set_today_override(None);
+        assert_ne!(today_local(), pinned);
+    }
+}
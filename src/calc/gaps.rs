@@ -0,0 +1,146 @@
+use crate::calc::workday::Workday;
+use chrono::NaiveDate;
+use std::collections::{BTreeMap, HashMap};
+
+/// Distribution of remote-day runs between office visits.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GapSummary {
+    /// gap length (workdays) → number of times it occurred
+    pub histogram: BTreeMap<i32, i32>,
+    pub mean: f64,
+    pub median: f64,
+    pub max: i32,
+}
+
+/// Returns the lengths of consecutive non-office workday runs that sit between
+/// two badged days, considering only counted days before `today`. Holidays,
+/// vacation days, and days outside the stats (before the effective start)
+/// neither count toward a gap nor break one.
+pub fn gap_lengths(workday_stats: &HashMap<String, Workday>, today: NaiveDate) -> Vec<i32> {
+    let mut days: Vec<&Workday> = workday_stats
+        .values()
+        .filter(|w| w.is_counted && w.date < today && !w.is_holiday && !w.is_vacation)
+        .collect();
+    days.sort_by_key(|w| w.date);
+
+    let mut gaps = Vec::new();
+    let mut seen_office = false;
+    let mut run = 0;
+    for day in days {
+        if day.is_badged_in {
+            if seen_office && run > 0 {
+                gaps.push(run);
+            }
+            seen_office = true;
+            run = 0;
+        } else {
+            run += 1;
+        }
+    }
+    gaps
+}
+
+/// Summarizes gap lengths; `None` when there are no gaps.
+pub fn summarize_gaps(lengths: &[i32]) -> Option<GapSummary> {
+    if lengths.is_empty() {
+        return None;
+    }
+    let mut histogram = BTreeMap::new();
+    for &len in lengths {
+        *histogram.entry(len).or_insert(0) += 1;
+    }
+    let mut sorted = lengths.to_vec();
+    sorted.sort_unstable();
+    let mid = sorted.len() / 2;
+    let median = if sorted.len().is_multiple_of(2) {
+        (sorted[mid - 1] + sorted[mid]) as f64 / 2.0
+    } else {
+        sorted[mid] as f64
+    };
+    Some(GapSummary {
+        histogram,
+        mean: lengths.iter().sum::<i32>() as f64 / lengths.len() as f64,
+        median,
+        max: *sorted.last().unwrap(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calc::workday::create_workday_map;
+    use crate::data::WorkWeek;
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).unwrap()
+    }
+
+    /// Workdays of `[start, end]`, all inside the stats.
+    fn counted_workdays(start: NaiveDate, end: NaiveDate) -> HashMap<String, Workday> {
+        let mut map = create_workday_map(start, end, WorkWeek::default());
+        for day in map.values_mut() {
+            day.is_counted = true;
+        }
+        map
+    }
+
+    fn badge(map: &mut HashMap<String, Workday>, date: &str) {
+        map.get_mut(date).unwrap().is_badged_in = true;
+    }
+
+    #[test]
+    fn test_gap_lengths_known_pattern() {
+        // Mon 01/06 – Fri 01/17. Office: Mon 6, Thu 9, Fri 10, Fri 17.
+        // Gaps: Tue–Wed (2), Mon–Thu 13–16 (4, weekend skipped)
+        let mut map = counted_workdays(d(2025, 1, 6), d(2025, 1, 17));
+        for date in ["2025-01-06", "2025-01-09", "2025-01-10", "2025-01-17"] {
+            badge(&mut map, date);
+        }
+        assert_eq!(gap_lengths(&map, d(2025, 1, 20)), vec![2, 4]);
+    }
+
+    #[test]
+    fn test_gap_lengths_ignores_unbounded_runs_and_future() {
+        let mut map = counted_workdays(d(2025, 1, 6), d(2025, 1, 17));
+        badge(&mut map, "2025-01-08");
+        badge(&mut map, "2025-01-10");
+        badge(&mut map, "2025-01-16");
+        // Leading Mon–Tue and everything from today (01/14) onward are excluded
+        assert_eq!(gap_lengths(&map, d(2025, 1, 14)), vec![1]);
+    }
+
+    #[test]
+    fn test_gap_lengths_skips_holidays_and_vacations() {
+        let mut map = counted_workdays(d(2025, 1, 6), d(2025, 1, 10));
+        badge(&mut map, "2025-01-06");
+        badge(&mut map, "2025-01-10");
+        map.get_mut("2025-01-07").unwrap().is_holiday = true;
+        map.get_mut("2025-01-08").unwrap().is_vacation = true;
+        assert_eq!(gap_lengths(&map, d(2025, 1, 20)), vec![1]);
+    }
+
+    #[test]
+    fn test_gap_lengths_skips_days_outside_the_stats() {
+        // Office Mon 6 and Thu 9: a 2-day gap, unless counting starts Wed 8
+        let mut map = counted_workdays(d(2025, 1, 6), d(2025, 1, 10));
+        badge(&mut map, "2025-01-06");
+        badge(&mut map, "2025-01-09");
+        assert_eq!(gap_lengths(&map, d(2025, 1, 20)), vec![2]);
+
+        for date in ["2025-01-06", "2025-01-07"] {
+            map.get_mut(date).unwrap().is_counted = false;
+        }
+        assert!(gap_lengths(&map, d(2025, 1, 20)).is_empty());
+    }
+
+    #[test]
+    fn test_summarize_gaps() {
+        let summary = summarize_gaps(&[2, 2, 5, 1]).unwrap();
+        assert_eq!(summary.histogram[&2], 2);
+        assert_eq!(summary.histogram[&5], 1);
+        assert_eq!(summary.mean, 2.5);
+        assert_eq!(summary.median, 2.0);
+        assert_eq!(summary.max, 5);
+        assert!(summarize_gaps(&[]).is_none());
+    }
+}
@@ -0,0 +1,197 @@
+use crate::data::Holiday;
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+
+/// How a recurring holiday's date is determined each year.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HolidayRule {
+    /// Same calendar date every year (e.g. July 4)
+    FixedDate { month: u32, day: u32 },
+    /// The `n`th `weekday` of `month`; negative `n` counts from the end of the
+    /// month (-1 is the last).
+    NthWeekday {
+        month: u32,
+        weekday: Weekday,
+        n: i32,
+    },
+}
+
+impl HolidayRule {
+    /// The concrete date in `year`, or `None` if the rule doesn't occur
+    /// (e.g. a fifth Monday, or February 30).
+    pub fn date_in(&self, year: i32) -> Option<NaiveDate> {
+        match *self {
+            HolidayRule::FixedDate { month, day } => NaiveDate::from_ymd_opt(year, month, day),
+            HolidayRule::NthWeekday { month, weekday, n } if n > 0 => {
+                NaiveDate::from_weekday_of_month_opt(year, month, weekday, n as u8)
+            }
+            HolidayRule::NthWeekday { month, weekday, n } if n < 0 => {
+                let first_of_next = if month == 12 {
+                    NaiveDate::from_ymd_opt(year + 1, 1, 1)?
+                } else {
+                    NaiveDate::from_ymd_opt(year, month + 1, 1)?
+                };
+                let last_day = first_of_next - Duration::days(1);
+                let back = (last_day.weekday().num_days_from_monday() + 7
+                    - weekday.num_days_from_monday())
+                    % 7;
+                let date = last_day - Duration::days(back as i64 + 7 * (-n - 1) as i64);
+                (date.month() == month).then_some(date)
+            }
+            HolidayRule::NthWeekday { .. } => None,
+        }
+    }
+}
+
+/// US federal holidays.
+pub const FEDERAL_HOLIDAYS: &[(&str, HolidayRule)] = &[
+    (
+        "New Year's Day",
+        HolidayRule::FixedDate { month: 1, day: 1 },
+    ),
+    (
+        "MLK Day",
+        HolidayRule::NthWeekday {
+            month: 1,
+            weekday: Weekday::Mon,
+            n: 3,
+        },
+    ),
+    (
+        "Presidents' Day",
+        HolidayRule::NthWeekday {
+            month: 2,
+            weekday: Weekday::Mon,
+            n: 3,
+        },
+    ),
+    (
+        "Memorial Day",
+        HolidayRule::NthWeekday {
+            month: 5,
+            weekday: Weekday::Mon,
+            n: -1,
+        },
+    ),
+    ("Juneteenth", HolidayRule::FixedDate { month: 6, day: 19 }),
+    (
+        "Independence Day",
+        HolidayRule::FixedDate { month: 7, day: 4 },
+    ),
+    (
+        "Labor Day",
+        HolidayRule::NthWeekday {
+            month: 9,
+            weekday: Weekday::Mon,
+            n: 1,
+        },
+    ),
+    (
+        "Columbus Day",
+        HolidayRule::NthWeekday {
+            month: 10,
+            weekday: Weekday::Mon,
+            n: 2,
+        },
+    ),
+    (
+        "Veterans Day",
+        HolidayRule::FixedDate { month: 11, day: 11 },
+    ),
+    (
+        "Thanksgiving Day",
+        HolidayRule::NthWeekday {
+            month: 11,
+            weekday: Weekday::Thu,
+            n: 4,
+        },
+    ),
+    (
+        "Christmas Day",
+        HolidayRule::FixedDate { month: 12, day: 25 },
+    ),
+];
+
+/// Materializes [`FEDERAL_HOLIDAYS`] for `year`, in calendar order. Dates are
+/// the actual holiday dates; weekend observance is left to the caller.
+pub fn generate_federal_holidays(year: i32) -> Vec<Holiday> {
+    FEDERAL_HOLIDAYS
+        .iter()
+        .filter_map(|(name, rule)| {
+            rule.date_in(year)
+                .map(|date| Holiday::new(name, &date.format("%Y-%m-%d").to_string()))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date_of(holidays: &[Holiday], name: &str) -> String {
+        holidays
+            .iter()
+            .find(|h| h.name == name)
+            .map(|h| h.date.clone())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_thanksgiving_is_fourth_thursday_of_november() {
+        assert_eq!(
+            date_of(&generate_federal_holidays(2025), "Thanksgiving Day"),
+            "2025-11-27"
+        );
+        assert_eq!(
+            date_of(&generate_federal_holidays(2026), "Thanksgiving Day"),
+            "2026-11-26"
+        );
+        // Nov 1 is a Thursday: the 4th Thursday is the 22nd
+        assert_eq!(
+            date_of(&generate_federal_holidays(2018), "Thanksgiving Day"),
+            "2018-11-22"
+        );
+    }
+
+    #[test]
+    fn test_memorial_day_is_last_monday_of_may() {
+        assert_eq!(
+            date_of(&generate_federal_holidays(2025), "Memorial Day"),
+            "2025-05-26"
+        );
+        // May 31 is itself a Monday
+        assert_eq!(
+            date_of(&generate_federal_holidays(2021), "Memorial Day"),
+            "2021-05-31"
+        );
+    }
+
+    #[test]
+    fn test_nth_weekday_out_of_range() {
+        let fifth_monday = HolidayRule::NthWeekday {
+            month: 2,
+            weekday: Weekday::Mon,
+            n: 5,
+        };
+        assert_eq!(fifth_monday.date_in(2025), None);
+        let second_last = HolidayRule::NthWeekday {
+            month: 12,
+            weekday: Weekday::Wed,
+            n: -2,
+        };
+        assert_eq!(
+            second_last.date_in(2025),
+            NaiveDate::from_ymd_opt(2025, 12, 24)
+        );
+        assert_eq!(
+            HolidayRule::FixedDate { month: 2, day: 30 }.date_in(2025),
+            None
+        );
+    }
+
+    #[test]
+    fn test_generates_all_federal_holidays_in_order() {
+        let h = generate_federal_holidays(2025);
+        assert_eq!(h.len(), 11);
+        assert!(h.windows(2).all(|w| w[0].date < w[1].date));
+    }
+}
@@ -0,0 +1,373 @@
+use crate::data::holiday::Holiday;
+use crate::data::{BadgeEntryData, HolidayData, VacationData};
+use chrono::{Duration, NaiveDate, NaiveDateTime};
+
+const PRODID: &str = "-//rto//RTO Tracker//EN";
+
+/// Renders badge days, vacations, and holidays as an iCalendar (RFC 5545)
+/// document of all-day events. Office days become "In Office" and flex days
+/// "Flex Credit"; each vacation range is a single event. Entries with
+/// unparseable dates are skipped. `dtstamp` (UTC) is written to every event.
+/// Returns the document and the number of events in it.
+pub fn to_ics(
+    badges: &BadgeEntryData,
+    vacations: &VacationData,
+    holidays: &HolidayData,
+    dtstamp: NaiveDateTime,
+) -> (String, usize) {
+    let stamp = dtstamp.format("%Y%m%dT%H%M%SZ").to_string();
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        format!("PRODID:{}", PRODID),
+        "CALSCALE:GREGORIAN".to_string(),
+    ];
+    let mut events = Vec::new();
+
+    let mut entries: Vec<_> = badges.data.iter().filter(|e| e.is_badged_in).collect();
+    entries.sort_by(|a, b| a.key.cmp(&b.key));
+    for e in entries {
+        let Some(day) = parse_date(&e.key) else {
+            continue;
+        };
+        let (kind, summary) = if e.is_flex_credit {
+            ("flex", "Flex Credit")
+        } else {
+            ("office", "In Office")
+        };
+        let mut event = all_day_event(
+            &format!("{}-{}@rto", kind, e.key),
+            &stamp,
+            day,
+            day,
+            summary,
+        );
+        if !e.is_flex_credit && !e.office.is_empty() {
+            event.push(format!("LOCATION:{}", escape_text(&e.office)));
+        }
+        events.push(event);
+    }
+
+    for (i, v) in vacations.vacations.iter().enumerate() {
+        let (Some(start), Some(end)) = (parse_date(&v.start_date), parse_date(&v.end_date)) else {
+            continue;
+        };
+        let (kind, label) = if v.is_excused {
+            ("excused", "Excused")
+        } else {
+            ("vacation", "Vacation")
+        };
+        let mut event = all_day_event(
+            &format!("{}-{}-{}@rto", kind, i + 1, v.start_date),
+            &stamp,
+            start,
+            end,
+            &format!("{}: {}", label, v.destination),
+        );
+        if !v.approved {
+            event.push("STATUS:TENTATIVE".to_string());
+        }
+        events.push(event);
+    }
+
+    for (i, h) in holidays.holidays.iter().enumerate() {
+        let Some(day) = parse_date(&h.date) else {
+            continue;
+        };
+        let event = all_day_event(
+            &format!("holiday-{}-{}@rto", i + 1, h.date),
+            &stamp,
+            day,
+            day,
+            &h.name,
+        );
+        events.push(event);
+    }
+
+    let count = events.len();
+    for event in events {
+        push_event(&mut lines, event);
+    }
+    lines.push("END:VCALENDAR".to_string());
+    let mut out = String::new();
+    for line in lines {
+        out.push_str(&fold_line(&line));
+        out.push_str("\r\n");
+    }
+    (out, count)
+}
+
+/// Reads the events of an iCalendar document as holidays named by their
+/// SUMMARY, one per day from DTSTART up to (not including) DTEND. Events
+/// without a summary or a readable DTSTART are skipped, and a recurring
+/// event contributes only its first occurrence. Accepts folded lines and
+/// either CRLF or LF line endings.
+pub fn parse_holidays(ics: &str) -> Vec<Holiday> {
+    let mut holidays = Vec::new();
+    let mut event: Option<(Option<String>, Option<NaiveDate>, Option<NaiveDate>)> = None;
+    for line in unfold_lines(ics) {
+        let Some((name, value)) = line.split_once(':') else {
+            continue;
+        };
+        let name = name.split(';').next().unwrap_or("").to_ascii_uppercase();
+        match (name.as_str(), event.as_mut()) {
+            ("BEGIN", _) if value.eq_ignore_ascii_case("VEVENT") => {
+                event = Some((None, None, None));
+            }
+            ("END", Some((summary, Some(start), end))) if value.eq_ignore_ascii_case("VEVENT") => {
+                if let Some(summary) = summary.take().filter(|s| !s.is_empty()) {
+                    let mut day = *start;
+                    loop {
+                        holidays.push(Holiday::new(&summary, &day.format("%Y-%m-%d").to_string()));
+                        day += Duration::days(1);
+                        if end.is_none_or(|end| day >= end) {
+                            break;
+                        }
+                    }
+                }
+                event = None;
+            }
+            ("END", _) if value.eq_ignore_ascii_case("VEVENT") => event = None,
+            ("SUMMARY", Some((summary, _, _))) => *summary = Some(unescape_text(value.trim())),
+            ("DTSTART", Some((_, start, _))) => *start = parse_ical_date(value),
+            ("DTEND", Some((_, _, end))) => *end = parse_ical_date(value),
+            _ => {}
+        }
+    }
+    holidays
+}
+
+/// Joins folded content lines: a line starting with a space or tab
+/// continues the previous one.
+fn unfold_lines(ics: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for raw in ics.split('\n') {
+        let raw = raw.strip_suffix('\r').unwrap_or(raw);
+        match (raw.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(rest), Some(last)) => last.push_str(rest),
+            _ => lines.push(raw.to_string()),
+        }
+    }
+    lines
+}
+
+/// The date of a DATE (`20250704`) or DATE-TIME (`20250704T090000Z`) value.
+fn parse_ical_date(value: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(value.trim().get(..8)?, "%Y%m%d").ok()
+}
+
+/// Reverses [`escape_text`]; an escaped newline becomes a space.
+fn unescape_text(s: &str) -> String {
+    let mut out = String::new();
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n' | 'N') => out.push(' '),
+            Some(other) => out.push(other),
+            None => out.push('\\'),
+        }
+    }
+    out
+}
+
+/// Properties of an all-day event covering `first` through `last` inclusive.
+/// DTEND is exclusive, so it falls the day after `last`.
+fn all_day_event(
+    uid: &str,
+    stamp: &str,
+    first: NaiveDate,
+    last: NaiveDate,
+    summary: &str,
+) -> Vec<String> {
+    vec![
+        format!("UID:{}", uid),
+        format!("DTSTAMP:{}", stamp),
+        format!("DTSTART;VALUE=DATE:{}", first.format("%Y%m%d")),
+        format!(
+            "DTEND;VALUE=DATE:{}",
+            (last + Duration::days(1)).format("%Y%m%d")
+        ),
+        format!("SUMMARY:{}", escape_text(summary)),
+        "TRANSP:TRANSPARENT".to_string(),
+    ]
+}
+
+fn push_event(lines: &mut Vec<String>, event: Vec<String>) {
+    lines.push("BEGIN:VEVENT".to_string());
+    lines.extend(event);
+    lines.push("END:VEVENT".to_string());
+}
+
+fn parse_date(s: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(s, "%Y-%m-%d").ok()
+}
+
+/// Escapes backslashes, semicolons, commas, and newlines in a TEXT value.
+fn escape_text(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+/// Folds a content line to at most 75 octets per physical line, continuing
+/// with a leading space. Never splits a UTF-8 character.
+fn fold_line(line: &str) -> String {
+    let mut out = String::new();
+    let mut width = 0;
+    for c in line.chars() {
+        let len = c.len_utf8();
+        if width + len > 75 {
+            out.push_str("\r\n ");
+            width = 1;
+        }
+        out.push(c);
+        width += len;
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::badge_entry::BadgeEntry;
+    use crate::data::vacation::Vacation;
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).unwrap()
+    }
+
+    fn stamp() -> NaiveDateTime {
+        d(2025, 3, 1).and_hms_opt(12, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_to_ics_event_counts_and_dates() {
+        let mut badges = BadgeEntryData::default();
+        badges.add(BadgeEntry::new(d(2025, 3, 14), "McLean, VA", false));
+        badges.add(BadgeEntry::new(d(2025, 3, 13), "Flex Credit", true));
+        let mut vacations = VacationData::default();
+        vacations.add(Vacation::new("Hawaii", "2025-06-30", "2025-07-04", true));
+        let mut holidays = HolidayData::default();
+        holidays.add(Holiday::new("Independence Day", "2025-07-04"));
+
+        let (ics, events) = to_ics(&badges, &vacations, &holidays, stamp());
+        assert_eq!(events, 4);
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"));
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+        assert_eq!(ics.matches("BEGIN:VEVENT").count(), 4);
+        assert_eq!(ics.matches("END:VEVENT").count(), 4);
+        assert_eq!(ics.matches("SUMMARY:In Office").count(), 1);
+        assert_eq!(ics.matches("SUMMARY:Flex Credit").count(), 1);
+        assert!(ics.contains("LOCATION:McLean\\, VA\r\n"));
+        assert!(ics.contains("DTSTAMP:20250301T120000Z\r\n"));
+
+        // Single badge day: DTEND is the following day
+        assert!(ics.contains("DTSTART;VALUE=DATE:20250314\r\nDTEND;VALUE=DATE:20250315\r\n"));
+        // Five-day vacation is one event ending the day after its last day
+        assert!(ics.contains("DTSTART;VALUE=DATE:20250630\r\nDTEND;VALUE=DATE:20250705\r\n"));
+        assert!(ics.contains("SUMMARY:Vacation: Hawaii\r\n"));
+        assert!(ics.contains("SUMMARY:Independence Day\r\n"));
+    }
+
+    #[test]
+    fn test_to_ics_skips_bad_dates_and_labels_vacation_kinds() {
+        let mut vacations = VacationData::default();
+        vacations.add(Vacation::new("Typo", "2025-13-01", "2025-13-02", true));
+        vacations.add(Vacation::new("Maybe", "2025-09-01", "2025-09-02", false));
+        vacations.add(Vacation::excused("Flu", "2025-10-06", "2025-10-06"));
+        let mut holidays = HolidayData::default();
+        holidays.add(Holiday::new("Bad", "not-a-date"));
+
+        let (ics, events) = to_ics(&BadgeEntryData::default(), &vacations, &holidays, stamp());
+        assert_eq!(events, 2);
+        assert_eq!(ics.matches("BEGIN:VEVENT").count(), 2);
+        assert!(ics.contains("STATUS:TENTATIVE"));
+        assert!(ics.contains("SUMMARY:Excused: Flu\r\n"));
+    }
+
+    const SAMPLE: &str = "BEGIN:VCALENDAR\r\n\
+VERSION:2.0\r\n\
+BEGIN:VEVENT\r\n\
+UID:1@example.com\r\n\
+DTSTART;VALUE=DATE:20250704\r\n\
+DTEND;VALUE=DATE:20250705\r\n\
+SUMMARY:Independence Day\r\n\
+END:VEVENT\r\n\
+BEGIN:VEVENT\r\n\
+DTSTART;VALUE=DATE:20251224\r\n\
+DTEND;VALUE=DATE:20251227\r\n\
+SUMMARY:Winter\\, shutdown \r\n \
+(company)\r\n\
+END:VEVENT\r\n\
+BEGIN:VEVENT\r\n\
+DTSTART:20250901T090000Z\r\n\
+SUMMARY:Labor Day\r\n\
+END:VEVENT\r\n\
+BEGIN:VEVENT\r\n\
+DTSTART;VALUE=DATE:20251001\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n";
+
+    #[test]
+    fn test_parse_holidays_all_day_events() {
+        let holidays = parse_holidays(SAMPLE);
+        let got: Vec<(&str, &str)> = holidays
+            .iter()
+            .map(|h| (h.date.as_str(), h.name.as_str()))
+            .collect();
+        assert_eq!(
+            got,
+            [
+                ("2025-07-04", "Independence Day"),
+                // DTEND is exclusive: three days, Dec 24–26
+                ("2025-12-24", "Winter, shutdown (company)"),
+                ("2025-12-25", "Winter, shutdown (company)"),
+                ("2025-12-26", "Winter, shutdown (company)"),
+                // A timed event without DTEND lands on its start date
+                ("2025-09-01", "Labor Day"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_holidays_accepts_lf_and_skips_bad_events() {
+        let ics = "BEGIN:VCALENDAR\nBEGIN:VEVENT\nDTSTART;VALUE=DATE:2025-13-01\nSUMMARY:Bad\nEND:VEVENT\nBEGIN:VEVENT\nsummary:Line\\nBreak\ndtstart;value=date:20260101\nEND:VEVENT\nEND:VCALENDAR\n";
+        let holidays = parse_holidays(ics);
+        assert_eq!(holidays.len(), 1);
+        assert_eq!(holidays[0].name, "Line Break");
+        assert_eq!(holidays[0].date, "2026-01-01");
+        assert!(parse_holidays("not a calendar").is_empty());
+    }
+
+    #[test]
+    fn test_parse_holidays_round_trips_exported_holidays() {
+        let mut holidays = HolidayData::default();
+        holidays.add(Holiday::new("New Year's Day; observed", "2027-01-01"));
+        let (ics, _) = to_ics(
+            &BadgeEntryData::default(),
+            &VacationData::default(),
+            &holidays,
+            stamp(),
+        );
+        let parsed = parse_holidays(&ics);
+        assert_eq!(parsed.len(), 1);
+        assert_eq!(parsed[0].name, "New Year's Day; observed");
+        assert_eq!(parsed[0].date, "2027-01-01");
+    }
+
+    #[test]
+    fn test_fold_line_long_values() {
+        let long = format!("SUMMARY:{}", "x".repeat(100));
+        let folded = fold_line(&long);
+        let parts: Vec<_> = folded.split("\r\n").collect();
+        assert_eq!(parts.len(), 2);
+        assert_eq!(parts[0].len(), 75);
+        assert!(parts[1].starts_with(' '));
+        assert_eq!(folded.replace("\r\n ", ""), long);
+    }
+}
@@ -1,4 +1,14 @@
+pub mod clock;
+pub mod gaps;
+pub mod holidays;
+pub mod ical;
+pub mod plan;
+pub mod pto;
 pub mod quarter_calc;
+pub mod weekly;
 pub mod workday;
 
-pub use quarter_calc::{QuarterStats, calculate_quarter_stats, calculate_year_stats};
+pub use quarter_calc::{
+    QuarterStats, StatsOptions, apply_carryover, calculate_quarter_stats, calculate_year_stats,
+    carryover,
+};
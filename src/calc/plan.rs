@@ -0,0 +1,171 @@
+use crate::calc::QuarterStats;
+use crate::calc::workday::Workday;
+use chrono::NaiveDate;
+
+/// How [`suggest_office_days`] picks from the open days still ahead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlanStrategy {
+    /// Front-load the remaining days, leaving slack at the end of the period.
+    EarliestFirst,
+    /// Spread the remaining days as evenly as possible across the period.
+    EvenlySpaced,
+}
+
+impl PlanStrategy {
+    pub fn label(self) -> &'static str {
+        match self {
+            PlanStrategy::EarliestFirst => "earliest first",
+            PlanStrategy::EvenlySpaced => "evenly spaced",
+        }
+    }
+}
+
+/// Suggests which future working days to go in on to reach the target.
+///
+/// Candidates are the `days_left` countable days (not holidays or vacation)
+/// at the end of the period that are not already badged. Picks
+/// `days_still_needed` of them, or all of them when the target is out of
+/// reach. The result is sorted by date.
+pub fn suggest_office_days(stats: &QuarterStats, strategy: PlanStrategy) -> Vec<NaiveDate> {
+    let mut days: Vec<&Workday> = stats
+        .workday_stats
+        .values()
+        .filter(|w| !w.is_holiday && !w.is_vacation)
+        .collect();
+    days.sort_by_key(|w| w.date);
+
+    let left = (stats.days_left.max(0) as usize).min(days.len());
+    let open: Vec<NaiveDate> = days[days.len() - left..]
+        .iter()
+        .filter(|w| !w.is_badged_in)
+        .map(|w| w.date)
+        .collect();
+
+    let needed = (stats.days_still_needed.max(0) as usize).min(open.len());
+    if needed == 0 {
+        return Vec::new();
+    }
+    match strategy {
+        PlanStrategy::EarliestFirst => open[..needed].to_vec(),
+        // Centre each pick in its share of the open days; shares are at least
+        // one day wide, so the picks never collide.
+        PlanStrategy::EvenlySpaced => (0..needed)
+            .map(|i| open[(2 * i + 1) * open.len() / (2 * needed)])
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calc::{StatsOptions, calculate_quarter_stats};
+    use crate::data::badge_entry::BadgeEntry;
+    use crate::data::vacation::Vacation;
+    use crate::data::{BadgeEntryData, HolidayData, TimePeriod, VacationData};
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).unwrap()
+    }
+
+    /// Two weeks of Mon–Fri (Jan 6–17, 2025) with today on the second Monday,
+    /// so Jan 13–17 are still ahead.
+    fn plan_stats(badged: &[u32], vacation: Option<&str>, pct: i32) -> QuarterStats {
+        let mut period = TimePeriod {
+            key: "T".to_string(),
+            name: "T".to_string(),
+            start_date_raw: "2025-01-06".to_string(),
+            end_date_raw: "2025-01-17".to_string(),
+            ..Default::default()
+        };
+        period.parse_dates().unwrap();
+        let mut bd = BadgeEntryData::default();
+        for &day in badged {
+            bd.add(BadgeEntry::new(d(2025, 1, day), "HQ", false));
+        }
+        let mut vd = VacationData::default();
+        if let Some(date) = vacation {
+            vd.add(Vacation::new("Trip", date, date, true));
+        }
+        calculate_quarter_stats(
+            &period,
+            &bd,
+            &HolidayData::default(),
+            &vd,
+            &StatsOptions {
+                target: pct.into(),
+                ..Default::default()
+            },
+            Some(d(2025, 1, 13)),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_earliest_first_takes_first_open_days() {
+        let stats = plan_stats(&[], None, 20);
+        assert_eq!(stats.days_still_needed, 2);
+        assert_eq!(
+            suggest_office_days(&stats, PlanStrategy::EarliestFirst),
+            vec![d(2025, 1, 13), d(2025, 1, 14)]
+        );
+    }
+
+    #[test]
+    fn test_earliest_first_skips_badged_and_vacation_days() {
+        // 30% of 9 countable days = 3; one already badged ahead of time
+        let stats = plan_stats(&[13], Some("2025-01-14"), 30);
+        assert_eq!(stats.days_still_needed, 2);
+        assert_eq!(
+            suggest_office_days(&stats, PlanStrategy::EarliestFirst),
+            vec![d(2025, 1, 15), d(2025, 1, 16)]
+        );
+    }
+
+    #[test]
+    fn test_earliest_first_ignores_past_days() {
+        let stats = plan_stats(&[6, 7], None, 40);
+        assert_eq!(stats.days_still_needed, 2);
+        let picks = suggest_office_days(&stats, PlanStrategy::EarliestFirst);
+        assert!(picks.iter().all(|&date| date >= d(2025, 1, 13)));
+    }
+
+    #[test]
+    fn test_evenly_spaced_spreads_picks() {
+        // Open days Mon–Fri; each pick sits in the middle of its share.
+        let two = plan_stats(&[], None, 20);
+        assert_eq!(
+            suggest_office_days(&two, PlanStrategy::EvenlySpaced),
+            vec![d(2025, 1, 14), d(2025, 1, 16)]
+        );
+        let one = plan_stats(&[], None, 10);
+        assert_eq!(
+            suggest_office_days(&one, PlanStrategy::EvenlySpaced),
+            vec![d(2025, 1, 15)]
+        );
+    }
+
+    #[test]
+    fn test_evenly_spaced_with_every_day_needed() {
+        let stats = plan_stats(&[], None, 50);
+        assert_eq!(
+            suggest_office_days(&stats, PlanStrategy::EvenlySpaced),
+            (13..=17).map(|day| d(2025, 1, day)).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_unreachable_target_suggests_all_open_days() {
+        let stats = plan_stats(&[], None, 90);
+        assert_eq!(
+            suggest_office_days(&stats, PlanStrategy::EarliestFirst).len(),
+            5
+        );
+    }
+
+    #[test]
+    fn test_nothing_needed_suggests_nothing() {
+        let stats = plan_stats(&[6, 7, 8, 9, 10], None, 50);
+        assert_eq!(stats.days_still_needed, 0);
+        assert!(suggest_office_days(&stats, PlanStrategy::EvenlySpaced).is_empty());
+    }
+}
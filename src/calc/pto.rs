@@ -0,0 +1,108 @@
+use crate::calc::workday::is_workday;
+use crate::data::{HolidayData, VacationData, WorkWeek};
+use chrono::{Datelike, Duration, NaiveDate};
+use std::collections::HashSet;
+
+/// Counts the PTO days booked in calendar `year`: work days covered by approved
+/// vacations, excluding holidays, excused absences, and working trips.
+/// Overlapping vacations count each day once.
+pub fn booked_pto_days(
+    vacation_data: &VacationData,
+    holiday_data: &HolidayData,
+    year: i32,
+    week: WorkWeek,
+    observe_weekends: bool,
+) -> i32 {
+    let holiday_map = holiday_data.get_holiday_map(week, observe_weekends);
+    let parse = |s: &str| NaiveDate::parse_from_str(s, "%Y-%m-%d").ok();
+    let mut days = HashSet::new();
+    for v in vacation_data
+        .vacations
+        .iter()
+        .filter(|v| v.approved && !v.is_excused && v.counts_as_off)
+    {
+        let (Some(start), Some(end)) = (parse(&v.start_date), parse(&v.end_date)) else {
+            continue;
+        };
+        let mut day = start;
+        while day <= end {
+            if day.year() == year
+                && is_workday(day, week)
+                && !holiday_map.contains_key(&day.format("%Y-%m-%d").to_string())
+            {
+                days.insert(day);
+            }
+            day += Duration::days(1);
+        }
+    }
+    days.len() as i32
+}
+
+/// PTO left at the end of `year` once every booked vacation is taken.
+/// Negative when more days are booked than granted.
+pub fn projected_pto_remaining(
+    pto_days_per_year: i32,
+    vacation_data: &VacationData,
+    holiday_data: &HolidayData,
+    year: i32,
+    week: WorkWeek,
+    observe_weekends: bool,
+) -> i32 {
+    pto_days_per_year - booked_pto_days(vacation_data, holiday_data, year, week, observe_weekends)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::holiday::Holiday;
+    use crate::data::vacation::Vacation;
+
+    #[test]
+    fn test_projection_subtracts_booked_vacations() {
+        let mut vacations = VacationData::default();
+        // Mon–Fri: 5 days, Independence Day on Fri excluded → 4
+        vacations.add(Vacation::new("Beach", "2025-06-30", "2025-07-04", true));
+        // Wed–Tue across a weekend: 5 weekdays
+        vacations.add(Vacation::new("Ski", "2025-02-12", "2025-02-18", true));
+        // Overlaps "Ski" on the 18th: only the 19th is new
+        vacations.add(Vacation::new("Ski+", "2025-02-18", "2025-02-19", true));
+        // Not approved: ignored
+        vacations.add(Vacation::new("Maybe", "2025-09-01", "2025-09-05", false));
+        // Sick days aren't PTO
+        vacations.add(Vacation::excused("Sick", "2025-10-06", "2025-10-07"));
+        // Neither is a trip you work through
+        let mut working = Vacation::new("Remote", "2025-11-03", "2025-11-07", true);
+        working.counts_as_off = false;
+        vacations.add(working);
+        // Spills into next year: only Dec 29–31 count
+        vacations.add(Vacation::new("NYE", "2025-12-29", "2026-01-02", true));
+        let mut holidays = HolidayData::default();
+        holidays.add(Holiday::new("Independence Day", "2025-07-04"));
+
+        assert_eq!(
+            booked_pto_days(&vacations, &holidays, 2025, WorkWeek::default(), false),
+            4 + 5 + 1 + 3
+        );
+        assert_eq!(
+            projected_pto_remaining(15, &vacations, &holidays, 2025, WorkWeek::default(), false),
+            2
+        );
+        assert_eq!(
+            projected_pto_remaining(10, &vacations, &holidays, 2025, WorkWeek::default(), false),
+            -3
+        );
+    }
+
+    #[test]
+    fn test_projection_with_no_vacations_is_full_grant() {
+        let remaining = projected_pto_remaining(
+            20,
+            &VacationData::default(),
+            &HolidayData::default(),
+            2025,
+            WorkWeek::default(),
+            false,
+        );
+        assert_eq!(remaining, 20);
+    }
+}
@@ -1,104 +1,441 @@
+use crate::calc::clock::today_local;
 use crate::calc::workday::{Workday, create_workday_map};
-use crate::data::{BadgeEntryData, HolidayData, TimePeriod, VacationData};
-use anyhow::Result;
-use chrono::{Local, NaiveDate};
-use std::collections::HashMap;
+use crate::data::{
+    AppSettings, BadgeEntryData, HolidayData, TargetMode, TimePeriod, VacationData, WorkWeek,
+};
+use anyhow::{Result, anyhow};
+use chrono::{Datelike, Duration, NaiveDate};
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct QuarterStats {
     pub name: String,
     pub start_date: NaiveDate,
     pub end_date: NaiveDate,
-    pub days_badged_in: i32,
-    pub flex_days: i32,
+    /// Sum of badge credits (half days count 0.5)
+    pub days_badged_in: f64,
+    /// All flex credits logged, including any beyond `max_flex_credits`
+    pub flex_days: f64,
+    /// Flex credits beyond `max_flex_credits`; not part of `days_badged_in`
+    pub excess_flex_days: f64,
+    /// Office badge credits at offices outside `qualifying_offices`; shown
+    /// on the calendar but not part of `days_badged_in`
+    pub non_qualifying_days: f64,
+    /// Office badge-in days per office label; flex credits are excluded
+    pub days_by_office: HashMap<String, i32>,
+    /// Distinct labels stored on this period's flex entries, sorted
+    pub flex_labels: Vec<String>,
     pub days_thus_far: i32,
     pub days_left: i32,
     pub total_calendar_days: i32,
+    /// Share of the period's calendar days before today, 0–100
+    pub quarter_elapsed_pct: f64,
+    /// Calendar days from today to the period end; 0 once it has ended
+    pub days_until_end: i32,
     pub available_workdays: i32,
     pub total_days: i32,
     pub days_required: i32,
     pub days_still_needed: i32,
+    /// Surplus days from the previous period already taken off
+    /// `days_still_needed` (see [`apply_carryover`])
+    pub carryover_days: i32,
+    /// Past countable days not covered by a badge, i.e. remote days so far
     pub days_off: i32,
+    /// Holidays on working days; a holiday inside a vacation counts only here
     pub holidays: i32,
     pub vacation_days: i32,
+    /// Sick days and other excused absences; excluded like vacation days
+    pub excused_days: i32,
+    /// Holidays on working days from today through the period end
+    pub future_holidays: i32,
+    /// Vacation days (excluding excused absences) from today through the period end
+    pub future_vacation_days: i32,
+    /// Badge entries in the period on weekends or holidays; they never count
+    pub ignored_badge_entries: i32,
     pub current_average: f64,
+    /// Final badge rate if every remaining workday (from today) is badged
+    pub best_case_average: f64,
+    /// Final badge rate if no remaining workday is badged
+    pub worst_case_average: f64,
     pub required_future_average: f64,
     pub compliance_status: String,
     pub days_ahead_of_pace: i32,
+    /// Office days so far beyond the pro-rated requirement to date, i.e. days
+    /// you went in but didn't need to yet. Badges past the whole requirement
+    /// are not counted, and it is never negative.
+    pub buffer_days_used: i32,
     pub remaining_missable_days: i32,
+    /// When the requirement is met if you keep badging at your current rate;
+    /// `None` when that pace would not get there before the period ends
     pub projected_completion_date: Option<NaiveDate>,
+    /// When the requirement is met if you badge every remaining open workday
+    pub earliest_completion_date: Option<NaiveDate>,
+    /// Consecutive workdays with a counted badge ending today (or yesterday,
+    /// if today isn't badged yet); holidays and vacation days don't break a run
+    pub current_streak: i32,
+    /// Longest run of consecutive workdays with a counted badge up to today
+    pub longest_streak: i32,
+    #[serde(skip)]
     pub workday_stats: HashMap<String, Workday>,
+    /// Past unbadged workdays after which the running badge total was behind
+    /// the pace needed to meet `days_required`
+    #[serde(skip)]
+    pub pace_misses: BTreeSet<String>,
+}
+
+impl QuarterStats {
+    /// Office badge-ins, i.e. goal credit that didn't come from flex days.
+    pub fn office_days(&self) -> f64 {
+        self.days_badged_in - (self.flex_days - self.excess_flex_days)
+    }
+
+    /// `days_by_office` as a list, most-visited office first.
+    pub fn offices_by_days(&self) -> Vec<(&str, i32)> {
+        let mut offices: Vec<(&str, i32)> = self
+            .days_by_office
+            .iter()
+            .map(|(office, &days)| (office.as_str(), days))
+            .collect();
+        offices.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+        offices
+    }
+}
+
+/// The settings that shape a period's stats. `Default` matches a fresh
+/// [`AppSettings`]: a 50% goal over a Monday–Friday week with no flex cap.
+#[derive(Debug, Clone)]
+pub struct StatsOptions {
+    /// The office requirement
+    pub target: TargetMode,
+    /// Which weekdays are workdays
+    pub work_week: WorkWeek,
+    /// Flex credits beyond this many (in date order) are still reported in
+    /// `flex_days` but don't count toward the goal
+    pub max_flex_credits: Option<u32>,
+    /// Moves today from the days left to the days so far, badged or not
+    pub count_today_as_elapsed: bool,
+    /// When false, only approved vacations reduce the workdays; pending ones
+    /// are flagged `is_pending` and otherwise count as workdays
+    pub count_unapproved_vacations: bool,
+    /// Office badges anywhere else (matched case-insensitively) are recorded
+    /// but count for nothing
+    pub qualifying_offices: Option<Vec<String>>,
+    /// Also treats holidays on days off as off on their observed workday
+    pub observe_weekend_holidays: bool,
+}
+
+impl Default for StatsOptions {
+    fn default() -> Self {
+        StatsOptions::from(&AppSettings::default())
+    }
+}
+
+impl From<&AppSettings> for StatsOptions {
+    fn from(settings: &AppSettings) -> Self {
+        StatsOptions {
+            target: settings.target(),
+            work_week: settings.work_week(),
+            max_flex_credits: settings.max_flex_credits,
+            count_today_as_elapsed: settings.count_today_as_elapsed,
+            count_unapproved_vacations: settings.count_unapproved_vacations,
+            qualifying_offices: settings.qualifying_offices.clone(),
+            observe_weekend_holidays: settings.observe_weekend_holidays,
+        }
+    }
+}
+
+/// Per-ISO-week tallies used by `TargetMode::DaysPerWeek`.
+#[derive(Default)]
+struct WeekTally {
+    /// Workdays that aren't holidays or vacation
+    available: i32,
+    badged: f64,
+    /// Unbadged available days from today onward — still usable this week
+    open: i32,
+}
+
+/// The parsed start and end dates of `period`, or an error if `parse_dates`
+/// hasn't been run on it.
+fn period_bounds(period: &TimePeriod) -> Result<(NaiveDate, NaiveDate)> {
+    match (period.start_date, period.end_date) {
+        (Some(start), Some(end)) => Ok((start, end)),
+        _ => Err(anyhow!(
+            "period '{}' has no parsed dates (start '{}', end '{}')",
+            period.key,
+            period.start_date_raw,
+            period.end_date_raw
+        )),
+    }
+}
+
+/// The days a set of stats covers: the calendar runs from `start` to `end`,
+/// but only days inside one of `spans` are counted. A period has a single
+/// span from its effective start; the year has one per included period, and
+/// `max_flex_credits` applies to each span separately.
+struct Coverage {
+    name: String,
+    start: NaiveDate,
+    end: NaiveDate,
+    /// Inclusive `(first, last)` counted days, sorted by `first`
+    spans: Vec<(NaiveDate, NaiveDate)>,
 }
 
-/// Computes full statistics for a time period.
-/// `goal_pct` is the required office percentage (e.g. 50 means 50%).
+impl Coverage {
+    fn of_period(period: &TimePeriod) -> Result<Self> {
+        let (start, end) = period_bounds(period)?;
+        Ok(Coverage {
+            name: period.name.clone(),
+            start,
+            end,
+            spans: vec![(period.effective_start.unwrap_or(start), end)],
+        })
+    }
+
+    /// Index of the span `date` is counted in, if any.
+    fn span_of(&self, date: NaiveDate) -> Option<usize> {
+        self.spans
+            .iter()
+            .position(|&(first, last)| first <= date && date <= last)
+    }
+
+    /// Whether `date` is one of the counted days.
+    fn counts(&self, date: NaiveDate) -> bool {
+        self.span_of(date).is_some()
+    }
+}
+
+/// Keys of past unbadged workdays (holidays and vacation excluded) that left
+/// the running badge total below the expected pace: `required / total` of
+/// the countable days so far, rounded as for `days_ahead_of_pace`.
+fn pace_misses(
+    keys: &[String],
+    workday_map: &HashMap<String, Workday>,
+    coverage: &Coverage,
+    today: NaiveDate,
+    days_required: i32,
+    total_days: i32,
+) -> BTreeSet<String> {
+    let mut misses = BTreeSet::new();
+    if total_days == 0 {
+        return misses;
+    }
+    let (mut counted, mut badged) = (0i32, 0.0f64);
+    for key in keys {
+        let day = &workday_map[key];
+        if !coverage.counts(day.date) || day.is_holiday || day.is_vacation {
+            continue;
+        }
+        if day.date >= today {
+            break;
+        }
+        counted += 1;
+        if day.is_badged_in {
+            badged += day.credit;
+            continue;
+        }
+        let expected = (counted as f64 * days_required as f64 / total_days as f64).round();
+        if badged < expected {
+            misses.insert(key.clone());
+        }
+    }
+    misses
+}
+
+/// Computes full statistics for a time period under `options`.
 pub fn calculate_quarter_stats(
     period: &TimePeriod,
     badge: &BadgeEntryData,
     holiday: &HolidayData,
     vacation: &VacationData,
-    goal_pct: i32,
+    options: &StatsOptions,
     today: Option<NaiveDate>,
 ) -> Result<QuarterStats> {
-    let today = today.unwrap_or_else(|| Local::now().date_naive());
+    let coverage = Coverage::of_period(period)?;
+    coverage_stats(&coverage, badge, holiday, vacation, options, today)
+}
 
-    let start = period.start_date.unwrap();
-    let end = period.end_date.unwrap();
+fn coverage_stats(
+    coverage: &Coverage,
+    badge: &BadgeEntryData,
+    holiday: &HolidayData,
+    vacation: &VacationData,
+    options: &StatsOptions,
+    today: Option<NaiveDate>,
+) -> Result<QuarterStats> {
+    let &StatsOptions {
+        target,
+        work_week,
+        max_flex_credits,
+        count_today_as_elapsed,
+        count_unapproved_vacations,
+        ref qualifying_offices,
+        observe_weekend_holidays,
+    } = options;
+    let today = today.unwrap_or_else(today_local);
+    // First day still open: days before it are "so far", the rest are left
+    let open_from = if count_today_as_elapsed {
+        today + Duration::days(1)
+    } else {
+        today
+    };
+
+    let (start, end) = (coverage.start, coverage.end);
 
-    let badge_map = badge.get_badge_map(start, end);
-    let vacation_map = vacation.get_vacation_map();
-    let holiday_map = holiday.get_holiday_map();
-    let mut workday_map = create_workday_map(start, end);
+    let vacation_map = vacation.get_vacation_map(work_week);
+    let holiday_map = holiday.get_holiday_map(work_week, observe_weekend_holidays);
+    // Badges on weekends or holidays never count; tally them for `rto doctor`.
+    let mut ignored_badge_entries = 0i32;
+    let badge_map: HashMap<String, _> = badge
+        .get_badge_map(start, end)
+        .into_iter()
+        .filter(|(key, entry)| {
+            let Some(day) = entry.entry_date() else {
+                return false;
+            };
+            let counts = work_week.is_workday(day) && !holiday_map.contains_key(key.as_str());
+            if !counts && entry.is_badged_in && coverage.counts(day) {
+                ignored_badge_entries += 1;
+            }
+            counts
+        })
+        .collect();
+    let mut workday_map = create_workday_map(start, end, work_week);
 
     let mut keys: Vec<String> = workday_map.keys().cloned().collect();
     keys.sort();
 
     let total_calendar_days = (end - start).num_days() as i32 + 1;
 
-    let mut days_badged_in = 0i32;
-    let mut flex_days = 0i32;
+    let mut days_badged_in = 0.0f64;
+    let mut flex_days = 0.0f64;
+    let mut excess_flex_days = 0.0f64;
+    // Flex credits counted so far in each span, for `max_flex_credits`
+    let mut span_flex = vec![0.0f64; coverage.spans.len()];
+    let mut non_qualifying_days = 0.0f64;
+    let qualifies = |office: &str| {
+        qualifying_offices.as_ref().is_none_or(|offices| {
+            offices
+                .iter()
+                .any(|q| q.trim().eq_ignore_ascii_case(office.trim()))
+        })
+    };
+    let mut days_by_office: HashMap<String, i32> = HashMap::new();
+    let mut flex_labels: BTreeSet<String> = BTreeSet::new();
     let mut days_thus_far = 0i32;
     let mut available_workdays = 0i32;
     let mut total_days = 0i32;
     let mut holidays = 0i32;
     let mut vacation_days = 0i32;
+    let mut excused_days = 0i32;
+    let mut future_holidays = 0i32;
+    let mut future_vacation_days = 0i32;
+    let mut weeks: BTreeMap<(i32, u32), WeekTally> = BTreeMap::new();
+    let mut badged_before_today = 0.0f64;
+    let mut current_streak = 0i32;
+    let mut longest_streak = 0i32;
+    // Countable, unbadged workdays from today on, in date order
+    let mut open_days: Vec<NaiveDate> = Vec::new();
 
     for key in &keys {
         let day = workday_map.get_mut(key).unwrap();
 
+        // Workdays before an onboarding date, or in a period left out of the
+        // year, are outside the stats entirely.
+        let Some(span) = coverage.span_of(day.date) else {
+            continue;
+        };
+        day.is_counted = true;
+
+        // Holidays take precedence, so a day is never tallied as both.
         if holiday_map.contains_key(key.as_str()) {
             day.is_holiday = true;
             holidays += 1;
+            if day.date >= today {
+                future_holidays += 1;
+            }
             available_workdays += 1;
             continue;
         }
 
         available_workdays += 1;
 
-        if vacation_map.contains_key(key.as_str()) {
+        if let Some(v) = vacation_map.get(key.as_str())
+            && v.counts_as_off
+        {
+            day.is_pending = !v.approved;
+        }
+        if let Some(v) = vacation_map.get(key.as_str())
+            && v.counts_as_off
+            && (v.approved || count_unapproved_vacations)
+        {
             day.is_vacation = true;
-            vacation_days += 1;
+            if v.is_excused {
+                day.is_excused = true;
+                excused_days += 1;
+            } else {
+                vacation_days += 1;
+                if day.date >= today {
+                    future_vacation_days += 1;
+                }
+            }
             continue;
         }
 
         total_days += 1;
+        let iso = day.date.iso_week();
+        let week = weeks.entry((iso.year(), iso.week())).or_default();
+        week.available += 1;
 
+        // Goal credit earned today; a badge can earn none (see below)
+        let mut counted = 0.0;
         if let Some(badge_entry) = badge_map.get(key.as_str())
             && badge_entry.is_badged_in
         {
             day.is_badged_in = true;
-            days_badged_in += 1;
+            day.credit = badge_entry.credit;
+            counted = badge_entry.credit;
             if badge_entry.is_flex_credit {
                 day.is_flex_credit = true;
-                flex_days += 1;
+                if let Some(cap) = max_flex_credits {
+                    let room = (cap as f64 - span_flex[span]).max(0.0);
+                    counted = counted.min(room);
+                    excess_flex_days += badge_entry.credit - counted;
+                }
+                span_flex[span] += counted;
+                flex_days += badge_entry.credit;
+                flex_labels.insert(badge_entry.office.clone());
+            } else {
+                *days_by_office
+                    .entry(badge_entry.office.clone())
+                    .or_default() += 1;
+                if !qualifies(&badge_entry.office) {
+                    non_qualifying_days += counted;
+                    counted = 0.0;
+                }
             }
+            day.counted_credit = counted;
+            days_badged_in += counted;
+            week.badged += counted;
+            if day.date < open_from {
+                badged_before_today += counted;
+            }
+        } else if day.date >= open_from {
+            week.open += 1;
+            open_days.push(day.date);
         }
 
-        if day.date > today {
-            continue;
+        // An unbadged today is still open, so only earlier misses end a run.
+        // Badges that earned no credit (over the flex cap, or at an office
+        // that doesn't qualify) don't extend it.
+        if counted > 0.0 && day.date <= today {
+            current_streak += 1;
+            longest_streak = longest_streak.max(current_streak);
+        } else if day.date < today {
+            current_streak = 0;
         }
 
-        if day.date == today {
+        if day.date >= open_from {
             continue;
         }
 
@@ -106,84 +443,159 @@ pub fn calculate_quarter_stats(
     }
 
     let days_left = total_days - days_thus_far;
-    let days_required = ((total_days as f64) * (goal_pct as f64) / 100.0).ceil() as i32;
-
-    let mut days_still_needed = days_required - days_badged_in;
-    if days_still_needed < 0 {
-        days_still_needed = 0;
-    }
+    let elapsed_days = (today - start)
+        .num_days()
+        .clamp(0, total_calendar_days as i64);
+    let quarter_elapsed_pct = elapsed_days as f64 / total_calendar_days as f64 * 100.0;
+    let days_until_end = (end - today).num_days().max(0) as i32;
+    let (days_required, days_still_needed, missed_week) = match target {
+        TargetMode::QuarterPercentage(pct) => {
+            let required = ((total_days as f64) * pct / 100.0).ceil() as i32;
+            let needed = (required as f64 - days_badged_in).max(0.0).ceil() as i32;
+            (required, needed, false)
+        }
+        TargetMode::DaysPerWeek(per_week) => weekly_requirement(&weeks, per_week as i32),
+    };
 
-    let days_off = days_thus_far - days_badged_in;
+    let days_off = (days_thus_far as f64 - days_badged_in).floor() as i32;
 
-    let days_ahead_of_pace = if days_thus_far > 0 && total_days > 0 {
+    let (days_ahead_of_pace, buffer_days_used) = if days_thus_far > 0 && total_days > 0 {
         let expected =
             ((days_thus_far as f64) * (days_required as f64) / (total_days as f64)).round() as i32;
-        days_badged_in - expected
+        let surplus = days_badged_in.min(days_required as f64) - expected as f64;
+        (
+            (days_badged_in - expected as f64).floor() as i32,
+            surplus.max(0.0).floor() as i32,
+        )
     } else {
-        0
+        (0, 0)
     };
 
     let remaining_missable = days_left - days_still_needed;
+    let pace_misses = pace_misses(
+        &keys,
+        &workday_map,
+        coverage,
+        open_from,
+        days_required,
+        total_days,
+    );
 
     let current_average = if days_thus_far > 0 {
-        days_badged_in as f64 / days_thus_far as f64
+        days_badged_in / days_thus_far as f64
     } else {
         0.0
     };
 
+    let (best_case_average, worst_case_average) = if days_left > 0 && total_days > 0 {
+        (
+            (badged_before_today + days_left as f64) / total_days as f64,
+            badged_before_today / total_days as f64,
+        )
+    } else {
+        (current_average, current_average)
+    };
+
     let required_future_average = if days_left > 0 {
         days_still_needed as f64 / days_left as f64
     } else {
         0.0
     };
 
-    let compliance_status = determine_compliance_status(
-        days_badged_in,
+    // Only badges that count toward the requirement; in weekly mode, extra
+    // days in one week can't make up for a short week.
+    let counted_badged = days_required - days_still_needed;
+    let mut compliance_status = determine_compliance_status(
+        counted_badged,
         days_required,
         days_ahead_of_pace,
         days_still_needed,
         days_left,
     );
+    if missed_week && compliance_status != "Achieved" {
+        compliance_status = "Impossible".to_string();
+    }
 
+    // Both projections count open workdays, so badging every one of them is
+    // never slower than the historical rate (capped at one a day).
+    let nth_open_day = |n: i64| {
+        usize::try_from(n - 1)
+            .ok()
+            .and_then(|i| open_days.get(i).copied())
+    };
+    let earliest_completion_date = nth_open_day(days_still_needed as i64);
     let projected_completion_date =
-        if days_badged_in > 0 && days_thus_far > 0 && days_still_needed > 0 {
-            let rate = days_badged_in as f64 / days_thus_far as f64;
-            if rate > 0.0 {
-                let estimated_days = (days_still_needed as f64 / rate).ceil() as i64;
-                today.checked_add_days(chrono::Days::new(estimated_days as u64))
-            } else {
-                None
-            }
+        if days_badged_in > 0.0 && days_thus_far > 0 && days_still_needed > 0 {
+            // needed ÷ min(badged ÷ so far, 1), as a single division so an
+            // exact ratio like 6 ÷ (3/5) isn't rounded up past 10
+            let thus_far = days_thus_far as f64;
+            let open_needed = days_still_needed as f64 * thus_far / days_badged_in.min(thus_far);
+            nth_open_day(open_needed.ceil() as i64)
         } else {
             None
         };
 
     Ok(QuarterStats {
-        name: period.name.clone(),
+        name: coverage.name.clone(),
         start_date: start,
         end_date: end,
         days_badged_in,
         flex_days,
+        excess_flex_days,
+        non_qualifying_days,
+        days_by_office,
+        flex_labels: flex_labels.into_iter().collect(),
         days_thus_far,
         days_left,
         total_calendar_days,
+        quarter_elapsed_pct,
+        days_until_end,
         available_workdays,
         total_days,
         days_required,
         days_still_needed,
+        carryover_days: 0,
         days_off,
         holidays,
         vacation_days,
+        excused_days,
+        future_holidays,
+        future_vacation_days,
+        ignored_badge_entries,
         current_average,
+        best_case_average,
+        worst_case_average,
         required_future_average,
         compliance_status,
         days_ahead_of_pace,
+        buffer_days_used,
         remaining_missable_days: remaining_missable,
         projected_completion_date,
+        earliest_completion_date,
+        current_streak,
+        longest_streak,
         workday_stats: workday_map,
+        pace_misses,
     })
 }
 
+/// Sums the weekly requirement `min(per_week, available)` and per-week
+/// shortfalls. Also reports whether any week's shortfall exceeds the days it
+/// has left, i.e. a week has been missed for good.
+fn weekly_requirement(weeks: &BTreeMap<(i32, u32), WeekTally>, per_week: i32) -> (i32, i32, bool) {
+    let mut required = 0;
+    let mut still_needed = 0;
+    let mut missed = false;
+    for week in weeks.values() {
+        let week_required = per_week.min(week.available);
+        let shortfall = (week_required as f64 - week.badged).max(0.0).ceil() as i32;
+        required += week_required;
+        still_needed += shortfall;
+        missed |= shortfall > week.open;
+    }
+    (required, still_needed, missed)
+}
+
 fn determine_compliance_status(
     days_badged_in: i32,
     days_required: i32,
@@ -206,44 +618,78 @@ fn determine_compliance_status(
     "On Track".to_string()
 }
 
+/// Whole office days `prev` was badged beyond its requirement, capped at
+/// `curr_required`. A deficit carries nothing.
+pub fn carryover(prev: &QuarterStats, curr_required: i32) -> i32 {
+    let surplus = (prev.days_badged_in - prev.days_required as f64).floor() as i32;
+    surplus.clamp(0, curr_required.max(0))
+}
+
+/// Takes `days` carried over from the previous period off the remaining
+/// requirement and updates the numbers and status derived from it.
+pub fn apply_carryover(stats: &mut QuarterStats, days: i32) {
+    if days <= 0 {
+        return;
+    }
+    let applied = days.min(stats.days_still_needed);
+    stats.carryover_days = applied;
+    stats.days_still_needed -= applied;
+    stats.remaining_missable_days = stats.days_left - stats.days_still_needed;
+    stats.required_future_average = if stats.days_left > 0 {
+        stats.days_still_needed as f64 / stats.days_left as f64
+    } else {
+        0.0
+    };
+    stats.compliance_status = determine_compliance_status(
+        stats.days_required - stats.days_still_needed,
+        stats.days_required,
+        stats.days_ahead_of_pace + applied,
+        stats.days_still_needed,
+        stats.days_left,
+    );
+}
+
 /// Computes aggregate statistics across multiple time periods (for year stats).
+/// `options.max_flex_credits` caps each period on its own, so the year counts
+/// the same flex credits as its periods do.
+/// Periods marked `exclude_from_year` are skipped: the year runs from the
+/// earliest start to the latest end of the rest, but only counts days inside
+/// one of them (from its effective start), so an excluded period in the
+/// middle leaves a gap. Returns `None` when every period is excluded.
 pub fn calculate_year_stats(
     periods: &[&TimePeriod],
     badge: &BadgeEntryData,
     holiday: &HolidayData,
     vacation: &VacationData,
-    goal_pct: i32,
+    options: &StatsOptions,
     today: Option<NaiveDate>,
 ) -> Result<Option<QuarterStats>> {
+    let periods: Vec<&TimePeriod> = periods
+        .iter()
+        .copied()
+        .filter(|tp| !tp.exclude_from_year)
+        .collect();
     if periods.is_empty() {
         return Ok(None);
     }
 
-    let mut start = periods[0].start_date.unwrap();
-    let mut end = periods[0].end_date.unwrap();
-    for tp in periods {
-        let s = tp.start_date.unwrap();
-        let e = tp.end_date.unwrap();
-        if s < start {
-            start = s;
-        }
-        if e > end {
-            end = e;
-        }
+    let (mut start, mut end) = period_bounds(periods[0])?;
+    let mut spans = Vec::new();
+    for tp in &periods {
+        let (s, e) = period_bounds(tp)?;
+        start = start.min(s);
+        end = end.max(e);
+        spans.push((tp.effective_start.unwrap_or(s), e));
     }
-
-    let synthetic = TimePeriod {
-        key: "Year".to_string(),
+    spans.sort();
+    let coverage = Coverage {
         name: "Year".to_string(),
-        start_date_raw: start.format("%Y-%m-%d").to_string(),
-        end_date_raw: end.format("%Y-%m-%d").to_string(),
-        start_date: Some(start),
-        end_date: Some(end),
+        start,
+        end,
+        spans,
     };
 
-    let mut stats = calculate_quarter_stats(&synthetic, badge, holiday, vacation, goal_pct, today)?;
-    stats.name = "Year".to_string();
-    Ok(Some(stats))
+    coverage_stats(&coverage, badge, holiday, vacation, options, today).map(Some)
 }
 
 #[cfg(test)]
@@ -253,6 +699,7 @@ mod tests {
     use crate::data::holiday::Holiday;
     use crate::data::vacation::Vacation;
     use crate::data::{BadgeEntryData, HolidayData, VacationData};
+    use chrono::Weekday;
 
     fn make_period(start: &str, end: &str) -> TimePeriod {
         let mut tp = TimePeriod {
@@ -262,6 +709,7 @@ mod tests {
             end_date_raw: end.to_string(),
             start_date: None,
             end_date: None,
+            ..Default::default()
         };
         tp.parse_dates().unwrap();
         tp
@@ -271,6 +719,32 @@ mod tests {
         NaiveDate::from_ymd_opt(y, m, d).unwrap()
     }
 
+    #[test]
+    fn test_default_today_comes_from_shared_clock() {
+        use crate::calc::clock::set_today_override;
+        let q = make_period("2025-01-06", "2025-01-17");
+        let stats = || {
+            calculate_quarter_stats(
+                &q,
+                &BadgeEntryData::default(),
+                &HolidayData::default(),
+                &VacationData::default(),
+                &StatsOptions::default(),
+                None,
+            )
+            .unwrap()
+        };
+        // Thursday: Mon–Wed are past, today itself still counts as left
+        set_today_override(Some(date(2025, 1, 9)));
+        let thursday = stats();
+        // One tick past midnight the day moves from "left" to "thus far"
+        set_today_override(Some(date(2025, 1, 10)));
+        let friday = stats();
+        set_today_override(None);
+        assert_eq!((thursday.days_thus_far, thursday.days_left), (3, 7));
+        assert_eq!((friday.days_thus_far, friday.days_left), (4, 6));
+    }
+
     #[test]
     fn test_on_track_status() {
         let q = make_period("2025-01-06", "2025-01-17");
@@ -281,9 +755,16 @@ mod tests {
         let holiday = HolidayData::default();
         let vacation = VacationData::default();
         let today = date(2025, 1, 13);
-        let stats =
-            calculate_quarter_stats(&q, &badge, &holiday, &vacation, 50, Some(today)).unwrap();
-        assert_eq!(stats.days_badged_in, 3);
+        let stats = calculate_quarter_stats(
+            &q,
+            &badge,
+            &holiday,
+            &vacation,
+            &StatsOptions::default(),
+            Some(today),
+        )
+        .unwrap();
+        assert_eq!(stats.days_badged_in, 3.0);
         assert!(stats.compliance_status == "On Track" || stats.compliance_status == "At Risk");
     }
 
@@ -297,8 +778,15 @@ mod tests {
         let holiday = HolidayData::default();
         let vacation = VacationData::default();
         let today = date(2025, 1, 20);
-        let stats =
-            calculate_quarter_stats(&q, &badge, &holiday, &vacation, 50, Some(today)).unwrap();
+        let stats = calculate_quarter_stats(
+            &q,
+            &badge,
+            &holiday,
+            &vacation,
+            &StatsOptions::default(),
+            Some(today),
+        )
+        .unwrap();
         assert_eq!(stats.compliance_status, "Achieved");
     }
 
@@ -309,8 +797,15 @@ mod tests {
         let holiday = HolidayData::default();
         let vacation = VacationData::default();
         let today = date(2025, 1, 20);
-        let stats =
-            calculate_quarter_stats(&q, &badge, &holiday, &vacation, 50, Some(today)).unwrap();
+        let stats = calculate_quarter_stats(
+            &q,
+            &badge,
+            &holiday,
+            &vacation,
+            &StatsOptions::default(),
+            Some(today),
+        )
+        .unwrap();
         assert_eq!(stats.compliance_status, "Impossible");
     }
 
@@ -324,8 +819,15 @@ mod tests {
         let holiday = HolidayData::default();
         let vacation = VacationData::default();
         let today = date(2025, 1, 10);
-        let stats =
-            calculate_quarter_stats(&q, &badge, &holiday, &vacation, 50, Some(today)).unwrap();
+        let stats = calculate_quarter_stats(
+            &q,
+            &badge,
+            &holiday,
+            &vacation,
+            &StatsOptions::default(),
+            Some(today),
+        )
+        .unwrap();
         assert!(stats.days_ahead_of_pace > 0);
     }
 
@@ -339,8 +841,15 @@ mod tests {
         let holiday = HolidayData::default();
         let vacation = VacationData::default();
         let today = date(2025, 1, 13);
-        let stats =
-            calculate_quarter_stats(&q, &badge, &holiday, &vacation, 50, Some(today)).unwrap();
+        let stats = calculate_quarter_stats(
+            &q,
+            &badge,
+            &holiday,
+            &vacation,
+            &StatsOptions::default(),
+            Some(today),
+        )
+        .unwrap();
         assert!(stats.remaining_missable_days >= 0);
     }
 
@@ -352,12 +861,62 @@ mod tests {
         holiday.add(Holiday::new("Test", "2025-01-06"));
         let vacation = VacationData::default();
         let today = date(2025, 1, 7);
-        let stats =
-            calculate_quarter_stats(&q, &badge, &holiday, &vacation, 50, Some(today)).unwrap();
+        let stats = calculate_quarter_stats(
+            &q,
+            &badge,
+            &holiday,
+            &vacation,
+            &StatsOptions::default(),
+            Some(today),
+        )
+        .unwrap();
         assert_eq!(stats.total_days, 0);
         assert_eq!(stats.current_average, 0.0);
     }
 
+    #[test]
+    fn test_saturday_work_week_grows_denominator() {
+        // Mon 2025-01-06 through Sun 2025-01-12
+        let q = make_period("2025-01-06", "2025-01-12");
+        let badge = BadgeEntryData::default();
+        let holiday = HolidayData::default();
+        let vacation = VacationData::default();
+        let today = date(2025, 1, 13);
+        let weekdays = calculate_quarter_stats(
+            &q,
+            &badge,
+            &holiday,
+            &vacation,
+            &StatsOptions::default(),
+            Some(today),
+        )
+        .unwrap();
+        let six_day = WorkWeek::from_days(&[
+            Weekday::Mon,
+            Weekday::Tue,
+            Weekday::Wed,
+            Weekday::Thu,
+            Weekday::Fri,
+            Weekday::Sat,
+        ]);
+        let with_saturday = calculate_quarter_stats(
+            &q,
+            &badge,
+            &holiday,
+            &vacation,
+            &StatsOptions {
+                work_week: six_day,
+                ..Default::default()
+            },
+            Some(today),
+        )
+        .unwrap();
+        assert_eq!(weekdays.total_days, 5);
+        assert_eq!(with_saturday.total_days, 6);
+        assert_eq!(with_saturday.available_workdays, 6);
+        assert_eq!(with_saturday.days_required, 3);
+    }
+
     #[test]
     fn test_flex_credit_counted_separately() {
         let q = make_period("2025-01-06", "2025-01-09");
@@ -368,13 +927,434 @@ mod tests {
         let holiday = HolidayData::default();
         let vacation = VacationData::default();
         let today = date(2025, 1, 20);
-        let stats =
-            calculate_quarter_stats(&q, &badge, &holiday, &vacation, 50, Some(today)).unwrap();
-        assert_eq!(stats.days_badged_in, 3);
-        assert_eq!(stats.flex_days, 1);
+        let stats = calculate_quarter_stats(
+            &q,
+            &badge,
+            &holiday,
+            &vacation,
+            &StatsOptions::default(),
+            Some(today),
+        )
+        .unwrap();
+        assert_eq!(stats.days_badged_in, 3.0);
+        assert_eq!(stats.flex_days, 1.0);
         assert_eq!(stats.compliance_status, "Achieved");
     }
 
+    #[test]
+    fn test_flex_credits_capped_toward_goal() {
+        // Two weeks (10 workdays): 2 office days and 6 flex days
+        let q = make_period("2025-01-06", "2025-01-17");
+        let mut badge = BadgeEntryData::default();
+        badge.add(BadgeEntry::new(date(2025, 1, 6), "Office", false));
+        badge.add(BadgeEntry::new(date(2025, 1, 7), "Office", false));
+        for day in [8, 9, 10, 13, 14, 15] {
+            badge.add(BadgeEntry::new(date(2025, 1, day), "Flex Credit", true));
+        }
+        let holiday = HolidayData::default();
+        let vacation = VacationData::default();
+        let today = date(2025, 1, 20);
+        let stats = calculate_quarter_stats(
+            &q,
+            &badge,
+            &holiday,
+            &vacation,
+            &StatsOptions {
+                target: 70.into(),
+                max_flex_credits: Some(4),
+                ..Default::default()
+            },
+            Some(today),
+        )
+        .unwrap();
+        assert_eq!(stats.flex_days, 6.0);
+        assert_eq!(stats.excess_flex_days, 2.0);
+        assert_eq!(stats.days_badged_in, 6.0);
+        assert_eq!(stats.office_days(), 2.0);
+        assert_eq!(stats.days_still_needed, 1);
+        assert_eq!(stats.compliance_status, "Impossible");
+
+        let uncapped = calculate_quarter_stats(
+            &q,
+            &badge,
+            &holiday,
+            &vacation,
+            &StatsOptions {
+                target: 70.into(),
+                ..Default::default()
+            },
+            Some(today),
+        )
+        .unwrap();
+        assert_eq!(uncapped.days_badged_in, 8.0);
+        assert_eq!(uncapped.excess_flex_days, 0.0);
+        assert_eq!(uncapped.compliance_status, "Achieved");
+    }
+
+    #[test]
+    fn test_count_unapproved_vacations_setting() {
+        // Two weeks: 10 workdays, one approved and one pending vacation day
+        let q = make_period("2025-01-06", "2025-01-17");
+        let mut vacation = VacationData::default();
+        vacation.add(Vacation::new("Dentist", "2025-01-08", "2025-01-08", true));
+        vacation.add(Vacation::new("Ski trip", "2025-01-13", "2025-01-13", false));
+        let stats = |count_unapproved| {
+            calculate_quarter_stats(
+                &q,
+                &BadgeEntryData::default(),
+                &HolidayData::default(),
+                &vacation,
+                &StatsOptions {
+                    count_unapproved_vacations: count_unapproved,
+                    ..Default::default()
+                },
+                Some(date(2025, 1, 6)),
+            )
+            .unwrap()
+        };
+
+        let counted = stats(true);
+        assert_eq!(counted.vacation_days, 2);
+        assert_eq!(counted.total_days, 8);
+        assert_eq!(counted.days_required, 4);
+        let pending = &counted.workday_stats["2025-01-13"];
+        assert!(pending.is_vacation && pending.is_pending);
+
+        let approved_only = stats(false);
+        assert_eq!(approved_only.vacation_days, 1);
+        assert_eq!(approved_only.total_days, 9);
+        assert_eq!(approved_only.days_required, 5);
+        // The pending day is an ordinary workday that is still flagged for display
+        let pending = &approved_only.workday_stats["2025-01-13"];
+        assert!(!pending.is_vacation && pending.is_pending);
+        let approved = &approved_only.workday_stats["2025-01-08"];
+        assert!(approved.is_vacation && !approved.is_pending);
+    }
+
+    #[test]
+    fn test_excused_days_shrink_denominator() {
+        // Two weeks: 10 workdays
+        let q = make_period("2025-01-06", "2025-01-17");
+        let badge = BadgeEntryData::default();
+        let holiday = HolidayData::default();
+        let today = date(2025, 1, 6);
+        let stats = |vacation: &VacationData| {
+            calculate_quarter_stats(
+                &q,
+                &badge,
+                &holiday,
+                vacation,
+                &StatsOptions::default(),
+                Some(today),
+            )
+            .unwrap()
+        };
+        let baseline = stats(&VacationData::default());
+        assert_eq!(baseline.total_days, 10);
+        assert_eq!(baseline.days_required, 5);
+
+        let mut vacation = VacationData::default();
+        vacation.add(Vacation::excused("Flu", "2025-01-06", "2025-01-09"));
+        let sick = stats(&vacation);
+        assert_eq!(sick.total_days, 6);
+        assert_eq!(sick.days_required, 3);
+        assert_eq!(sick.excused_days, 4);
+        assert_eq!(sick.vacation_days, 0);
+        let day = &sick.workday_stats["2025-01-07"];
+        assert!(day.is_excused && day.is_vacation);
+        assert!(!day.is_pending);
+
+        vacation.add(Vacation::new("Maybe", "2025-01-13", "2025-01-13", false));
+        assert!(stats(&vacation).workday_stats["2025-01-13"].is_pending);
+    }
+
+    #[test]
+    fn test_working_trip_leaves_total_days_unchanged() {
+        let q = make_period("2025-01-06", "2025-01-17");
+        let mut badge = BadgeEntryData::default();
+        badge.add(BadgeEntry::new(date(2025, 1, 8), "HQ", true));
+        let mut vacation = VacationData::default();
+        let mut beach = Vacation::new("Beach", "2025-01-06", "2025-01-10", true);
+        beach.counts_as_off = false;
+        vacation.add(beach);
+        let stats = calculate_quarter_stats(
+            &q,
+            &badge,
+            &HolidayData::default(),
+            &vacation,
+            &StatsOptions::default(),
+            Some(date(2025, 1, 6)),
+        )
+        .unwrap();
+        assert_eq!(stats.total_days, 10);
+        assert_eq!(stats.days_required, 5);
+        assert_eq!(stats.vacation_days, 0);
+        let day = &stats.workday_stats["2025-01-08"];
+        assert!(!day.is_vacation);
+        assert!(day.is_badged_in);
+        assert_eq!(stats.days_badged_in, 1.0);
+    }
+
+    #[test]
+    fn test_badge_on_saturday_is_ignored_and_counted() {
+        let q = make_period("2025-01-06", "2025-01-17");
+        let mut badge = BadgeEntryData::default();
+        badge.add(BadgeEntry::new(date(2025, 1, 8), "HQ", false));
+        badge.add(BadgeEntry::new(date(2025, 1, 11), "HQ", false)); // Saturday
+        let stats = calculate_quarter_stats(
+            &q,
+            &badge,
+            &HolidayData::default(),
+            &VacationData::default(),
+            &StatsOptions::default(),
+            Some(date(2025, 1, 13)),
+        )
+        .unwrap();
+        assert_eq!(stats.days_badged_in, 1.0);
+        assert_eq!(stats.ignored_badge_entries, 1);
+        assert!(!stats.workday_stats.contains_key("2025-01-11"));
+    }
+
+    #[test]
+    fn test_badge_on_holiday_is_ignored_and_counted() {
+        let q = make_period("2025-01-06", "2025-01-17");
+        let mut badge = BadgeEntryData::default();
+        badge.add(BadgeEntry::new(date(2025, 1, 8), "HQ", false));
+        let mut holiday = HolidayData::default();
+        holiday.add(Holiday::new("Office closed", "2025-01-08"));
+        let stats = calculate_quarter_stats(
+            &q,
+            &badge,
+            &holiday,
+            &VacationData::default(),
+            &StatsOptions::default(),
+            Some(date(2025, 1, 13)),
+        )
+        .unwrap();
+        assert_eq!(stats.days_badged_in, 0.0);
+        assert_eq!(stats.ignored_badge_entries, 1);
+        assert!(!stats.workday_stats["2025-01-08"].is_badged_in);
+    }
+
+    #[test]
+    fn test_quarter_elapsed_and_days_until_end() {
+        // Q1 2025: Jan 1 – Mar 31, 90 calendar days
+        let q = make_period("2025-01-01", "2025-03-31");
+        let stats = |today: NaiveDate| {
+            calculate_quarter_stats(
+                &q,
+                &BadgeEntryData::default(),
+                &HolidayData::default(),
+                &VacationData::default(),
+                &StatsOptions::default(),
+                Some(today),
+            )
+            .unwrap()
+        };
+
+        let before = stats(date(2024, 12, 15));
+        assert_eq!(before.quarter_elapsed_pct, 0.0);
+        assert_eq!(before.days_until_end, 106);
+
+        let first = stats(date(2025, 1, 1));
+        assert_eq!(first.quarter_elapsed_pct, 0.0);
+
+        let mid = stats(date(2025, 2, 15));
+        assert_eq!(mid.quarter_elapsed_pct, 50.0);
+        assert_eq!(mid.days_until_end, 44);
+
+        let last = stats(date(2025, 3, 31));
+        assert!(last.quarter_elapsed_pct > 98.0 && last.quarter_elapsed_pct < 100.0);
+        assert_eq!(last.days_until_end, 0);
+
+        let after = stats(date(2025, 5, 1));
+        assert_eq!(after.quarter_elapsed_pct, 100.0);
+        assert_eq!(after.days_until_end, 0);
+    }
+
+    #[test]
+    fn test_best_and_worst_case_mid_quarter() {
+        // Two weeks (10 workdays); 3 badged before today, 1 planned after
+        let q = make_period("2025-01-06", "2025-01-17");
+        let mut badge = BadgeEntryData::default();
+        for day in [6, 7, 8, 15] {
+            badge.add(BadgeEntry::new(date(2025, 1, day), "Office", false));
+        }
+        let stats = calculate_quarter_stats(
+            &q,
+            &badge,
+            &HolidayData::default(),
+            &VacationData::default(),
+            &StatsOptions::default(),
+            Some(date(2025, 1, 13)),
+        )
+        .unwrap();
+        assert_eq!(stats.days_left, 5);
+        // Best: 3 past + all 5 remaining; worst: the planned day isn't kept
+        assert!((stats.best_case_average - 0.8).abs() < 1e-9);
+        assert!((stats.worst_case_average - 0.3).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_best_and_worst_case_collapse_at_quarter_end() {
+        let q = make_period("2025-01-06", "2025-01-17");
+        let mut badge = BadgeEntryData::default();
+        for day in [6, 7, 8, 9] {
+            badge.add(BadgeEntry::new(date(2025, 1, day), "Office", false));
+        }
+        let stats = calculate_quarter_stats(
+            &q,
+            &badge,
+            &HolidayData::default(),
+            &VacationData::default(),
+            &StatsOptions::default(),
+            Some(date(2025, 1, 20)),
+        )
+        .unwrap();
+        assert_eq!(stats.days_left, 0);
+        assert!((stats.current_average - 0.4).abs() < 1e-9);
+        assert_eq!(stats.best_case_average, stats.current_average);
+        assert_eq!(stats.worst_case_average, stats.current_average);
+    }
+
+    fn streaks(badged: &[u32], holiday: &HolidayData, today: NaiveDate) -> (i32, i32) {
+        let q = make_period("2025-03-03", "2025-03-28");
+        let mut badge = BadgeEntryData::default();
+        for &day in badged {
+            badge.add(BadgeEntry::new(date(2025, 3, day), "Office", false));
+        }
+        let stats = calculate_quarter_stats(
+            &q,
+            &badge,
+            holiday,
+            &VacationData::default(),
+            &StatsOptions::default(),
+            Some(today),
+        )
+        .unwrap();
+        (stats.current_streak, stats.longest_streak)
+    }
+
+    #[test]
+    fn test_streak_broken_by_unbadged_workday() {
+        // Mon 3–Mon 10, then Tue 11 missed, then Wed 12–Fri 14
+        let badged = [3, 4, 5, 6, 7, 10, 12, 13, 14];
+        let holiday = HolidayData::default();
+        // The weekend doesn't break Fri 7 → Mon 10, but Tue 11 does.
+        assert_eq!(streaks(&badged, &holiday, date(2025, 3, 14)), (3, 6));
+        // Today isn't badged yet: the run through yesterday still counts.
+        assert_eq!(streaks(&badged, &holiday, date(2025, 3, 17)), (3, 6));
+        // A missed day before today resets the current streak.
+        assert_eq!(streaks(&badged, &holiday, date(2025, 3, 18)), (0, 6));
+    }
+
+    #[test]
+    fn test_streak_spans_holiday() {
+        let badged = [3, 4, 6, 7];
+        let mut holiday = HolidayData::default();
+        let none = streaks(&badged, &holiday, date(2025, 3, 7));
+        assert_eq!(none, (2, 2));
+
+        holiday.add(Holiday::new("Office Closed", "2025-03-05"));
+        assert_eq!(streaks(&badged, &holiday, date(2025, 3, 7)), (4, 4));
+    }
+
+    #[test]
+    fn test_streak_ignores_non_qualifying_office() {
+        // HQ Mon 3–Tue 4, WeWork Wed 5–Fri 7, HQ Mon 10
+        let q = make_period("2025-03-03", "2025-03-28");
+        let mut badge = BadgeEntryData::default();
+        for (day, office) in [
+            (3, "HQ"),
+            (4, "HQ"),
+            (5, "WeWork"),
+            (6, "WeWork"),
+            (7, "WeWork"),
+        ] {
+            badge.add(BadgeEntry::new(date(2025, 3, day), office, false));
+        }
+        badge.add(BadgeEntry::new(date(2025, 3, 10), "HQ", false));
+        let options = StatsOptions {
+            qualifying_offices: Some(vec!["HQ".to_string()]),
+            ..Default::default()
+        };
+        let stats = |today| {
+            calculate_quarter_stats(
+                &q,
+                &badge,
+                &HolidayData::default(),
+                &VacationData::default(),
+                &options,
+                Some(today),
+            )
+            .unwrap()
+        };
+        // The WeWork days are badged on the calendar but don't extend the run
+        let friday = stats(date(2025, 3, 7));
+        assert!(friday.workday_stats["2025-03-06"].is_badged_in);
+        assert_eq!((friday.current_streak, friday.longest_streak), (0, 2));
+        // …and they break it, so Mon 10 starts over
+        let monday = stats(date(2025, 3, 10));
+        assert_eq!((monday.current_streak, monday.longest_streak), (1, 2));
+    }
+
+    #[test]
+    fn test_days_by_office_excludes_flex() {
+        let q = make_period("2025-03-03", "2025-03-14");
+        let mut badge = BadgeEntryData::default();
+        for day in [3, 5, 10] {
+            badge.add(BadgeEntry::new(date(2025, 3, day), "McLean, VA", false));
+        }
+        for day in [4, 11] {
+            badge.add(BadgeEntry::new(date(2025, 3, day), "Reston, VA", false));
+        }
+        badge.add(BadgeEntry::new(date(2025, 3, 12), "Flex Credit", true));
+        let stats = calculate_quarter_stats(
+            &q,
+            &badge,
+            &HolidayData::default(),
+            &VacationData::default(),
+            &StatsOptions::default(),
+            Some(date(2025, 3, 17)),
+        )
+        .unwrap();
+        assert_eq!(stats.days_by_office.len(), 2);
+        assert_eq!(stats.days_by_office["McLean, VA"], 3);
+        assert_eq!(stats.days_by_office["Reston, VA"], 2);
+        assert!(!stats.days_by_office.contains_key("Flex Credit"));
+        assert_eq!(
+            stats.offices_by_days(),
+            vec![("McLean, VA", 3), ("Reston, VA", 2)]
+        );
+    }
+
+    #[test]
+    fn test_holiday_inside_vacation_counts_only_as_holiday() {
+        // Trip runs Mon 6–Wed 8, starting on the Monday holiday
+        let q = make_period("2025-01-06", "2025-01-10");
+        let mut holiday = HolidayData::default();
+        holiday.add(Holiday::new("Holiday", "2025-01-06"));
+        let mut vacation = VacationData::default();
+        vacation.add(Vacation::new("Trip", "2025-01-06", "2025-01-08", true));
+        let stats = calculate_quarter_stats(
+            &q,
+            &BadgeEntryData::default(),
+            &holiday,
+            &vacation,
+            &StatsOptions::default(),
+            Some(date(2025, 1, 20)),
+        )
+        .unwrap();
+        assert_eq!(stats.holidays, 1);
+        assert_eq!(stats.vacation_days, 2);
+        assert_eq!(
+            stats.holidays + stats.vacation_days + stats.excused_days + stats.total_days,
+            stats.available_workdays
+        );
+        let monday = &stats.workday_stats["2025-01-06"];
+        assert!(monday.is_holiday && !monday.is_vacation);
+    }
+
     #[test]
     fn test_working_days_and_available_workdays() {
         // Mon 01/06 = Holiday, Wed 01/08 = Vacation, Tue+Thu+Fri = work days
@@ -387,8 +1367,15 @@ mod tests {
         let mut vacation = VacationData::default();
         vacation.add(Vacation::new("Trip", "2025-01-08", "2025-01-08", true));
         let today = date(2025, 1, 20);
-        let stats =
-            calculate_quarter_stats(&q, &badge, &holiday, &vacation, 50, Some(today)).unwrap();
+        let stats = calculate_quarter_stats(
+            &q,
+            &badge,
+            &holiday,
+            &vacation,
+            &StatsOptions::default(),
+            Some(today),
+        )
+        .unwrap();
         assert_eq!(stats.total_calendar_days, 5);
         assert_eq!(stats.available_workdays, 5);
         assert_eq!(stats.total_days, 3);
@@ -401,8 +1388,15 @@ mod tests {
         let holiday = HolidayData::default();
         let vacation = VacationData::default();
         let today = date(2025, 1, 20);
-        let stats =
-            calculate_quarter_stats(&q, &badge, &holiday, &vacation, 50, Some(today)).unwrap();
+        let stats = calculate_quarter_stats(
+            &q,
+            &badge,
+            &holiday,
+            &vacation,
+            &StatsOptions::default(),
+            Some(today),
+        )
+        .unwrap();
         assert_eq!(stats.total_calendar_days, 7);
         assert_eq!(stats.available_workdays, 5);
         assert_eq!(stats.total_days, 5);
@@ -415,10 +1409,27 @@ mod tests {
         let holiday = HolidayData::default();
         let vacation = VacationData::default();
         let today = date(2025, 1, 20);
-        let stats_50 =
-            calculate_quarter_stats(&q, &badge, &holiday, &vacation, 50, Some(today)).unwrap();
-        let stats_60 =
-            calculate_quarter_stats(&q, &badge, &holiday, &vacation, 60, Some(today)).unwrap();
+        let stats_50 = calculate_quarter_stats(
+            &q,
+            &badge,
+            &holiday,
+            &vacation,
+            &StatsOptions::default(),
+            Some(today),
+        )
+        .unwrap();
+        let stats_60 = calculate_quarter_stats(
+            &q,
+            &badge,
+            &holiday,
+            &vacation,
+            &StatsOptions {
+                target: 60.into(),
+                ..Default::default()
+            },
+            Some(today),
+        )
+        .unwrap();
         assert_eq!(stats_50.days_required, 5);
         assert_eq!(stats_60.days_required, 6);
     }
@@ -432,14 +1443,335 @@ mod tests {
         let vacation = VacationData::default();
         let today = date(2025, 1, 7);
         let periods: Vec<&TimePeriod> = vec![&q1, &q2];
-        let year_stats =
-            calculate_year_stats(&periods, &badge, &holiday, &vacation, 50, Some(today)).unwrap();
+        let year_stats = calculate_year_stats(
+            &periods,
+            &badge,
+            &holiday,
+            &vacation,
+            &StatsOptions::default(),
+            Some(today),
+        )
+        .unwrap();
         assert!(year_stats.is_some());
         let ys = year_stats.unwrap();
         assert_eq!(ys.name, "Year");
         assert!(ys.total_days > 0);
     }
 
+    #[test]
+    fn test_year_stats_spans_periods_and_scales_flex_cap() {
+        let q1 = make_period("2025-01-06", "2025-01-17"); // 10 workdays
+        let q2 = make_period("2025-01-20", "2025-01-31"); // 10 workdays
+        let mut badge = BadgeEntryData::default();
+        badge.add(BadgeEntry::new(date(2025, 1, 6), "Office", false));
+        badge.add(BadgeEntry::new(date(2025, 1, 7), "Flex", true));
+        badge.add(BadgeEntry::new(date(2025, 1, 20), "Office", false));
+        badge.add(BadgeEntry::new(date(2025, 1, 21), "Office", false));
+        badge.add(BadgeEntry::new(date(2025, 1, 22), "Flex", true));
+        let ys = calculate_year_stats(
+            &[&q2, &q1],
+            &badge,
+            &HolidayData::default(),
+            &VacationData::default(),
+            &StatsOptions {
+                max_flex_credits: Some(1),
+                ..Default::default()
+            },
+            Some(date(2025, 2, 3)),
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(ys.start_date, date(2025, 1, 6));
+        assert_eq!(ys.end_date, date(2025, 1, 31));
+        assert_eq!(ys.total_days, 20);
+        assert_eq!(ys.days_required, 10);
+        // One flex credit per period → both flex days count toward the year
+        assert_eq!(ys.days_badged_in, 5.0);
+        assert_eq!(ys.excess_flex_days, 0.0);
+    }
+
+    #[test]
+    fn test_year_stats_caps_flex_per_period() {
+        let q1 = make_period("2025-01-06", "2025-01-17");
+        let q2 = make_period("2025-01-20", "2025-01-31");
+        let mut badge = BadgeEntryData::default();
+        badge.add(BadgeEntry::new(date(2025, 1, 6), "Flex", true));
+        badge.add(BadgeEntry::new(date(2025, 1, 7), "Flex", true));
+        badge.add(BadgeEntry::new(date(2025, 1, 20), "Office", false));
+        let options = StatsOptions {
+            max_flex_credits: Some(1),
+            ..Default::default()
+        };
+        let today = Some(date(2025, 2, 3));
+        let quarters: Vec<QuarterStats> = [&q1, &q2]
+            .iter()
+            .map(|q| {
+                calculate_quarter_stats(
+                    q,
+                    &badge,
+                    &HolidayData::default(),
+                    &VacationData::default(),
+                    &options,
+                    today,
+                )
+                .unwrap()
+            })
+            .collect();
+        let ys = calculate_year_stats(
+            &[&q1, &q2],
+            &badge,
+            &HolidayData::default(),
+            &VacationData::default(),
+            &options,
+            today,
+        )
+        .unwrap()
+        .unwrap();
+        // Q1's second flex day is over its cap; Q2's unused allowance can't absorb it
+        assert_eq!(ys.days_badged_in, 2.0);
+        assert_eq!(ys.excess_flex_days, 1.0);
+        let quarter_total: f64 = quarters.iter().map(|q| q.days_badged_in).sum();
+        assert_eq!(ys.days_badged_in, quarter_total);
+    }
+
+    #[test]
+    fn test_year_stats_skip_excluded_periods() {
+        let mut q1 = make_period("2025-01-06", "2025-01-17"); // 10 workdays
+        let q2 = make_period("2025-01-20", "2025-01-31"); // 10 workdays
+        q1.exclude_from_year = true;
+        let mut badge = BadgeEntryData::default();
+        badge.add(BadgeEntry::new(date(2025, 1, 6), "Office", false));
+        badge.add(BadgeEntry::new(date(2025, 1, 20), "Office", false));
+        badge.add(BadgeEntry::new(date(2025, 1, 21), "Office", false));
+        let year = |periods: &[&TimePeriod]| {
+            calculate_year_stats(
+                periods,
+                &badge,
+                &HolidayData::default(),
+                &VacationData::default(),
+                &StatsOptions::default(),
+                Some(date(2025, 2, 3)),
+            )
+            .unwrap()
+        };
+
+        let ys = year(&[&q1, &q2]).unwrap();
+        assert_eq!(ys.start_date, date(2025, 1, 20));
+        assert_eq!(ys.total_days, 10);
+        assert_eq!(ys.days_required, 5);
+        // The badge in the excluded period no longer counts
+        assert_eq!(ys.days_badged_in, 2.0);
+
+        assert!(year(&[&q1]).is_none());
+    }
+
+    #[test]
+    fn test_year_stats_skip_excluded_middle_period() {
+        let q1 = make_period("2025-01-06", "2025-01-17"); // 10 workdays
+        let mut q2 = make_period("2025-01-20", "2025-01-31"); // 10 workdays
+        let q3 = make_period("2025-02-03", "2025-02-14"); // 10 workdays
+        q2.exclude_from_year = true;
+        let mut badge = BadgeEntryData::default();
+        badge.add(BadgeEntry::new(date(2025, 1, 6), "Office", false));
+        for day in [20, 21, 22] {
+            badge.add(BadgeEntry::new(date(2025, 1, day), "Office", false));
+        }
+        badge.add(BadgeEntry::new(date(2025, 2, 3), "Office", false));
+        let ys = calculate_year_stats(
+            &[&q1, &q2, &q3],
+            &badge,
+            &HolidayData::default(),
+            &VacationData::default(),
+            &StatsOptions::default(),
+            Some(date(2025, 2, 17)),
+        )
+        .unwrap()
+        .unwrap();
+        // The year still spans all three periods on the calendar…
+        assert_eq!(ys.start_date, date(2025, 1, 6));
+        assert_eq!(ys.end_date, date(2025, 2, 14));
+        // …but the excluded period's days and badges don't count
+        assert_eq!(ys.total_days, 20);
+        assert_eq!(ys.days_thus_far, 20);
+        assert_eq!(ys.days_required, 10);
+        assert_eq!(ys.days_badged_in, 2.0);
+        assert_eq!(ys.days_off, 18);
+        let excluded = "2025-01-20".."2025-02-01";
+        assert!(
+            !ys.pace_misses
+                .iter()
+                .any(|key| excluded.contains(&key.as_str()))
+        );
+    }
+
+    fn two_week_stats(start: &str, end: &str, badge: &BadgeEntryData) -> QuarterStats {
+        calculate_quarter_stats(
+            &make_period(start, end),
+            badge,
+            &HolidayData::default(),
+            &VacationData::default(),
+            &StatsOptions::default(),
+            Some(date(2025, 1, 22)),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_carryover_surplus_reduces_requirement() {
+        let mut badge = BadgeEntryData::default();
+        for day in [6, 7, 8, 9, 10, 13, 14, 15, 20] {
+            badge.add(BadgeEntry::new(date(2025, 1, day), "Office", false));
+        }
+        let prev = two_week_stats("2025-01-06", "2025-01-17", &badge);
+        let mut curr = two_week_stats("2025-01-20", "2025-01-31", &badge);
+        assert_eq!((prev.days_badged_in, prev.days_required), (8.0, 5));
+        assert_eq!(curr.days_still_needed, 4);
+
+        let days = carryover(&prev, curr.days_required);
+        assert_eq!(days, 3);
+        apply_carryover(&mut curr, days);
+        assert_eq!(curr.days_still_needed, 1);
+        assert_eq!(curr.carryover_days, 3);
+        assert_eq!(curr.remaining_missable_days, curr.days_left - 1);
+
+        // Never more than the current requirement
+        assert_eq!(carryover(&prev, 2), 2);
+    }
+
+    #[test]
+    fn test_carryover_deficit_has_no_effect() {
+        let mut badge = BadgeEntryData::default();
+        for day in [6, 7, 20] {
+            badge.add(BadgeEntry::new(date(2025, 1, day), "Office", false));
+        }
+        let prev = two_week_stats("2025-01-06", "2025-01-17", &badge);
+        let mut curr = two_week_stats("2025-01-20", "2025-01-31", &badge);
+        let before = (curr.days_still_needed, curr.compliance_status.clone());
+
+        let days = carryover(&prev, curr.days_required);
+        assert_eq!(days, 0);
+        apply_carryover(&mut curr, days);
+        assert_eq!((curr.days_still_needed, curr.compliance_status), before);
+        assert_eq!(curr.carryover_days, 0);
+    }
+
+    #[test]
+    fn test_pace_misses_flag_unbadged_days_behind_pace() {
+        let q = make_period("2025-01-06", "2025-01-17"); // 10 workdays, 5 required
+        let mut badge = BadgeEntryData::default();
+        badge.add(BadgeEntry::new(date(2025, 1, 6), "Office", false));
+        badge.add(BadgeEntry::new(date(2025, 1, 9), "Office", false));
+        let stats = calculate_quarter_stats(
+            &q,
+            &badge,
+            &HolidayData::default(),
+            &VacationData::default(),
+            &StatsOptions::default(),
+            Some(date(2025, 1, 16)),
+        )
+        .unwrap();
+        // Jan 7 leaves 1 badged against 1 expected; later misses fall behind.
+        // Today (Jan 16) and the future are never flagged.
+        let misses: Vec<_> = stats.pace_misses.iter().map(String::as_str).collect();
+        assert_eq!(
+            misses,
+            vec![
+                "2025-01-08",
+                "2025-01-10",
+                "2025-01-13",
+                "2025-01-14",
+                "2025-01-15"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_future_holidays_and_vacation_days_count_from_today() {
+        let q = make_period("2025-01-06", "2025-01-31");
+        let mut holiday = HolidayData::default();
+        holiday.add(Holiday::new("Past", "2025-01-07"));
+        holiday.add(Holiday::new("Ahead", "2025-01-20"));
+        let mut vacation = VacationData::default();
+        vacation.add(Vacation::new("Done", "2025-01-08", "2025-01-09", true));
+        // Starts today, so today counts as ahead
+        vacation.add(Vacation::new("Trip", "2025-01-15", "2025-01-17", false));
+        vacation.add(Vacation::excused("Dentist", "2025-01-27", "2025-01-27"));
+        let stats = calculate_quarter_stats(
+            &q,
+            &BadgeEntryData::default(),
+            &holiday,
+            &vacation,
+            &StatsOptions::default(),
+            Some(date(2025, 1, 15)),
+        )
+        .unwrap();
+        assert_eq!((stats.holidays, stats.future_holidays), (2, 1));
+        assert_eq!((stats.vacation_days, stats.future_vacation_days), (5, 3));
+    }
+
+    #[test]
+    fn test_observed_saturday_holiday_reduces_friday() {
+        let q = make_period("2026-06-29", "2026-07-10"); // 10 workdays
+        let mut holiday = HolidayData::default();
+        holiday.add(Holiday::new("Independence Day", "2026-07-04")); // Saturday
+        let stats = |observe_weekend_holidays| {
+            calculate_quarter_stats(
+                &q,
+                &BadgeEntryData::default(),
+                &holiday,
+                &VacationData::default(),
+                &StatsOptions {
+                    observe_weekend_holidays,
+                    ..Default::default()
+                },
+                Some(date(2026, 6, 29)),
+            )
+            .unwrap()
+        };
+        let unobserved = stats(false);
+        assert_eq!(unobserved.holidays, 0);
+        assert_eq!(unobserved.total_days, 10);
+
+        let observed = stats(true);
+        assert_eq!(observed.holidays, 1);
+        assert_eq!(observed.total_days, 9);
+        assert!(observed.workday_stats["2026-07-03"].is_holiday);
+    }
+
+    #[test]
+    fn test_unparsed_period_returns_error() {
+        let q = TimePeriod {
+            key: "Q1_2025".to_string(),
+            start_date_raw: "2025-01-06".to_string(),
+            end_date_raw: "2025-01-17".to_string(),
+            ..Default::default()
+        };
+        let badge = BadgeEntryData::default();
+        let holiday = HolidayData::default();
+        let vacation = VacationData::default();
+        let err = calculate_quarter_stats(
+            &q,
+            &badge,
+            &holiday,
+            &vacation,
+            &StatsOptions::default(),
+            Some(date(2025, 1, 8)),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("Q1_2025"));
+
+        let parsed = make_period("2025-01-20", "2025-01-31");
+        let year = calculate_year_stats(
+            &[&parsed, &q],
+            &badge,
+            &holiday,
+            &vacation,
+            &StatsOptions::default(),
+            Some(date(2025, 1, 8)),
+        );
+        assert!(year.is_err());
+    }
+
     #[test]
     fn test_year_stats_empty_periods() {
         let badge = BadgeEntryData::default();
@@ -447,14 +1779,390 @@ mod tests {
         let vacation = VacationData::default();
         let today = date(2025, 1, 1);
         let periods: Vec<&TimePeriod> = vec![];
-        let result =
-            calculate_year_stats(&periods, &badge, &holiday, &vacation, 50, Some(today)).unwrap();
+        let result = calculate_year_stats(
+            &periods,
+            &badge,
+            &holiday,
+            &vacation,
+            &StatsOptions::default(),
+            Some(today),
+        )
+        .unwrap();
         assert!(result.is_none());
     }
 
+    #[test]
+    fn test_effective_start_reduces_required_days() {
+        let mut q = make_period("2025-01-06", "2025-01-17"); // 10 workdays
+        q.effective_start_raw = Some("2025-01-13".to_string());
+        q.parse_dates().unwrap();
+        let badge = BadgeEntryData::default();
+        let holiday = HolidayData::default();
+        let vacation = VacationData::default();
+        let today = date(2025, 1, 15);
+        let stats = calculate_quarter_stats(
+            &q,
+            &badge,
+            &holiday,
+            &vacation,
+            &StatsOptions::default(),
+            Some(today),
+        )
+        .unwrap();
+        assert_eq!(stats.total_days, 5);
+        assert_eq!(stats.days_required, 3);
+        assert_eq!(stats.days_thus_far, 2);
+    }
+
     #[test]
     fn test_compliance_status_on_track_no_badges() {
         let status = determine_compliance_status(0, 5, 0, 5, 10);
         assert_eq!(status, "On Track");
     }
+
+    #[test]
+    fn test_days_per_week_partial_weeks_at_both_ends() {
+        // Thu 2025-01-02 .. Tue 2025-01-28:
+        //   week 1: Thu–Fri (2 avail) → min(3, 2) = 2
+        //   weeks 2–4: 5 avail each → 3 each
+        //   week 5: Mon–Tue (2 avail) → 2
+        let q = make_period("2025-01-02", "2025-01-28");
+        let badge = BadgeEntryData::default();
+        let (holiday, vacation) = (HolidayData::default(), VacationData::default());
+        let today = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+        let stats = calculate_quarter_stats(
+            &q,
+            &badge,
+            &holiday,
+            &vacation,
+            &StatsOptions {
+                target: TargetMode::DaysPerWeek(3),
+                ..Default::default()
+            },
+            Some(today),
+        )
+        .unwrap();
+        assert_eq!(stats.total_days, 19);
+        assert_eq!(stats.days_required, 2 + 3 + 3 + 3 + 2);
+        assert_eq!(stats.days_still_needed, 13);
+        assert_eq!(stats.compliance_status, "On Track");
+    }
+
+    #[test]
+    fn test_days_per_week_extra_days_do_not_cover_short_week() {
+        let q = make_period("2025-01-06", "2025-01-17");
+        let mut badge = BadgeEntryData::default();
+        // Week 1: all five days; week 2: one day so far
+        for day in [6, 7, 8, 9, 10, 13] {
+            badge.add(BadgeEntry::new(
+                NaiveDate::from_ymd_opt(2025, 1, day).unwrap(),
+                "HQ",
+                false,
+            ));
+        }
+        let (holiday, vacation) = (HolidayData::default(), VacationData::default());
+        // Thu of week 2: Thu + Fri still open, 2 still needed → recoverable
+        let today = NaiveDate::from_ymd_opt(2025, 1, 16).unwrap();
+        let target = TargetMode::DaysPerWeek(3);
+        let stats = calculate_quarter_stats(
+            &q,
+            &badge,
+            &holiday,
+            &vacation,
+            &StatsOptions {
+                target,
+                ..Default::default()
+            },
+            Some(today),
+        )
+        .unwrap();
+        assert_eq!(stats.days_required, 6);
+        assert_eq!(stats.days_badged_in, 6.0);
+        assert_eq!(stats.days_still_needed, 2);
+        assert_ne!(stats.compliance_status, "Achieved");
+        assert_ne!(stats.compliance_status, "Impossible");
+
+        // Fri of week 2: only one day left for a 2-day shortfall
+        let today = NaiveDate::from_ymd_opt(2025, 1, 17).unwrap();
+        let stats = calculate_quarter_stats(
+            &q,
+            &badge,
+            &holiday,
+            &vacation,
+            &StatsOptions {
+                target,
+                ..Default::default()
+            },
+            Some(today),
+        )
+        .unwrap();
+        assert_eq!(stats.compliance_status, "Impossible");
+    }
+
+    #[test]
+    fn test_days_per_week_caps_weeks_with_holidays() {
+        // Week of Mon 2025-05-26 (Memorial Day) + vacation Tue–Thu → 1 available
+        let q = make_period("2025-05-26", "2025-05-30");
+        let badge = BadgeEntryData::default();
+        let mut holiday = HolidayData::default();
+        holiday.add(Holiday::new("Memorial Day", "2025-05-26"));
+        let mut vacation = VacationData::default();
+        vacation.add(Vacation::new("Trip", "2025-05-27", "2025-05-29", true));
+        let today = NaiveDate::from_ymd_opt(2025, 5, 20).unwrap();
+        let stats = calculate_quarter_stats(
+            &q,
+            &badge,
+            &holiday,
+            &vacation,
+            &StatsOptions {
+                target: TargetMode::DaysPerWeek(3),
+                ..Default::default()
+            },
+            Some(today),
+        )
+        .unwrap();
+        assert_eq!(stats.days_required, 1);
+    }
+
+    #[test]
+    fn test_half_day_credits_sum() {
+        let q = make_period("2025-01-06", "2025-01-10");
+        let mut badge = BadgeEntryData::default();
+        let d = |day| NaiveDate::from_ymd_opt(2025, 1, day).unwrap();
+        badge.add(BadgeEntry::new(d(6), "HQ", false));
+        badge.add(BadgeEntry::new(d(7), "HQ", false).with_credit(0.5));
+        badge.add(BadgeEntry::new(d(8), "HQ", false).with_credit(0.5));
+        let (holiday, vacation) = (HolidayData::default(), VacationData::default());
+        let stats = calculate_quarter_stats(
+            &q,
+            &badge,
+            &holiday,
+            &vacation,
+            &StatsOptions {
+                target: 60.into(),
+                ..Default::default()
+            },
+            Some(d(9)),
+        )
+        .unwrap();
+        assert_eq!(stats.days_badged_in, 2.0);
+        assert_eq!(stats.days_required, 3);
+        assert_eq!(stats.days_still_needed, 1);
+        assert_eq!(stats.workday_stats["2025-01-07"].credit, 0.5);
+
+        // 2.5 credits against 3 required still needs one more (partial) day
+        badge.add(BadgeEntry::new(d(9), "HQ", false).with_credit(0.5));
+        let stats = calculate_quarter_stats(
+            &q,
+            &badge,
+            &holiday,
+            &vacation,
+            &StatsOptions {
+                target: 60.into(),
+                ..Default::default()
+            },
+            Some(d(9)),
+        )
+        .unwrap();
+        assert_eq!(stats.days_badged_in, 2.5);
+        assert_eq!(stats.days_still_needed, 1);
+        assert_ne!(stats.compliance_status, "Achieved");
+    }
+
+    /// January 6–31, 2025 (20 weekdays, 10 required at 50%) with `badged`
+    /// days of the month badged, as seen on `today`.
+    fn january_stats(badged: &[u32], today: u32) -> QuarterStats {
+        let q = make_period("2025-01-06", "2025-01-31");
+        let mut badge = BadgeEntryData::default();
+        for &day in badged {
+            badge.add(BadgeEntry::new(date(2025, 1, day), "HQ", false));
+        }
+        calculate_quarter_stats(
+            &q,
+            &badge,
+            &HolidayData::default(),
+            &VacationData::default(),
+            &StatsOptions::default(),
+            Some(date(2025, 1, today)),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_count_today_as_elapsed_moves_unbadged_today() {
+        let q = make_period("2025-01-06", "2025-01-31");
+        let mut badge = BadgeEntryData::default();
+        badge.add(BadgeEntry::new(date(2025, 1, 6), "HQ", false));
+        badge.add(BadgeEntry::new(date(2025, 1, 7), "HQ", false));
+        let stats = |count_today| {
+            calculate_quarter_stats(
+                &q,
+                &badge,
+                &HolidayData::default(),
+                &VacationData::default(),
+                &StatsOptions {
+                    count_today_as_elapsed: count_today,
+                    ..Default::default()
+                },
+                Some(date(2025, 1, 9)),
+            )
+            .unwrap()
+        };
+
+        // Thursday the 9th, not yet badged: by default it is still left
+        let left = stats(false);
+        assert_eq!(left.days_thus_far, 3);
+        assert_eq!(left.days_left, 17);
+        assert!((left.current_average - 2.0 / 3.0).abs() < 1e-9);
+        assert_eq!(left.earliest_completion_date, Some(date(2025, 1, 20)));
+        assert!(!left.pace_misses.contains("2025-01-09"));
+
+        // ...and with the setting it counts as a day gone by
+        let elapsed = stats(true);
+        assert_eq!(elapsed.days_thus_far, 4);
+        assert_eq!(elapsed.days_left, 16);
+        assert!((elapsed.current_average - 0.5).abs() < 1e-9);
+        assert_eq!(elapsed.earliest_completion_date, Some(date(2025, 1, 21)));
+        assert_eq!(elapsed.days_still_needed, left.days_still_needed);
+    }
+
+    #[test]
+    fn test_non_qualifying_office_is_shown_but_not_counted() {
+        let q = make_period("2025-01-06", "2025-01-31");
+        let mut badge = BadgeEntryData::default();
+        badge.add(BadgeEntry::new(date(2025, 1, 6), "HQ", false));
+        badge.add(BadgeEntry::new(date(2025, 1, 7), "WeWork", false));
+        badge.add(BadgeEntry::new(date(2025, 1, 8), "hq ", false));
+        badge.add(BadgeEntry::new(date(2025, 1, 9), "Flex Credit", true));
+        let stats = |qualifying_offices: Option<Vec<String>>| {
+            calculate_quarter_stats(
+                &q,
+                &badge,
+                &HolidayData::default(),
+                &VacationData::default(),
+                &StatsOptions {
+                    qualifying_offices,
+                    ..Default::default()
+                },
+                Some(date(2025, 1, 13)),
+            )
+            .unwrap()
+        };
+
+        let all = stats(None);
+        assert_eq!(all.days_badged_in, 4.0);
+        assert_eq!(all.non_qualifying_days, 0.0);
+
+        let hq_only = stats(Some(vec!["HQ".to_string()]));
+        // Office names match ignoring case and surrounding spaces; flex still counts
+        assert_eq!(hq_only.days_badged_in, 3.0);
+        assert_eq!(hq_only.office_days(), 2.0);
+        assert_eq!(hq_only.non_qualifying_days, 1.0);
+        assert_eq!(hq_only.days_still_needed, all.days_still_needed + 1);
+        // The coworking day is still a badged day on the calendar
+        let wework = &hq_only.workday_stats["2025-01-07"];
+        assert!(wework.is_badged_in);
+        assert_eq!(hq_only.days_by_office.get("WeWork"), Some(&1));
+    }
+
+    #[test]
+    fn test_buffer_days_used_zero_when_behind() {
+        // 5 workdays in, 3 expected by now, only 1 badged
+        let stats = january_stats(&[6], 13);
+        assert!(stats.days_ahead_of_pace < 0);
+        assert_eq!(stats.buffer_days_used, 0);
+    }
+
+    #[test]
+    fn test_buffer_days_used_counts_days_beyond_pace() {
+        // 5 of 5 badged against 3 expected by now
+        let stats = january_stats(&[6, 7, 8, 9, 10], 13);
+        assert_eq!(stats.buffer_days_used, 2);
+        assert_eq!(stats.days_ahead_of_pace, 2);
+        assert_eq!(stats.remaining_missable_days, 10);
+    }
+
+    #[test]
+    fn test_buffer_days_used_stops_at_requirement() {
+        // 15 badged, 8 expected: only the 10 required days can be buffer
+        let badged = [6, 7, 8, 9, 10, 13, 14, 15, 16, 17, 20, 21, 22, 23, 24];
+        let stats = january_stats(&badged, 27);
+        assert_eq!(stats.days_ahead_of_pace, 7);
+        assert_eq!(stats.buffer_days_used, 2);
+    }
+
+    #[test]
+    fn test_completion_dates_count_open_workdays() {
+        // Thursday, perfect record: 7 more needed, Thu 9th through Fri 17th
+        let stats = january_stats(&[6, 7, 8], 9);
+        assert_eq!(stats.days_still_needed, 7);
+        assert_eq!(stats.earliest_completion_date, Some(date(2025, 1, 17)));
+        assert_eq!(stats.projected_completion_date, Some(date(2025, 1, 17)));
+
+        // Half the days so far: 9 needed, at one every other open day
+        let stats = january_stats(&[6], 8);
+        assert_eq!(stats.days_still_needed, 9);
+        assert_eq!(stats.earliest_completion_date, Some(date(2025, 1, 20)));
+        assert_eq!(stats.projected_completion_date, Some(date(2025, 1, 31)));
+    }
+
+    #[test]
+    fn test_projected_completion_skips_holidays_and_vacation() {
+        let q = make_period("2025-01-06", "2025-01-31");
+        let mut badge = BadgeEntryData::default();
+        for day in [6, 8, 10] {
+            badge.add(BadgeEntry::new(date(2025, 1, day), "HQ", false));
+        }
+        let mut holiday = HolidayData::default();
+        holiday.add(Holiday::new("MLK Day", "2025-01-20"));
+        let mut vacation = VacationData::default();
+        vacation.add(Vacation::new("Trip", "2025-01-22", "2025-01-22", true));
+        let stats = calculate_quarter_stats(
+            &q,
+            &badge,
+            &holiday,
+            &vacation,
+            &StatsOptions::default(),
+            Some(date(2025, 1, 13)),
+        )
+        .unwrap();
+
+        // 3 of 5 so far leaves 6 of 9 needed: ten open days at that rate.
+        // Ten calendar days from the 13th is the 22nd, ten weekdays the 24th;
+        // skipping the holiday and the vacation day lands on the 28th.
+        assert_eq!(stats.days_still_needed, 6);
+        assert_eq!(stats.projected_completion_date, Some(date(2025, 1, 28)));
+        assert_eq!(stats.earliest_completion_date, Some(date(2025, 1, 21)));
+    }
+
+    #[test]
+    fn test_completion_dates_none_when_out_of_reach() {
+        // 1 of 3 so far leaves 9 needed at a third of the pace
+        let stats = january_stats(&[6], 9);
+        assert_eq!(stats.earliest_completion_date, Some(date(2025, 1, 21)));
+        assert_eq!(stats.projected_completion_date, None);
+
+        let stats = january_stats(&[], 24);
+        assert_eq!(stats.compliance_status, "Impossible");
+        assert_eq!(stats.earliest_completion_date, None);
+
+        let done = january_stats(&(6..=17).collect::<Vec<_>>(), 20);
+        assert_eq!(done.days_still_needed, 0);
+        assert_eq!(done.earliest_completion_date, None);
+        assert_eq!(done.projected_completion_date, None);
+    }
+
+    #[test]
+    fn test_earliest_completion_never_after_projected() {
+        let patterns: [&[u32]; 4] = [&[6, 7, 8, 9, 10], &[6, 8, 10, 14], &[7, 13], &[6, 20]];
+        for badged in patterns {
+            for today in 7..=31 {
+                let stats = january_stats(badged, today);
+                if let Some(proj) = stats.projected_completion_date {
+                    let earliest = stats.earliest_completion_date.unwrap();
+                    assert!(earliest <= proj, "{badged:?} on Jan {today}");
+                }
+            }
+        }
+    }
 }
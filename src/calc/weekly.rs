@@ -0,0 +1,337 @@
+use crate::calc::QuarterStats;
+use crate::data::TargetMode;
+use chrono::{Datelike, Duration, NaiveDate};
+use std::collections::BTreeMap;
+
+/// Progress toward the per-week office target for the Monday–Sunday week
+/// containing a given day.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WeeklyProgress {
+    /// Goal credit earned this week, as counted by the period's stats
+    pub badged: f64,
+    pub target: i32,
+    /// Work days from today through Sunday that are not holidays, vacation,
+    /// or already badged — the days still available to close the gap.
+    pub days_left: i32,
+}
+
+impl WeeklyProgress {
+    pub fn remaining_to_target(&self) -> f64 {
+        (self.target as f64 - self.badged).max(0.0)
+    }
+}
+
+/// Computes the credit earned in the week containing `today` against
+/// `target`, from `stats` for the period containing `today`. The week resets
+/// each Monday; days outside the period's counted days are ignored, and flex
+/// credit over the cap or at a non-qualifying office adds nothing.
+pub fn weekly_progress(stats: &QuarterStats, today: NaiveDate, target: i32) -> WeeklyProgress {
+    let monday = today - Duration::days(today.weekday().num_days_from_monday() as i64);
+    let sunday = monday + Duration::days(6);
+    let mut badged = 0.0;
+    let mut days_left = 0;
+    for day in stats
+        .workday_stats
+        .values()
+        .filter(|day| day.is_counted && monday <= day.date && day.date <= sunday)
+    {
+        badged += day.counted_credit;
+        if day.date >= today && !day.is_badged_in && !day.is_holiday && !day.is_vacation {
+            days_left += 1;
+        }
+    }
+
+    WeeklyProgress {
+        badged,
+        target,
+        days_left,
+    }
+}
+
+/// Badge tally for one ISO week of a period.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WeekSummary {
+    pub iso_year: i32,
+    pub iso_week: u32,
+    /// First and last workdays of the week that fall inside the period
+    pub first_day: NaiveDate,
+    pub last_day: NaiveDate,
+    /// Workdays that aren't holidays or vacation
+    pub working_days: i32,
+    pub badged: f64,
+}
+
+impl WeekSummary {
+    /// Office days this week needs to keep pace with `target`.
+    pub fn required(&self, target: TargetMode) -> f64 {
+        match target {
+            TargetMode::QuarterPercentage(pct) => self.working_days as f64 * pct / 100.0,
+            TargetMode::DaysPerWeek(n) => (n as i32).min(self.working_days) as f64,
+        }
+    }
+
+    /// True once every workday of the week is before `today` and the week
+    /// fell short of `target`.
+    pub fn missed(&self, target: TargetMode, today: NaiveDate) -> bool {
+        self.last_day < today && self.badged < self.required(target)
+    }
+}
+
+/// Groups a period's counted workdays by ISO week, in date order. A week's
+/// `badged` is the goal credit its days earned, so flex credit over the cap
+/// and non-qualifying offices add nothing.
+pub fn weekly_breakdown(stats: &QuarterStats) -> Vec<WeekSummary> {
+    let mut weeks: BTreeMap<(i32, u32), WeekSummary> = BTreeMap::new();
+    for day in stats.workday_stats.values().filter(|day| day.is_counted) {
+        let iso = day.date.iso_week();
+        let week = weeks
+            .entry((iso.year(), iso.week()))
+            .or_insert_with(|| WeekSummary {
+                iso_year: iso.year(),
+                iso_week: iso.week(),
+                first_day: day.date,
+                last_day: day.date,
+                working_days: 0,
+                badged: 0.0,
+            });
+        week.first_day = week.first_day.min(day.date);
+        week.last_day = week.last_day.max(day.date);
+        if day.is_holiday || day.is_vacation {
+            continue;
+        }
+        week.working_days += 1;
+        week.badged += day.counted_credit;
+    }
+    weeks.into_values().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calc::{StatsOptions, calculate_quarter_stats};
+    use crate::data::badge_entry::BadgeEntry;
+    use crate::data::holiday::Holiday;
+    use crate::data::{BadgeEntryData, HolidayData, TimePeriod, VacationData, WorkWeek};
+    use chrono::Weekday;
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).unwrap()
+    }
+
+    /// Stats for March 2025 as of `today`.
+    fn march_stats(
+        badges: &BadgeEntryData,
+        holidays: &HolidayData,
+        options: &StatsOptions,
+        today: NaiveDate,
+    ) -> QuarterStats {
+        let mut period = TimePeriod {
+            key: "T".to_string(),
+            name: "Test".to_string(),
+            start_date_raw: "2025-03-01".to_string(),
+            end_date_raw: "2025-03-31".to_string(),
+            ..Default::default()
+        };
+        period.parse_dates().unwrap();
+        calculate_quarter_stats(
+            &period,
+            badges,
+            holidays,
+            &VacationData::default(),
+            options,
+            Some(today),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_weekly_progress_mid_week() {
+        // Week of Mon 2025-03-10. Badged Mon + Tue (and last Friday, which
+        // belongs to the previous week); Friday is a holiday; today is Wed.
+        let mut badges = BadgeEntryData::default();
+        for day in [d(2025, 3, 7), d(2025, 3, 10), d(2025, 3, 11)] {
+            badges.add(BadgeEntry::new(day, "HQ", false));
+        }
+        let mut holidays = HolidayData::default();
+        holidays.add(Holiday::new("Office Closed", "2025-03-14"));
+        let today = d(2025, 3, 12);
+        let stats = march_stats(&badges, &holidays, &StatsOptions::default(), today);
+        let progress = weekly_progress(&stats, today, 3);
+        assert_eq!(progress.badged, 2.0);
+        assert_eq!(progress.remaining_to_target(), 1.0);
+        assert_eq!(progress.days_left, 2); // Wed, Thu
+    }
+
+    #[test]
+    fn test_weekly_progress_resets_on_monday() {
+        let mut badges = BadgeEntryData::default();
+        badges.add(BadgeEntry::new(d(2025, 3, 14), "HQ", false));
+        let today = d(2025, 3, 17);
+        let stats = march_stats(
+            &badges,
+            &HolidayData::default(),
+            &StatsOptions::default(),
+            today,
+        );
+        let progress = weekly_progress(&stats, today, 3);
+        assert_eq!(progress.badged, 0.0);
+        assert_eq!(progress.remaining_to_target(), 3.0);
+        assert_eq!(progress.days_left, 5);
+    }
+
+    #[test]
+    fn test_weekly_progress_over_target_and_weekend() {
+        let mut badges = BadgeEntryData::default();
+        for day in 10..=13 {
+            badges.add(BadgeEntry::new(d(2025, 3, day), "HQ", false));
+        }
+        let today = d(2025, 3, 15);
+        let stats = march_stats(
+            &badges,
+            &HolidayData::default(),
+            &StatsOptions::default(),
+            today,
+        );
+        let progress = weekly_progress(&stats, today, 3);
+        assert_eq!(progress.badged, 4.0);
+        assert_eq!(progress.remaining_to_target(), 0.0);
+        assert_eq!(progress.days_left, 0);
+    }
+
+    #[test]
+    fn test_weekly_progress_counts_saturday_in_six_day_week() {
+        let mut badges = BadgeEntryData::default();
+        badges.add(BadgeEntry::new(d(2025, 3, 15), "HQ", false));
+        let six_day = StatsOptions {
+            work_week: WorkWeek::from_days(&[
+                Weekday::Mon,
+                Weekday::Tue,
+                Weekday::Wed,
+                Weekday::Thu,
+                Weekday::Fri,
+                Weekday::Sat,
+            ]),
+            ..Default::default()
+        };
+        let today = d(2025, 3, 14);
+        let stats = march_stats(&badges, &HolidayData::default(), &six_day, today);
+        let progress = weekly_progress(&stats, today, 3);
+        assert_eq!(progress.badged, 1.0);
+        assert_eq!(progress.days_left, 1); // Fri; Sat is already badged
+
+        let stats = march_stats(
+            &badges,
+            &HolidayData::default(),
+            &StatsOptions::default(),
+            today,
+        );
+        assert_eq!(weekly_progress(&stats, today, 3).badged, 0.0);
+    }
+
+    #[test]
+    fn test_weekly_progress_uses_counted_credit() {
+        // One flex credit allowed; Tuesday's flex is over the cap
+        let mut badges = BadgeEntryData::default();
+        badges.add(BadgeEntry::new(d(2025, 3, 10), "Flex Credit", true));
+        badges.add(BadgeEntry::new(d(2025, 3, 11), "Flex Credit", true));
+        badges.add(BadgeEntry::new(d(2025, 3, 12), "HQ", false).with_credit(0.5));
+        let options = StatsOptions {
+            max_flex_credits: Some(1),
+            ..Default::default()
+        };
+        let today = d(2025, 3, 13);
+        let stats = march_stats(&badges, &HolidayData::default(), &options, today);
+        let progress = weekly_progress(&stats, today, 3);
+        assert_eq!(progress.badged, 1.5);
+        assert_eq!(progress.remaining_to_target(), 1.5);
+        assert_eq!(progress.days_left, 2); // Thu, Fri
+    }
+
+    #[test]
+    fn test_weekly_breakdown_groups_by_iso_week() {
+        // Mon 2025-03-03 through Wed 2025-03-19: weeks 10, 11, and a partial 12
+        let mut period = TimePeriod {
+            key: "T".to_string(),
+            name: "Test".to_string(),
+            start_date_raw: "2025-03-03".to_string(),
+            end_date_raw: "2025-03-19".to_string(),
+            ..Default::default()
+        };
+        period.parse_dates().unwrap();
+        let mut badges = BadgeEntryData::default();
+        for day in [3, 4, 5, 6, 10] {
+            badges.add(BadgeEntry::new(d(2025, 3, day), "HQ", false));
+        }
+        let mut holidays = HolidayData::default();
+        holidays.add(Holiday::new("Office Closed", "2025-03-14"));
+        let stats = calculate_quarter_stats(
+            &period,
+            &badges,
+            &holidays,
+            &VacationData::default(),
+            &StatsOptions {
+                target: 3.into(),
+                ..Default::default()
+            },
+            Some(d(2025, 3, 17)),
+        )
+        .unwrap();
+
+        let weeks = weekly_breakdown(&stats);
+        assert_eq!(weeks.len(), 3);
+        assert_eq!(weeks[0].iso_week, 10);
+        assert_eq!(weeks[0].first_day, d(2025, 3, 3));
+        assert_eq!(weeks[0].working_days, 5);
+        assert_eq!(weeks[0].badged, 4.0);
+        assert_eq!(weeks[1].working_days, 4); // Friday is a holiday
+        assert_eq!(weeks[1].badged, 1.0);
+        assert_eq!(weeks[2].last_day, d(2025, 3, 19));
+        assert_eq!(weeks[2].working_days, 3);
+
+        let target = TargetMode::DaysPerWeek(3);
+        let today = d(2025, 3, 17);
+        assert!(!weeks[0].missed(target, today));
+        assert!(weeks[1].missed(target, today));
+        // Still in progress
+        assert!(!weeks[2].missed(target, today));
+        assert_eq!(weeks[2].required(TargetMode::from(50)), 1.5);
+    }
+
+    #[test]
+    fn test_weekly_breakdown_sums_counted_credit() {
+        // Counting starts Wed 2025-03-05, so Monday's badge is outside the stats
+        let mut period = TimePeriod {
+            key: "T".to_string(),
+            name: "Test".to_string(),
+            start_date_raw: "2025-03-03".to_string(),
+            end_date_raw: "2025-03-14".to_string(),
+            effective_start_raw: Some("2025-03-05".to_string()),
+            ..Default::default()
+        };
+        period.parse_dates().unwrap();
+        let mut badges = BadgeEntryData::default();
+        badges.add(BadgeEntry::new(d(2025, 3, 3), "HQ", false));
+        badges.add(BadgeEntry::new(d(2025, 3, 6), "Flex Credit", true));
+        badges.add(BadgeEntry::new(d(2025, 3, 7), "Flex Credit", true)); // over the cap
+        let stats = calculate_quarter_stats(
+            &period,
+            &badges,
+            &HolidayData::default(),
+            &VacationData::default(),
+            &StatsOptions {
+                max_flex_credits: Some(1),
+                ..Default::default()
+            },
+            Some(d(2025, 3, 17)),
+        )
+        .unwrap();
+
+        let weeks = weekly_breakdown(&stats);
+        assert_eq!(weeks.len(), 2);
+        assert_eq!(weeks[0].first_day, d(2025, 3, 5));
+        assert_eq!(weeks[0].working_days, 3);
+        assert_eq!(weeks[0].badged, 1.0);
+        assert_eq!(weeks[0].badged, stats.days_badged_in);
+        assert_eq!(weeks[1].badged, 0.0);
+    }
+}
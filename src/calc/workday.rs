@@ -1,4 +1,5 @@
-use chrono::{Datelike, NaiveDate, Weekday};
+use crate::data::WorkWeek;
+use chrono::NaiveDate;
 use std::collections::HashMap;
 
 #[derive(Clone, Debug, Default)]
@@ -12,19 +13,38 @@ pub struct Workday {
     pub is_flex_credit: bool,
     pub is_holiday: bool,
     pub is_vacation: bool,
+    /// An excused absence; also marked `is_vacation`
+    pub is_excused: bool,
+    /// A day of a vacation that has not been approved yet. Also marked
+    /// `is_vacation` unless `count_unapproved_vacations` is off, in which
+    /// case it counts as an ordinary workday.
+    pub is_pending: bool,
+    /// Credit from the day's badge entry (0.0 when not badged)
+    pub credit: f64,
+    /// Inside the days the stats cover: on or after the period's effective
+    /// start and, for year stats, in an included period
+    pub is_counted: bool,
+    /// Goal credit the day earned: `credit` less any flex credit over the cap,
+    /// or 0.0 at a non-qualifying office or outside the counted days
+    pub counted_credit: f64,
 }
 
-/// Returns true for Monday–Friday, false for Saturday/Sunday.
-pub fn is_workday(date: NaiveDate) -> bool {
-    !matches!(date.weekday(), Weekday::Sat | Weekday::Sun)
+/// Returns true when `date` falls on one of the week's working days.
+pub fn is_workday(date: NaiveDate, week: WorkWeek) -> bool {
+    week.is_workday(date)
 }
 
-/// Builds a map of date-string -> Workday for all weekdays in [start, end] inclusive.
-pub fn create_workday_map(start: NaiveDate, end: NaiveDate) -> HashMap<String, Workday> {
+/// Builds a map of date-string -> Workday for every working day of `week`
+/// in [start, end] inclusive.
+pub fn create_workday_map(
+    start: NaiveDate,
+    end: NaiveDate,
+    week: WorkWeek,
+) -> HashMap<String, Workday> {
     let mut map = HashMap::new();
     let mut current = start;
     while current <= end {
-        if is_workday(current) {
+        if is_workday(current, week) {
             let key = current.format("%Y-%m-%d").to_string();
             map.insert(
                 key.clone(),
@@ -36,6 +56,11 @@ pub fn create_workday_map(start: NaiveDate, end: NaiveDate) -> HashMap<String, W
                     is_flex_credit: false,
                     is_holiday: false,
                     is_vacation: false,
+                    is_excused: false,
+                    is_pending: false,
+                    credit: 0.0,
+                    is_counted: false,
+                    counted_credit: 0.0,
                 },
             );
         }
@@ -47,27 +72,30 @@ pub fn create_workday_map(start: NaiveDate, end: NaiveDate) -> HashMap<String, W
 #[cfg(test)]
 mod tests {
     use super::*;
-    use chrono::NaiveDate;
+    use chrono::{NaiveDate, Weekday};
 
     #[test]
     fn test_is_workday_for_each_weekday() {
         // 2025-01-06 is Monday
         let monday = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
-        assert!(is_workday(monday));
-        assert!(is_workday(monday.succ_opt().unwrap())); // Tuesday
-        assert!(is_workday(monday.succ_opt().unwrap().succ_opt().unwrap())); // Wednesday
+        assert!(is_workday(monday, WorkWeek::default()));
+        assert!(is_workday(monday.succ_opt().unwrap(), WorkWeek::default())); // Tuesday
+        assert!(is_workday(
+            monday.succ_opt().unwrap().succ_opt().unwrap(),
+            WorkWeek::default()
+        )); // Wednesday
         let thursday = NaiveDate::from_ymd_opt(2025, 1, 9).unwrap();
         let friday = NaiveDate::from_ymd_opt(2025, 1, 10).unwrap();
-        assert!(is_workday(thursday));
-        assert!(is_workday(friday));
+        assert!(is_workday(thursday, WorkWeek::default()));
+        assert!(is_workday(friday, WorkWeek::default()));
     }
 
     #[test]
     fn test_is_not_workday_for_weekend() {
         let saturday = NaiveDate::from_ymd_opt(2025, 1, 11).unwrap();
         let sunday = NaiveDate::from_ymd_opt(2025, 1, 12).unwrap();
-        assert!(!is_workday(saturday));
-        assert!(!is_workday(sunday));
+        assert!(!is_workday(saturday, WorkWeek::default()));
+        assert!(!is_workday(sunday, WorkWeek::default()));
     }
 
     #[test]
@@ -75,17 +103,48 @@ mod tests {
         // 2025-01-06 (Mon) to 2025-01-12 (Sun) = 5 weekdays
         let start = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
         let end = NaiveDate::from_ymd_opt(2025, 1, 12).unwrap();
-        let map = create_workday_map(start, end);
+        let map = create_workday_map(start, end, WorkWeek::default());
         assert_eq!(map.len(), 5);
         assert!(!map.contains_key("2025-01-11")); // Saturday
         assert!(!map.contains_key("2025-01-12")); // Sunday
     }
 
+    #[test]
+    fn test_workday_map_with_saturday_work_week() {
+        // Tue–Sat week: 2025-01-06 (Mon) to 2025-01-12 (Sun)
+        let week = WorkWeek::from_days(&[
+            Weekday::Tue,
+            Weekday::Wed,
+            Weekday::Thu,
+            Weekday::Fri,
+            Weekday::Sat,
+        ]);
+        let start = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+        let end = NaiveDate::from_ymd_opt(2025, 1, 12).unwrap();
+        let map = create_workday_map(start, end, week);
+        assert_eq!(map.len(), 5);
+        assert!(map.contains_key("2025-01-11")); // Saturday
+        assert!(!map.contains_key("2025-01-06")); // Monday
+        assert!(!map.contains_key("2025-01-12")); // Sunday
+    }
+
+    #[test]
+    fn test_four_day_work_week() {
+        let week = WorkWeek::from_days(&[Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu]);
+        let start = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+        let end = NaiveDate::from_ymd_opt(2025, 1, 19).unwrap();
+        assert_eq!(create_workday_map(start, end, week).len(), 8);
+        assert!(!is_workday(
+            NaiveDate::from_ymd_opt(2025, 1, 10).unwrap(),
+            week
+        ));
+    }
+
     #[test]
     fn test_workday_map_inclusive_range() {
         let start = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap(); // Monday
         let end = NaiveDate::from_ymd_opt(2025, 1, 10).unwrap(); // Friday
-        let map = create_workday_map(start, end);
+        let map = create_workday_map(start, end, WorkWeek::default());
         assert_eq!(map.len(), 5);
         assert!(map.contains_key("2025-01-06"));
         assert!(map.contains_key("2025-01-10"));
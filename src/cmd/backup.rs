@@ -30,7 +30,7 @@ pub fn run(remote: Option<&str>, target_dir: &str) -> Result<()> {
     if result.is_error {
         anyhow::bail!("{}", result.message);
     }
-    println!("{}", result.message);
+    log::info!("{}", result.message);
     Ok(())
 }
 
@@ -144,6 +144,24 @@ pub fn status(dir: &Path) -> StatusInfo {
     info
 }
 
+/// Restores every tracked file in `dir` to its state at `git_ref` (default:
+/// `HEAD`) with `git checkout <ref> -- .`. Untracked files are left alone.
+pub fn restore(dir: &Path, git_ref: Option<&str>) -> Result<String> {
+    if !is_git_repo(dir) {
+        anyhow::bail!(
+            "{} is not a git repository; run `rto backup` first",
+            dir.display()
+        );
+    }
+    let git_ref = git_ref.unwrap_or("HEAD");
+    let commit = format!("{}^{{commit}}", git_ref);
+    run_git_silent(dir, &["rev-parse", "--verify", "--quiet", &commit])
+        .map_err(|_| anyhow::anyhow!("unknown commit or branch '{}'", git_ref))?;
+    run_git_silent(dir, &["checkout", git_ref, "--", "."])?;
+    let subject = run_git_output(dir, &["log", "-1", "--format=%h %s", git_ref])?;
+    Ok(format!("Restored data files from {}", subject.trim()))
+}
+
 fn is_git_repo(dir: &Path) -> bool {
     dir.join(".git").exists()
 }
@@ -317,6 +335,52 @@ mod tests {
         assert_eq!(info.untracked, 1);
     }
 
+    #[test]
+    fn test_restore_reverts_modified_file() {
+        if !has_git() {
+            return;
+        }
+        let tmp = tempfile::TempDir::new().unwrap();
+        let _ = run_git_silent(tmp.path(), &["init"]);
+        set_git_identity(tmp.path());
+        fs::write(tmp.path().join("data.txt"), "first").unwrap();
+        let first = perform(tmp.path(), "");
+        assert!(!first.is_error, "{}", first.message);
+        let first_commit = run_git_output(tmp.path(), &["rev-parse", "HEAD"]).unwrap();
+        fs::write(tmp.path().join("data.txt"), "second").unwrap();
+        let _ = run_git_silent(tmp.path(), &["commit", "-am", "second"]);
+
+        fs::write(tmp.path().join("data.txt"), "scribbled").unwrap();
+        let msg = restore(tmp.path(), None).unwrap();
+        assert!(msg.contains("second"), "got: {}", msg);
+        assert_eq!(
+            fs::read_to_string(tmp.path().join("data.txt")).unwrap(),
+            "second"
+        );
+        assert!(status(tmp.path()).clean);
+
+        restore(tmp.path(), Some(first_commit.trim())).unwrap();
+        assert_eq!(
+            fs::read_to_string(tmp.path().join("data.txt")).unwrap(),
+            "first"
+        );
+    }
+
+    #[test]
+    fn test_restore_rejects_unknown_ref_and_non_repo() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        assert!(restore(tmp.path(), None).is_err());
+        if !has_git() {
+            return;
+        }
+        let _ = run_git_silent(tmp.path(), &["init"]);
+        set_git_identity(tmp.path());
+        fs::write(tmp.path().join("data.txt"), "first").unwrap();
+        assert!(!perform(tmp.path(), "").is_error);
+        let err = restore(tmp.path(), Some("no-such-branch")).unwrap_err();
+        assert!(err.to_string().contains("no-such-branch"));
+    }
+
     #[test]
     fn test_perform_with_remote() {
         if !has_git() {
@@ -0,0 +1,337 @@
+use crate::calc::workday::is_workday;
+use crate::cmd::restore::confirm;
+use crate::data::{
+    AppSettings, BadgeEntry, BadgeEntryData, Persistable, TimePeriod, TimePeriodData, WorkWeek,
+};
+use anyhow::{Context, Result, bail};
+use chrono::NaiveDate;
+
+/// What `rto badge` should do with the given date.
+pub(crate) enum BadgeAction {
+    /// Badge in at an office for the given credit (replaces any existing entry)
+    Office(String, f64),
+    /// Record a flex credit with the given label and credit (replaces any existing entry)
+    Flex(String, f64),
+    /// Clear the date's entry
+    Remove,
+    /// Report whether the date is badged
+    Status,
+}
+
+pub fn run(
+    date: &str,
+    office: Option<&str>,
+    flex: bool,
+    remove: bool,
+    out: bool,
+    force: bool,
+    credit: f64,
+) -> Result<()> {
+    let settings = AppSettings::load()?;
+    let action = if remove {
+        BadgeAction::Remove
+    } else if out {
+        BadgeAction::Status
+    } else if flex {
+        BadgeAction::Flex(settings.flex_credit.clone(), credit)
+    } else {
+        BadgeAction::Office(
+            office.unwrap_or(&settings.default_office).to_string(),
+            credit,
+        )
+    };
+
+    let read_only = matches!(action, BadgeAction::Status);
+    let mut badge_data = BadgeEntryData::load()?;
+    let message = apply_badge(&mut badge_data, date, &action, settings.work_week(), force)?;
+    if !read_only {
+        badge_data.save(settings.backup_count)?;
+    }
+    println!("{}", message);
+    Ok(())
+}
+
+/// Relabels every flex entry with the flex label now in effect for its date:
+/// the period's `flex_credit` override, else the `flex_credit` setting.
+pub fn run_relabel_flex(dry_run: bool) -> Result<()> {
+    let settings = AppSettings::load()?;
+    let td = TimePeriodData::load()?;
+    let mut badge_data = BadgeEntryData::load()?;
+    let changed = badge_data.relabel_flex(|date| {
+        td.get_period_by_date(date)
+            .and_then(|p| p.flex_credit.clone())
+            .unwrap_or_else(|| settings.flex_credit.clone())
+    });
+    if dry_run {
+        println!("Would relabel {} flex entries.", changed);
+    } else {
+        if changed > 0 {
+            badge_data.save(settings.backup_count)?;
+        }
+        println!("Relabeled {} flex entries.", changed);
+    }
+    Ok(())
+}
+
+/// Removes every badge entry dated within a period, after confirming unless
+/// `yes` is set.
+pub fn run_clear(period_key: &str, yes: bool) -> Result<()> {
+    let settings = AppSettings::load()?;
+    let td = TimePeriodData::load()?;
+    let Some(period) = td.get_period_by_key(period_key) else {
+        bail!(
+            "Period key '{}' not found — run 'rto init' to create data files",
+            period_key
+        );
+    };
+    let mut badge_data = BadgeEntryData::load()?;
+    let count = badge_data
+        .data
+        .iter()
+        .filter(|e| in_period(e, period))
+        .count();
+    if count == 0 {
+        println!("No badge entries in {}.", period.key);
+        return Ok(());
+    }
+    if !yes {
+        let prompt = format!(
+            "Remove {} badge entries in {} ({} – {})?",
+            count, period.key, period.start_date_raw, period.end_date_raw
+        );
+        let stdin = std::io::stdin();
+        if !confirm(&mut stdin.lock(), &mut std::io::stdout(), &prompt)? {
+            println!("Clear cancelled.");
+            return Ok(());
+        }
+    }
+    let removed = clear_period(&mut badge_data, period);
+    badge_data.save(settings.backup_count)?;
+    println!(
+        "Removed {} badge entries from {}. {} entries remain.",
+        removed,
+        period.key,
+        badge_data.len()
+    );
+    Ok(())
+}
+
+/// Removes every entry dated within `period`, returning how many were
+/// removed. Entries with unreadable dates are kept.
+pub(crate) fn clear_period(data: &mut BadgeEntryData, period: &TimePeriod) -> usize {
+    let before = data.data.len();
+    data.data.retain(|e| !in_period(e, period));
+    before - data.data.len()
+}
+
+fn in_period(entry: &BadgeEntry, period: &TimePeriod) -> bool {
+    entry
+        .entry_date()
+        .is_some_and(|date| period.is_date_in_range(date))
+}
+
+/// Applies `action` to `date` and returns a human-readable summary. Adding is
+/// idempotent: an existing entry for the date is replaced rather than duplicated.
+pub(crate) fn apply_badge(
+    data: &mut BadgeEntryData,
+    date: &str,
+    action: &BadgeAction,
+    week: WorkWeek,
+    force: bool,
+) -> Result<String> {
+    let day = NaiveDate::parse_from_str(date, "%Y-%m-%d")
+        .with_context(|| format!("invalid date '{}' (use YYYY-MM-DD)", date))?;
+    let key = day.format("%Y-%m-%d").to_string();
+
+    let message = match action {
+        BadgeAction::Status => match data.get(&key) {
+            Some(e) if e.is_flex_credit => format!("{}: flex credit ({})", key, e.office),
+            Some(e) => format!("{}: badged in at {}", key, e.office),
+            None => format!("{}: not badged", key),
+        },
+        BadgeAction::Remove => {
+            if data.has(&key) {
+                data.remove(&key);
+                format!("{}: badge removed", key)
+            } else {
+                format!("{}: not badged; nothing to remove", key)
+            }
+        }
+        BadgeAction::Office(label, credit) | BadgeAction::Flex(label, credit) => {
+            if !force && !is_workday(day, week) {
+                bail!(
+                    "{} is not a work day ({}); use --force to badge it anyway",
+                    key,
+                    day.format("%A")
+                );
+            }
+            if !(*credit > 0.0 && *credit <= 1.0) {
+                bail!(
+                    "credit must be greater than 0 and at most 1 (got {})",
+                    credit
+                );
+            }
+            let is_flex = matches!(action, BadgeAction::Flex(..));
+            let verb = if data.has(&key) { "updated" } else { "added" };
+            data.remove(&key);
+            data.add(BadgeEntry::new(day, label, is_flex).with_credit(*credit));
+            let kind = if is_flex { "flex credit" } else { "badge" };
+            if *credit < 1.0 {
+                format!("{}: {} {} ({}, {} day)", key, kind, verb, label, credit)
+            } else {
+                format!("{}: {} {} ({})", key, kind, verb, label)
+            }
+        }
+    };
+    Ok(message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clear_period_removes_only_entries_inside_it() {
+        let mut period = TimePeriod {
+            key: "Q1_2025".to_string(),
+            name: "Q1".to_string(),
+            start_date_raw: "2025-01-01".to_string(),
+            end_date_raw: "2025-03-31".to_string(),
+            ..Default::default()
+        };
+        period.parse_dates().unwrap();
+        let mut data = BadgeEntryData::default();
+        for (y, m, d) in [
+            (2024, 12, 31),
+            (2025, 1, 1),
+            (2025, 2, 14),
+            (2025, 3, 31),
+            (2025, 4, 1),
+        ] {
+            data.add(BadgeEntry::new(
+                NaiveDate::from_ymd_opt(y, m, d).unwrap(),
+                "HQ",
+                false,
+            ));
+        }
+        data.data.push(BadgeEntry {
+            key: "garbage".to_string(),
+            ..BadgeEntry::new(NaiveDate::from_ymd_opt(2025, 2, 3).unwrap(), "HQ", false)
+        });
+
+        assert_eq!(clear_period(&mut data, &period), 3);
+        let keys: Vec<&str> = data.data.iter().map(|e| e.key.as_str()).collect();
+        assert_eq!(keys, ["2024-12-31", "2025-04-01", "garbage"]);
+        assert_eq!(clear_period(&mut data, &period), 0);
+    }
+
+    #[test]
+    fn test_badge_office_then_update_is_idempotent() {
+        let mut data = BadgeEntryData::default();
+        let office = BadgeAction::Office("McLean, VA".to_string(), 1.0);
+        let msg =
+            apply_badge(&mut data, "2025-03-14", &office, WorkWeek::default(), false).unwrap();
+        assert!(msg.contains("added"));
+
+        let office = BadgeAction::Office("Reston, VA".to_string(), 1.0);
+        let msg =
+            apply_badge(&mut data, "2025-03-14", &office, WorkWeek::default(), false).unwrap();
+        assert!(msg.contains("updated"));
+        assert_eq!(data.len(), 1);
+        assert_eq!(data.get("2025-03-14").unwrap().office, "Reston, VA");
+    }
+
+    #[test]
+    fn test_badge_flex_replaces_office() {
+        let mut data = BadgeEntryData::default();
+        let office = BadgeAction::Office("HQ".to_string(), 1.0);
+        apply_badge(&mut data, "2025-03-14", &office, WorkWeek::default(), false).unwrap();
+        let flex = BadgeAction::Flex("Flex Credit".to_string(), 1.0);
+        apply_badge(&mut data, "2025-03-14", &flex, WorkWeek::default(), false).unwrap();
+        assert_eq!(data.len(), 1);
+        assert!(data.get("2025-03-14").unwrap().is_flex_credit);
+    }
+
+    #[test]
+    fn test_badge_remove_and_status() {
+        let mut data = BadgeEntryData::default();
+        let office = BadgeAction::Office("HQ".to_string(), 1.0);
+        apply_badge(&mut data, "2025-03-14", &office, WorkWeek::default(), false).unwrap();
+        let msg = apply_badge(
+            &mut data,
+            "2025-03-14",
+            &BadgeAction::Status,
+            WorkWeek::default(),
+            false,
+        )
+        .unwrap();
+        assert_eq!(msg, "2025-03-14: badged in at HQ");
+
+        apply_badge(
+            &mut data,
+            "2025-03-14",
+            &BadgeAction::Remove,
+            WorkWeek::default(),
+            false,
+        )
+        .unwrap();
+        assert!(data.is_empty());
+        let msg = apply_badge(
+            &mut data,
+            "2025-03-14",
+            &BadgeAction::Status,
+            WorkWeek::default(),
+            false,
+        )
+        .unwrap();
+        assert_eq!(msg, "2025-03-14: not badged");
+    }
+
+    #[test]
+    fn test_badge_rejects_bad_date_and_non_work_day() {
+        let mut data = BadgeEntryData::default();
+        let office = || BadgeAction::Office("HQ".to_string(), 1.0);
+        assert!(
+            apply_badge(
+                &mut data,
+                "03/14/2025",
+                &office(),
+                WorkWeek::default(),
+                false
+            )
+            .is_err()
+        );
+        let err = apply_badge(
+            &mut data,
+            "2025-03-15",
+            &office(),
+            WorkWeek::default(),
+            false,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("not a work day (Saturday)"));
+        assert!(data.is_empty());
+
+        apply_badge(
+            &mut data,
+            "2025-03-15",
+            &office(),
+            WorkWeek::default(),
+            true,
+        )
+        .unwrap();
+        assert_eq!(data.len(), 1);
+    }
+
+    #[test]
+    fn test_badge_half_day_credit() {
+        let mut data = BadgeEntryData::default();
+        let half = BadgeAction::Office("HQ".to_string(), 0.5);
+        let msg = apply_badge(&mut data, "2025-03-14", &half, WorkWeek::default(), false).unwrap();
+        assert!(msg.contains("0.5 day"));
+        assert_eq!(data.get("2025-03-14").unwrap().credit, 0.5);
+
+        let bad = BadgeAction::Office("HQ".to_string(), 1.5);
+        assert!(apply_badge(&mut data, "2025-03-14", &bad, WorkWeek::default(), false).is_err());
+    }
+}
@@ -0,0 +1,715 @@
+use crate::calc::workday::is_workday;
+use crate::calc::{StatsOptions, calculate_quarter_stats};
+use crate::data::holiday::observed_date;
+use crate::data::persistence::get_data_dir;
+use crate::data::{
+    AppSettings, BadgeEntryData, EventData, HolidayData, Persistable, TimePeriod, TimePeriodData,
+    VacationData, WorkWeek,
+};
+use crate::ui::theme::THEME_NAMES;
+use anyhow::{Result, bail};
+use chrono::NaiveDate;
+use std::collections::HashMap;
+use std::fmt;
+use std::path::Path;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Severity {
+    /// Data that is silently dropped or miscounted
+    Error,
+    /// Data that is allowed but probably a mistake
+    Warning,
+}
+
+/// One problem found in a data file.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Diagnostic {
+    pub severity: Severity,
+    pub file: String,
+    pub message: String,
+}
+
+impl Diagnostic {
+    fn error(file: &str, message: String) -> Self {
+        Diagnostic {
+            severity: Severity::Error,
+            file: file.to_string(),
+            message,
+        }
+    }
+
+    fn warning(file: &str, message: String) -> Self {
+        Diagnostic {
+            severity: Severity::Warning,
+            file: file.to_string(),
+            message,
+        }
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let level = match self.severity {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        };
+        write!(f, "{}: {}: {}", level, self.file, self.message)
+    }
+}
+
+pub fn run() -> Result<()> {
+    let diagnostics = diagnose(&get_data_dir()?);
+    for d in &diagnostics {
+        println!("{}", d);
+    }
+    let errors = diagnostics
+        .iter()
+        .filter(|d| d.severity == Severity::Error)
+        .count();
+    let warnings = diagnostics.len() - errors;
+    if diagnostics.is_empty() {
+        println!("No problems found.");
+    } else if errors > 0 {
+        bail!("{} error(s), {} warning(s)", errors, warnings);
+    } else {
+        println!("{} warning(s), no errors.", warnings);
+    }
+    Ok(())
+}
+
+/// Loads every data file in `dir` and returns all problems found. A file that
+/// cannot be read or parsed at all is reported as a single error.
+pub(crate) fn diagnose(dir: &Path) -> Vec<Diagnostic> {
+    let mut out = Vec::new();
+
+    let settings_file = AppSettings::filename_in(dir);
+    let settings = match AppSettings::load_from(dir) {
+        Ok(s) => s,
+        Err(e) => {
+            out.push(Diagnostic::error(settings_file, format!("{:#}", e)));
+            AppSettings::default()
+        }
+    };
+    if !THEME_NAMES.contains(&settings.theme.to_ascii_lowercase().as_str()) {
+        out.push(Diagnostic::warning(
+            settings_file,
+            format!(
+                "unknown theme '{}' (using dark; choose from {})",
+                settings.theme,
+                THEME_NAMES.join(", ")
+            ),
+        ));
+    }
+    let mut tp_files: Vec<&str> = settings.time_periods.iter().map(String::as_str).collect();
+    if tp_files.is_empty() {
+        tp_files.push(settings.active_time_period_file(0));
+    }
+    let mut periods = Vec::new();
+    for file in tp_files {
+        match TimePeriodData::load_raw_from(dir, file) {
+            Ok(td) => {
+                out.extend(check_time_periods(file, &td.all()));
+                periods.extend(td.all());
+            }
+            Err(e) => out.push(Diagnostic::error(file, format!("{:#}", e))),
+        }
+    }
+
+    let holidays = match HolidayData::load_from(dir) {
+        Ok(data) => {
+            out.extend(check_holidays(
+                &data,
+                settings.work_week(),
+                settings.observe_weekend_holidays,
+            ));
+            data
+        }
+        Err(e) => {
+            out.push(Diagnostic::error(
+                &HolidayData::resolved_filename(),
+                format!("{:#}", e),
+            ));
+            HolidayData::default()
+        }
+    };
+    match VacationData::load_from(dir) {
+        Ok(data) => out.extend(check_vacations(&data)),
+        Err(e) => out.push(Diagnostic::error(
+            &VacationData::resolved_filename(),
+            format!("{:#}", e),
+        )),
+    }
+    match EventData::load_from(dir) {
+        Ok(data) => out.extend(check_events(&data)),
+        Err(e) => out.push(Diagnostic::error(
+            &EventData::resolved_filename(),
+            format!("{:#}", e),
+        )),
+    }
+    match BadgeEntryData::load_raw_from(dir) {
+        Ok(data) => {
+            out.extend(check_badges(&data, settings.work_week()));
+            out.extend(check_ignored_badges(&periods, &data, &holidays, &settings));
+            out.extend(check_orphan_badges(&periods, &data));
+        }
+        Err(e) => out.push(Diagnostic::error(
+            &BadgeEntryData::resolved_filename(),
+            format!("{:#}", e),
+        )),
+    }
+    out
+}
+
+/// One warning per period whose stats ignore badge entries on weekends or
+/// holidays. Periods with bad dates are skipped; `check_time_periods`
+/// already reports them.
+pub(crate) fn check_ignored_badges(
+    periods: &[TimePeriod],
+    badges: &BadgeEntryData,
+    holidays: &HolidayData,
+    settings: &AppSettings,
+) -> Vec<Diagnostic> {
+    let file = &BadgeEntryData::resolved_filename();
+    let options = StatsOptions::from(settings);
+    let mut out = Vec::new();
+    for period in periods {
+        let mut period = period.clone();
+        if period.parse_dates().is_err() {
+            continue;
+        }
+        let Ok(stats) = calculate_quarter_stats(
+            &period,
+            badges,
+            holidays,
+            &VacationData::default(),
+            &options,
+            None,
+        ) else {
+            continue;
+        };
+        if stats.ignored_badge_entries > 0 {
+            out.push(Diagnostic::warning(
+                file,
+                format!(
+                    "{}: {} badge entr{} on weekends or holidays ignored by stats",
+                    period.key,
+                    stats.ignored_badge_entries,
+                    if stats.ignored_badge_entries == 1 {
+                        "y"
+                    } else {
+                        "ies"
+                    }
+                ),
+            ));
+        }
+    }
+    out
+}
+
+/// Date keys of badge entries that fall outside every period in `periods`,
+/// oldest first. Stats never see these. Entries with unreadable dates are
+/// left to `check_badges`.
+pub(crate) fn orphan_badges(badge: &BadgeEntryData, periods: &TimePeriodData) -> Vec<String> {
+    let mut keys: Vec<String> = badge
+        .data
+        .iter()
+        .filter(|e| {
+            e.entry_date()
+                .is_some_and(|date| periods.get_period_by_date(date).is_none())
+        })
+        .map(|e| e.key.clone())
+        .collect();
+    keys.sort();
+    keys.dedup();
+    keys
+}
+
+/// "N badge entries outside every configured period: …", listing up to ten
+/// date keys.
+pub(crate) fn orphan_summary(keys: &[String]) -> String {
+    const SHOWN: usize = 10;
+    let mut list = keys[..keys.len().min(SHOWN)].join(", ");
+    if keys.len() > SHOWN {
+        list.push_str(&format!(" and {} more", keys.len() - SHOWN));
+    }
+    format!(
+        "{} badge entr{} outside every configured period, not counted in any stats: {}",
+        keys.len(),
+        if keys.len() == 1 { "y" } else { "ies" },
+        list
+    )
+}
+
+/// A warning for badge entries no period covers, across every time period
+/// file. Periods with bad dates are skipped; `check_time_periods` already
+/// reports them.
+pub(crate) fn check_orphan_badges(
+    periods: &[TimePeriod],
+    badges: &BadgeEntryData,
+) -> Vec<Diagnostic> {
+    let mut parsed = TimePeriodData::new();
+    for period in periods {
+        let mut period = period.clone();
+        if period.parse_dates().is_ok() {
+            parsed.add(period);
+        }
+    }
+    let keys = orphan_badges(badges, &parsed);
+    if keys.is_empty() {
+        return Vec::new();
+    }
+    vec![Diagnostic::warning(
+        &BadgeEntryData::resolved_filename(),
+        orphan_summary(&keys),
+    )]
+}
+
+fn parse_date(s: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(s, "%Y-%m-%d").ok()
+}
+
+pub(crate) fn check_time_periods(file: &str, periods: &[TimePeriod]) -> Vec<Diagnostic> {
+    let mut out = Vec::new();
+    let mut ranges = Vec::new();
+    for tp in periods {
+        let start = parse_date(&tp.start_date_raw);
+        let end = parse_date(&tp.end_date_raw);
+        if start.is_none() {
+            out.push(Diagnostic::error(
+                file,
+                format!("{}: unparseable start_date '{}'", tp.key, tp.start_date_raw),
+            ));
+        }
+        if end.is_none() {
+            out.push(Diagnostic::error(
+                file,
+                format!("{}: unparseable end_date '{}'", tp.key, tp.end_date_raw),
+            ));
+        }
+        if let (Some(s), Some(e)) = (start, end) {
+            if e < s {
+                out.push(Diagnostic::error(
+                    file,
+                    format!("{}: end_date {} is before start_date {}", tp.key, e, s),
+                ));
+            } else {
+                ranges.push((tp.key.as_str(), s, e));
+            }
+        }
+    }
+    for (i, (a_key, a_start, a_end)) in ranges.iter().enumerate() {
+        for (b_key, b_start, b_end) in &ranges[i + 1..] {
+            if a_start <= b_end && b_start <= a_end {
+                out.push(Diagnostic::error(
+                    file,
+                    format!(
+                        "{} ({} to {}) overlaps {} ({} to {})",
+                        a_key, a_start, a_end, b_key, b_start, b_end
+                    ),
+                ));
+            }
+        }
+    }
+    out
+}
+
+/// Flags unparseable holiday dates, and holidays on non-work days, which
+/// reduce no workdays unless `observe_weekends` shifts them to a weekday.
+pub(crate) fn check_holidays(
+    data: &HolidayData,
+    week: WorkWeek,
+    observe_weekends: bool,
+) -> Vec<Diagnostic> {
+    let file = &HolidayData::resolved_filename();
+    let mut out = Vec::new();
+    for h in &data.holidays {
+        let Some(date) = parse_date(&h.date) else {
+            out.push(Diagnostic::error(
+                file,
+                format!("{}: unparseable date '{}'", h.name, h.date),
+            ));
+            continue;
+        };
+        if is_workday(date, week) {
+            continue;
+        }
+        match observed_date(&h.date, week) {
+            Some(_) if observe_weekends => {}
+            Some(observed) => out.push(Diagnostic::warning(
+                file,
+                format!(
+                    "{}: {} is a {} and reduces no workdays (set observe_weekend_holidays to observe it on {})",
+                    h.name,
+                    h.date,
+                    date.format("%A"),
+                    observed.format("%A %Y-%m-%d")
+                ),
+            )),
+            None => out.push(Diagnostic::warning(
+                file,
+                format!(
+                    "{}: {} is a {}, not a work day, and reduces no workdays",
+                    h.name,
+                    h.date,
+                    date.format("%A")
+                ),
+            )),
+        }
+    }
+    out
+}
+
+pub(crate) fn check_vacations(data: &VacationData) -> Vec<Diagnostic> {
+    let file = &VacationData::resolved_filename();
+    let mut out = Vec::new();
+    for v in &data.vacations {
+        let start = parse_date(&v.start_date);
+        let end = parse_date(&v.end_date);
+        if start.is_none() {
+            out.push(Diagnostic::error(
+                file,
+                format!(
+                    "{}: unparseable start_date '{}'",
+                    v.destination, v.start_date
+                ),
+            ));
+        }
+        if end.is_none() {
+            out.push(Diagnostic::error(
+                file,
+                format!("{}: unparseable end_date '{}'", v.destination, v.end_date),
+            ));
+        }
+        if let (Some(s), Some(e)) = (start, end)
+            && e < s
+        {
+            out.push(Diagnostic::error(
+                file,
+                format!(
+                    "{}: start_date {} is after end_date {}",
+                    v.destination, s, e
+                ),
+            ));
+        }
+    }
+    for (i, j) in data.find_overlaps() {
+        let a = &data.vacations[i];
+        let b = &data.vacations[j];
+        out.push(Diagnostic::warning(
+            file,
+            format!(
+                "#{} {} ({} to {}) overlaps #{} {} ({} to {})",
+                i + 1,
+                a.destination,
+                a.start_date,
+                a.end_date,
+                j + 1,
+                b.destination,
+                b.start_date,
+                b.end_date
+            ),
+        ));
+    }
+    out
+}
+
+pub(crate) fn check_events(data: &EventData) -> Vec<Diagnostic> {
+    let file = &EventData::resolved_filename();
+    data.events
+        .iter()
+        .filter(|e| parse_date(&e.date).is_none())
+        .map(|e| {
+            Diagnostic::error(
+                file,
+                format!("{}: unparseable date '{}'", e.description, e.date),
+            )
+        })
+        .collect()
+}
+
+pub(crate) fn check_badges(data: &BadgeEntryData, week: WorkWeek) -> Vec<Diagnostic> {
+    let file = &BadgeEntryData::resolved_filename();
+    let mut out = Vec::new();
+    let mut seen: HashMap<&str, usize> = HashMap::new();
+    for e in &data.data {
+        *seen.entry(e.key.as_str()).or_default() += 1;
+        match parse_date(&e.key) {
+            None => out.push(Diagnostic::error(
+                file,
+                format!("unparseable entry_date '{}'", e.key),
+            )),
+            Some(day) if !is_workday(day, week) => out.push(Diagnostic::warning(
+                file,
+                format!(
+                    "{}: badge entry on a non-work day ({})",
+                    e.key,
+                    day.format("%A")
+                ),
+            )),
+            Some(_) => {}
+        }
+    }
+    let mut dupes: Vec<_> = seen.into_iter().filter(|&(_, n)| n > 1).collect();
+    dupes.sort();
+    for (key, n) in dupes {
+        out.push(Diagnostic::error(
+            file,
+            format!("{}: duplicate entry_date ({} entries)", key, n),
+        ));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::vacation::Vacation;
+    use crate::data::{BadgeEntry, Event, Holiday};
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).unwrap()
+    }
+
+    fn period(key: &str, start: &str, end: &str) -> TimePeriod {
+        TimePeriod {
+            key: key.to_string(),
+            name: key.to_string(),
+            start_date_raw: start.to_string(),
+            end_date_raw: end.to_string(),
+            start_date: None,
+            end_date: None,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_time_periods_bad_date_and_overlap() {
+        let periods = vec![
+            period("Q1", "2025-01-01", "2025-03-31"),
+            period("Q2", "2025-03-15", "2025-06-30"),
+            period("Q3", "2025-07-01", "2025-09-31"),
+        ];
+        let diags = check_time_periods("quarters.yaml", &periods);
+        assert_eq!(diags.len(), 2);
+        assert_eq!(
+            diags[0].to_string(),
+            "error: quarters.yaml: Q3: unparseable end_date '2025-09-31'"
+        );
+        assert!(
+            diags[1]
+                .message
+                .starts_with("Q1 (2025-01-01 to 2025-03-31) overlaps Q2")
+        );
+    }
+
+    #[test]
+    fn test_weekend_holiday_warns_unless_observed() {
+        let mut holidays = HolidayData::default();
+        holidays.add(Holiday::new("Independence Day", "2026-07-04"));
+        let diags = check_holidays(&holidays, WorkWeek::default(), false);
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].severity, Severity::Warning);
+        assert!(diags[0].message.contains("is a Saturday"));
+        assert!(diags[0].message.contains("Friday 2026-07-03"));
+
+        assert!(check_holidays(&holidays, WorkWeek::default(), true).is_empty());
+    }
+
+    #[test]
+    fn test_holiday_and_event_bad_dates() {
+        let mut holidays = HolidayData::default();
+        holidays.add(Holiday::new("New Year", "2025-01-01"));
+        holidays.add(Holiday::new("Typo Day", "2025-13-01"));
+        let diags = check_holidays(&holidays, WorkWeek::default(), false);
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].file, "holidays.yaml");
+        assert!(diags[0].message.contains("'2025-13-01'"));
+
+        let mut events = EventData::default();
+        events.events.push(Event::new("March 3", "Offsite"));
+        let diags = check_events(&events);
+        assert_eq!(diags.len(), 1);
+        assert!(
+            diags[0]
+                .message
+                .contains("Offsite: unparseable date 'March 3'")
+        );
+    }
+
+    #[test]
+    fn test_vacation_start_after_end_and_overlap() {
+        let mut data = VacationData::default();
+        data.add(Vacation::new("Backwards", "2025-05-10", "2025-05-01", true));
+        data.add(Vacation::new("Hawaii", "2025-06-10", "2025-06-17", true));
+        data.add(Vacation::new("Paris", "2025-06-15", "2025-06-20", true));
+        let diags = check_vacations(&data);
+        assert_eq!(diags.len(), 2);
+        assert_eq!(diags[0].severity, Severity::Error);
+        assert!(
+            diags[0]
+                .message
+                .contains("start_date 2025-05-10 is after end_date")
+        );
+        assert_eq!(diags[1].severity, Severity::Warning);
+        assert!(diags[1].message.contains("#2 Hawaii"));
+    }
+
+    #[test]
+    fn test_badges_duplicates_and_non_work_days() {
+        let mut data = BadgeEntryData::default();
+        // `add` replaces same-date entries, so build the duplicate directly
+        data.data.push(BadgeEntry::new(d(2025, 3, 14), "HQ", false));
+        data.data.push(BadgeEntry::new(d(2025, 3, 14), "HQ", false));
+        data.add(BadgeEntry::new(d(2025, 3, 15), "HQ", false));
+        let diags = check_badges(&data, WorkWeek::default());
+        assert_eq!(diags.len(), 2);
+        assert_eq!(
+            diags[0].to_string(),
+            "warning: badge_data.json: 2025-03-15: badge entry on a non-work day (Saturday)"
+        );
+        assert_eq!(
+            diags[1].to_string(),
+            "error: badge_data.json: 2025-03-14: duplicate entry_date (2 entries)"
+        );
+    }
+
+    #[test]
+    fn test_ignored_badges_counted_per_period() {
+        let periods = vec![
+            period("Q1", "2025-01-01", "2025-03-31"),
+            period("Q2", "2025-04-01", "2025-06-30"),
+            period("Bad", "2025-13-01", "2025-12-31"),
+        ];
+        let mut badges = BadgeEntryData::default();
+        badges.add(BadgeEntry::new(d(2025, 3, 14), "HQ", false)); // Friday
+        badges.add(BadgeEntry::new(d(2025, 3, 15), "HQ", false)); // Saturday
+        badges.add(BadgeEntry::new(d(2025, 1, 1), "HQ", false)); // holiday
+        let mut holidays = HolidayData::default();
+        holidays.add(Holiday::new("New Year's Day", "2025-01-01"));
+        let diags = check_ignored_badges(&periods, &badges, &holidays, &AppSettings::default());
+        assert_eq!(diags.len(), 1);
+        assert_eq!(
+            diags[0].to_string(),
+            "warning: badge_data.json: Q1: 2 badge entries on weekends or holidays ignored by stats"
+        );
+
+        // Saturday May 31's holiday is observed on Friday May 30
+        badges.add(BadgeEntry::new(d(2025, 5, 30), "HQ", false));
+        holidays.add(Holiday::new("Closed", "2025-05-31"));
+        let diags = check_ignored_badges(&periods, &badges, &holidays, &AppSettings::default());
+        assert_eq!(diags.len(), 1);
+        let observing = AppSettings {
+            observe_weekend_holidays: true,
+            ..Default::default()
+        };
+        let diags = check_ignored_badges(&periods, &badges, &holidays, &observing);
+        assert_eq!(diags.len(), 2);
+        assert_eq!(
+            diags[1].to_string(),
+            "warning: badge_data.json: Q2: 1 badge entry on weekends or holidays ignored by stats"
+        );
+    }
+
+    #[test]
+    fn test_orphan_badges_lists_dates_outside_every_period() {
+        let mut periods = TimePeriodData::new();
+        let mut q1 = period("Q1", "2025-01-01", "2025-03-31");
+        q1.parse_dates().unwrap();
+        periods.add(q1);
+        let mut badges = BadgeEntryData::default();
+        badges.add(BadgeEntry::new(d(2025, 3, 14), "HQ", false));
+        badges.add(BadgeEntry::new(d(2025, 4, 1), "HQ", false));
+        assert_eq!(orphan_badges(&badges, &periods), ["2025-04-01"]);
+    }
+
+    #[test]
+    fn test_check_orphan_badges_warns_once_and_ignores_bad_periods() {
+        let periods = vec![
+            period("Q1", "2025-01-01", "2025-03-31"),
+            period("Bad", "2025-13-01", "2025-12-31"),
+        ];
+        let mut badges = BadgeEntryData::default();
+        badges.add(BadgeEntry::new(d(2025, 3, 14), "HQ", false));
+        assert!(check_orphan_badges(&periods, &badges).is_empty());
+
+        badges.add(BadgeEntry::new(d(2025, 4, 2), "HQ", false));
+        badges.add(BadgeEntry::new(d(2025, 4, 1), "HQ", false));
+        let diags = check_orphan_badges(&periods, &badges);
+        assert_eq!(diags.len(), 1);
+        assert_eq!(
+            diags[0].to_string(),
+            "warning: badge_data.json: 2 badge entries outside every configured period, \
+             not counted in any stats: 2025-04-01, 2025-04-02"
+        );
+    }
+
+    #[test]
+    fn test_orphan_summary_truncates_long_lists() {
+        let keys: Vec<String> = (1..=12).map(|day| format!("2026-01-{:02}", day)).collect();
+        let summary = orphan_summary(&keys);
+        assert!(summary.starts_with("12 badge entries"));
+        assert!(summary.ends_with("2026-01-10 and 2 more"), "{summary}");
+    }
+
+    #[test]
+    fn test_diagnose_reads_every_file() {
+        let dir = tempfile::tempdir().unwrap();
+        crate::cmd::init::run_in_dir(dir.path()).unwrap();
+        assert!(diagnose(dir.path()).is_empty());
+
+        std::fs::write(
+            dir.path().join("holidays.yaml"),
+            "holidays:\n  - name: \"Typo\"\n    date: \"2025-02-30\"\n",
+        )
+        .unwrap();
+        std::fs::write(dir.path().join("events.json"), "{ not json").unwrap();
+        let diags = diagnose(dir.path());
+        assert_eq!(diags.len(), 2);
+        assert!(diags.iter().all(|d| d.severity == Severity::Error));
+        assert!(diags.iter().any(|d| d.file == "holidays.yaml"));
+        assert!(diags.iter().any(|d| d.file == "events.json"));
+    }
+
+    #[test]
+    fn test_diagnose_flags_badge_on_observed_holiday() {
+        let dir = tempfile::tempdir().unwrap();
+        crate::cmd::init::run_in_dir(dir.path()).unwrap();
+        let settings = AppSettings {
+            observe_weekend_holidays: true,
+            ..AppSettings::default()
+        };
+        settings.save_to(dir.path()).unwrap();
+        let mut periods = TimePeriodData::new_with_file(settings.active_time_period_file(0));
+        periods.add(period("Q2", "2025-04-01", "2025-06-30"));
+        periods.save_to(dir.path(), 0).unwrap();
+        let mut holidays = HolidayData::default();
+        holidays.add(Holiday::new("Closed", "2025-05-31")); // Saturday
+        holidays.save_to(dir.path(), 0).unwrap();
+        let mut badges = BadgeEntryData::default();
+        badges.add(BadgeEntry::new(d(2025, 5, 30), "HQ", false)); // observed Friday
+        badges.save_to(dir.path(), 0).unwrap();
+
+        let diags = diagnose(dir.path());
+        assert_eq!(diags.len(), 1, "{diags:?}");
+        assert!(
+            diags[0]
+                .message
+                .contains("Q2: 1 badge entry on weekends or holidays")
+        );
+    }
+
+    #[test]
+    fn test_diagnose_warns_on_unknown_theme() {
+        let dir = tempfile::tempdir().unwrap();
+        crate::cmd::init::run_in_dir(dir.path()).unwrap();
+        let settings = AppSettings {
+            theme: "solarized".to_string(),
+            ..AppSettings::default()
+        };
+        settings.save_to(dir.path()).unwrap();
+        let diags = diagnose(dir.path());
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].severity, Severity::Warning);
+        assert!(diags[0].message.contains("unknown theme 'solarized'"));
+    }
+}
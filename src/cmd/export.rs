@@ -0,0 +1,125 @@
+use crate::calc::ical::to_ics;
+use crate::data::badge_csv::to_csv;
+use crate::data::{BadgeEntryData, HolidayData, Persistable, VacationData};
+use anyhow::{Context, Result};
+use chrono::Utc;
+use std::fs;
+use std::path::Path;
+
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq)]
+pub enum ExportFormat {
+    Csv,
+    Json,
+    /// iCalendar: badge days, vacations, and holidays as all-day events
+    Ics,
+}
+
+pub fn run(format: ExportFormat, out: Option<&Path>) -> Result<()> {
+    let badge_data = BadgeEntryData::load()?;
+    let (vacation_data, holiday_data) = if format == ExportFormat::Ics {
+        (VacationData::load()?, HolidayData::load()?)
+    } else {
+        Default::default()
+    };
+    let (contents, count) = render(&badge_data, &vacation_data, &holiday_data, format)?;
+    match out {
+        Some(path) => {
+            fs::write(path, contents).with_context(|| format!("writing {}", path.display()))?;
+            eprintln!("Exported {} entries to {}", count, path.display());
+        }
+        None => print!("{}", contents),
+    }
+    Ok(())
+}
+
+/// Renders badge history in `format`, sorted by date, along with the number
+/// of entries written (events, for ICS). Only ICS output includes vacations
+/// and holidays.
+pub(crate) fn render(
+    data: &BadgeEntryData,
+    vacations: &VacationData,
+    holidays: &HolidayData,
+    format: ExportFormat,
+) -> Result<(String, usize)> {
+    match format {
+        ExportFormat::Csv => Ok((to_csv(data), data.len())),
+        ExportFormat::Ics => Ok(to_ics(data, vacations, holidays, Utc::now().naive_utc())),
+        ExportFormat::Json => {
+            let mut sorted = data.clone();
+            sorted.data.sort_by(|a, b| a.key.cmp(&b.key));
+            let mut json = serde_json::to_string_pretty(&sorted).context("serializing JSON")?;
+            json.push('\n');
+            Ok((json, data.len()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::BadgeEntry;
+    use crate::data::badge_csv::parse_csv;
+    use crate::data::holiday::Holiday;
+    use crate::data::vacation::Vacation;
+    use chrono::NaiveDate;
+
+    fn sample() -> BadgeEntryData {
+        let mut data = BadgeEntryData::default();
+        for (day, office, flex) in [(14, "McLean, VA", false), (3, "Flex Credit", true)] {
+            let date = NaiveDate::from_ymd_opt(2025, 3, day).unwrap();
+            data.add(BadgeEntry::new(date, office, flex));
+        }
+        data
+    }
+
+    #[test]
+    fn test_export_csv_roundtrips_through_import_parser() {
+        let data = sample();
+        let (csv, count) = render(
+            &data,
+            &VacationData::default(),
+            &HolidayData::default(),
+            ExportFormat::Csv,
+        )
+        .unwrap();
+        let lines: Vec<_> = csv.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(count, 2);
+        assert!(lines[1].starts_with("2025-03-03,"));
+
+        let imported = parse_csv(&csv).unwrap();
+        assert_eq!(imported.len(), data.len());
+        for entry in &imported {
+            let original = data.get(&entry.key).unwrap();
+            assert_eq!(entry.office, original.office);
+            assert_eq!(entry.is_flex_credit, original.is_flex_credit);
+            assert_eq!(entry.is_badged_in, original.is_badged_in);
+        }
+    }
+
+    #[test]
+    fn test_export_json_sorted_and_loadable() {
+        let (json, _) = render(
+            &sample(),
+            &VacationData::default(),
+            &HolidayData::default(),
+            ExportFormat::Json,
+        )
+        .unwrap();
+        let back: BadgeEntryData = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.data[0].key, "2025-03-03");
+        assert_eq!(back.data[1].key, "2025-03-14");
+    }
+
+    #[test]
+    fn test_export_ics_includes_vacations_and_holidays() {
+        let mut vacations = VacationData::default();
+        vacations.add(Vacation::new("Hawaii", "2025-06-30", "2025-07-04", true));
+        let mut holidays = HolidayData::default();
+        holidays.add(Holiday::new("Independence Day", "2025-07-04"));
+        let (ics, events) = render(&sample(), &vacations, &holidays, ExportFormat::Ics).unwrap();
+        assert!(ics.starts_with("BEGIN:VCALENDAR"));
+        assert_eq!(ics.matches("BEGIN:VEVENT").count(), 4);
+        assert_eq!(events, 4);
+    }
+}
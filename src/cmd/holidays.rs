@@ -1,12 +1,154 @@
-use crate::data::{HolidayData, Persistable};
-use anyhow::Result;
+use crate::calc::ical::parse_holidays;
+use crate::data::holiday::{Holiday, observed_date};
+use crate::data::holiday_calendar::generate_holidays;
+use crate::data::{AppSettings, HolidayData, Persistable, WorkWeek};
+use anyhow::{Context, Result, bail};
+use chrono::NaiveDate;
 
 pub fn run() -> Result<()> {
+    let settings = AppSettings::load()?;
     let holiday_data = HolidayData::load()?;
-    write_holidays(&holiday_data, &mut std::io::stdout())
+    write_holidays(
+        &holiday_data,
+        settings.work_week(),
+        settings.observe_weekend_holidays,
+        &mut std::io::stdout(),
+    )
 }
 
-pub(crate) fn write_holidays<W: std::io::Write>(data: &HolidayData, out: &mut W) -> Result<()> {
+pub fn run_generate(country: Option<&str>, year: i32) -> Result<()> {
+    let settings = AppSettings::load()?;
+    let country = country.unwrap_or(&settings.country);
+    let mut holiday_data = HolidayData::load()?;
+    let added = merge_generated(&mut holiday_data, country, year)?;
+    holiday_data.save(settings.backup_count)?;
+    println!(
+        "Added {} {} holiday(s) for {}. {} holiday(s) recorded.",
+        added,
+        country.to_uppercase(),
+        year,
+        holiday_data.len()
+    );
+    Ok(())
+}
+
+pub fn run_import(source: &str) -> Result<()> {
+    let holidays = parse_holidays(&read_ics(source)?);
+    if holidays.is_empty() {
+        bail!("no events with a date and summary found in {}", source);
+    }
+    let found = holidays.len();
+    let mut holiday_data = HolidayData::load()?;
+    let added = merge_holidays(&mut holiday_data, holidays);
+    holiday_data.save(AppSettings::load()?.backup_count)?;
+    println!(
+        "Added {} of {} holiday(s) from {}. {} holiday(s) recorded.",
+        added,
+        found,
+        source,
+        holiday_data.len()
+    );
+    Ok(())
+}
+
+/// The text of an .ics file, or of a URL when built with the `http` feature.
+fn read_ics(source: &str) -> Result<String> {
+    if let Some(url) = remote_url(source) {
+        return fetch(&url);
+    }
+    std::fs::read_to_string(source).with_context(|| format!("failed to read {}", source))
+}
+
+/// `source` as a URL to fetch, when it is one. `webcal://` links are
+/// fetched over https.
+fn remote_url(source: &str) -> Option<String> {
+    let lower = source.to_ascii_lowercase();
+    if lower.starts_with("http://") || lower.starts_with("https://") {
+        Some(source.to_string())
+    } else if lower.starts_with("webcal://") {
+        Some(format!("https://{}", &source["webcal://".len()..]))
+    } else {
+        None
+    }
+}
+
+#[cfg(feature = "http")]
+fn fetch(url: &str) -> Result<String> {
+    ureq::get(url)
+        .call()
+        .with_context(|| format!("failed to fetch {}", url))?
+        .into_body()
+        .read_to_string()
+        .with_context(|| format!("failed to read the response from {}", url))
+}
+
+#[cfg(not(feature = "http"))]
+fn fetch(url: &str) -> Result<String> {
+    bail!(
+        "rto was built without the `http` feature; download {} and pass the file path instead",
+        url
+    )
+}
+
+pub fn run_remove(date: &str) -> Result<()> {
+    let mut holiday_data = HolidayData::load()?;
+    let removed = remove_holidays_on(&mut holiday_data, date)?;
+    holiday_data.save(AppSettings::load()?.backup_count)?;
+    let names: Vec<&str> = removed.iter().map(|h| h.name.as_str()).collect();
+    println!(
+        "Removed {} holiday(s) on {}: {}. {} holiday(s) recorded.",
+        removed.len(),
+        date,
+        names.join(", "),
+        holiday_data.len()
+    );
+    Ok(())
+}
+
+/// Removes every holiday on `date`, returning them. Errors when the date is
+/// invalid or nothing is on it.
+pub(crate) fn remove_holidays_on(data: &mut HolidayData, date: &str) -> Result<Vec<Holiday>> {
+    let day = NaiveDate::parse_from_str(date, "%Y-%m-%d")
+        .with_context(|| format!("invalid date '{}' (use YYYY-MM-DD)", date))?;
+    let key = day.format("%Y-%m-%d").to_string();
+    let (removed, kept): (Vec<Holiday>, Vec<Holiday>) =
+        data.holidays.drain(..).partition(|h| h.date == key);
+    data.holidays = kept;
+    if removed.is_empty() {
+        bail!("no holiday on {}", key);
+    }
+    Ok(removed)
+}
+
+/// Merges a country's generated holidays into `data`, skipping any date that
+/// already has a holiday. Returns the number added.
+pub(crate) fn merge_generated(data: &mut HolidayData, country: &str, year: i32) -> Result<usize> {
+    Ok(merge_holidays(data, generate_holidays(country, year)?))
+}
+
+/// Adds each of `holidays` whose date has no holiday yet, including one
+/// added earlier in the same batch. Returns the number added.
+pub(crate) fn merge_holidays(data: &mut HolidayData, holidays: Vec<Holiday>) -> usize {
+    let mut added = 0;
+    for holiday in holidays {
+        if data.holidays.iter().any(|h| h.date == holiday.date) {
+            continue;
+        }
+        data.add(holiday);
+        added += 1;
+    }
+    data.holidays.sort_by(|a, b| a.date.cmp(&b.date));
+    added
+}
+
+/// Lists the holidays; with `observe_weekends`, a holiday on a day off in
+/// `week` also shows the workday it is observed on.
+pub(crate) fn write_holidays<W: std::io::Write>(
+    data: &HolidayData,
+    week: WorkWeek,
+    observe_weekends: bool,
+    out: &mut W,
+) -> Result<()> {
     let all = data.all();
     if all.is_empty() {
         writeln!(out, "No holidays recorded.")?;
@@ -21,7 +163,16 @@ pub(crate) fn write_holidays<W: std::io::Write>(data: &HolidayData, out: &mut W)
     )?;
 
     for h in &all {
-        writeln!(out, "{:<12}  {}", h.date, h.name)?;
+        match observed_date(&h.date, week).filter(|_| observe_weekends) {
+            Some(observed) => writeln!(
+                out,
+                "{:<12}  {} (observed {})",
+                h.date,
+                h.name,
+                observed.format("%a %Y-%m-%d")
+            )?,
+            None => writeln!(out, "{:<12}  {}", h.date, h.name)?,
+        }
     }
     Ok(())
 }
@@ -39,7 +190,7 @@ mod tests {
     fn test_write_holidays_empty() {
         let data = make_data(vec![]);
         let mut buf = Vec::new();
-        write_holidays(&data, &mut buf).unwrap();
+        write_holidays(&data, WorkWeek::default(), false, &mut buf).unwrap();
         let out = String::from_utf8(buf).unwrap();
         assert!(out.contains("No holidays recorded"));
     }
@@ -48,7 +199,7 @@ mod tests {
     fn test_write_holidays_single() {
         let data = make_data(vec![Holiday::new("New Year's Day", "2025-01-01")]);
         let mut buf = Vec::new();
-        write_holidays(&data, &mut buf).unwrap();
+        write_holidays(&data, WorkWeek::default(), false, &mut buf).unwrap();
         let out = String::from_utf8(buf).unwrap();
         assert!(out.contains("New Year's Day"));
         assert!(out.contains("2025-01-01"));
@@ -61,7 +212,7 @@ mod tests {
             Holiday::new("Independence Day", "2025-07-04"),
         ]);
         let mut buf = Vec::new();
-        write_holidays(&data, &mut buf).unwrap();
+        write_holidays(&data, WorkWeek::default(), false, &mut buf).unwrap();
         let out = String::from_utf8(buf).unwrap();
         assert!(out.contains("Independence Day"));
     }
@@ -70,9 +221,120 @@ mod tests {
     fn test_write_holidays_date_column_aligned() {
         let data = make_data(vec![Holiday::new("MLK Day", "2025-01-20")]);
         let mut buf = Vec::new();
-        write_holidays(&data, &mut buf).unwrap();
+        write_holidays(&data, WorkWeek::default(), false, &mut buf).unwrap();
         let out = String::from_utf8(buf).unwrap();
         assert!(out.contains("2025-01-20"));
         assert!(out.contains("MLK Day"));
     }
+
+    #[test]
+    fn test_write_holidays_shows_observed_weekday() {
+        let data = make_data(vec![
+            Holiday::new("Independence Day", "2026-07-04"), // Saturday
+            Holiday::new("Labor Day", "2026-09-07"),
+        ]);
+        let mut buf = Vec::new();
+        write_holidays(&data, WorkWeek::default(), false, &mut buf).unwrap();
+        assert!(!String::from_utf8(buf).unwrap().contains("observed"));
+
+        let mut buf = Vec::new();
+        write_holidays(&data, WorkWeek::default(), true, &mut buf).unwrap();
+        let out = String::from_utf8(buf).unwrap();
+        assert!(out.contains("2026-07-04    Independence Day (observed Fri 2026-07-03)\n"));
+        assert!(out.contains("2026-09-07    Labor Day\n"));
+    }
+
+    #[test]
+    fn test_merge_generated_skips_existing_dates() {
+        let mut data = make_data(vec![Holiday::new("Bank Holiday", "2026-05-04")]);
+        let added = merge_generated(&mut data, "UK", 2026).unwrap();
+        assert_eq!(added, 7);
+        assert_eq!(data.len(), 8);
+        assert_eq!(data.holidays[0].date, "2026-01-01");
+        let may4: Vec<_> = data
+            .holidays
+            .iter()
+            .filter(|h| h.date == "2026-05-04")
+            .collect();
+        assert_eq!(may4.len(), 1);
+        assert_eq!(may4[0].name, "Bank Holiday");
+    }
+
+    #[test]
+    fn test_remove_holidays_on_matches_every_entry_for_the_date() {
+        let mut data = make_data(vec![
+            Holiday::new("Independence Day", "2025-07-04"),
+            Holiday::new("Labor Day", "2025-09-01"),
+            Holiday::new("Company Picnic", "2025-07-04"),
+        ]);
+        let removed = remove_holidays_on(&mut data, "2025-07-04").unwrap();
+        assert_eq!(removed.len(), 2);
+        assert_eq!(data.len(), 1);
+        assert_eq!(data.holidays[0].name, "Labor Day");
+
+        let err = remove_holidays_on(&mut data, "2025-07-04").unwrap_err();
+        assert!(err.to_string().contains("no holiday on 2025-07-04"));
+        assert!(remove_holidays_on(&mut data, "July 4").is_err());
+        assert_eq!(data.len(), 1);
+    }
+
+    #[test]
+    fn test_merge_holidays_dedupes_by_date() {
+        let mut data = make_data(vec![Holiday::new("Independence Day", "2025-07-04")]);
+        let added = merge_holidays(
+            &mut data,
+            vec![
+                Holiday::new("Winter Break", "2025-12-26"),
+                Holiday::new("July 4th", "2025-07-04"),
+                Holiday::new("Christmas", "2025-12-25"),
+                Holiday::new("Boxing Day", "2025-12-26"),
+            ],
+        );
+        assert_eq!(added, 2);
+        let got: Vec<(&str, &str)> = data
+            .holidays
+            .iter()
+            .map(|h| (h.date.as_str(), h.name.as_str()))
+            .collect();
+        assert_eq!(
+            got,
+            [
+                ("2025-07-04", "Independence Day"),
+                ("2025-12-25", "Christmas"),
+                ("2025-12-26", "Winter Break"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_read_ics_from_file_and_url_detection() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let path = tmp.path().join("company.ics");
+        std::fs::write(
+            &path,
+            "BEGIN:VEVENT\r\nDTSTART;VALUE=DATE:20251128\r\nSUMMARY:Day after Thanksgiving\r\nEND:VEVENT\r\n",
+        )
+        .unwrap();
+        let holidays = parse_holidays(&read_ics(path.to_str().unwrap()).unwrap());
+        assert_eq!(holidays.len(), 1);
+        assert_eq!(holidays[0].date, "2025-11-28");
+        assert!(read_ics(tmp.path().join("missing.ics").to_str().unwrap()).is_err());
+
+        assert_eq!(remote_url("company.ics"), None);
+        assert_eq!(
+            remote_url("HTTPS://example.com/h.ics").as_deref(),
+            Some("HTTPS://example.com/h.ics")
+        );
+        assert_eq!(
+            remote_url("webcal://example.com/h.ics").as_deref(),
+            Some("https://example.com/h.ics")
+        );
+    }
+
+    #[test]
+    fn test_merge_generated_unknown_country() {
+        let mut data = make_data(vec![]);
+        assert!(merge_generated(&mut data, "Atlantis", 2026).is_err());
+        assert!(data.is_empty());
+    }
 }
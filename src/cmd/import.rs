@@ -0,0 +1,70 @@
+use crate::data::badge_csv::parse_csv;
+use crate::data::{AppSettings, BadgeEntry, BadgeEntryData, Persistable};
+use anyhow::{Context, Result};
+use std::fs;
+use std::path::Path;
+
+pub fn run(path: &Path, dry_run: bool) -> Result<()> {
+    let text = fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+    let entries = parse_csv(&text).with_context(|| format!("parsing {}", path.display()))?;
+
+    let mut badge_data = BadgeEntryData::load()?;
+    let (added, updated) = merge_entries(&mut badge_data, entries);
+    if dry_run {
+        println!(
+            "Dry run: would add {} and update {} entries (nothing written).",
+            added, updated
+        );
+        return Ok(());
+    }
+    badge_data.save(AppSettings::load()?.backup_count)?;
+    println!("Imported: {} added, {} updated.", added, updated);
+    Ok(())
+}
+
+/// Merges `entries` into `data`, replacing any entry with the same date key.
+/// Returns `(added, updated)` counts.
+pub(crate) fn merge_entries(data: &mut BadgeEntryData, entries: Vec<BadgeEntry>) -> (usize, usize) {
+    let (mut added, mut updated) = (0, 0);
+    for entry in entries {
+        if data.has(&entry.key) {
+            data.remove(&entry.key);
+            updated += 1;
+        } else {
+            added += 1;
+        }
+        data.add(entry);
+    }
+    (added, updated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn test_merge_overwrites_same_date() {
+        let mut data = BadgeEntryData::default();
+        let date = NaiveDate::from_ymd_opt(2025, 3, 14).unwrap();
+        data.add(BadgeEntry::new(date, "Old Office", false));
+
+        let csv = "date,office,is_flex\n2025-03-14,New Office,false\n2025-03-17,Flex,true\n";
+        let (added, updated) = merge_entries(&mut data, parse_csv(csv).unwrap());
+        assert_eq!((added, updated), (1, 1));
+        assert_eq!(data.len(), 2);
+        assert_eq!(data.get("2025-03-14").unwrap().office, "New Office");
+        assert!(data.get("2025-03-17").unwrap().is_flex_credit);
+    }
+
+    #[test]
+    fn test_malformed_line_refuses_import() {
+        let err = parse_csv("2025-03-14,HQ,false\n2025-02-30,HQ,false\n").unwrap_err();
+        let msg = format!("{err:#}");
+        assert!(msg.contains("line 2"), "{msg}");
+        assert!(msg.contains("invalid date"), "{msg}");
+
+        let err = parse_csv("2025-03-14,HQ\n").unwrap_err();
+        assert!(format!("{err:#}").contains("line 1"));
+    }
+}
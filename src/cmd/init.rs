@@ -1,9 +1,13 @@
+use crate::calc::clock::today_local;
+use crate::calc::holidays::generate_federal_holidays;
+use crate::data::holiday::observed_date;
 use crate::data::{
-    AppSettings, BadgeEntry, BadgeEntryData, Event, EventData, Holiday, HolidayData, TimePeriod,
-    TimePeriodData, Vacation, VacationData,
+    AppSettings, BadgeEntry, BadgeEntryData, Event, EventData, Holiday, HolidayData, Persistable,
+    TimePeriod, TimePeriodData, Vacation, VacationData, WorkWeek,
 };
 use anyhow::Result;
-use chrono::Local;
+use chrono::{Datelike, NaiveDate};
+use std::collections::BTreeSet;
 use std::fs;
 use std::path::Path;
 
@@ -11,7 +15,7 @@ pub fn run() -> Result<()> {
     let dir = crate::data::persistence::get_data_dir()?;
     fs::create_dir_all(&dir)?;
     run_in_dir(&dir)?;
-    println!("Initialized data files in: {}", dir.display());
+    log::info!("Initialized data files in: {}", dir.display());
     Ok(())
 }
 
@@ -20,7 +24,7 @@ pub(crate) fn run_in_dir(dir: &Path) -> Result<()> {
     fs::create_dir_all(dir)?;
 
     let settings = AppSettings::default();
-    if !file_exists(dir, "settings.yaml") {
+    if !file_exists(dir, "settings.yaml") && !file_exists(dir, "settings.toml") {
         settings.save_to(dir)?;
     }
 
@@ -30,37 +34,33 @@ pub(crate) fn run_in_dir(dir: &Path) -> Result<()> {
         for tp in default_time_periods() {
             tp_data.add(tp);
         }
-        tp_data.save_to(dir)?;
+        tp_data.save_to(dir, settings.backup_count)?;
     }
 
-    if !file_exists(dir, "badge_data.json") {
+    if !file_exists(dir, &BadgeEntryData::resolved_filename()) {
         let mut badge_data = BadgeEntryData::default();
         badge_data.add(sample_badge_entry(&settings.default_office));
-        use crate::data::Persistable;
-        badge_data.save_to(dir)?;
+        badge_data.save_to(dir, settings.backup_count)?;
     }
 
-    if !file_exists(dir, "holidays.yaml") {
+    if !file_exists(dir, &HolidayData::resolved_filename()) {
         let mut holiday_data = HolidayData::default();
-        for h in default_holidays() {
+        for h in default_holidays(&default_time_periods()) {
             holiday_data.add(h);
         }
-        use crate::data::Persistable;
-        holiday_data.save_to(dir)?;
+        holiday_data.save_to(dir, settings.backup_count)?;
     }
 
-    if !file_exists(dir, "vacations.yaml") {
+    if !file_exists(dir, &VacationData::resolved_filename()) {
         let mut vacation_data = VacationData::default();
         vacation_data.add(sample_vacation());
-        use crate::data::Persistable;
-        vacation_data.save_to(dir)?;
+        vacation_data.save_to(dir, settings.backup_count)?;
     }
 
-    if !file_exists(dir, "events.json") {
+    if !file_exists(dir, &EventData::resolved_filename()) {
         let mut event_data = EventData::default();
         event_data.add(sample_event());
-        use crate::data::Persistable;
-        event_data.save_to(dir)?;
+        event_data.save_to(dir, settings.backup_count)?;
     }
 
     Ok(())
@@ -71,7 +71,7 @@ fn file_exists(dir: &Path, name: &str) -> bool {
 }
 
 fn sample_badge_entry(office: &str) -> BadgeEntry {
-    let today = Local::now().date_naive();
+    let today = today_local();
     BadgeEntry::new(today, office, false)
 }
 
@@ -80,24 +80,34 @@ fn sample_vacation() -> Vacation {
 }
 
 fn sample_event() -> Event {
-    let today = Local::now().date_naive();
-    Event {
-        date: today.format("%Y-%m-%d").to_string(),
-        description: "Sample event".to_string(),
-    }
+    let today = today_local();
+    Event::new(&today.format("%Y-%m-%d").to_string(), "Sample event")
 }
 
 pub fn default_time_periods() -> Vec<TimePeriod> {
-    vec![
-        tp("Q1_2025", "Q1", "2025-01-01", "2025-03-31"),
-        tp("Q2_2025", "Q2", "2025-04-01", "2025-06-30"),
-        tp("Q3_2025", "Q3", "2025-07-01", "2025-09-30"),
-        tp("Q4_2025", "Q4", "2025-10-01", "2025-12-31"),
-        tp("Q1_2026", "Q1", "2026-01-01", "2026-03-31"),
-        tp("Q2_2026", "Q2", "2026-04-01", "2026-06-30"),
-        tp("Q3_2026", "Q3", "2026-07-01", "2026-09-30"),
-        tp("Q4_2026", "Q4", "2026-10-01", "2026-12-31"),
+    (2025..=2026).flat_map(quarters_for_year).collect()
+}
+
+/// The four calendar quarters of `year`, keyed `Q1_<year>`..`Q4_<year>` like
+/// the default time periods.
+pub fn quarters_for_year(year: i32) -> Vec<TimePeriod> {
+    [
+        ("01-01", "03-31"),
+        ("04-01", "06-30"),
+        ("07-01", "09-30"),
+        ("10-01", "12-31"),
     ]
+    .iter()
+    .enumerate()
+    .map(|(i, (start, end))| {
+        tp(
+            &format!("Q{}_{}", i + 1, year),
+            &format!("Q{}", i + 1),
+            &format!("{}-{}", year, start),
+            &format!("{}-{}", year, end),
+        )
+    })
+    .collect()
 }
 
 fn tp(key: &str, name: &str, start: &str, end: &str) -> TimePeriod {
@@ -108,34 +118,32 @@ fn tp(key: &str, name: &str, start: &str, end: &str) -> TimePeriod {
         end_date_raw: end.to_string(),
         start_date: None,
         end_date: None,
+        ..Default::default()
     }
 }
 
-pub fn default_holidays() -> Vec<Holiday> {
-    vec![
-        Holiday::new("New Year's Day", "2025-01-01"),
-        Holiday::new("MLK Day", "2025-01-20"),
-        Holiday::new("Presidents' Day", "2025-02-17"),
-        Holiday::new("Memorial Day", "2025-05-26"),
-        Holiday::new("Juneteenth", "2025-06-19"),
-        Holiday::new("Independence Day", "2025-07-04"),
-        Holiday::new("Labor Day", "2025-09-01"),
-        Holiday::new("Columbus Day", "2025-10-13"),
-        Holiday::new("Veterans Day", "2025-11-11"),
-        Holiday::new("Thanksgiving Day", "2025-11-27"),
-        Holiday::new("Christmas Day", "2025-12-25"),
-        Holiday::new("New Year's Day", "2026-01-01"),
-        Holiday::new("MLK Day", "2026-01-19"),
-        Holiday::new("Presidents' Day", "2026-02-16"),
-        Holiday::new("Memorial Day", "2026-05-25"),
-        Holiday::new("Juneteenth", "2026-06-19"),
-        Holiday::new("Independence Day (observed)", "2026-07-03"),
-        Holiday::new("Labor Day", "2026-09-07"),
-        Holiday::new("Columbus Day", "2026-10-12"),
-        Holiday::new("Veterans Day", "2026-11-11"),
-        Holiday::new("Thanksgiving Day", "2026-11-26"),
-        Holiday::new("Christmas Day", "2026-12-25"),
-    ]
+/// US federal holidays for every calendar year touched by `periods`. A
+/// holiday that falls on a weekend is recorded on its observed weekday
+/// (for the default Monday–Friday week).
+pub fn default_holidays(periods: &[TimePeriod]) -> Vec<Holiday> {
+    let parse = |s: &str| NaiveDate::parse_from_str(s, "%Y-%m-%d").ok();
+    let mut years = BTreeSet::new();
+    for p in periods {
+        if let (Some(start), Some(end)) = (parse(&p.start_date_raw), parse(&p.end_date_raw)) {
+            years.extend(start.year()..=end.year());
+        }
+    }
+    years
+        .into_iter()
+        .flat_map(generate_federal_holidays)
+        .map(|h| match observed_date(&h.date, WorkWeek::default()) {
+            Some(observed) => Holiday::new(
+                &format!("{} (observed)", h.name),
+                &observed.format("%Y-%m-%d").to_string(),
+            ),
+            None => h,
+        })
+        .collect()
 }
 
 #[cfg(test)]
@@ -212,6 +220,20 @@ mod tests {
         assert_eq!(data.len(), 22);
     }
 
+    #[test]
+    fn test_default_holidays_cover_period_years() {
+        let holidays = default_holidays(&default_time_periods());
+        let dates: Vec<_> = holidays.iter().map(|h| h.date.as_str()).collect();
+        assert!(dates.contains(&"2025-05-26")); // Memorial Day
+        assert!(dates.contains(&"2026-11-26")); // Thanksgiving
+        // July 4, 2026 is a Saturday
+        let july4 = holidays.iter().find(|h| h.date == "2026-07-03").unwrap();
+        assert_eq!(july4.name, "Independence Day (observed)");
+
+        let one_year = vec![tp("FY", "FY", "2027-01-01", "2027-12-31")];
+        assert_eq!(default_holidays(&one_year).len(), 11);
+    }
+
     #[test]
     fn test_vacations_file_has_one_entry() {
         let tmp = TempDir::new().unwrap();
@@ -1,6 +1,13 @@
 pub mod backup;
+pub mod badge;
+pub mod doctor;
+pub mod export;
 pub mod holidays;
+pub mod import;
 pub mod init;
+pub mod periods;
+pub mod report;
+pub mod restore;
 pub mod root;
 pub mod stats;
 pub mod vacations;
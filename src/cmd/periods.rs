@@ -0,0 +1,145 @@
+use crate::cmd::init::quarters_for_year;
+use crate::data::persistence::get_data_dir;
+use crate::data::{AppSettings, TimePeriod, TimePeriodData};
+use anyhow::{Result, bail};
+use chrono::NaiveDate;
+
+pub fn run_add_year(year: i32) -> Result<()> {
+    let dir = get_data_dir()?;
+    let settings = AppSettings::load_from(&dir)?;
+    let mut data = TimePeriodData::load_raw_from(&dir, settings.active_time_period_file(0))?;
+    let added = add_year(&mut data, year)?;
+    data.save_to(&dir, settings.backup_count)?;
+    let keys: Vec<&str> = added.iter().map(|tp| tp.key.as_str()).collect();
+    println!(
+        "Added {} to {}. {} period(s) configured.",
+        keys.join(", "),
+        data.filename(),
+        data.len()
+    );
+    println!(
+        "Run 'rto holidays generate --year {}' to add that year's holidays.",
+        year
+    );
+    Ok(())
+}
+
+/// Appends the four quarters of `year` to `data`, leaving existing periods
+/// and settings untouched. Refuses when any of them is already configured,
+/// by key or by an overlapping date range.
+pub(crate) fn add_year(data: &mut TimePeriodData, year: i32) -> Result<Vec<TimePeriod>> {
+    if !(1..=9999).contains(&year) {
+        bail!("year {} is out of range (1–9999)", year);
+    }
+    let parse = |s: &str| NaiveDate::parse_from_str(s, "%Y-%m-%d").ok();
+    let quarters = quarters_for_year(year);
+    for existing in data.all() {
+        for q in &quarters {
+            if existing.key == q.key {
+                bail!("{} already exists in {}", q.key, data.filename());
+            }
+            if let (Some(s), Some(e), Some(qs), Some(qe)) = (
+                parse(&existing.start_date_raw),
+                parse(&existing.end_date_raw),
+                parse(&q.start_date_raw),
+                parse(&q.end_date_raw),
+            ) && s <= qe
+                && qs <= e
+            {
+                bail!(
+                    "{} would overlap existing period {} ({} – {})",
+                    q.key,
+                    existing.key,
+                    existing.start_date_raw,
+                    existing.end_date_raw
+                );
+            }
+        }
+    }
+    for q in &quarters {
+        data.add(q.clone());
+    }
+    Ok(quarters)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cmd::init::default_time_periods;
+    use tempfile::TempDir;
+
+    fn default_data() -> TimePeriodData {
+        let mut data = TimePeriodData::new();
+        for tp in default_time_periods() {
+            data.add(tp);
+        }
+        data
+    }
+
+    #[test]
+    fn test_add_year_appends_four_parseable_quarters() {
+        let mut data = default_data();
+        let added = add_year(&mut data, 2027).unwrap();
+        assert_eq!(added.len(), 4);
+        assert_eq!(data.len(), 12);
+        let all = data.all();
+        let keys: Vec<&str> = all[8..].iter().map(|tp| tp.key.as_str()).collect();
+        assert_eq!(keys, ["Q1_2027", "Q2_2027", "Q3_2027", "Q4_2027"]);
+        for mut tp in all {
+            tp.parse_dates().unwrap();
+        }
+        let q1 = data.get_period_by_key("Q1_2027").unwrap();
+        assert_eq!(q1.start_date_raw, "2027-01-01");
+        let q4 = data.get_period_by_key("Q4_2027").unwrap();
+        assert_eq!(q4.end_date_raw, "2027-12-31");
+    }
+
+    #[test]
+    fn test_add_year_refuses_existing_quarters() {
+        let mut data = default_data();
+        let err = add_year(&mut data, 2026).unwrap_err();
+        assert!(err.to_string().contains("Q1_2026 already exists"));
+        assert_eq!(data.len(), 8);
+    }
+
+    #[test]
+    fn test_add_year_refuses_overlapping_period() {
+        let mut data = TimePeriodData::new();
+        data.add(TimePeriod {
+            key: "FY2027".to_string(),
+            name: "FY".to_string(),
+            start_date_raw: "2027-02-01".to_string(),
+            end_date_raw: "2028-01-31".to_string(),
+            ..Default::default()
+        });
+        let err = add_year(&mut data, 2027).unwrap_err();
+        assert!(err.to_string().contains("overlap existing period FY2027"));
+        assert_eq!(data.len(), 1);
+    }
+
+    #[test]
+    fn test_add_year_saved_file_keeps_existing_periods_and_columns() {
+        let tmp = TempDir::new().unwrap();
+        let mut data = default_data();
+        data.set_calendar_display_columns(2);
+        data.save_to(tmp.path(), 0).unwrap();
+
+        let mut loaded = TimePeriodData::load_raw_from(tmp.path(), data.filename()).unwrap();
+        add_year(&mut loaded, 2027).unwrap();
+        loaded.save_to(tmp.path(), 0).unwrap();
+
+        let reloaded = TimePeriodData::load_from(tmp.path(), data.filename()).unwrap();
+        assert_eq!(reloaded.len(), 12);
+        assert_eq!(reloaded.calendar_display_columns(), 2);
+        assert!(reloaded.get_period_by_key("Q1_2025").is_some());
+        assert!(reloaded.get_period_by_key("Q4_2027").is_some());
+    }
+
+    #[test]
+    fn test_add_year_rejects_out_of_range_year() {
+        let mut data = TimePeriodData::new();
+        assert!(add_year(&mut data, 0).is_err());
+        assert!(add_year(&mut data, 10000).is_err());
+        assert!(data.is_empty());
+    }
+}
@@ -0,0 +1,226 @@
+use crate::calc::clock::today_local;
+use crate::calc::{QuarterStats, StatsOptions, calculate_quarter_stats, calculate_year_stats};
+use crate::data::badge_csv::quote;
+use crate::data::{
+    AppSettings, BadgeEntryData, HolidayData, Persistable, TimePeriodData, VacationData,
+};
+use anyhow::{Result, bail};
+use chrono::Datelike;
+
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq)]
+pub enum ReportFormat {
+    /// Markdown: a per-period table followed by the year summary
+    Md,
+    /// CSV: one row per period plus a final "Year" row
+    Csv,
+}
+
+pub fn run(year: Option<i32>, format: ReportFormat) -> Result<()> {
+    let settings = AppSettings::load()?;
+    let td = TimePeriodData::load()?;
+    let badge_data = BadgeEntryData::load()?;
+    let holiday_data = HolidayData::load()?;
+    let vacation_data = VacationData::load()?;
+    let today = today_local();
+    let year = year.unwrap_or(today.year());
+
+    let periods = td.periods_in_year(year);
+    let options = StatsOptions::from(&settings);
+    let mut rows = Vec::new();
+    for period in &periods {
+        rows.push(calculate_quarter_stats(
+            period,
+            &badge_data,
+            &holiday_data,
+            &vacation_data,
+            &options,
+            Some(today),
+        )?);
+    }
+    let Some(year_stats) = calculate_year_stats(
+        &periods,
+        &badge_data,
+        &holiday_data,
+        &vacation_data,
+        &options,
+        Some(today),
+    )?
+    else {
+        if periods.is_empty() {
+            bail!("no time periods start in {}", year);
+        }
+        bail!("every time period in {} is marked exclude_from_year", year);
+    };
+
+    let mut out = std::io::stdout();
+    match format {
+        ReportFormat::Md => write_markdown(year, &rows, &year_stats, &settings, &mut out),
+        ReportFormat::Csv => write_csv(&rows, &year_stats, &mut out),
+    }
+}
+
+/// Writes the year report as Markdown: a compliance table with one row per
+/// period, then the aggregate year stats.
+pub(crate) fn write_markdown<W: std::io::Write>(
+    year: i32,
+    rows: &[QuarterStats],
+    year_stats: &QuarterStats,
+    settings: &AppSettings,
+    out: &mut W,
+) -> Result<()> {
+    writeln!(out, "# RTO Report {}", year)?;
+    writeln!(out)?;
+    writeln!(
+        out,
+        "| Period | Dates | Badged | Required | Total Days | Average | Status |"
+    )?;
+    writeln!(out, "|---|---|--:|--:|--:|--:|---|")?;
+    for stats in rows {
+        writeln!(
+            out,
+            "| {} | {} – {} | {} | {} | {} | {:.1}% | {} |",
+            stats.name,
+            stats.start_date.format("%b %-d"),
+            stats.end_date.format("%b %-d"),
+            stats.days_badged_in,
+            stats.days_required,
+            stats.total_days,
+            stats.current_average * 100.0,
+            stats.compliance_status
+        )?;
+    }
+
+    writeln!(out)?;
+    writeln!(out, "## Year")?;
+    writeln!(out)?;
+    writeln!(
+        out,
+        "- **Dates:** {} – {}",
+        year_stats.start_date.format("%b %-d, %Y"),
+        year_stats.end_date.format("%b %-d, %Y")
+    )?;
+    writeln!(out, "- **Status:** {}", year_stats.compliance_status)?;
+    writeln!(
+        out,
+        "- **Badged in:** {} of {} required ({} total days, {})",
+        year_stats.days_badged_in,
+        year_stats.days_required,
+        year_stats.total_days,
+        settings.target()
+    )?;
+    writeln!(
+        out,
+        "- **Average:** {:.1}%",
+        year_stats.current_average * 100.0
+    )?;
+    writeln!(out, "- **Flex credits:** {}", year_stats.flex_days)?;
+    writeln!(
+        out,
+        "- **Days off:** {} holidays, {} vacation days, {} excused",
+        year_stats.holidays, year_stats.vacation_days, year_stats.excused_days
+    )?;
+    writeln!(out, "- **Longest streak:** {}", year_stats.longest_streak)?;
+    Ok(())
+}
+
+/// Writes one CSV row per period, then a final row for the whole year.
+pub(crate) fn write_csv<W: std::io::Write>(
+    rows: &[QuarterStats],
+    year_stats: &QuarterStats,
+    out: &mut W,
+) -> Result<()> {
+    writeln!(
+        out,
+        "period,start,end,badged_in,required,total_days,average,status"
+    )?;
+    for stats in rows.iter().chain(std::iter::once(year_stats)) {
+        writeln!(
+            out,
+            "{},{},{},{},{},{},{:.3},{}",
+            quote(&stats.name),
+            stats.start_date.format("%Y-%m-%d"),
+            stats.end_date.format("%Y-%m-%d"),
+            stats.days_badged_in,
+            stats.days_required,
+            stats.total_days,
+            stats.current_average,
+            quote(&stats.compliance_status)
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::TimePeriod;
+    use crate::data::badge_entry::BadgeEntry;
+    use chrono::NaiveDate;
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).unwrap()
+    }
+
+    fn period(key: &str, start: &str, end: &str) -> TimePeriod {
+        let mut tp = TimePeriod {
+            key: key.to_string(),
+            name: key[..2].to_string(),
+            start_date_raw: start.to_string(),
+            end_date_raw: end.to_string(),
+            ..Default::default()
+        };
+        tp.parse_dates().unwrap();
+        tp
+    }
+
+    fn sample() -> (Vec<QuarterStats>, QuarterStats) {
+        let q1 = period("Q1_2025", "2025-01-06", "2025-01-17");
+        let q2 = period("Q2_2025", "2025-01-20", "2025-01-31");
+        let mut badge = BadgeEntryData::default();
+        badge.add(BadgeEntry::new(d(2025, 1, 6), "HQ", false));
+        badge.add(BadgeEntry::new(d(2025, 1, 20), "HQ", false));
+        badge.add(BadgeEntry::new(d(2025, 1, 21), "HQ", false));
+        let holiday = HolidayData::default();
+        let vacation = VacationData::default();
+        let today = Some(d(2025, 2, 3));
+        let options = StatsOptions::default();
+        let rows = [&q1, &q2]
+            .iter()
+            .map(|p| {
+                calculate_quarter_stats(p, &badge, &holiday, &vacation, &options, today).unwrap()
+            })
+            .collect();
+        let year = calculate_year_stats(&[&q1, &q2], &badge, &holiday, &vacation, &options, today)
+            .unwrap()
+            .unwrap();
+        (rows, year)
+    }
+
+    #[test]
+    fn test_markdown_report_has_period_rows_and_year_summary() {
+        let (rows, year) = sample();
+        let mut out = Vec::new();
+        write_markdown(2025, &rows, &year, &AppSettings::default(), &mut out).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        assert!(text.starts_with("# RTO Report 2025\n"));
+        assert!(text.contains("| Q1 | Jan 6 – Jan 17 | 1 | 5 | 10 | 10.0% |"));
+        assert!(text.contains("| Q2 | Jan 20 – Jan 31 | 2 | 5 | 10 | 20.0% |"));
+        assert!(text.contains("- **Badged in:** 3 of 10 required (20 total days"));
+    }
+
+    #[test]
+    fn test_csv_report_ends_with_year_row() {
+        let (rows, year) = sample();
+        let mut out = Vec::new();
+        write_csv(&rows, &year, &mut out).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        let lines: Vec<_> = text.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(
+            lines[0],
+            "period,start,end,badged_in,required,total_days,average,status"
+        );
+        assert!(lines[1].starts_with("Q1,2025-01-06,2025-01-17,1,5,10,0.100,"));
+        assert!(lines[3].starts_with("Year,2025-01-06,2025-01-31,3,10,20,0.150,"));
+    }
+}
@@ -0,0 +1,67 @@
+use crate::cmd::backup;
+use anyhow::{Result, bail};
+use std::io::{self, BufRead, Write};
+
+pub fn run(git_ref: Option<&str>, yes: bool) -> Result<()> {
+    let dir = crate::data::persistence::get_data_dir()?;
+    let info = backup::status(&dir);
+    if !info.is_repo {
+        bail!(
+            "{} is not a git repository; run `rto backup` first",
+            dir.display()
+        );
+    }
+    if info.modified > 0 {
+        eprintln!(
+            "Warning: {} modified file(s) in {} will be overwritten.",
+            info.modified,
+            dir.display()
+        );
+    }
+    if !yes {
+        let prompt = format!(
+            "Restore data files in {} from {}?",
+            dir.display(),
+            git_ref.unwrap_or("the latest commit")
+        );
+        let stdin = io::stdin();
+        if !confirm(&mut stdin.lock(), &mut io::stdout(), &prompt)? {
+            println!("Restore cancelled.");
+            return Ok(());
+        }
+    }
+    println!("{}", backup::restore(&dir, git_ref)?);
+    Ok(())
+}
+
+/// Asks a yes/no question; only an answer starting with `y` counts as yes.
+pub(crate) fn confirm<R: BufRead, W: Write>(
+    input: &mut R,
+    out: &mut W,
+    prompt: &str,
+) -> Result<bool> {
+    write!(out, "{} [y/N] ", prompt)?;
+    out.flush()?;
+    let mut answer = String::new();
+    input.read_line(&mut answer)?;
+    Ok(answer.trim().to_ascii_lowercase().starts_with('y'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_confirm_defaults_to_no() {
+        let mut out = Vec::new();
+        assert!(confirm(&mut "y\n".as_bytes(), &mut out, "Restore?").unwrap());
+        assert!(confirm(&mut "Yes\n".as_bytes(), &mut out, "Restore?").unwrap());
+        assert!(!confirm(&mut "\n".as_bytes(), &mut out, "Restore?").unwrap());
+        assert!(!confirm(&mut "".as_bytes(), &mut out, "Restore?").unwrap());
+        assert!(
+            String::from_utf8(out)
+                .unwrap()
+                .starts_with("Restore? [y/N] ")
+        );
+    }
+}
@@ -1,11 +1,11 @@
+use crate::calc::clock::today_local;
 use crate::data::{
-    AppSettings, BadgeEntryData, EventData, HolidayData, Persistable, TimePeriodData, VacationData,
-    persistence::get_data_dir,
+    AppSettings, BadgeEntryData, EventData, HolidayData, Persistable, TimePeriodData, UiState,
+    VacationData, persistence::get_data_dir,
 };
 use crate::ui::calendar_view::{App, run_app};
 use crate::ui::{restore_terminal, setup_terminal};
 use anyhow::Result;
-use chrono::Local;
 
 pub fn run() -> Result<()> {
     let settings = AppSettings::load()?;
@@ -17,6 +17,7 @@ pub fn run() -> Result<()> {
     let mut holiday_data = HolidayData::load()?;
     let mut vacation_data = VacationData::load()?;
     let mut event_data = EventData::load()?;
+    let ui_state = UiState::load_from(&data_dir).unwrap_or_default();
 
     let original_hook = std::panic::take_hook();
     std::panic::set_hook(Box::new(move |info| {
@@ -27,7 +28,7 @@ pub fn run() -> Result<()> {
 
     let mut terminal = setup_terminal()?;
 
-    let today = Local::now().date_naive();
+    let today = today_local();
     let mut app = App::new(
         time_period_data,
         &mut badge_data,
@@ -38,19 +39,27 @@ pub fn run() -> Result<()> {
         today,
         data_dir.clone(),
     );
+    if let Some(key) = &ui_state.period_key {
+        app.show_period(key);
+    }
 
     let result = run_app(&mut terminal, &mut app);
 
     restore_terminal(&mut terminal)?;
 
     let final_settings = app.settings.clone();
+    let final_ui_state = UiState {
+        period_key: app.current_period_key(),
+    };
     drop(app);
 
-    badge_data.save()?;
-    event_data.save()?;
-    vacation_data.save()?;
-    holiday_data.save()?;
+    let backup_count = final_settings.backup_count;
+    badge_data.save(backup_count)?;
+    event_data.save(backup_count)?;
+    vacation_data.save(backup_count)?;
+    holiday_data.save(backup_count)?;
     final_settings.save_to(&data_dir)?;
+    final_ui_state.save_to(&data_dir, backup_count)?;
 
     result
 }
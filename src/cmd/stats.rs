@@ -1,124 +1,566 @@
-use crate::calc::{QuarterStats, calculate_quarter_stats};
+use crate::calc::clock::today_local;
+use crate::calc::gaps::{gap_lengths, summarize_gaps};
+use crate::calc::{
+    QuarterStats, StatsOptions, apply_carryover, calculate_quarter_stats, carryover,
+};
+use crate::cmd::doctor::{orphan_badges, orphan_summary};
+use crate::data::persistence::get_data_dir;
 use crate::data::{
-    AppSettings, BadgeEntryData, HolidayData, Persistable, TimePeriodData, VacationData,
+    AppSettings, BadgeEntryData, HolidayData, Persistable, TimePeriod, TimePeriodData, VacationData,
 };
 use anyhow::{Result, bail};
+use chrono::NaiveDate;
+use std::io::Write;
+use std::path::Path;
+
+pub fn run(
+    period_key: Option<&str>,
+    gaps: bool,
+    all: bool,
+    json: bool,
+    check: bool,
+    as_of: Option<NaiveDate>,
+    format: StatsFormat,
+) -> Result<i32> {
+    let today = as_of.unwrap_or_else(today_local);
+    if all {
+        stats_table_in(&get_data_dir()?, today, &mut std::io::stdout())?;
+        return Ok(0);
+    }
 
-pub fn run(period_key: Option<&str>) -> Result<()> {
     let settings = AppSettings::load()?;
     let td = TimePeriodData::load()?;
     let badge_data = BadgeEntryData::load()?;
     let holiday_data = HolidayData::load()?;
     let vacation_data = VacationData::load()?;
+    warn_orphans(&badge_data, &td);
 
-    let key = match period_key {
-        Some(k) => k.to_string(),
-        None => {
-            let tp = td.get_current_period();
-            match tp {
-                Some(tp) => tp.key.clone(),
-                None => bail!("cannot determine current period — try specifying a period key"),
-            }
-        }
-    };
+    let stats = period_stats(
+        &td,
+        period_key,
+        &badge_data,
+        &holiday_data,
+        &vacation_data,
+        &settings,
+        today,
+    )?;
 
-    let period = match td.get_period_by_key(&key) {
-        Some(p) => p,
-        None => bail!(
-            "Period key '{}' not found — run 'rto init' to create data files",
-            key
-        ),
-    };
+    let mut out = std::io::stdout();
+    if check {
+        return write_check_status(&stats, &mut out);
+    }
+    if json {
+        write_stats_json(&stats, &mut out)?;
+        return Ok(0);
+    }
+    write_stats_with(&stats, &settings, &mut out, format.layout())?;
+    if gaps {
+        write_gaps(&stats, today, &mut out)?;
+    }
+    Ok(0)
+}
+
+fn warn_orphans(badge_data: &BadgeEntryData, td: &TimePeriodData) {
+    let orphans = orphan_badges(badge_data, td);
+    if !orphans.is_empty() {
+        log::warn!("{}", orphan_summary(&orphans));
+    }
+}
+
+/// `rto stats --all` for the data in `dir`. Periods are loaded without
+/// parsing their dates, so a period with a bad date only skips its own row.
+pub(crate) fn stats_table_in<W: std::io::Write>(
+    dir: &Path,
+    today: NaiveDate,
+    out: &mut W,
+) -> Result<()> {
+    let settings = AppSettings::load_from(dir)?;
+    let td = TimePeriodData::load_raw_from(dir, settings.active_time_period_file(0))?;
+    let badge_data = BadgeEntryData::load_from(dir)?;
+    let holiday_data = HolidayData::load_from(dir)?;
+    let vacation_data = VacationData::load_from(dir)?;
 
-    let stats = calculate_quarter_stats(
-        period,
+    let mut parsed = TimePeriodData::new();
+    for mut period in td.all() {
+        if period.parse_dates().is_ok() {
+            parsed.add(period);
+        }
+    }
+    warn_orphans(&badge_data, &parsed);
+
+    write_stats_table(
+        &td.all(),
         &badge_data,
         &holiday_data,
         &vacation_data,
-        settings.goal,
-        None,
-    )?;
+        &settings,
+        Some(today),
+        out,
+    )
+}
+
+/// Exit code for `rto stats --check`: 0 when achieved or on track, 1 when at
+/// risk, 2 when the target can no longer be reached.
+pub(crate) fn status_exit_code(status: &str) -> i32 {
+    match status {
+        "Achieved" | "On Track" => 0,
+        "Impossible" => 2,
+        _ => 1,
+    }
+}
 
-    write_stats(&stats, &settings, &mut std::io::stdout())
+/// Writes the one-line `rto stats --check` status and returns its exit code.
+pub(crate) fn write_check_status<W: Write>(stats: &QuarterStats, out: &mut W) -> Result<i32> {
+    writeln!(out, "{}: {}", stats.name, stats.compliance_status)?;
+    Ok(status_exit_code(&stats.compliance_status))
 }
 
+/// Stats for the period `period_key`, or for the period containing `today`
+/// when no key is given, computed as if the current date were `today`.
+pub(crate) fn period_stats(
+    td: &TimePeriodData,
+    period_key: Option<&str>,
+    badge_data: &BadgeEntryData,
+    holiday_data: &HolidayData,
+    vacation_data: &VacationData,
+    settings: &AppSettings,
+    today: NaiveDate,
+) -> Result<QuarterStats> {
+    let period = match period_key {
+        Some(key) => match td.get_period_by_key(key) {
+            Some(p) => p,
+            None => bail!(
+                "Period key '{}' not found — run 'rto init' to create data files",
+                key
+            ),
+        },
+        None => match td.get_period_by_date(today) {
+            Some(p) => p,
+            None => {
+                let keys: Vec<&str> = td.periods().iter().map(|tp| tp.key.as_str()).collect();
+                if keys.is_empty() {
+                    bail!(
+                        "no period configured for {} — run 'rto add-year <YEAR>' to add one",
+                        today
+                    );
+                }
+                bail!(
+                    "no period configured for {}; available keys: {}",
+                    today,
+                    keys.join(", ")
+                );
+            }
+        },
+    };
+
+    let options = StatsOptions::from(settings);
+    let stats_for = |period| {
+        calculate_quarter_stats(
+            period,
+            badge_data,
+            holiday_data,
+            vacation_data,
+            &options,
+            Some(today),
+        )
+    };
+    let mut stats = stats_for(period)?;
+    if settings.allow_carryover
+        && let Some(prev) = td.previous_period(&period.key)
+    {
+        let surplus = carryover(&stats_for(prev)?, stats.days_required);
+        apply_carryover(&mut stats, surplus);
+    }
+    Ok(stats)
+}
+
+/// Writes `stats` as pretty-printed JSON. The per-day `workday_stats` map is
+/// omitted.
+pub(crate) fn write_stats_json<W: std::io::Write>(stats: &QuarterStats, out: &mut W) -> Result<()> {
+    serde_json::to_writer_pretty(&mut *out, stats)?;
+    writeln!(out)?;
+    Ok(())
+}
+
+/// Column layout for the text report, chosen with `rto stats --format`.
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq)]
+pub enum StatsFormat {
+    /// Labels padded to 22 columns
+    Standard,
+    /// More indentation and a wider label column
+    Wide,
+    /// Values wrapped so no line is longer than 40 columns
+    Narrow,
+}
+
+impl StatsFormat {
+    pub(crate) fn layout(self) -> StatsLayout {
+        match self {
+            StatsFormat::Standard => StatsLayout {
+                indent: 2,
+                label_width: 22,
+                max_width: None,
+                wrap_indent: 4,
+            },
+            StatsFormat::Wide => StatsLayout {
+                indent: 4,
+                label_width: 28,
+                max_width: None,
+                wrap_indent: 6,
+            },
+            StatsFormat::Narrow => StatsLayout {
+                indent: 1,
+                label_width: 22,
+                max_width: Some(40),
+                wrap_indent: 3,
+            },
+        }
+    }
+}
+
+/// Column widths for [`write_stats_with`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct StatsLayout {
+    /// Spaces before each label
+    pub indent: usize,
+    /// Width the "Label:" column is padded to
+    pub label_width: usize,
+    /// Longest line allowed; longer lines wrap between words
+    pub max_width: Option<usize>,
+    /// Spaces before each continuation line of a wrapped value
+    pub wrap_indent: usize,
+}
+
+/// [`write_stats_with`] in the standard layout.
+#[cfg(test)]
 pub(crate) fn write_stats<W: std::io::Write>(
     stats: &QuarterStats,
     settings: &AppSettings,
     out: &mut W,
 ) -> Result<()> {
-    writeln!(
+    write_stats_with(stats, settings, out, StatsFormat::Standard.layout())
+}
+
+pub(crate) fn write_stats_with<W: std::io::Write>(
+    stats: &QuarterStats,
+    settings: &AppSettings,
+    out: &mut W,
+    layout: StatsLayout,
+) -> Result<()> {
+    let row = |out: &mut W, label: &str, value: String| {
+        let head = format!(
+            "{:indent$}{:<width$}",
+            "",
+            format!("{}:", label),
+            indent = layout.indent,
+            width = layout.label_width
+        );
+        write_wrapped(
+            out,
+            &format!("{}{}", head, value),
+            head.chars().count(),
+            layout,
+        )
+    };
+
+    write_wrapped(
         out,
-        "Period: {}  ({} – {})",
-        stats.name,
-        stats.start_date.format("%b %-d, %Y"),
-        stats.end_date.format("%b %-d, %Y"),
+        &format!(
+            "Period: {}  ({} – {})",
+            stats.name,
+            stats.start_date.format("%b %-d, %Y"),
+            stats.end_date.format("%b %-d, %Y"),
+        ),
+        "Period: ".len(),
+        layout,
     )?;
 
     writeln!(out)?;
-    writeln!(out, "  Status:               {}", stats.compliance_status)?;
-    writeln!(
+    row(out, "Status", stats.compliance_status.clone())?;
+    row(
         out,
-        "  Days ahead of pace:   {:+}",
-        stats.days_ahead_of_pace
+        "Days ahead of pace",
+        format!("{:+}", stats.days_ahead_of_pace),
     )?;
+    if stats.buffer_days_used > 0 {
+        row(
+            out,
+            "Buffer used",
+            format!("{} day(s) in beyond pace", stats.buffer_days_used),
+        )?;
+    }
     if stats.remaining_missable_days >= 0 {
-        writeln!(
+        row(
             out,
-            "  Skippable days left:  {}",
-            stats.remaining_missable_days
+            "Skippable days left",
+            stats.remaining_missable_days.to_string(),
         )?;
     }
 
     writeln!(out)?;
-    writeln!(
+    row(
         out,
-        "  Required badge-ins:   {} of {} total days ({}%)",
-        stats.days_required, stats.total_days, settings.goal
+        "Required badge-ins",
+        format!(
+            "{} of {} total days ({})",
+            stats.days_required,
+            stats.total_days,
+            settings.target()
+        ),
     )?;
-    writeln!(out, "  Badged in:            {}", stats.days_badged_in)?;
-    writeln!(out, "  Still needed:         {}", stats.days_still_needed)?;
+    row(out, "Badged in", stats.days_badged_in.to_string())?;
+    row(out, "Still needed", stats.days_still_needed.to_string())?;
+    if stats.carryover_days > 0 {
+        row(
+            out,
+            "Carried over",
+            format!(
+                "{} surplus day(s) from the previous period",
+                stats.carryover_days
+            ),
+        )?;
+    }
 
-    let office_days = stats.days_badged_in - stats.flex_days;
     writeln!(out)?;
-    writeln!(
+    row(
         out,
-        "  Badge-ins:            {}  ({} office, {} flex)",
-        stats.days_badged_in, office_days, stats.flex_days
+        "Badge-ins",
+        format!(
+            "{}  ({} office, {} flex)",
+            stats.days_badged_in,
+            stats.office_days(),
+            stats.flex_days
+        ),
     )?;
+    if stats.excess_flex_days > 0.0 {
+        row(
+            out,
+            "Excess flex credits",
+            format!(
+                "{}  (over the {} cap; not counted)",
+                stats.excess_flex_days,
+                settings.max_flex_credits.unwrap_or_default()
+            ),
+        )?;
+    }
+    if stats.non_qualifying_days > 0.0 {
+        row(
+            out,
+            "Non-qualifying",
+            format!(
+                "{}  (offices not in qualifying_offices; not counted)",
+                stats.non_qualifying_days
+            ),
+        )?;
+    }
+    if stats.flex_labels.len() > 1 {
+        row(out, "Flex labels", stats.flex_labels.join(", "))?;
+    }
+
+    if !stats.days_by_office.is_empty() {
+        writeln!(out)?;
+        writeln!(out, "{:indent$}By office:", "", indent = layout.indent)?;
+        let indent = layout.indent * 2;
+        let width = layout.label_width - 2;
+        for (office, days) in stats.offices_by_days() {
+            let head = format!("{:indent$}{:<width$}", "", office);
+            write_wrapped(out, &format!("{}{}", head, days), indent, layout)?;
+        }
+    }
 
     writeln!(out)?;
-    writeln!(out, "  Days worked so far:   {}", stats.days_thus_far)?;
-    writeln!(out, "  Days remaining:       {}", stats.days_left)?;
+    row(out, "Days worked so far", stats.days_thus_far.to_string())?;
+    row(out, "Days remaining", stats.days_left.to_string())?;
+    if stats.future_holidays > 0 || stats.future_vacation_days > 0 {
+        row(
+            out,
+            "Looking ahead",
+            format!(
+                "{} holiday(s), {} vacation day(s) still to come",
+                stats.future_holidays, stats.future_vacation_days
+            ),
+        )?;
+    }
     if stats.days_thus_far > 0 {
-        writeln!(
+        row(
             out,
-            "  Current average:      {:.1}%",
-            stats.current_average * 100.0
+            "Current average",
+            format!("{:.1}%", stats.current_average * 100.0),
         )?;
     }
     if stats.days_left > 0 && stats.days_still_needed > 0 {
-        writeln!(
+        row(
             out,
-            "  Rate needed:          {:.1}%",
-            stats.required_future_average * 100.0
+            "Rate needed",
+            format!("{:.1}%", stats.required_future_average * 100.0),
+        )?;
+    }
+    if stats.longest_streak > 0 {
+        row(
+            out,
+            "Streak",
+            format!(
+                "{} current, {} longest",
+                stats.current_streak, stats.longest_streak
+            ),
         )?;
     }
 
-    if let Some(proj) = stats.projected_completion_date {
+    if let Some(earliest) = stats.earliest_completion_date {
         writeln!(out)?;
-        writeln!(out, "  Projected completion: {}", proj.format("%b %-d, %Y"))?;
+        if let Some(proj) = stats.projected_completion_date {
+            row(
+                out,
+                "Projected completion",
+                proj.format("%b %-d, %Y").to_string(),
+            )?;
+        }
+        row(
+            out,
+            "Earliest completion",
+            earliest.format("%b %-d, %Y").to_string(),
+        )?;
     }
 
     writeln!(out)?;
-    writeln!(out, "  Holidays:             {}", stats.holidays)?;
-    writeln!(out, "  Vacation days:        {}", stats.vacation_days)?;
-    writeln!(out, "  Days off (remote):    {}", stats.days_off)?;
-    writeln!(out, "  Available workdays:   {}", stats.available_workdays)?;
+    row(out, "Holidays", stats.holidays.to_string())?;
+    row(out, "Vacation days", stats.vacation_days.to_string())?;
+    if stats.excused_days > 0 {
+        row(out, "Excused days", stats.excused_days.to_string())?;
+    }
+    row(out, "Days off (remote)", stats.days_off.to_string())?;
+    row(
+        out,
+        "Available workdays",
+        stats.available_workdays.to_string(),
+    )?;
 
     Ok(())
 }
 
+/// Writes `line` as is when it fits `layout.max_width`. Otherwise keeps its
+/// first `hang` columns (the label) intact and breaks the rest between words,
+/// starting each continuation line with `layout.wrap_indent` spaces.
+fn write_wrapped<W: std::io::Write>(
+    out: &mut W,
+    line: &str,
+    hang: usize,
+    layout: StatsLayout,
+) -> Result<()> {
+    let max = match layout.max_width {
+        Some(max) if line.chars().count() > max => max,
+        _ => {
+            writeln!(out, "{}", line)?;
+            return Ok(());
+        }
+    };
+    let split = line.char_indices().nth(hang).map_or(line.len(), |(i, _)| i);
+    let (head, rest) = line.split_at(split);
+    let mut current = head.to_string();
+    let mut has_word = false;
+    for word in rest.split_whitespace() {
+        if has_word && current.chars().count() + 1 + word.chars().count() > max {
+            writeln!(out, "{}", current)?;
+            current = " ".repeat(layout.wrap_indent);
+            has_word = false;
+        }
+        if has_word {
+            current.push(' ');
+        }
+        current.push_str(word);
+        has_word = true;
+    }
+    writeln!(out, "{}", current)?;
+    Ok(())
+}
+
+/// Prints one line per period: key, status, badged/required, and current
+/// average. `periods` may be unparsed; each is parsed here, and one whose
+/// dates don't parse, or whose stats fail, gets a warning line instead of
+/// aborting the run.
+pub(crate) fn write_stats_table<W: std::io::Write>(
+    periods: &[TimePeriod],
+    badge_data: &BadgeEntryData,
+    holiday_data: &HolidayData,
+    vacation_data: &VacationData,
+    settings: &AppSettings,
+    today: Option<NaiveDate>,
+    out: &mut W,
+) -> Result<()> {
+    writeln!(
+        out,
+        "{:<14}  {:<10}  {:>15}  {:>7}",
+        "Period", "Status", "Badged/Required", "Avg"
+    )?;
+    writeln!(
+        out,
+        "{:<14}  {:<10}  {:>15}  {:>7}",
+        "--------------", "----------", "---------------", "-------"
+    )?;
+
+    let options = StatsOptions::from(settings);
+    // ISO dates sort the same as strings, so unparsed periods sort too
+    let mut sorted: Vec<&TimePeriod> = periods.iter().collect();
+    sorted.sort_by(|a, b| a.start_date_raw.cmp(&b.start_date_raw));
+    for period in sorted {
+        let mut period = period.clone();
+        if let Err(e) = period.parse_dates() {
+            writeln!(out, "{:<14}  warning: skipped — {:#}", period.key, e)?;
+            continue;
+        }
+        match calculate_quarter_stats(
+            &period,
+            badge_data,
+            holiday_data,
+            vacation_data,
+            &options,
+            today,
+        ) {
+            Ok(stats) => writeln!(
+                out,
+                "{:<14}  {:<10}  {:>15}  {:>6.1}%",
+                period.key,
+                stats.compliance_status,
+                format!("{} / {}", stats.days_badged_in, stats.days_required),
+                stats.current_average * 100.0
+            )?,
+            Err(e) => writeln!(out, "{:<14}  warning: skipped — {}", period.key, e)?,
+        }
+    }
+    Ok(())
+}
+
+/// Prints a text histogram of remote-day gaps between office visits.
+pub(crate) fn write_gaps<W: std::io::Write>(
+    stats: &QuarterStats,
+    today: NaiveDate,
+    out: &mut W,
+) -> Result<()> {
+    writeln!(out)?;
+    writeln!(out, "  Gaps between office days:")?;
+    let Some(summary) = summarize_gaps(&gap_lengths(&stats.workday_stats, today)) else {
+        writeln!(out, "    (no gaps yet)")?;
+        return Ok(());
+    };
+    for (len, count) in &summary.histogram {
+        let unit = if *len == 1 { "day " } else { "days" };
+        writeln!(
+            out,
+            "    {:>2} {}  {:<20} {}",
+            len,
+            unit,
+            "#".repeat(*count as usize),
+            count
+        )?;
+    }
+    writeln!(
+        out,
+        "  Mean gap: {:.1}   Median: {:.1}   Max: {}",
+        summary.mean, summary.median, summary.max
+    )?;
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -141,25 +583,43 @@ mod tests {
             name: "Q1".to_string(),
             start_date: d(2025, 1, 1),
             end_date: d(2025, 3, 31),
-            days_badged_in: 30,
-            flex_days: 5,
+            days_badged_in: 30.0,
+            flex_days: 5.0,
+            excess_flex_days: 0.0,
+            non_qualifying_days: 0.0,
+            days_by_office: HashMap::new(),
+            flex_labels: vec!["Flex Credit".to_string()],
             days_thus_far: 50,
             days_left,
             total_calendar_days: 90,
+            quarter_elapsed_pct: 50.0,
+            days_until_end: 44,
             available_workdays: 62,
             total_days: 60,
             days_required: 30,
             days_still_needed,
+            carryover_days: 0,
             days_off: 3,
             holidays: 1,
             vacation_days: 2,
+            excused_days: 0,
+            future_holidays: 0,
+            future_vacation_days: 0,
+            ignored_badge_entries: 0,
             current_average: 0.60,
+            best_case_average: 0.75,
+            worst_case_average: 0.50,
             required_future_average: 0.40,
             compliance_status: compliance_status.to_string(),
             days_ahead_of_pace,
+            buffer_days_used: days_ahead_of_pace.max(0),
             remaining_missable_days: 5,
+            earliest_completion_date: projected_completion_date,
             projected_completion_date,
+            current_streak: 3,
+            longest_streak: 7,
             workday_stats: HashMap::new(),
+            pace_misses: Default::default(),
         }
     }
 
@@ -205,6 +665,39 @@ mod tests {
         assert!(output.contains("Mar 15, 2025"));
     }
 
+    #[test]
+    fn test_write_stats_earliest_completion_without_projection() {
+        let mut stats = make_stats("At Risk", -4, 10, 8, None);
+        stats.earliest_completion_date = Some(d(2025, 3, 27));
+        let mut buf = Vec::new();
+        write_stats(&stats, &default_settings(), &mut buf).unwrap();
+        let output = String::from_utf8(buf).unwrap();
+        assert!(output.contains("Earliest completion:  Mar 27, 2025"));
+        assert!(!output.contains("Projected completion"));
+    }
+
+    #[test]
+    fn test_write_stats_buffer_used_only_when_ahead() {
+        let mut buf = Vec::new();
+        write_stats(
+            &make_stats("On Track", 3, 10, 3, None),
+            &default_settings(),
+            &mut buf,
+        )
+        .unwrap();
+        let output = String::from_utf8(buf).unwrap();
+        assert!(output.contains("Buffer used:          3 day(s) in beyond pace"));
+
+        let mut buf = Vec::new();
+        write_stats(
+            &make_stats("At Risk", -2, 10, 8, None),
+            &default_settings(),
+            &mut buf,
+        )
+        .unwrap();
+        assert!(!String::from_utf8(buf).unwrap().contains("Buffer used"));
+    }
+
     #[test]
     fn test_write_stats_no_projected_when_none() {
         let stats = make_stats("On Track", 2, 10, 3, None);
@@ -235,5 +728,353 @@ mod tests {
         let output = String::from_utf8(buf).unwrap();
         assert!(output.contains("25 office"));
         assert!(output.contains("5 flex"));
+        assert!(!output.contains("Flex labels"));
+    }
+
+    #[test]
+    fn test_write_stats_lists_mixed_flex_labels() {
+        let mut stats = make_stats("On Track", 0, 10, 2, None);
+        stats.flex_labels = vec!["Flex Credit".to_string(), "Remote Day".to_string()];
+        let mut buf = Vec::new();
+        write_stats(&stats, &default_settings(), &mut buf).unwrap();
+        let output = String::from_utf8(buf).unwrap();
+        assert!(output.contains("Flex labels:          Flex Credit, Remote Day"));
+    }
+
+    #[test]
+    fn test_write_stats_streak_line() {
+        let mut stats = make_stats("On Track", 0, 10, 2, None);
+        let mut buf = Vec::new();
+        write_stats(&stats, &default_settings(), &mut buf).unwrap();
+        let output = String::from_utf8(buf).unwrap();
+        assert!(output.contains("Streak:               3 current, 7 longest"));
+
+        stats.current_streak = 0;
+        stats.longest_streak = 0;
+        let mut buf = Vec::new();
+        write_stats(&stats, &default_settings(), &mut buf).unwrap();
+        assert!(!String::from_utf8(buf).unwrap().contains("Streak:"));
+    }
+
+    #[test]
+    fn test_write_stats_looking_ahead_line() {
+        let mut stats = make_stats("On Track", 0, 10, 2, None);
+        let mut buf = Vec::new();
+        write_stats(&stats, &default_settings(), &mut buf).unwrap();
+        assert!(!String::from_utf8(buf).unwrap().contains("Looking ahead"));
+
+        stats.future_holidays = 1;
+        stats.future_vacation_days = 4;
+        let mut buf = Vec::new();
+        write_stats(&stats, &default_settings(), &mut buf).unwrap();
+        assert!(
+            String::from_utf8(buf)
+                .unwrap()
+                .contains("Looking ahead:        1 holiday(s), 4 vacation day(s) still to come")
+        );
+    }
+
+    #[test]
+    fn test_write_stats_by_office() {
+        let mut stats = make_stats("On Track", 0, 10, 2, None);
+        stats.days_by_office = HashMap::from([
+            ("Reston, VA".to_string(), 5),
+            ("McLean, VA".to_string(), 20),
+        ]);
+        let mut buf = Vec::new();
+        write_stats(&stats, &default_settings(), &mut buf).unwrap();
+        let output = String::from_utf8(buf).unwrap();
+        let mclean = output.find("    McLean, VA          20").unwrap();
+        let reston = output.find("    Reston, VA          5").unwrap();
+        assert!(output.contains("By office:"));
+        assert!(mclean < reston);
+    }
+
+    #[test]
+    fn test_write_stats_shows_excess_flex() {
+        let mut stats = make_stats("On Track", 0, 10, 2, None);
+        stats.flex_days = 7.0;
+        stats.excess_flex_days = 2.0;
+        let settings = AppSettings {
+            max_flex_credits: Some(5),
+            ..AppSettings::default()
+        };
+        let mut buf = Vec::new();
+        write_stats(&stats, &settings, &mut buf).unwrap();
+        let output = String::from_utf8(buf).unwrap();
+        assert!(output.contains("25 office, 7 flex"));
+        assert!(output.contains("Excess flex credits:  2  (over the 5 cap; not counted)"));
+    }
+
+    #[test]
+    fn test_narrow_format_keeps_lines_within_40_columns() {
+        let mut stats = make_stats("On Track", 2, 10, 2, Some(d(2025, 3, 20)));
+        stats.earliest_completion_date = Some(d(2025, 3, 14));
+        stats.excess_flex_days = 2.0;
+        stats.non_qualifying_days = 1.0;
+        stats.carryover_days = 3;
+        stats.future_holidays = 1;
+        stats.future_vacation_days = 4;
+        stats.days_by_office = HashMap::from([("McLean, VA".to_string(), 25)]);
+        let settings = AppSettings {
+            max_flex_credits: Some(5),
+            ..AppSettings::default()
+        };
+        let mut standard = Vec::new();
+        write_stats(&stats, &settings, &mut standard).unwrap();
+        let standard = String::from_utf8(standard).unwrap();
+        assert!(standard.lines().any(|l| l.chars().count() > 40));
+
+        let mut buf = Vec::new();
+        write_stats_with(&stats, &settings, &mut buf, StatsFormat::Narrow.layout()).unwrap();
+        let output = String::from_utf8(buf).unwrap();
+        for line in output.lines() {
+            assert!(line.chars().count() <= 40, "{line:?} is too long");
+        }
+        // Wrapped values continue on indented lines and keep every word
+        assert!(output.contains(" Non-qualifying:       1 (offices not in\n"));
+        assert!(output.contains("\n   qualifying_offices; not counted)\n"));
+        let words = |text: &str| text.split_whitespace().collect::<Vec<_>>().join(" ");
+        assert_eq!(words(&output), words(&standard));
+    }
+
+    #[test]
+    fn test_wide_format_widens_label_column() {
+        let stats = make_stats("On Track", 0, 10, 2, None);
+        let mut buf = Vec::new();
+        write_stats_with(
+            &stats,
+            &default_settings(),
+            &mut buf,
+            StatsFormat::Wide.layout(),
+        )
+        .unwrap();
+        let output = String::from_utf8(buf).unwrap();
+        assert!(output.contains("\n    Status:                     On Track\n"));
+        assert!(output.contains("\n    Badged in:                  30\n"));
+    }
+
+    #[test]
+    fn test_write_gaps_histogram() {
+        use crate::calc::workday::create_workday_map;
+        use crate::data::WorkWeek;
+        let mut stats = make_stats("On Track", 0, 10, 2, None);
+        let mut map = create_workday_map(d(2025, 1, 6), d(2025, 1, 17), WorkWeek::default());
+        for day in map.values_mut() {
+            day.is_counted = true;
+        }
+        for date in ["2025-01-06", "2025-01-09", "2025-01-10", "2025-01-17"] {
+            map.get_mut(date).unwrap().is_badged_in = true;
+        }
+        stats.workday_stats = map;
+        let mut buf = Vec::new();
+        write_gaps(&stats, d(2025, 1, 20), &mut buf).unwrap();
+        let output = String::from_utf8(buf).unwrap();
+        assert!(output.contains(" 2 days  #"));
+        assert!(output.contains(" 4 days  #"));
+        assert!(output.contains("Mean gap: 3.0"));
+        assert!(output.contains("Max: 4"));
+    }
+
+    #[test]
+    fn test_status_exit_code() {
+        assert_eq!(status_exit_code("Achieved"), 0);
+        assert_eq!(status_exit_code("On Track"), 0);
+        assert_eq!(status_exit_code("At Risk"), 1);
+        assert_eq!(status_exit_code("Impossible"), 2);
+    }
+
+    #[test]
+    fn test_write_check_status() {
+        let stats = make_stats("At Risk", 0, 10, 2, None);
+        let mut buf = Vec::new();
+        let code = write_check_status(&stats, &mut buf).unwrap();
+        assert_eq!(code, 1);
+        let output = String::from_utf8(buf).unwrap();
+        assert_eq!(output, format!("{}: At Risk\n", stats.name));
+    }
+
+    #[test]
+    fn test_write_gaps_none() {
+        let stats = make_stats("On Track", 0, 10, 2, None);
+        let mut buf = Vec::new();
+        write_gaps(&stats, d(2025, 1, 20), &mut buf).unwrap();
+        let output = String::from_utf8(buf).unwrap();
+        assert!(output.contains("no gaps yet"));
+    }
+
+    #[test]
+    fn test_write_stats_json() {
+        let stats = make_stats("On Track", 3, 20, 10, Some(d(2025, 3, 14)));
+        let mut buf = Vec::new();
+        write_stats_json(&stats, &mut buf).unwrap();
+        let value: serde_json::Value = serde_json::from_slice(&buf).unwrap();
+        assert_eq!(value["compliance_status"], "On Track");
+        assert_eq!(value["days_required"], 30);
+        assert_eq!(value["projected_completion_date"], "2025-03-14");
+        assert!(value.get("workday_stats").is_none());
+    }
+
+    fn make_period(key: &str, start: &str, end: &str) -> TimePeriod {
+        let mut tp = TimePeriod {
+            key: key.to_string(),
+            name: key.to_string(),
+            start_date_raw: start.to_string(),
+            end_date_raw: end.to_string(),
+            start_date: None,
+            end_date: None,
+            ..Default::default()
+        };
+        let _ = tp.parse_dates();
+        tp
+    }
+
+    #[test]
+    fn test_period_stats_as_of_moves_today() {
+        let mut td = TimePeriodData::new();
+        td.add(make_period("Q1_2025", "2025-01-01", "2025-03-31"));
+        let stats = |key: Option<&str>, today: NaiveDate| {
+            period_stats(
+                &td,
+                key,
+                &BadgeEntryData::default(),
+                &HolidayData::default(),
+                &VacationData::default(),
+                &AppSettings::default(),
+                today,
+            )
+        };
+        let early = stats(Some("Q1_2025"), d(2025, 1, 15)).unwrap();
+        let late = stats(Some("Q1_2025"), d(2025, 3, 15)).unwrap();
+        // Jan 1–14: 10 workdays; Jan 1–Mar 14: 53 workdays
+        assert_eq!(early.days_thus_far, 10);
+        assert_eq!(late.days_thus_far, 53);
+
+        // Without a key, the period is the one containing the as-of date
+        assert_eq!(stats(None, d(2025, 2, 1)).unwrap().name, "Q1_2025");
+        assert!(stats(None, d(2025, 5, 1)).is_err());
+    }
+
+    #[test]
+    fn test_period_stats_without_current_period_lists_keys() {
+        let mut td = TimePeriodData::new();
+        td.add(make_period("Q1_2025", "2025-01-01", "2025-03-31"));
+        td.add(make_period("Q2_2025", "2025-04-01", "2025-06-30"));
+        let err = period_stats(
+            &td,
+            None,
+            &BadgeEntryData::default(),
+            &HolidayData::default(),
+            &VacationData::default(),
+            &AppSettings::default(),
+            d(2025, 8, 1),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "no period configured for 2025-08-01; available keys: Q1_2025, Q2_2025"
+        );
+    }
+
+    #[test]
+    fn test_period_stats_applies_carryover_when_enabled() {
+        let mut td = TimePeriodData::new();
+        td.add(make_period("Q1_2025", "2025-01-01", "2025-03-31"));
+        td.add(make_period("Q2_2025", "2025-04-01", "2025-06-30"));
+        let mut badges = BadgeEntryData::default();
+        // 40 office days in Q1 against 32 required: 8 surplus
+        let mut day = d(2025, 1, 1);
+        while badges.len() < 40 {
+            if crate::calc::workday::is_workday(day, Default::default()) {
+                badges.add(crate::data::BadgeEntry::new(day, "HQ", false));
+            }
+            day = day.succ_opt().unwrap();
+        }
+        let stats = |settings: &AppSettings| {
+            period_stats(
+                &td,
+                Some("Q2_2025"),
+                &badges,
+                &HolidayData::default(),
+                &VacationData::default(),
+                settings,
+                d(2025, 4, 1),
+            )
+            .unwrap()
+        };
+        let plain = stats(&AppSettings::default());
+        let carried = stats(&AppSettings {
+            allow_carryover: true,
+            ..AppSettings::default()
+        });
+        assert_eq!(plain.carryover_days, 0);
+        assert_eq!(carried.carryover_days, 8);
+        assert_eq!(carried.days_still_needed, plain.days_still_needed - 8);
+
+        let mut buf = Vec::new();
+        write_stats(&carried, &AppSettings::default(), &mut buf).unwrap();
+        let output = String::from_utf8(buf).unwrap();
+        assert!(output.contains("Carried over:         8 surplus day(s)"));
+    }
+
+    #[test]
+    fn test_write_stats_table_one_line_per_period() {
+        let periods = vec![
+            make_period("Q2_2025", "2025-04-01", "2025-06-30"),
+            make_period("Q1_2025", "2025-01-01", "2025-03-31"),
+        ];
+        let mut badges = BadgeEntryData::default();
+        badges.add(crate::data::BadgeEntry::new(d(2025, 1, 6), "HQ", false));
+        let mut buf = Vec::new();
+        write_stats_table(
+            &periods,
+            &badges,
+            &HolidayData::default(),
+            &VacationData::default(),
+            &AppSettings::default(),
+            Some(d(2025, 5, 1)),
+            &mut buf,
+        )
+        .unwrap();
+        let output = String::from_utf8(buf).unwrap();
+        let lines: Vec<_> = output.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert!(lines[2].starts_with("Q1_2025"));
+        assert!(lines[2].contains("1 / 32"));
+        assert!(lines[3].starts_with("Q2_2025"));
+    }
+
+    #[test]
+    fn test_stats_table_in_skips_period_with_bad_date() {
+        let dir = tempfile::tempdir().unwrap();
+        crate::cmd::init::run_in_dir(dir.path()).unwrap();
+        let settings = AppSettings::load_from(dir.path()).unwrap();
+        let mut td = TimePeriodData::new_with_file(settings.active_time_period_file(0));
+        for (key, start, end) in [
+            ("Q2_2025", "2025-04-01", "2025-06-31"),
+            ("Q1_2025", "2025-01-01", "2025-03-31"),
+        ] {
+            td.add(TimePeriod {
+                key: key.to_string(),
+                name: key.to_string(),
+                start_date_raw: start.to_string(),
+                end_date_raw: end.to_string(),
+                ..Default::default()
+            });
+        }
+        td.save_to(dir.path(), 0).unwrap();
+        // The normal loader refuses the whole file
+        assert!(
+            TimePeriodData::load_from(dir.path(), settings.active_time_period_file(0)).is_err()
+        );
+
+        let mut buf = Vec::new();
+        stats_table_in(dir.path(), d(2025, 2, 1), &mut buf).unwrap();
+        let output = String::from_utf8(buf).unwrap();
+        let lines: Vec<_> = output.lines().collect();
+        assert_eq!(lines.len(), 4, "{output}");
+        assert!(lines[2].starts_with("Q1_2025") && !lines[2].contains("warning"));
+        assert!(lines[3].starts_with("Q2_2025 "));
+        assert!(lines[3].contains("warning: skipped — parsing end_date \"2025-06-31\""));
     }
 }
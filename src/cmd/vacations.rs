@@ -1,11 +1,85 @@
-use crate::data::{Persistable, VacationData};
-use anyhow::Result;
+use crate::data::vacation::Vacation;
+use crate::data::{AppSettings, Persistable, VacationData};
+use anyhow::{Context, Result, bail};
+use chrono::NaiveDate;
 
 pub fn run() -> Result<()> {
     let vacation_data = VacationData::load()?;
     write_vacations(&vacation_data, &mut std::io::stdout())
 }
 
+pub fn run_add(destination: &str, from: &str, to: &str, approved: bool, force: bool) -> Result<()> {
+    let mut vacation_data = VacationData::load()?;
+    add_vacation(&mut vacation_data, destination, from, to, approved, force)?;
+    vacation_data.save(AppSettings::load()?.backup_count)?;
+    println!(
+        "Added vacation to {} ({} to {}). {} vacation(s) recorded.",
+        destination,
+        from,
+        to,
+        vacation_data.len()
+    );
+    Ok(())
+}
+
+pub fn run_remove(index: usize) -> Result<()> {
+    let mut vacation_data = VacationData::load()?;
+    let removed = remove_vacation(&mut vacation_data, index)?;
+    vacation_data.save(AppSettings::load()?.backup_count)?;
+    println!(
+        "Removed vacation #{}: {} ({} to {}). {} vacation(s) recorded.",
+        index,
+        removed.destination,
+        removed.start_date,
+        removed.end_date,
+        vacation_data.len()
+    );
+    Ok(())
+}
+
+/// Removes the vacation at 1-based `index`, as numbered by `rto vacations`.
+pub(crate) fn remove_vacation(data: &mut VacationData, index: usize) -> Result<Vacation> {
+    let count = data.vacations.len();
+    if index == 0 || index > count {
+        bail!(
+            "no vacation #{} (there {} {}; see 'rto vacations')",
+            index,
+            if count == 1 { "is" } else { "are" },
+            count
+        );
+    }
+    Ok(data.vacations.remove(index - 1))
+}
+
+/// Validates the range and appends a vacation. Overlapping an existing
+/// vacation is an error unless `force` is set.
+pub(crate) fn add_vacation(
+    data: &mut VacationData,
+    destination: &str,
+    from: &str,
+    to: &str,
+    approved: bool,
+    force: bool,
+) -> Result<()> {
+    let start = NaiveDate::parse_from_str(from, "%Y-%m-%d")
+        .with_context(|| format!("invalid start date '{}' (use YYYY-MM-DD)", from))?;
+    let end = NaiveDate::parse_from_str(to, "%Y-%m-%d")
+        .with_context(|| format!("invalid end date '{}' (use YYYY-MM-DD)", to))?;
+    if end < start {
+        bail!("end date {} is before start date {}", to, from);
+    }
+    if !force && let Some(existing) = data.vacations.iter().find(|v| v.overlaps(start, end)) {
+        bail!(
+            "overlaps existing vacation to {} ({} to {}); use --force to add anyway",
+            existing.destination,
+            existing.start_date,
+            existing.end_date
+        );
+    }
+    data.add(Vacation::new(destination, from, to, approved));
+    Ok(())
+}
+
 pub(crate) fn write_vacations<W: std::io::Write>(data: &VacationData, out: &mut W) -> Result<()> {
     let all = data.all();
     if all.is_empty() {
@@ -115,4 +189,62 @@ mod tests {
         assert_eq!(result.chars().count(), 30);
         assert!(result.ends_with("..."));
     }
+
+    #[test]
+    fn test_remove_vacation_by_one_based_index() {
+        let mut data = make_data(vec![
+            Vacation::new("Hawaii", "2025-05-10", "2025-05-17", true),
+            Vacation::new("Paris", "2025-08-01", "2025-08-14", true),
+        ]);
+        let err = remove_vacation(&mut data, 3).unwrap_err();
+        assert!(err.to_string().contains("no vacation #3 (there are 2"));
+        assert!(remove_vacation(&mut data, 0).is_err());
+        assert_eq!(data.len(), 2);
+
+        let removed = remove_vacation(&mut data, 2).unwrap();
+        assert_eq!(removed.destination, "Paris");
+        assert_eq!(data.vacations[0].destination, "Hawaii");
+    }
+
+    #[test]
+    fn test_add_vacation_valid() {
+        let mut data = make_data(vec![]);
+        add_vacation(&mut data, "Hawaii", "2025-05-10", "2025-05-17", true, false).unwrap();
+        assert_eq!(data.len(), 1);
+        assert!(data.vacations[0].approved);
+    }
+
+    #[test]
+    fn test_add_vacation_rejects_reversed_range() {
+        let mut data = make_data(vec![]);
+        let err = add_vacation(
+            &mut data,
+            "Hawaii",
+            "2025-05-17",
+            "2025-05-10",
+            false,
+            false,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("before"));
+        assert!(add_vacation(&mut data, "Hawaii", "May 1", "2025-05-10", false, false).is_err());
+        assert!(data.is_empty());
+    }
+
+    #[test]
+    fn test_add_vacation_rejects_overlap_unless_forced() {
+        let mut data = make_data(vec![Vacation::new(
+            "Hawaii",
+            "2025-05-10",
+            "2025-05-17",
+            true,
+        )]);
+        let err =
+            add_vacation(&mut data, "Paris", "2025-05-15", "2025-05-20", false, false).unwrap_err();
+        assert!(err.to_string().contains("Hawaii"));
+        assert_eq!(data.len(), 1);
+
+        add_vacation(&mut data, "Paris", "2025-05-15", "2025-05-20", false, true).unwrap();
+        assert_eq!(data.len(), 2);
+    }
 }
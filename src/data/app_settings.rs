@@ -1,13 +1,96 @@
-use crate::data::persistence::{load_yaml_from, save_yaml_to};
+use crate::data::persistence::{load_file_from, save_file_to};
 use anyhow::Result;
+use chrono::{Datelike, NaiveDate, Weekday};
 use serde::{Deserialize, Serialize};
+use std::fmt;
 use std::path::Path;
 
 const SETTINGS_FILENAME: &str = "settings.yaml";
+const SETTINGS_TOML_FILENAME: &str = "settings.toml";
 const DEFAULT_OFFICE: &str = "McLean, VA";
 const DEFAULT_FLEX: &str = "Flex Credit";
 const DEFAULT_GOAL: i32 = 50;
 const DEFAULT_TIME_PERIOD_FILE: &str = "workday-fiscal-quarters.yaml";
+const DEFAULT_COUNTRY: &str = "US";
+
+/// How the per-period office requirement is expressed.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum TargetMode {
+    /// Percentage of available workdays in the period (e.g. 50.0)
+    QuarterPercentage(f64),
+    /// Office days per ISO week, capped at the days available that week
+    DaysPerWeek(u32),
+}
+
+impl From<i32> for TargetMode {
+    fn from(goal_pct: i32) -> Self {
+        TargetMode::QuarterPercentage(goal_pct as f64)
+    }
+}
+
+impl fmt::Display for TargetMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TargetMode::QuarterPercentage(pct) => write!(f, "{}%", pct),
+            TargetMode::DaysPerWeek(n) => write!(f, "{} days/week", n),
+        }
+    }
+}
+
+/// Which weekdays are working days, as a 7-bit mask (bit 0 = Monday).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WorkWeek(u8);
+
+impl Default for WorkWeek {
+    /// Monday through Friday
+    fn default() -> Self {
+        WorkWeek(0b001_1111)
+    }
+}
+
+impl WorkWeek {
+    pub fn from_days(days: &[Weekday]) -> Self {
+        WorkWeek(
+            days.iter()
+                .fold(0, |mask, d| mask | (1 << d.num_days_from_monday())),
+        )
+    }
+
+    pub fn contains(&self, weekday: Weekday) -> bool {
+        self.0 & (1 << weekday.num_days_from_monday()) != 0
+    }
+
+    pub fn is_workday(&self, date: NaiveDate) -> bool {
+        self.contains(date.weekday())
+    }
+}
+
+fn default_theme() -> String {
+    "dark".to_string()
+}
+
+fn default_week_start() -> Weekday {
+    Weekday::Sun
+}
+
+fn default_poll_ms() -> u64 {
+    100
+}
+
+fn default_count_unapproved_vacations() -> bool {
+    true
+}
+
+fn default_work_days() -> Vec<Weekday> {
+    vec![
+        Weekday::Mon,
+        Weekday::Tue,
+        Weekday::Wed,
+        Weekday::Thu,
+        Weekday::Fri,
+    ]
+}
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct AppSettings {
@@ -15,6 +98,62 @@ pub struct AppSettings {
     pub flex_credit: String,
     pub goal: i32,
     pub time_periods: Vec<String>,
+    /// Observe holidays on days off on the nearest workday (Saturday to Friday,
+    /// Sunday to Monday for a Monday–Friday week).
+    #[serde(default)]
+    pub observe_weekend_holidays: bool,
+    /// Office days to aim for each Monday–Friday week; 0 hides the weekly readout.
+    #[serde(default)]
+    pub min_days_per_week: i32,
+    /// Country whose public holidays `rto holidays generate` produces by default.
+    #[serde(default)]
+    pub country: String,
+    /// Annual PTO grant in days; 0 hides the year-end projection.
+    #[serde(default)]
+    pub pto_days_per_year: i32,
+    /// Overrides `goal` when set, e.g. `target_mode: { days_per_week: 3 }`.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "serde_norway::with::singleton_map"
+    )]
+    pub target_mode: Option<TargetMode>,
+    /// Timestamped `.bak` copies to keep of each data file; 0 disables backups.
+    #[serde(default)]
+    pub backup_count: usize,
+    /// Weekdays that count as working days, e.g. `[Tue, Wed, Thu, Fri, Sat]`.
+    #[serde(default = "default_work_days")]
+    pub work_days: Vec<Weekday>,
+    /// Most flex credits per period that count toward the goal; unset means no cap.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_flex_credits: Option<u32>,
+    /// Offices whose badge-ins count toward the goal; unset means all of them.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub qualifying_offices: Option<Vec<String>>,
+    /// TUI color theme: "dark", "light", or "high-contrast".
+    #[serde(default = "default_theme")]
+    pub theme: String,
+    /// Let the previous period's surplus office days reduce this period's
+    /// remaining requirement.
+    #[serde(default)]
+    pub allow_carryover: bool,
+    /// Count today toward the days so far even before it is badged, instead
+    /// of leaving it among the days left until it is over.
+    #[serde(default)]
+    pub count_today_as_elapsed: bool,
+    /// Let vacations that aren't approved yet reduce the required days, like
+    /// approved ones. When off they are still shown but count as workdays.
+    #[serde(default = "default_count_unapproved_vacations")]
+    pub count_unapproved_vacations: bool,
+    /// How long the TUI waits for input before checking for a date change.
+    #[serde(default = "default_poll_ms")]
+    pub poll_ms: u64,
+    /// Delete vacations/holidays in the TUI on `x` without asking to confirm.
+    #[serde(default)]
+    pub quick_delete: bool,
+    /// First column of the TUI calendar, e.g. `Mon` for Monday-first weeks.
+    #[serde(default = "default_week_start")]
+    pub week_start: Weekday,
 }
 
 impl Default for AppSettings {
@@ -24,6 +163,22 @@ impl Default for AppSettings {
             flex_credit: DEFAULT_FLEX.to_string(),
             goal: DEFAULT_GOAL,
             time_periods: vec![DEFAULT_TIME_PERIOD_FILE.to_string()],
+            observe_weekend_holidays: false,
+            min_days_per_week: 0,
+            country: DEFAULT_COUNTRY.to_string(),
+            pto_days_per_year: 0,
+            target_mode: None,
+            backup_count: 0,
+            work_days: default_work_days(),
+            max_flex_credits: None,
+            qualifying_offices: None,
+            theme: default_theme(),
+            allow_carryover: false,
+            count_today_as_elapsed: false,
+            count_unapproved_vacations: default_count_unapproved_vacations(),
+            poll_ms: default_poll_ms(),
+            quick_delete: false,
+            week_start: default_week_start(),
         }
     }
 }
@@ -36,7 +191,7 @@ impl AppSettings {
 
     pub fn load_from(dir: &Path) -> Result<Self> {
         let mut settings = Self::default();
-        let loaded: Option<AppSettings> = load_yaml_from(dir, SETTINGS_FILENAME)?;
+        let loaded: Option<AppSettings> = load_file_from(dir, Self::filename_in(dir))?;
         if let Some(loaded) = loaded {
             if !loaded.default_office.is_empty() {
                 settings.default_office = loaded.default_office;
@@ -50,6 +205,34 @@ impl AppSettings {
             if !loaded.time_periods.is_empty() {
                 settings.time_periods = loaded.time_periods;
             }
+            settings.observe_weekend_holidays = loaded.observe_weekend_holidays;
+            if loaded.min_days_per_week > 0 {
+                settings.min_days_per_week = loaded.min_days_per_week;
+            }
+            if !loaded.country.is_empty() {
+                settings.country = loaded.country;
+            }
+            if loaded.pto_days_per_year > 0 {
+                settings.pto_days_per_year = loaded.pto_days_per_year;
+            }
+            settings.target_mode = loaded.target_mode;
+            settings.backup_count = loaded.backup_count;
+            if !loaded.work_days.is_empty() {
+                settings.work_days = loaded.work_days;
+            }
+            settings.max_flex_credits = loaded.max_flex_credits;
+            settings.qualifying_offices = loaded.qualifying_offices;
+            if !loaded.theme.is_empty() {
+                settings.theme = loaded.theme;
+            }
+            settings.allow_carryover = loaded.allow_carryover;
+            settings.count_today_as_elapsed = loaded.count_today_as_elapsed;
+            settings.count_unapproved_vacations = loaded.count_unapproved_vacations;
+            if loaded.poll_ms > 0 {
+                settings.poll_ms = loaded.poll_ms;
+            }
+            settings.quick_delete = loaded.quick_delete;
+            settings.week_start = loaded.week_start;
         }
         Ok(settings)
     }
@@ -61,7 +244,27 @@ impl AppSettings {
     }
 
     pub fn save_to(&self, dir: &Path) -> Result<()> {
-        save_yaml_to(dir, SETTINGS_FILENAME, self)
+        save_file_to(dir, Self::filename_in(dir), self, self.backup_count)
+    }
+
+    /// The settings file used in `dir`: `settings.toml` when present, else
+    /// `settings.yaml` (the default for new data directories).
+    pub fn filename_in(dir: &Path) -> &'static str {
+        if dir.join(SETTINGS_TOML_FILENAME).exists() {
+            SETTINGS_TOML_FILENAME
+        } else {
+            SETTINGS_FILENAME
+        }
+    }
+
+    /// The configured `work_days` as a [`WorkWeek`].
+    pub fn work_week(&self) -> WorkWeek {
+        WorkWeek::from_days(&self.work_days)
+    }
+
+    /// The requirement used for stats: `target_mode` if set, else `goal` percent.
+    pub fn target(&self) -> TargetMode {
+        self.target_mode.unwrap_or(TargetMode::from(self.goal))
     }
 
     pub fn active_time_period_file(&self, idx: usize) -> &str {
@@ -87,6 +290,7 @@ mod tests {
         assert_eq!(settings.flex_credit, "Flex Credit");
         assert_eq!(settings.goal, 50);
         assert_eq!(settings.time_periods.len(), 1);
+        assert_eq!(settings.country, "US");
     }
 
     #[test]
@@ -106,6 +310,22 @@ mod tests {
             flex_credit: "Remote Credit".to_string(),
             goal: 60,
             time_periods: vec!["quarters.yaml".to_string(), "halves.yaml".to_string()],
+            observe_weekend_holidays: true,
+            min_days_per_week: 3,
+            country: "UK".to_string(),
+            pto_days_per_year: 20,
+            target_mode: Some(TargetMode::DaysPerWeek(3)),
+            backup_count: 5,
+            work_days: vec![Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Sat],
+            max_flex_credits: Some(4),
+            qualifying_offices: Some(vec!["HQ".to_string(), "Reston, VA".to_string()]),
+            theme: "light".to_string(),
+            allow_carryover: true,
+            count_today_as_elapsed: true,
+            count_unapproved_vacations: false,
+            poll_ms: 250,
+            quick_delete: true,
+            week_start: Weekday::Mon,
         };
         settings.save_to(tmp.path()).unwrap();
         let loaded = AppSettings::load_from(tmp.path()).unwrap();
@@ -113,6 +333,58 @@ mod tests {
         assert_eq!(loaded.flex_credit, "Remote Credit");
         assert_eq!(loaded.goal, 60);
         assert_eq!(loaded.time_periods.len(), 2);
+        assert!(loaded.observe_weekend_holidays);
+        assert_eq!(loaded.min_days_per_week, 3);
+        assert_eq!(loaded.country, "UK");
+        assert_eq!(loaded.pto_days_per_year, 20);
+        assert_eq!(loaded.target(), TargetMode::DaysPerWeek(3));
+        assert_eq!(loaded.backup_count, 5);
+        let week = loaded.work_week();
+        assert!(week.contains(Weekday::Sat));
+        assert!(!week.contains(Weekday::Mon));
+        assert_eq!(week, WorkWeek::from_days(&settings.work_days));
+        assert_eq!(loaded.max_flex_credits, Some(4));
+        assert_eq!(
+            loaded.qualifying_offices,
+            Some(vec!["HQ".to_string(), "Reston, VA".to_string()])
+        );
+        assert_eq!(loaded.theme, "light");
+        assert!(loaded.allow_carryover);
+        assert!(loaded.count_today_as_elapsed);
+        assert!(!loaded.count_unapproved_vacations);
+        assert_eq!(loaded.poll_ms, 250);
+        assert!(loaded.quick_delete);
+        assert_eq!(loaded.week_start, Weekday::Mon);
+    }
+
+    #[test]
+    fn test_settings_toml_is_used_when_present() {
+        let tmp = TempDir::new().unwrap();
+        assert_eq!(AppSettings::filename_in(tmp.path()), "settings.yaml");
+        std::fs::write(
+            tmp.path().join("settings.toml"),
+            r#"default_office = "HQ"
+flex_credit = "Flex Credit"
+goal = 60
+time_periods = ["workday-fiscal-quarters.yaml"]
+work_days = ["Mon", "Tue"]
+
+[target_mode]
+days_per_week = 2
+"#,
+        )
+        .unwrap();
+        assert_eq!(AppSettings::filename_in(tmp.path()), "settings.toml");
+
+        let mut loaded = AppSettings::load_from(tmp.path()).unwrap();
+        assert_eq!(loaded.goal, 60);
+        assert_eq!(loaded.target(), TargetMode::DaysPerWeek(2));
+        assert!(!loaded.work_week().contains(Weekday::Wed));
+
+        loaded.theme = "light".to_string();
+        loaded.save_to(tmp.path()).unwrap();
+        assert!(!tmp.path().join("settings.yaml").exists());
+        assert_eq!(AppSettings::load_from(tmp.path()).unwrap().theme, "light");
     }
 
     #[test]
@@ -121,6 +393,7 @@ mod tests {
         let loaded = AppSettings::load_from(tmp.path()).unwrap();
         assert_eq!(loaded.default_office, "McLean, VA");
         assert_eq!(loaded.goal, 50);
+        assert_eq!(loaded.week_start, Weekday::Sun);
     }
 
     #[test]
@@ -145,4 +418,27 @@ mod tests {
             "workday-fiscal-quarters.yaml"
         );
     }
+
+    #[test]
+    fn test_target_defaults_to_goal_percentage() {
+        let settings = AppSettings {
+            goal: 60,
+            ..AppSettings::default()
+        };
+        assert_eq!(settings.target(), TargetMode::QuarterPercentage(60.0));
+        assert_eq!(settings.target().to_string(), "60%");
+        assert_eq!(TargetMode::DaysPerWeek(3).to_string(), "3 days/week");
+    }
+
+    #[test]
+    fn test_target_mode_parses_from_yaml() {
+        let tmp = TempDir::new().unwrap();
+        std::fs::write(
+            tmp.path().join(SETTINGS_FILENAME),
+            "default_office: HQ\nflex_credit: Flex\ngoal: 50\ntime_periods: []\ntarget_mode:\n  days_per_week: 3\n",
+        )
+        .unwrap();
+        let loaded = AppSettings::load_from(tmp.path()).unwrap();
+        assert_eq!(loaded.target(), TargetMode::DaysPerWeek(3));
+    }
 }
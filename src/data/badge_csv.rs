@@ -0,0 +1,184 @@
+use crate::data::badge_entry::{BadgeEntry, BadgeEntryData};
+use anyhow::{Context, Result, bail};
+use chrono::NaiveDate;
+
+pub const CSV_HEADER: &str = "date,office,is_flex,is_badged_in,credit,note";
+
+/// Renders badge entries as CSV, one row per entry, sorted by date.
+pub fn to_csv(data: &BadgeEntryData) -> String {
+    let mut entries = data.data.clone();
+    entries.sort_by(|a, b| a.key.cmp(&b.key));
+    let mut out = String::from(CSV_HEADER);
+    out.push('\n');
+    for e in &entries {
+        out.push_str(&format!(
+            "{},{},{},{},{},{}\n",
+            e.key,
+            quote(&e.office),
+            e.is_flex_credit,
+            e.is_badged_in,
+            e.credit,
+            quote(e.note.as_deref().unwrap_or_default())
+        ));
+    }
+    out
+}
+
+/// Parses CSV rows of `date,office,is_flex[,is_badged_in[,credit[,note]]]` into badge
+/// entries. A header row is skipped if present, as are blank lines. Errors name
+/// the 1-based line number.
+pub fn parse_csv(text: &str) -> Result<Vec<BadgeEntry>> {
+    let mut entries = Vec::new();
+    for (idx, line) in text.lines().enumerate() {
+        let line_no = idx + 1;
+        if line.trim().is_empty() || (idx == 0 && line.trim_start().starts_with("date")) {
+            continue;
+        }
+        let entry = parse_row(line).with_context(|| format!("line {}: {:?}", line_no, line))?;
+        entries.push(entry);
+    }
+    Ok(entries)
+}
+
+fn parse_row(line: &str) -> Result<BadgeEntry> {
+    let fields = split_fields(line)?;
+    if !(3..=6).contains(&fields.len()) {
+        bail!(
+            "expected 3 to 6 fields (date,office,is_flex[,is_badged_in[,credit[,note]]]), found {}",
+            fields.len()
+        );
+    }
+    let date = NaiveDate::parse_from_str(fields[0].trim(), "%Y-%m-%d")
+        .with_context(|| format!("invalid date '{}' (use YYYY-MM-DD)", fields[0].trim()))?;
+    let office = fields[1].trim();
+    let is_flex = parse_bool(&fields[2])?;
+    let mut entry = BadgeEntry::new(date, office, is_flex);
+    if let Some(badged) = fields.get(3) {
+        entry.is_badged_in = parse_bool(badged)?;
+    }
+    if let Some(credit) = fields.get(4) {
+        let credit: f64 = credit
+            .trim()
+            .parse()
+            .with_context(|| format!("invalid credit '{}'", credit.trim()))?;
+        if !(credit > 0.0 && credit <= 1.0) {
+            bail!(
+                "credit must be greater than 0 and at most 1 (got {})",
+                credit
+            );
+        }
+        entry.credit = credit;
+    }
+    if let Some(note) = fields.get(5).map(|n| n.trim())
+        && !note.is_empty()
+    {
+        entry = entry.with_note(note);
+    }
+    Ok(entry)
+}
+
+fn parse_bool(field: &str) -> Result<bool> {
+    match field.trim().to_ascii_lowercase().as_str() {
+        "true" | "yes" | "y" | "1" => Ok(true),
+        "false" | "no" | "n" | "0" | "" => Ok(false),
+        other => bail!("invalid boolean '{}'", other),
+    }
+}
+
+/// Quotes a field if it contains a comma, quote, or newline.
+pub(crate) fn quote(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Splits one CSV line, honoring double-quoted fields with `""` escapes.
+fn split_fields(line: &str) -> Result<Vec<String>> {
+    let mut fields = Vec::new();
+    let mut current = String::new();
+    let mut in_quotes = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                current.push('"');
+                chars.next();
+            }
+            '"' => in_quotes = !in_quotes,
+            ',' if !in_quotes => fields.push(std::mem::take(&mut current)),
+            _ => current.push(c),
+        }
+    }
+    if in_quotes {
+        bail!("unterminated quoted field");
+    }
+    fields.push(current);
+    Ok(fields)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_csv_roundtrip() {
+        let mut data = BadgeEntryData::default();
+        data.add(BadgeEntry::new(date(2025, 3, 14), "McLean, VA", false));
+        data.add(BadgeEntry::new(date(2025, 3, 3), "Flex \"Remote\"", true));
+        data.add(
+            BadgeEntry::new(date(2025, 3, 10), "HQ", false)
+                .with_credit(0.5)
+                .with_note("client on-site, all day"),
+        );
+
+        let csv = to_csv(&data);
+        assert!(csv.starts_with("date,office,is_flex,is_badged_in"));
+        assert!(csv.contains("2025-03-14,\"McLean, VA\",false,true,1,\n"));
+
+        let parsed = parse_csv(&csv).unwrap();
+        let keys: Vec<_> = parsed.iter().map(|e| e.key.as_str()).collect();
+        assert_eq!(keys, vec!["2025-03-03", "2025-03-10", "2025-03-14"]);
+        assert_eq!(parsed[0].office, "Flex \"Remote\"");
+        assert!(parsed[0].is_flex_credit);
+        assert_eq!(parsed[1].credit, 0.5);
+        assert_eq!(parsed[1].note.as_deref(), Some("client on-site, all day"));
+        assert_eq!(parsed[2].office, "McLean, VA");
+        assert_eq!(parsed[2].note, None);
+    }
+
+    #[test]
+    fn test_parse_csv_minimal_columns_without_header() {
+        let parsed = parse_csv("2025-03-14,HQ,false\n\n2025-03-17,Flex,yes\n").unwrap();
+        assert_eq!(parsed.len(), 2);
+        assert!(parsed[0].is_badged_in);
+        assert_eq!(parsed[0].credit, 1.0);
+        assert!(parsed[1].is_flex_credit);
+    }
+
+    #[test]
+    fn test_parse_csv_reports_line_number() {
+        let err = parse_csv("date,office,is_flex\n2025-03-14,HQ,false\n03/15/2025,HQ,false\n")
+            .unwrap_err();
+        assert!(format!("{err:#}").contains("line 3"));
+        assert!(format!("{err:#}").contains("invalid date"));
+    }
+
+    #[test]
+    fn test_parse_csv_rejects_out_of_range_credit() {
+        for credit in ["-3", "0", "5", "NaN"] {
+            let csv = format!("2025-03-14,HQ,false,true,1\n2025-03-17,HQ,false,true,{credit}\n");
+            let err = format!("{:#}", parse_csv(&csv).unwrap_err());
+            assert!(err.contains("line 2"), "{err}");
+            assert!(
+                err.contains("credit must be greater than 0 and at most 1"),
+                "{err}"
+            );
+        }
+    }
+}
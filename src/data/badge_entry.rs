@@ -1,8 +1,10 @@
-use crate::data::persistence::Persistable;
-use chrono::{NaiveDate, NaiveDateTime};
+use crate::data::persistence::{Format, Persistable, load_json_from};
+use anyhow::Result;
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
+use std::path::Path;
 
 const BADGE_DATE_FORMAT: &str = "%Y-%m-%d";
 
@@ -66,9 +68,20 @@ pub struct BadgeEntry {
     pub is_badged_in: bool,
     #[serde(default)]
     pub is_flex_credit: bool,
+    /// Fraction of a day this entry counts for (e.g. 0.5 for a morning in the office).
+    #[serde(default = "default_credit")]
+    pub credit: f64,
+    /// Free-form remark about the day, e.g. "client on-site".
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+fn default_credit() -> f64 {
+    1.0
 }
 
 impl BadgeEntry {
+    /// An entry stamped at midnight, as for a day logged after the fact.
     pub fn new(date: NaiveDate, office: &str, is_flex_credit: bool) -> Self {
         let key = date.format(BADGE_DATE_FORMAT).to_string();
         BadgeEntry {
@@ -77,12 +90,41 @@ impl BadgeEntry {
             office: office.to_string(),
             is_badged_in: true,
             is_flex_credit,
+            credit: default_credit(),
+            note: None,
+        }
+    }
+
+    /// An entry stamped with the time of day it was logged.
+    pub fn new_at(date: NaiveDate, office: &str, is_flex_credit: bool, time: NaiveTime) -> Self {
+        BadgeEntry {
+            date_time: FlexTime(date.and_time(time)),
+            ..Self::new(date, office, is_flex_credit)
         }
     }
 
+    /// Same entry, counting for `credit` days instead of a full day.
+    pub fn with_credit(mut self, credit: f64) -> Self {
+        self.credit = credit;
+        self
+    }
+
+    /// Same entry, with `note` attached.
+    pub fn with_note(mut self, note: &str) -> Self {
+        self.note = Some(note.to_string());
+        self
+    }
+
     pub fn entry_date(&self) -> Option<NaiveDate> {
         NaiveDate::parse_from_str(&self.key, BADGE_DATE_FORMAT).ok()
     }
+
+    /// When the badge was logged, or `None` for a midnight stamp, which is
+    /// what retroactive and imported entries carry.
+    pub fn logged_time(&self) -> Option<NaiveTime> {
+        let time = self.date_time.0.time();
+        (time != NaiveTime::MIN).then_some(time)
+    }
 }
 
 #[derive(Serialize, Deserialize, Default, Debug, Clone)]
@@ -95,23 +137,79 @@ impl Persistable for BadgeEntryData {
     fn filename() -> &'static str {
         "badge_data.json"
     }
-    fn is_json() -> bool {
-        true
+    fn format() -> Format {
+        Format::Json
+    }
+    fn after_load(&mut self) {
+        self.dedupe();
     }
 }
 
 impl BadgeEntryData {
+    /// Loads the file exactly as written, without collapsing duplicate dates.
+    /// Used by `rto doctor` to report them.
+    pub fn load_raw_from(dir: &Path) -> Result<Self> {
+        Ok(load_json_from(dir, &Self::resolved_filename())?.unwrap_or_default())
+    }
+
     pub fn has(&self, key: &str) -> bool {
         self.data.iter().any(|e| e.key == key)
     }
 
-    #[allow(dead_code)]
     pub fn get(&self, key: &str) -> Option<&BadgeEntry> {
         self.data.iter().find(|e| e.key == key)
     }
 
+    /// Adds `entry`, replacing any existing entry for the same date.
     pub fn add(&mut self, entry: BadgeEntry) {
-        self.data.push(entry);
+        match self.data.iter_mut().find(|e| e.key == entry.key) {
+            Some(existing) => *existing = entry,
+            None => self.data.push(entry),
+        }
+    }
+
+    /// Drops all but the last entry for each date.
+    pub fn dedupe(&mut self) {
+        let mut seen = HashSet::new();
+        let mut kept: Vec<BadgeEntry> = self
+            .data
+            .drain(..)
+            .rev()
+            .filter(|e| seen.insert(e.key.clone()))
+            .collect();
+        kept.reverse();
+        self.data = kept;
+    }
+
+    /// Sets each flex entry's label to `label_for(date)`, e.g. the current
+    /// `flex_credit` setting, so entries made under an old label match.
+    /// Entries with unparseable dates are left alone. Returns how many changed.
+    pub fn relabel_flex(&mut self, label_for: impl Fn(NaiveDate) -> String) -> usize {
+        let mut changed = 0;
+        for entry in self.data.iter_mut().filter(|e| e.is_flex_credit) {
+            let Some(date) = entry.entry_date() else {
+                continue;
+            };
+            let label = label_for(date);
+            if entry.office != label {
+                entry.office = label;
+                changed += 1;
+            }
+        }
+        changed
+    }
+
+    /// Sets or clears the note on the entry for `key`; a blank note clears it.
+    /// Returns false when there is no entry for that date.
+    pub fn set_note(&mut self, key: &str, note: &str) -> bool {
+        match self.data.iter_mut().find(|e| e.key == key) {
+            Some(entry) => {
+                let note = note.trim();
+                entry.note = (!note.is_empty()).then(|| note.to_string());
+                true
+            }
+            None => false,
+        }
     }
 
     pub fn remove(&mut self, key: &str) {
@@ -155,6 +253,20 @@ mod tests {
         NaiveDate::from_ymd_opt(y, m, d).unwrap()
     }
 
+    #[test]
+    fn test_relabel_flex_updates_only_stale_flex_entries() {
+        let mut data = BadgeEntryData::default();
+        data.add(BadgeEntry::new(date(2025, 1, 6), "Flex Credit", true));
+        data.add(BadgeEntry::new(date(2025, 1, 7), "Remote Day", true));
+        data.add(BadgeEntry::new(date(2025, 1, 8), "McLean, VA", false));
+
+        let changed = data.relabel_flex(|_| "Remote Day".to_string());
+        assert_eq!(changed, 1);
+        assert_eq!(data.get("2025-01-06").unwrap().office, "Remote Day");
+        assert_eq!(data.get("2025-01-08").unwrap().office, "McLean, VA");
+        assert_eq!(data.relabel_flex(|_| "Remote Day".to_string()), 0);
+    }
+
     #[test]
     fn test_badge_entry_new_sets_fields() {
         let entry = BadgeEntry::new(date(2025, 3, 15), "McLean, VA", false);
@@ -171,6 +283,26 @@ mod tests {
         assert!(entry.is_flex_credit);
     }
 
+    #[test]
+    fn test_badge_entry_new_at_keeps_logged_time() {
+        let time = NaiveTime::from_hms_opt(9, 14, 0).unwrap();
+        let entry = BadgeEntry::new_at(date(2025, 3, 14), "HQ", false, time);
+        assert_eq!(entry.key, "2025-03-14");
+        assert_eq!(entry.date_time.to_string(), "2025-03-14T09:14:00");
+        assert_eq!(entry.logged_time(), Some(time));
+
+        let json = serde_json::to_string(&entry).unwrap();
+        let back: BadgeEntry = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.logged_time(), Some(time));
+    }
+
+    #[test]
+    fn test_retroactive_entry_has_no_logged_time() {
+        let entry = BadgeEntry::new(date(2025, 3, 14), "HQ", false);
+        assert_eq!(entry.date_time.to_string(), "2025-03-14T00:00:00");
+        assert_eq!(entry.logged_time(), None);
+    }
+
     #[test]
     fn test_has_returns_true_when_present() {
         let mut data = BadgeEntryData::default();
@@ -209,6 +341,32 @@ mod tests {
         assert_eq!(data.len(), 2);
     }
 
+    #[test]
+    fn test_add_replaces_same_date() {
+        let mut data = BadgeEntryData::default();
+        data.add(BadgeEntry::new(date(2025, 3, 15), "McLean, VA", false));
+        data.add(BadgeEntry::new(date(2025, 3, 15), "Reston, VA", false));
+        assert_eq!(data.len(), 1);
+        assert_eq!(data.get("2025-03-15").unwrap().office, "Reston, VA");
+    }
+
+    #[test]
+    fn test_load_collapses_duplicate_dates() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let json = r#"{"badge_data": [
+            {"entry_date": "2025-03-14", "date_time": "2025-03-14T00:00:00", "office": "HQ"},
+            {"entry_date": "2025-03-15", "date_time": "2025-03-15T00:00:00", "office": "McLean, VA"},
+            {"entry_date": "2025-03-15", "date_time": "2025-03-15T00:00:00", "office": "Reston, VA"}
+        ]}"#;
+        std::fs::write(tmp.path().join("badge_data.json"), json).unwrap();
+
+        let loaded = BadgeEntryData::load_from(tmp.path()).unwrap();
+        assert_eq!(loaded.len(), 2);
+        assert_eq!(loaded.data[0].key, "2025-03-14");
+        assert_eq!(loaded.get("2025-03-15").unwrap().office, "Reston, VA");
+        assert_eq!(BadgeEntryData::load_raw_from(tmp.path()).unwrap().len(), 3);
+    }
+
     #[test]
     fn test_remove_deletes_entry() {
         let mut data = BadgeEntryData::default();
@@ -300,4 +458,51 @@ mod tests {
         let entry: BadgeEntry = serde_json::from_str(json).unwrap();
         assert_eq!(entry.key, "2025-01-10");
     }
+
+    #[test]
+    fn test_credit_defaults_to_full_day_when_missing() {
+        let json = r#"{"badge_data":[{"entry_date":"2025-03-14","date_time":"2025-03-14T00:00:00","office":"HQ","is_badged_in":true}]}"#;
+        let data: BadgeEntryData = serde_json::from_str(json).unwrap();
+        assert_eq!(data.get("2025-03-14").unwrap().credit, 1.0);
+    }
+
+    #[test]
+    fn test_with_credit_roundtrip() {
+        let entry = BadgeEntry::new(date(2025, 3, 14), "HQ", false).with_credit(0.5);
+        let json = serde_json::to_string(&entry).unwrap();
+        let back: BadgeEntry = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.credit, 0.5);
+    }
+
+    #[test]
+    fn test_note_roundtrips_through_save_and_load() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let mut data = BadgeEntryData::default();
+        data.add(BadgeEntry::new(date(2025, 3, 14), "HQ", false).with_note("client on-site"));
+        data.add(BadgeEntry::new(date(2025, 3, 17), "HQ", false));
+        data.save_to(tmp.path(), 0).unwrap();
+
+        let loaded = BadgeEntryData::load_from(tmp.path()).unwrap();
+        assert_eq!(
+            loaded.get("2025-03-14").unwrap().note.as_deref(),
+            Some("client on-site")
+        );
+        assert_eq!(loaded.get("2025-03-17").unwrap().note, None);
+        let raw = std::fs::read_to_string(tmp.path().join("badge_data.json")).unwrap();
+        assert_eq!(raw.matches("\"note\"").count(), 1);
+    }
+
+    #[test]
+    fn test_set_note_trims_and_clears() {
+        let mut data = BadgeEntryData::default();
+        data.add(BadgeEntry::new(date(2025, 3, 14), "HQ", false));
+        assert!(data.set_note("2025-03-14", "  team offsite "));
+        assert_eq!(
+            data.get("2025-03-14").unwrap().note.as_deref(),
+            Some("team offsite")
+        );
+        assert!(data.set_note("2025-03-14", "   "));
+        assert_eq!(data.get("2025-03-14").unwrap().note, None);
+        assert!(!data.set_note("2025-03-15", "nothing here"));
+    }
 }
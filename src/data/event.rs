@@ -1,11 +1,97 @@
-use crate::data::persistence::Persistable;
+use crate::data::persistence::{Format, Persistable};
+use chrono::{Datelike, NaiveDate, Weekday};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
 pub struct Event {
+    /// The event's day, or the first day of a recurring series
     pub date: String,
     pub description: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recurrence: Option<Recurrence>,
+    /// Occurrences (YYYY-MM-DD) deleted from a recurring series
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub skipped: Vec<String>,
+}
+
+/// How a recurring event repeats after its first `date`, optionally stopping
+/// after `until` (inclusive).
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+#[serde(tag = "every", rename_all = "lowercase")]
+pub enum Recurrence {
+    Weekly {
+        weekday: Weekday,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        until: Option<NaiveDate>,
+    },
+    /// On day `day` of each month; months too short for it use their last day.
+    Monthly {
+        day: u32,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        until: Option<NaiveDate>,
+    },
+}
+
+impl Recurrence {
+    pub fn until(&self) -> Option<NaiveDate> {
+        match self {
+            Recurrence::Weekly { until, .. } | Recurrence::Monthly { until, .. } => *until,
+        }
+    }
+
+    /// Whether the pattern lands on `date`, ignoring the series' start and end.
+    pub fn matches(&self, date: NaiveDate) -> bool {
+        match *self {
+            Recurrence::Weekly { weekday, .. } => date.weekday() == weekday,
+            Recurrence::Monthly { day, .. } => date.day() == day.min(days_in_month(date)),
+        }
+    }
+
+    /// Short description for prompts, e.g. "weekly on Mon".
+    pub fn label(&self) -> String {
+        match self {
+            Recurrence::Weekly { weekday, .. } => format!("weekly on {}", weekday),
+            Recurrence::Monthly { day, .. } => format!("monthly on day {}", day),
+        }
+    }
+}
+
+fn days_in_month(date: NaiveDate) -> u32 {
+    let (y, m) = (date.year(), date.month());
+    let next = if m == 12 {
+        NaiveDate::from_ymd_opt(y + 1, 1, 1)
+    } else {
+        NaiveDate::from_ymd_opt(y, m + 1, 1)
+    };
+    next.and_then(|d| d.pred_opt()).map_or(31, |d| d.day())
+}
+
+impl Event {
+    pub fn new(date: &str, description: &str) -> Self {
+        Event {
+            date: date.to_string(),
+            description: description.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Whether the event falls on `day`: its own date, or for a recurring
+    /// event any matching day from its date through `until` that hasn't been
+    /// skipped.
+    pub fn occurs_on(&self, day: NaiveDate) -> bool {
+        let key = day.format("%Y-%m-%d").to_string();
+        let Some(rec) = self.recurrence else {
+            return self.date == key;
+        };
+        let Ok(start) = NaiveDate::parse_from_str(&self.date, "%Y-%m-%d") else {
+            return false;
+        };
+        day >= start
+            && rec.until().is_none_or(|until| day <= until)
+            && rec.matches(day)
+            && !self.skipped.contains(&key)
+    }
 }
 
 #[derive(Serialize, Deserialize, Default, Debug, Clone)]
@@ -17,8 +103,8 @@ impl Persistable for EventData {
     fn filename() -> &'static str {
         "events.json"
     }
-    fn is_json() -> bool {
-        true
+    fn format() -> Format {
+        Format::Json
     }
 }
 
@@ -33,6 +119,21 @@ impl EventData {
             .retain(|e| !(e.date == date && e.description == description));
     }
 
+    /// Replaces the description of the first event on `date` matching
+    /// `old_desc`. Returns false if there is no such event.
+    pub fn update(&mut self, date: &str, old_desc: &str, new_desc: &str) -> bool {
+        let Some(event) = self
+            .events
+            .iter_mut()
+            .find(|e| e.date == date && e.description == old_desc)
+        else {
+            return false;
+        };
+        event.description = new_desc.to_string();
+        self.events.sort_by(|a, b| a.date.cmp(&b.date));
+        true
+    }
+
     #[allow(dead_code)]
     pub fn all(&self) -> Vec<Event> {
         self.events.clone()
@@ -48,10 +149,38 @@ impl EventData {
         self.events.is_empty()
     }
 
-    pub fn get_event_map(&self) -> HashMap<String, Vec<&Event>> {
+    /// Stops a recurring series from occurring on `day`, leaving the rest.
+    pub fn skip_occurrence(&mut self, date: &str, description: &str, day: NaiveDate) {
+        if let Some(event) = self
+            .events
+            .iter_mut()
+            .find(|e| e.date == date && e.description == description)
+        {
+            event.skipped.push(day.format("%Y-%m-%d").to_string());
+        }
+    }
+
+    /// Events on `day`, including occurrences of recurring series.
+    pub fn events_on(&self, day: NaiveDate) -> Vec<&Event> {
+        self.events.iter().filter(|e| e.occurs_on(day)).collect()
+    }
+
+    /// Events keyed by date. One-off events always appear under their own
+    /// date; recurring events are expanded over `start..=end`.
+    pub fn get_event_map(&self, start: NaiveDate, end: NaiveDate) -> HashMap<String, Vec<&Event>> {
         let mut map: HashMap<String, Vec<&Event>> = HashMap::new();
         for event in &self.events {
-            map.entry(event.date.clone()).or_default().push(event);
+            if event.recurrence.is_none() {
+                map.entry(event.date.clone()).or_default().push(event);
+                continue;
+            }
+            for day in start.iter_days().take_while(|d| *d <= end) {
+                if event.occurs_on(day) {
+                    map.entry(day.format("%Y-%m-%d").to_string())
+                        .or_default()
+                        .push(event);
+                }
+            }
         }
         map
     }
@@ -62,12 +191,29 @@ mod tests {
     use super::*;
 
     fn ev(date: &str, desc: &str) -> Event {
+        Event::new(date, desc)
+    }
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).unwrap()
+    }
+
+    fn weekly(date: &str, desc: &str, until: Option<NaiveDate>) -> Event {
         Event {
-            date: date.to_string(),
-            description: desc.to_string(),
+            recurrence: Some(Recurrence::Weekly {
+                weekday: Weekday::Mon,
+                until,
+            }),
+            ..Event::new(date, desc)
         }
     }
 
+    fn keys(map: &HashMap<String, Vec<&Event>>) -> Vec<String> {
+        let mut keys: Vec<_> = map.keys().cloned().collect();
+        keys.sort();
+        keys
+    }
+
     #[test]
     fn test_add_inserts_event() {
         let mut data = EventData::default();
@@ -105,6 +251,18 @@ mod tests {
         assert_eq!(data.len(), 1);
     }
 
+    #[test]
+    fn test_update_replaces_description_in_place() {
+        let mut data = EventData::default();
+        data.add(ev("2025-03-01", "Meeting"));
+        data.add(ev("2025-03-02", "Lnuch"));
+        data.add(ev("2025-03-03", "Review"));
+        assert!(data.update("2025-03-02", "Lnuch", "Lunch"));
+        let descs: Vec<_> = data.events.iter().map(|e| e.description.as_str()).collect();
+        assert_eq!(descs, vec!["Meeting", "Lunch", "Review"]);
+        assert!(!data.update("2025-03-01", "Lnuch", "Lunch"));
+    }
+
     #[test]
     fn test_remove_nonexistent_is_noop() {
         let mut data = EventData::default();
@@ -126,7 +284,7 @@ mod tests {
         data.add(ev("2025-03-01", "Event A"));
         data.add(ev("2025-03-01", "Event B"));
         data.add(ev("2025-03-02", "Event C"));
-        let map = data.get_event_map();
+        let map = data.get_event_map(d(2025, 3, 1), d(2025, 3, 31));
         assert_eq!(map["2025-03-01"].len(), 2);
         assert_eq!(map["2025-03-02"].len(), 1);
     }
@@ -134,10 +292,78 @@ mod tests {
     #[test]
     fn test_get_event_map_empty() {
         let data = EventData::default();
-        let map = data.get_event_map();
+        let map = data.get_event_map(d(2025, 3, 1), d(2025, 3, 31));
         assert!(map.is_empty());
     }
 
+    #[test]
+    fn test_weekly_expands_across_month() {
+        let mut data = EventData::default();
+        data.add(weekly("2025-02-24", "Standup", None));
+        let map = data.get_event_map(d(2025, 3, 1), d(2025, 3, 31));
+        assert_eq!(
+            keys(&map),
+            vec![
+                "2025-03-03",
+                "2025-03-10",
+                "2025-03-17",
+                "2025-03-24",
+                "2025-03-31"
+            ]
+        );
+        // Nothing before the series starts
+        let feb = data.get_event_map(d(2025, 2, 1), d(2025, 2, 23));
+        assert!(feb.is_empty());
+    }
+
+    #[test]
+    fn test_recurrence_stops_after_until() {
+        let mut data = EventData::default();
+        data.add(weekly("2025-03-03", "Standup", Some(d(2025, 3, 17))));
+        let map = data.get_event_map(d(2025, 3, 1), d(2025, 3, 31));
+        assert_eq!(keys(&map), vec!["2025-03-03", "2025-03-10", "2025-03-17"]);
+        assert!(!data.events[0].occurs_on(d(2025, 3, 24)));
+    }
+
+    #[test]
+    fn test_monthly_uses_last_day_of_short_months() {
+        let event = Event {
+            recurrence: Some(Recurrence::Monthly {
+                day: 31,
+                until: None,
+            }),
+            ..Event::new("2025-01-31", "Month-end close")
+        };
+        assert!(event.occurs_on(d(2025, 2, 28)));
+        assert!(event.occurs_on(d(2025, 3, 31)));
+        assert!(!event.occurs_on(d(2025, 3, 30)));
+        assert!(event.occurs_on(d(2025, 4, 30)));
+    }
+
+    #[test]
+    fn test_skip_occurrence_keeps_rest_of_series() {
+        let mut data = EventData::default();
+        data.add(weekly("2025-03-03", "Standup", None));
+        data.skip_occurrence("2025-03-03", "Standup", d(2025, 3, 10));
+        assert!(data.events_on(d(2025, 3, 10)).is_empty());
+        assert_eq!(data.events_on(d(2025, 3, 17)).len(), 1);
+        data.remove("2025-03-03", "Standup");
+        assert!(data.events_on(d(2025, 3, 17)).is_empty());
+    }
+
+    #[test]
+    fn test_one_off_event_json_has_no_recurrence_fields() {
+        let json = serde_json::to_string(&ev("2025-03-01", "Lunch")).unwrap();
+        assert_eq!(json, r#"{"date":"2025-03-01","description":"Lunch"}"#);
+        let json = serde_json::to_string(&weekly("2025-03-03", "Standup", None)).unwrap();
+        assert!(json.contains(r#""recurrence":{"every":"weekly","weekday":"Mon"}"#));
+        let back: Event = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            back.recurrence,
+            weekly("2025-03-03", "Standup", None).recurrence
+        );
+    }
+
     #[test]
     fn test_default_event_data_is_empty() {
         let data = EventData::default();
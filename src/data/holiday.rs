@@ -1,4 +1,6 @@
-use crate::data::persistence::Persistable;
+use crate::data::WorkWeek;
+use crate::data::persistence::{Format, Persistable};
+use chrono::{Duration, NaiveDate};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -26,21 +28,29 @@ impl Persistable for HolidayData {
     fn filename() -> &'static str {
         "holidays.yaml"
     }
-    fn is_json() -> bool {
-        false
+    fn format() -> Format {
+        Format::Yaml
+    }
+    fn after_load(&mut self) {
+        self.sort();
     }
 }
 
 impl HolidayData {
+    /// Adds `holiday`, keeping the list in date order.
     pub fn add(&mut self, holiday: Holiday) {
         self.holidays.push(holiday);
+        self.sort();
+    }
+
+    fn sort(&mut self) {
+        self.holidays.sort_by(|a, b| a.date.cmp(&b.date));
     }
 
     pub fn all(&self) -> Vec<Holiday> {
         self.holidays.clone()
     }
 
-    #[allow(dead_code)]
     pub fn len(&self) -> usize {
         self.holidays.len()
     }
@@ -50,15 +60,51 @@ impl HolidayData {
         self.holidays.is_empty()
     }
 
-    pub fn get_holiday_map(&self) -> HashMap<String, &Holiday> {
+    /// Maps each holiday date to its holiday. With `observe_weekends`
+    /// (`AppSettings::observe_weekend_holidays`), a holiday on a day off in
+    /// `week` is also mapped on its [`observed_date`]; the original date stays
+    /// in the map for display.
+    pub fn get_holiday_map(
+        &self,
+        week: WorkWeek,
+        observe_weekends: bool,
+    ) -> HashMap<String, &Holiday> {
         let mut map = HashMap::new();
         for h in &self.holidays {
             map.insert(h.date.clone(), h);
         }
+        if observe_weekends {
+            for h in &self.holidays {
+                if let Some(observed) = observed_date(&h.date, week) {
+                    map.entry(observed.format("%Y-%m-%d").to_string())
+                        .or_insert(h);
+                }
+            }
+        }
         map
     }
 }
 
+/// Returns the workday on which a holiday falling on a day off in `week` is
+/// observed: the nearest workday, the earlier one on a tie. With a
+/// Monday–Friday week this is the US federal rule (Saturday to Friday, Sunday
+/// to Monday). `None` if the date is a workday, unparseable, or `week` has no
+/// workdays.
+pub fn observed_date(date: &str, week: WorkWeek) -> Option<NaiveDate> {
+    let d = NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()?;
+    if week.is_workday(d) {
+        return None;
+    }
+    (1..7).find_map(|n| {
+        let (before, after) = (d - Duration::days(n), d + Duration::days(n));
+        if week.is_workday(before) {
+            Some(before)
+        } else {
+            week.is_workday(after).then_some(after)
+        }
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -85,6 +131,26 @@ mod tests {
         assert_eq!(data.len(), 2);
     }
 
+    #[test]
+    fn test_add_keeps_holidays_sorted_by_date() {
+        let mut data = HolidayData::default();
+        data.add(Holiday::new("Christmas Day", "2025-12-25"));
+        data.add(Holiday::new("New Year's Day", "2025-01-01"));
+        data.add(Holiday::new("Independence Day", "2025-07-04"));
+        let dates: Vec<&str> = data.holidays.iter().map(|h| h.date.as_str()).collect();
+        assert_eq!(dates, ["2025-01-01", "2025-07-04", "2025-12-25"]);
+    }
+
+    #[test]
+    fn test_load_sorts_hand_edited_holidays() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let yaml = "holidays:\n- name: Labor Day\n  date: 2025-09-01\n- name: Memorial Day\n  date: 2025-05-26\n";
+        std::fs::write(tmp.path().join("holidays.yaml"), yaml).unwrap();
+        let data = HolidayData::load_from(tmp.path()).unwrap();
+        assert_eq!(data.holidays[0].name, "Memorial Day");
+        assert_eq!(data.holidays[1].name, "Labor Day");
+    }
+
     #[test]
     fn test_all_returns_copy() {
         let mut data = HolidayData::default();
@@ -98,7 +164,7 @@ mod tests {
         let mut data = HolidayData::default();
         data.add(Holiday::new("Independence Day", "2025-07-04"));
         data.add(Holiday::new("Labor Day", "2025-09-01"));
-        let map = data.get_holiday_map();
+        let map = data.get_holiday_map(WorkWeek::default(), false);
         assert!(map.contains_key("2025-07-04"));
         assert!(map.contains_key("2025-09-01"));
         assert!(!map.contains_key("2025-12-25"));
@@ -108,7 +174,62 @@ mod tests {
     #[test]
     fn test_get_holiday_map_empty() {
         let data = HolidayData::default();
-        assert!(data.get_holiday_map().is_empty());
+        assert!(data.get_holiday_map(WorkWeek::default(), false).is_empty());
+    }
+
+    #[test]
+    fn test_observed_weekend_holiday_off_by_default() {
+        let mut data = HolidayData::default();
+        data.add(Holiday::new("Independence Day", "2026-07-04")); // Saturday
+        let map = data.get_holiday_map(WorkWeek::default(), false);
+        assert_eq!(map.len(), 1);
+        assert!(!map.contains_key("2026-07-03"));
+    }
+
+    #[test]
+    fn test_observed_saturday_holiday_maps_to_friday() {
+        let mut data = HolidayData::default();
+        data.add(Holiday::new("Independence Day", "2026-07-04")); // Saturday
+        let map = data.get_holiday_map(WorkWeek::default(), true);
+        assert!(map.contains_key("2026-07-04"));
+        assert_eq!(map["2026-07-03"].name, "Independence Day");
+    }
+
+    #[test]
+    fn test_observed_sunday_holiday_maps_to_monday() {
+        let mut data = HolidayData::default();
+        data.add(Holiday::new("New Year's Day", "2023-01-01")); // Sunday
+        let map = data.get_holiday_map(WorkWeek::default(), true);
+        assert!(map.contains_key("2023-01-02"));
+    }
+
+    #[test]
+    fn test_observed_date_moves_to_nearest_workday_of_week() {
+        use chrono::Weekday;
+        let date = |s| NaiveDate::parse_from_str(s, "%Y-%m-%d").ok();
+        // Sunday–Thursday week: Friday and Saturday are off
+        let sun_thu = WorkWeek::from_days(&[
+            Weekday::Sun,
+            Weekday::Mon,
+            Weekday::Tue,
+            Weekday::Wed,
+            Weekday::Thu,
+        ]);
+        assert_eq!(observed_date("2026-07-03", sun_thu), date("2026-07-02")); // Fri → Thu
+        assert_eq!(observed_date("2026-07-04", sun_thu), date("2026-07-05")); // Sat → Sun
+        assert_eq!(observed_date("2026-07-05", sun_thu), None); // Sunday is a workday
+
+        // Monday–Wednesday plus Friday: Thursday is off, tie goes to Wednesday
+        let no_thu = WorkWeek::from_days(&[Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Fri]);
+        assert_eq!(observed_date("2026-07-02", no_thu), date("2026-07-01"));
+
+        assert_eq!(observed_date("2026-07-04", WorkWeek::from_days(&[])), None);
+
+        let mut data = HolidayData::default();
+        data.add(Holiday::new("Independence Day", "2026-07-04")); // Saturday
+        let map = data.get_holiday_map(sun_thu, true);
+        assert_eq!(map["2026-07-05"].name, "Independence Day");
+        assert!(!map.contains_key("2026-07-03"));
     }
 
     #[test]
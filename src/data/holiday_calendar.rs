@@ -0,0 +1,183 @@
+use crate::calc::holidays::{HolidayRule, generate_federal_holidays};
+use crate::data::holiday::Holiday;
+use anyhow::{Result, bail};
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+
+/// Country codes accepted by [`generate_holidays`].
+pub const SUPPORTED_COUNTRIES: &[&str] = &["US", "UK", "CA"];
+
+/// Generates the public holidays for `country` in `year`.
+///
+/// US holidays come from [`generate_federal_holidays`]; their dates are the actual holiday dates (weekend observance is handled by the
+/// `observe_weekend_holidays` setting). UK bank holidays (England & Wales) are
+/// emitted on their substitute weekdays, since that is how they are published.
+/// Country codes are case-insensitive; "GB" and "CANADA" are accepted aliases.
+pub fn generate_holidays(country: &str, year: i32) -> Result<Vec<Holiday>> {
+    let dates = match country.to_ascii_uppercase().as_str() {
+        "US" | "USA" => return Ok(generate_federal_holidays(year)),
+        "UK" | "GB" => uk_holidays(year),
+        "CA" | "CANADA" => canada_holidays(year),
+        other => bail!(
+            "unknown country '{}' (supported: {})",
+            other,
+            SUPPORTED_COUNTRIES.join(", ")
+        ),
+    };
+    Ok(dates
+        .into_iter()
+        .map(|(name, date)| Holiday::new(name, &date.format("%Y-%m-%d").to_string()))
+        .collect())
+}
+
+fn uk_holidays(year: i32) -> Vec<(&'static str, NaiveDate)> {
+    let easter = easter_sunday(year);
+    let (christmas, boxing_day) = christmas_substitutes(year);
+    vec![
+        ("New Year's Day", next_weekday(ymd(year, 1, 1))),
+        ("Good Friday", easter - Duration::days(2)),
+        ("Easter Monday", easter + Duration::days(1)),
+        (
+            "Early May Bank Holiday",
+            nth_weekday(year, 5, Weekday::Mon, 1),
+        ),
+        ("Spring Bank Holiday", last_weekday(year, 5, Weekday::Mon)),
+        ("Summer Bank Holiday", last_weekday(year, 8, Weekday::Mon)),
+        ("Christmas Day", christmas),
+        ("Boxing Day", boxing_day),
+    ]
+}
+
+fn canada_holidays(year: i32) -> Vec<(&'static str, NaiveDate)> {
+    // Victoria Day: the last Monday before May 25
+    let mut victoria = ymd(year, 5, 24);
+    while victoria.weekday() != Weekday::Mon {
+        victoria -= Duration::days(1);
+    }
+    vec![
+        ("New Year's Day", ymd(year, 1, 1)),
+        ("Good Friday", easter_sunday(year) - Duration::days(2)),
+        ("Victoria Day", victoria),
+        ("Canada Day", ymd(year, 7, 1)),
+        ("Civic Holiday", nth_weekday(year, 8, Weekday::Mon, 1)),
+        ("Labour Day", nth_weekday(year, 9, Weekday::Mon, 1)),
+        ("Truth and Reconciliation Day", ymd(year, 9, 30)),
+        ("Thanksgiving Day", nth_weekday(year, 10, Weekday::Mon, 2)),
+        ("Remembrance Day", ymd(year, 11, 11)),
+        ("Christmas Day", ymd(year, 12, 25)),
+        ("Boxing Day", ymd(year, 12, 26)),
+    ]
+}
+
+fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+/// The `n`th (1-based) `weekday` of the month.
+fn nth_weekday(year: i32, month: u32, weekday: Weekday, n: i32) -> NaiveDate {
+    HolidayRule::NthWeekday { month, weekday, n }
+        .date_in(year)
+        .unwrap()
+}
+
+/// The last `weekday` of the month.
+fn last_weekday(year: i32, month: u32, weekday: Weekday) -> NaiveDate {
+    nth_weekday(year, month, weekday, -1)
+}
+
+/// Rolls a Saturday or Sunday forward to the following Monday.
+fn next_weekday(date: NaiveDate) -> NaiveDate {
+    match date.weekday() {
+        Weekday::Sat => date + Duration::days(2),
+        Weekday::Sun => date + Duration::days(1),
+        _ => date,
+    }
+}
+
+/// UK Christmas and Boxing Day, moved to the next free weekdays when either
+/// falls on a weekend.
+fn christmas_substitutes(year: i32) -> (NaiveDate, NaiveDate) {
+    match ymd(year, 12, 25).weekday() {
+        Weekday::Fri => (ymd(year, 12, 25), ymd(year, 12, 28)),
+        Weekday::Sat => (ymd(year, 12, 27), ymd(year, 12, 28)),
+        Weekday::Sun => (ymd(year, 12, 27), ymd(year, 12, 26)),
+        _ => (ymd(year, 12, 25), ymd(year, 12, 26)),
+    }
+}
+
+/// Gregorian Easter Sunday (anonymous Gregorian algorithm).
+fn easter_sunday(year: i32) -> NaiveDate {
+    let a = year % 19;
+    let b = year / 100;
+    let c = year % 100;
+    let d = b / 4;
+    let e = b % 4;
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+    ymd(year, month as u32, day as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date_of(holidays: &[Holiday], name: &str) -> String {
+        holidays
+            .iter()
+            .find(|h| h.name == name)
+            .map(|h| h.date.clone())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_easter_sunday() {
+        assert_eq!(easter_sunday(2025), ymd(2025, 4, 20));
+        assert_eq!(easter_sunday(2026), ymd(2026, 4, 5));
+    }
+
+    #[test]
+    fn test_uk_bank_holidays_2026() {
+        let h = generate_holidays("UK", 2026).unwrap();
+        assert_eq!(date_of(&h, "Good Friday"), "2026-04-03");
+        assert_eq!(date_of(&h, "Easter Monday"), "2026-04-06");
+        assert_eq!(date_of(&h, "Early May Bank Holiday"), "2026-05-04");
+        assert_eq!(date_of(&h, "Spring Bank Holiday"), "2026-05-25");
+        assert_eq!(date_of(&h, "Summer Bank Holiday"), "2026-08-31");
+        // Boxing Day is a Saturday → substitute Monday
+        assert_eq!(date_of(&h, "Boxing Day"), "2026-12-28");
+    }
+
+    #[test]
+    fn test_uk_christmas_on_sunday_substitutes_tuesday() {
+        let h = generate_holidays("gb", 2022).unwrap();
+        assert_eq!(date_of(&h, "Christmas Day"), "2022-12-27");
+        assert_eq!(date_of(&h, "Boxing Day"), "2022-12-26");
+    }
+
+    #[test]
+    fn test_us_matches_default_holidays() {
+        let h = generate_holidays("US", 2025).unwrap();
+        assert_eq!(date_of(&h, "MLK Day"), "2025-01-20");
+        assert_eq!(date_of(&h, "Memorial Day"), "2025-05-26");
+        assert_eq!(date_of(&h, "Thanksgiving Day"), "2025-11-27");
+        assert_eq!(h.len(), 11);
+    }
+
+    #[test]
+    fn test_canada_victoria_day() {
+        let h = generate_holidays("CA", 2025).unwrap();
+        assert_eq!(date_of(&h, "Victoria Day"), "2025-05-19");
+        assert_eq!(date_of(&h, "Thanksgiving Day"), "2025-10-13");
+    }
+
+    #[test]
+    fn test_unknown_country_errors() {
+        assert!(generate_holidays("FR", 2025).is_err());
+    }
+}
@@ -1,15 +1,20 @@
 pub mod app_settings;
+pub mod badge_csv;
 pub mod badge_entry;
 pub mod event;
 pub mod holiday;
+pub mod holiday_calendar;
 pub mod persistence;
+pub mod scenario;
 pub mod time_period;
+pub mod ui_state;
 pub mod vacation;
 
-pub use app_settings::AppSettings;
+pub use app_settings::{AppSettings, TargetMode, WorkWeek};
 pub use badge_entry::{BadgeEntry, BadgeEntryData};
-pub use event::{Event, EventData};
+pub use event::{Event, EventData, Recurrence};
 pub use holiday::{Holiday, HolidayData};
 pub use persistence::Persistable;
 pub use time_period::{TimePeriod, TimePeriodData};
+pub use ui_state::UiState;
 pub use vacation::{Vacation, VacationData};
@@ -1,9 +1,62 @@
-use anyhow::{Context, Result};
+use anyhow::{Context, Result, bail};
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::sync::OnceLock;
 
+/// On-disk format of a data or config file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Json,
+    Yaml,
+    Toml,
+}
+
+impl Format {
+    /// Picks the format from a filename's extension; anything other than
+    /// `.json` or `.toml` is YAML.
+    pub fn from_filename(name: &str) -> Self {
+        let ext = Path::new(name)
+            .extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        match ext.as_str() {
+            "json" => Format::Json,
+            "toml" => Format::Toml,
+            _ => Format::Yaml,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Format::Json => "JSON",
+            Format::Yaml => "YAML",
+            Format::Toml => "TOML",
+        }
+    }
+
+    fn parse<T: for<'de> Deserialize<'de>>(self, contents: &str, path: &Path) -> Result<T> {
+        let parsed = match self {
+            Format::Json => serde_json::from_str(contents).map_err(anyhow::Error::from),
+            Format::Yaml => serde_norway::from_str(contents).map_err(anyhow::Error::from),
+            Format::Toml => toml::from_str(contents).map_err(anyhow::Error::from),
+        };
+        parsed.with_context(|| format!("failed to parse {} from {}", self.name(), path.display()))
+    }
+
+    fn serialize<T: Serialize>(self, value: &T) -> Result<String> {
+        let contents = match self {
+            Format::Json => serde_json::to_string_pretty(value).map_err(anyhow::Error::from),
+            Format::Yaml => serde_norway::to_string(value)
+                .map(|raw| normalize_yaml_strings(&raw))
+                .map_err(anyhow::Error::from),
+            Format::Toml => toml::to_string(value).map_err(anyhow::Error::from),
+        };
+        contents.with_context(|| format!("failed to serialize {}", self.name()))
+    }
+}
+
 /// Post-processes `serde_norway` YAML output so every string scalar value
 /// is consistently double-quoted. Leaves booleans, numbers, and null as-is.
 fn normalize_yaml_strings(yaml: &str) -> String {
@@ -79,6 +132,60 @@ fn to_double_quoted(value: &str) -> String {
     format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
 }
 
+/// Writes `contents` to `<path>.tmp` and renames it over `path`, so a crash
+/// mid-write leaves the previous file intact instead of a truncated one.
+/// With `backup_count` above 0 (`AppSettings::backup_count`), the old file is
+/// first backed up (see [`backup_and_prune`]).
+pub(crate) fn write_atomic(path: &Path, contents: &str, backup_count: usize) -> Result<()> {
+    let mut tmp_name = path.file_name().unwrap_or_default().to_os_string();
+    tmp_name.push(".tmp");
+    let tmp = path.with_file_name(tmp_name);
+    if backup_count > 0 {
+        backup_and_prune(path, backup_count, chrono::Local::now().naive_local())?;
+    }
+    fs::write(&tmp, contents).with_context(|| format!("failed to write {}", tmp.display()))?;
+    fs::rename(&tmp, path).with_context(|| {
+        format!(
+            "failed to replace {} with {}",
+            path.display(),
+            tmp.display()
+        )
+    })
+}
+
+/// Copies an existing `path` to `<name>.YYYYMMDD-HHMMSS.bak` alongside it, then
+/// deletes the oldest backups of that file beyond `keep`. Does nothing if
+/// `path` doesn't exist yet.
+pub(crate) fn backup_and_prune(path: &Path, keep: usize, now: chrono::NaiveDateTime) -> Result<()> {
+    if !path.exists() {
+        return Ok(());
+    }
+    let name = path.file_name().unwrap_or_default().to_string_lossy();
+    let backup = path.with_file_name(format!("{}.{}.bak", name, now.format("%Y%m%d-%H%M%S")));
+    fs::copy(path, &backup).with_context(|| format!("failed to back up {}", path.display()))?;
+
+    let dir = path.parent().unwrap_or(Path::new("."));
+    let prefix = format!("{}.", name);
+    let mut backups: Vec<PathBuf> = fs::read_dir(dir)
+        .with_context(|| format!("failed to read {}", dir.display()))?
+        .filter_map(|e| e.ok().map(|e| e.path()))
+        .filter(|p| {
+            p.file_name()
+                .and_then(|n| n.to_str())
+                .and_then(|n| n.strip_prefix(&prefix))
+                .and_then(|rest| rest.strip_suffix(".bak"))
+                .is_some_and(|stamp| stamp.len() == 15)
+        })
+        .collect();
+    // Timestamps sort lexicographically in chronological order
+    backups.sort();
+    let excess = backups.len().saturating_sub(keep);
+    for old in &backups[..excess] {
+        fs::remove_file(old).with_context(|| format!("failed to remove {}", old.display()))?;
+    }
+    Ok(())
+}
+
 static DATA_DIR: OnceLock<PathBuf> = OnceLock::new();
 
 pub fn set_data_dir(path: PathBuf) {
@@ -93,12 +200,53 @@ pub fn get_data_dir() -> Result<PathBuf> {
     Ok(cwd.join("config"))
 }
 
+static PROFILE: OnceLock<String> = OnceLock::new();
+
+/// Selects the data profile for this run. Without one, data files keep their
+/// plain names.
+pub fn set_profile(name: String) {
+    let _ = PROFILE.set(name);
+}
+
+/// The profile chosen with `--profile`, if any.
+pub fn active_profile() -> Option<&'static str> {
+    PROFILE.get().map(String::as_str)
+}
+
+/// Checks a `--profile` name: letters, digits, `-` and `_` only, so it can
+/// be spliced into a filename.
+pub fn validate_profile(name: &str) -> Result<()> {
+    if name.is_empty()
+        || !name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        bail!(
+            "invalid profile '{}' (use letters, digits, '-' and '_')",
+            name
+        );
+    }
+    Ok(())
+}
+
+/// `filename` namespaced for `profile`: `badge_data.json` becomes
+/// `badge_data.<profile>.json`. With no profile the name is unchanged.
+pub fn profile_filename(filename: &str, profile: Option<&str>) -> String {
+    let Some(profile) = profile else {
+        return filename.to_string();
+    };
+    match filename.rsplit_once('.') {
+        Some((stem, ext)) => format!("{}.{}.{}", stem, profile, ext),
+        None => format!("{}.{}", filename, profile),
+    }
+}
+
 pub fn get_file_path(name: &str) -> Result<PathBuf> {
     let dir = get_data_dir()?;
     Ok(dir.join(name))
 }
 
-pub fn load_yaml_from<T: for<'de> Deserialize<'de>>(
+pub(crate) fn load_json_from<T: for<'de> Deserialize<'de>>(
     dir: &Path,
     filename: &str,
 ) -> Result<Option<T>> {
@@ -108,22 +256,14 @@ pub fn load_yaml_from<T: for<'de> Deserialize<'de>>(
     }
     let contents =
         fs::read_to_string(&path).with_context(|| format!("reading {}", path.display()))?;
-    let val: T = serde_norway::from_str(&contents)
-        .with_context(|| format!("parsing YAML from {}", path.display()))?;
+    let val: T = serde_json::from_str(&contents)
+        .with_context(|| format!("parsing JSON from {}", path.display()))?;
     Ok(Some(val))
 }
 
-pub fn save_yaml_to<T: Serialize>(dir: &Path, filename: &str, value: &T) -> Result<()> {
-    fs::create_dir_all(dir).with_context(|| format!("creating directory {}", dir.display()))?;
-    let path = dir.join(filename);
-    let raw = serde_norway::to_string(value).context("serializing YAML")?;
-    let contents = normalize_yaml_strings(&raw);
-    fs::write(&path, contents).with_context(|| format!("writing {}", path.display()))?;
-    Ok(())
-}
-
-#[allow(dead_code)]
-pub(crate) fn load_json_from<T: for<'de> Deserialize<'de>>(
+/// Loads `filename` from `dir` in the format its extension names
+/// (see [`Format::from_filename`]). Returns `None` when the file is missing.
+pub fn load_file_from<T: for<'de> Deserialize<'de>>(
     dir: &Path,
     filename: &str,
 ) -> Result<Option<T>> {
@@ -133,85 +273,101 @@ pub(crate) fn load_json_from<T: for<'de> Deserialize<'de>>(
     }
     let contents =
         fs::read_to_string(&path).with_context(|| format!("reading {}", path.display()))?;
-    let val: T = serde_json::from_str(&contents)
-        .with_context(|| format!("parsing JSON from {}", path.display()))?;
-    Ok(Some(val))
+    Format::from_filename(filename)
+        .parse(&contents, &path)
+        .map(Some)
+}
+
+/// Saves `value` to `filename` in `dir` in the format its extension names,
+/// keeping up to `backup_count` backups of the previous file.
+pub fn save_file_to<T: Serialize>(
+    dir: &Path,
+    filename: &str,
+    value: &T,
+    backup_count: usize,
+) -> Result<()> {
+    fs::create_dir_all(dir).with_context(|| format!("creating directory {}", dir.display()))?;
+    let contents = Format::from_filename(filename).serialize(value)?;
+    write_atomic(&dir.join(filename), &contents, backup_count)
 }
 
 #[allow(dead_code)]
-pub(crate) fn save_json_to<T: Serialize>(dir: &Path, filename: &str, value: &T) -> Result<()> {
+pub(crate) fn save_json_to<T: Serialize>(
+    dir: &Path,
+    filename: &str,
+    value: &T,
+    backup_count: usize,
+) -> Result<()> {
     fs::create_dir_all(dir).with_context(|| format!("creating directory {}", dir.display()))?;
     let path = dir.join(filename);
     let contents = serde_json::to_string_pretty(value).context("serializing JSON")?;
-    fs::write(&path, contents).with_context(|| format!("writing {}", path.display()))?;
+    write_atomic(&path, &contents, backup_count)?;
     Ok(())
 }
 
 pub trait Persistable: Sized + Default + Serialize + for<'de> Deserialize<'de> {
     fn filename() -> &'static str;
-    fn is_json() -> bool;
+    fn format() -> Format;
+
+    /// Runs on every value read by `load` / `load_from`, e.g. to repair
+    /// hand-edited files. Does nothing by default.
+    fn after_load(&mut self) {}
+
+    /// [`Self::filename`] namespaced for the active profile, if any.
+    fn resolved_filename() -> String {
+        profile_filename(Self::filename(), active_profile())
+    }
 
     fn load() -> Result<Self> {
-        let path = get_file_path(Self::filename())?;
+        let path = get_file_path(&Self::resolved_filename())?;
         if !path.exists() {
             return Ok(Self::default());
         }
         let contents = fs::read_to_string(&path)
             .with_context(|| format!("failed to read {}", path.display()))?;
-        if Self::is_json() {
-            serde_json::from_str(&contents)
-                .with_context(|| format!("failed to parse JSON from {}", path.display()))
-        } else {
-            serde_norway::from_str(&contents)
-                .with_context(|| format!("failed to parse YAML from {}", path.display()))
-        }
+        let mut value: Self = Self::format().parse(&contents, &path)?;
+        value.after_load();
+        Ok(value)
     }
 
-    fn save(&self) -> Result<()> {
-        let path = get_file_path(Self::filename())?;
-        if let Some(parent) = path.parent() {
-            fs::create_dir_all(parent)
-                .with_context(|| format!("failed to create dir {}", parent.display()))?;
-        }
-        let contents = if Self::is_json() {
-            serde_json::to_string_pretty(self).context("failed to serialize JSON")?
-        } else {
-            let raw = serde_norway::to_string(self).context("failed to serialize YAML")?;
-            normalize_yaml_strings(&raw)
-        };
-        fs::write(&path, contents)
-            .with_context(|| format!("failed to write {}", path.display()))?;
-        Ok(())
+    /// Saves to the data directory, keeping up to `backup_count` backups of
+    /// the previous file (`AppSettings::backup_count`; 0 for none).
+    fn save(&self, backup_count: usize) -> Result<()> {
+        self.save_to(&get_data_dir()?, backup_count)
     }
 
     #[allow(dead_code)]
     fn load_from(dir: &Path) -> Result<Self> {
-        let path = dir.join(Self::filename());
+        Self::load_from_profile(dir, active_profile())
+    }
+
+    fn save_to(&self, dir: &Path, backup_count: usize) -> Result<()> {
+        self.save_to_profile(dir, active_profile(), backup_count)
+    }
+
+    /// Loads `profile`'s copy of the file from `dir` (the plain file for `None`).
+    fn load_from_profile(dir: &Path, profile: Option<&str>) -> Result<Self> {
+        let path = dir.join(profile_filename(Self::filename(), profile));
         if !path.exists() {
             return Ok(Self::default());
         }
         let contents = fs::read_to_string(&path)
             .with_context(|| format!("failed to read {}", path.display()))?;
-        if Self::is_json() {
-            serde_json::from_str(&contents)
-                .with_context(|| format!("failed to parse JSON from {}", path.display()))
-        } else {
-            serde_norway::from_str(&contents)
-                .with_context(|| format!("failed to parse YAML from {}", path.display()))
-        }
+        let mut value: Self = Self::format().parse(&contents, &path)?;
+        value.after_load();
+        Ok(value)
     }
 
-    fn save_to(&self, dir: &Path) -> Result<()> {
+    fn save_to_profile(
+        &self,
+        dir: &Path,
+        profile: Option<&str>,
+        backup_count: usize,
+    ) -> Result<()> {
         fs::create_dir_all(dir)?;
-        let path = dir.join(Self::filename());
-        let contents = if Self::is_json() {
-            serde_json::to_string_pretty(self).context("failed to serialize JSON")?
-        } else {
-            let raw = serde_norway::to_string(self).context("failed to serialize YAML")?;
-            normalize_yaml_strings(&raw)
-        };
-        fs::write(&path, contents)
-            .with_context(|| format!("failed to write {}", path.display()))?;
+        let path = dir.join(profile_filename(Self::filename(), profile));
+        let contents = Self::format().serialize(self)?;
+        write_atomic(&path, &contents, backup_count)?;
         Ok(())
     }
 }
@@ -230,8 +386,8 @@ mod tests {
         fn filename() -> &'static str {
             "test_data.json"
         }
-        fn is_json() -> bool {
-            true
+        fn format() -> Format {
+            Format::Json
         }
     }
 
@@ -244,11 +400,78 @@ mod tests {
         fn filename() -> &'static str {
             "test_data.yaml"
         }
-        fn is_json() -> bool {
-            false
+        fn format() -> Format {
+            Format::Yaml
         }
     }
 
+    #[test]
+    fn test_format_from_filename() {
+        assert_eq!(Format::from_filename("badge_data.json"), Format::Json);
+        assert_eq!(Format::from_filename("settings.TOML"), Format::Toml);
+        assert_eq!(Format::from_filename("holidays.yaml"), Format::Yaml);
+        assert_eq!(Format::from_filename("quarters.yml"), Format::Yaml);
+    }
+
+    #[test]
+    fn test_standalone_toml_roundtrip() {
+        use tempfile::TempDir;
+        let tmp = TempDir::new().unwrap();
+        let data = TestYamlData { count: 7 };
+        save_file_to(tmp.path(), "test.toml", &data, 0).unwrap();
+        let raw = fs::read_to_string(tmp.path().join("test.toml")).unwrap();
+        assert_eq!(raw.trim(), "count = 7");
+        let loaded: Option<TestYamlData> = load_file_from(tmp.path(), "test.toml").unwrap();
+        assert_eq!(loaded.unwrap(), data);
+    }
+
+    #[test]
+    fn test_profile_filename() {
+        assert_eq!(profile_filename("badge_data.json", None), "badge_data.json");
+        assert_eq!(
+            profile_filename("badge_data.json", Some("alex")),
+            "badge_data.alex.json"
+        );
+        assert_eq!(profile_filename("notes", Some("alex")), "notes.alex");
+        assert!(validate_profile("team-lead_2").is_ok());
+        assert!(validate_profile("").is_err());
+        assert!(validate_profile("../alex").is_err());
+    }
+
+    #[test]
+    fn test_profiles_read_and_write_independent_files() {
+        use crate::data::badge_entry::{BadgeEntry, BadgeEntryData};
+        use tempfile::TempDir;
+        let tmp = TempDir::new().unwrap();
+        let day = chrono::NaiveDate::from_ymd_opt(2025, 3, 14).unwrap();
+
+        let mut mine = BadgeEntryData::default();
+        mine.add(BadgeEntry::new(day, "McLean, VA", false));
+        mine.save_to_profile(tmp.path(), None, 0).unwrap();
+        let mut alex = BadgeEntryData::default();
+        alex.add(BadgeEntry::new(day, "Reston, VA", false));
+        alex.add(BadgeEntry::new(
+            day.succ_opt().unwrap(),
+            "Reston, VA",
+            false,
+        ));
+        alex.save_to_profile(tmp.path(), Some("alex"), 0).unwrap();
+
+        assert!(tmp.path().join("badge_data.json").exists());
+        assert!(tmp.path().join("badge_data.alex.json").exists());
+        let mine = BadgeEntryData::load_from_profile(tmp.path(), None).unwrap();
+        let alex = BadgeEntryData::load_from_profile(tmp.path(), Some("alex")).unwrap();
+        assert_eq!(mine.len(), 1);
+        assert_eq!(mine.data[0].office, "McLean, VA");
+        assert_eq!(alex.len(), 2);
+        assert_eq!(alex.data[0].office, "Reston, VA");
+        assert!(
+            BadgeEntryData::load_from_profile(tmp.path(), Some("sam"))
+                .unwrap()
+                .is_empty()
+        );
+    }
+
     #[test]
     fn test_get_data_dir_returns_a_path() {
         let result = get_data_dir();
@@ -268,7 +491,7 @@ mod tests {
         let data = TestJsonData {
             value: "hello".to_string(),
         };
-        data.save_to(tmp.path()).unwrap();
+        data.save_to(tmp.path(), 0).unwrap();
         let loaded = TestJsonData::load_from(tmp.path()).unwrap();
         assert_eq!(loaded, data);
     }
@@ -278,11 +501,101 @@ mod tests {
         use tempfile::TempDir;
         let tmp = TempDir::new().unwrap();
         let data = TestYamlData { count: 42 };
-        data.save_to(tmp.path()).unwrap();
+        data.save_to(tmp.path(), 0).unwrap();
         let loaded = TestYamlData::load_from(tmp.path()).unwrap();
         assert_eq!(loaded, data);
     }
 
+    #[test]
+    fn test_leftover_tmp_file_is_ignored_and_replaced() {
+        use tempfile::TempDir;
+        let tmp = TempDir::new().unwrap();
+        let good = TestJsonData {
+            value: "good".to_string(),
+        };
+        good.save_to(tmp.path(), 0).unwrap();
+        assert!(!tmp.path().join("test_data.json.tmp").exists());
+
+        // A save killed mid-write leaves a truncated temp file behind
+        fs::write(tmp.path().join("test_data.json.tmp"), "{\"val").unwrap();
+        assert_eq!(TestJsonData::load_from(tmp.path()).unwrap(), good);
+
+        let newer = TestJsonData {
+            value: "newer".to_string(),
+        };
+        newer.save_to(tmp.path(), 0).unwrap();
+        assert_eq!(TestJsonData::load_from(tmp.path()).unwrap(), newer);
+        assert!(!tmp.path().join("test_data.json.tmp").exists());
+    }
+
+    #[test]
+    fn test_backup_and_prune_keeps_newest() {
+        use tempfile::TempDir;
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("badge_data.json");
+        // Nothing to back up before the first save
+        backup_and_prune(&path, 2, stamp(1)).unwrap();
+        assert_eq!(fs::read_dir(tmp.path()).unwrap().count(), 0);
+
+        fs::write(
+            tmp.path().join("holidays.yaml.20250101-000000.bak"),
+            "other",
+        )
+        .unwrap();
+        for i in 1..=4 {
+            fs::write(&path, format!("v{i}")).unwrap();
+            backup_and_prune(&path, 2, stamp(i)).unwrap();
+        }
+        let mut names: Vec<String> = fs::read_dir(tmp.path())
+            .unwrap()
+            .map(|e| e.unwrap().file_name().to_string_lossy().into_owned())
+            .collect();
+        names.sort();
+        assert_eq!(
+            names,
+            vec![
+                "badge_data.json",
+                "badge_data.json.20250314-090003.bak",
+                "badge_data.json.20250314-090004.bak",
+                "holidays.yaml.20250101-000000.bak",
+            ]
+        );
+        let newest = tmp.path().join("badge_data.json.20250314-090004.bak");
+        assert_eq!(fs::read_to_string(newest).unwrap(), "v4");
+    }
+
+    #[test]
+    fn test_save_to_keeps_requested_backups() {
+        use tempfile::TempDir;
+        let tmp = TempDir::new().unwrap();
+        let bak_count = || {
+            fs::read_dir(tmp.path())
+                .unwrap()
+                .filter(|e| {
+                    let name = e.as_ref().unwrap().file_name();
+                    let name = name.to_string_lossy();
+                    name.starts_with("test_data.json.") && name.ends_with(".bak")
+                })
+                .count()
+        };
+        let data = TestJsonData {
+            value: "v".to_string(),
+        };
+
+        data.save_to(tmp.path(), 0).unwrap();
+        data.save_to(tmp.path(), 0).unwrap();
+        assert_eq!(bak_count(), 0);
+        data.save_to(tmp.path(), 1).unwrap();
+        assert_eq!(bak_count(), 1);
+    }
+
+    fn stamp(second: u32) -> chrono::NaiveDateTime {
+        chrono::NaiveDate::from_ymd_opt(2025, 3, 14)
+            .unwrap()
+            .and_hms_opt(9, 0, second)
+            .unwrap()
+    }
+
     #[test]
     fn test_persistable_load_returns_default_for_missing_file() {
         let data: TestJsonData = serde_json::from_str("{}").unwrap_or_default();
@@ -304,7 +617,7 @@ mod tests {
         let data = TestJsonData {
             value: "round-trip".to_string(),
         };
-        data.save_to(tmp.path()).unwrap();
+        data.save_to(tmp.path(), 0).unwrap();
         let loaded = TestJsonData::load_from(tmp.path()).unwrap();
         assert_eq!(loaded, data);
     }
@@ -314,7 +627,7 @@ mod tests {
         use tempfile::TempDir;
         let tmp = TempDir::new().unwrap();
         let data = TestYamlData { count: 99 };
-        data.save_to(tmp.path()).unwrap();
+        data.save_to(tmp.path(), 0).unwrap();
         let loaded = TestYamlData::load_from(tmp.path()).unwrap();
         assert_eq!(loaded, data);
     }
@@ -326,7 +639,7 @@ mod tests {
         let data = TestJsonData {
             value: "standalone".to_string(),
         };
-        save_json_to(tmp.path(), "test.json", &data).unwrap();
+        save_json_to(tmp.path(), "test.json", &data, 0).unwrap();
         let loaded: Option<TestJsonData> = load_json_from(tmp.path(), "test.json").unwrap();
         assert_eq!(loaded.unwrap(), data);
     }
@@ -336,8 +649,8 @@ mod tests {
         use tempfile::TempDir;
         let tmp = TempDir::new().unwrap();
         let data = TestYamlData { count: 77 };
-        save_yaml_to(tmp.path(), "test.yaml", &data).unwrap();
-        let loaded: Option<TestYamlData> = load_yaml_from(tmp.path(), "test.yaml").unwrap();
+        save_file_to(tmp.path(), "test.yaml", &data, 0).unwrap();
+        let loaded: Option<TestYamlData> = load_file_from(tmp.path(), "test.yaml").unwrap();
         assert_eq!(loaded.unwrap(), data);
     }
 
@@ -360,7 +673,7 @@ mod tests {
             "McLean, VA",
             false,
         ));
-        data.save_to(tmp.path()).unwrap();
+        data.save_to(tmp.path(), 0).unwrap();
         let loaded = BadgeEntryData::load_from(tmp.path()).unwrap();
         assert_eq!(loaded.len(), 1);
     }
@@ -371,11 +684,8 @@ mod tests {
         use tempfile::TempDir;
         let tmp = TempDir::new().unwrap();
         let mut data = EventData::default();
-        data.add(Event {
-            date: "2025-06-01".to_string(),
-            description: "Conference".to_string(),
-        });
-        data.save_to(tmp.path()).unwrap();
+        data.add(Event::new("2025-06-01", "Conference"));
+        data.save_to(tmp.path(), 0).unwrap();
         let loaded = EventData::load_from(tmp.path()).unwrap();
         assert_eq!(loaded.len(), 1);
     }
@@ -387,7 +697,7 @@ mod tests {
         let tmp = TempDir::new().unwrap();
         let mut data = HolidayData::default();
         data.add(Holiday::new("Labor Day", "2025-09-01"));
-        data.save_to(tmp.path()).unwrap();
+        data.save_to(tmp.path(), 0).unwrap();
         let loaded = HolidayData::load_from(tmp.path()).unwrap();
         assert_eq!(loaded.len(), 1);
         assert_eq!(loaded.holidays[0].name, "Labor Day");
@@ -401,7 +711,7 @@ mod tests {
         let data = TestJsonData {
             value: "nested".to_string(),
         };
-        data.save_to(&nested).unwrap();
+        data.save_to(&nested, 0).unwrap();
         let loaded = TestJsonData::load_from(&nested).unwrap();
         assert_eq!(loaded, data);
     }
@@ -0,0 +1,155 @@
+use crate::data::badge_entry::BadgeEntryData;
+use crate::data::persistence::{load_json_from, save_json_to};
+use anyhow::{Context, Result, bail};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const SCENARIOS_DIR: &str = "scenarios";
+const SCENARIO_EXT: &str = "json";
+
+/// Returns the directory holding named what-if scenarios for a data directory.
+pub fn scenarios_dir(dir: &Path) -> PathBuf {
+    dir.join(SCENARIOS_DIR)
+}
+
+/// Lists saved scenario names (file stems), sorted alphabetically.
+/// A missing `scenarios/` directory yields an empty list.
+pub fn list_scenarios(dir: &Path) -> Result<Vec<String>> {
+    let sdir = scenarios_dir(dir);
+    if !sdir.exists() {
+        return Ok(Vec::new());
+    }
+    let mut names = Vec::new();
+    for entry in fs::read_dir(&sdir).with_context(|| format!("reading {}", sdir.display()))? {
+        let path = entry?.path();
+        if path.extension().and_then(|e| e.to_str()) != Some(SCENARIO_EXT) {
+            continue;
+        }
+        if let Some(stem) = path.file_stem().and_then(|s| s.to_str()) {
+            names.push(stem.to_string());
+        }
+    }
+    names.sort();
+    Ok(names)
+}
+
+/// Loads the badge data stored in `scenarios/<name>.json`.
+pub fn load_scenario(dir: &Path, name: &str) -> Result<BadgeEntryData> {
+    validate_scenario_name(name)?;
+    let filename = format!("{}.{}", name, SCENARIO_EXT);
+    let loaded: Option<BadgeEntryData> = load_json_from(&scenarios_dir(dir), &filename)?;
+    loaded.with_context(|| format!("scenario '{}' not found", name))
+}
+
+/// Writes `data` to `scenarios/<name>.json`, replacing any scenario of that
+/// name (backed up per `backup_count`). Returns the file's path.
+pub fn save_scenario(
+    dir: &Path,
+    name: &str,
+    data: &BadgeEntryData,
+    backup_count: usize,
+) -> Result<PathBuf> {
+    validate_scenario_name(name)?;
+    let filename = format!("{}.{}", name, SCENARIO_EXT);
+    let sdir = scenarios_dir(dir);
+    save_json_to(&sdir, &filename, data, backup_count)?;
+    Ok(sdir.join(filename))
+}
+
+/// Checks a scenario name: letters, digits, `-` and `_` only, so it is
+/// usable as a filename.
+pub fn validate_scenario_name(name: &str) -> Result<()> {
+    if name.is_empty()
+        || !name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        bail!(
+            "invalid scenario name '{}' (use letters, digits, '-' and '_')",
+            name
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::badge_entry::BadgeEntry;
+    use chrono::NaiveDate;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_list_scenarios_missing_dir_is_empty() {
+        let tmp = TempDir::new().unwrap();
+        assert!(list_scenarios(tmp.path()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_list_scenarios_sorted_json_only() {
+        let tmp = TempDir::new().unwrap();
+        let sdir = scenarios_dir(tmp.path());
+        save_json_to(&sdir, "plan-b.json", &BadgeEntryData::default(), 0).unwrap();
+        save_json_to(&sdir, "plan-a.json", &BadgeEntryData::default(), 0).unwrap();
+        fs::write(sdir.join("notes.txt"), "ignored").unwrap();
+        assert_eq!(
+            list_scenarios(tmp.path()).unwrap(),
+            vec!["plan-a", "plan-b"]
+        );
+    }
+
+    #[test]
+    fn test_load_scenario_roundtrip() {
+        let tmp = TempDir::new().unwrap();
+        let mut data = BadgeEntryData::default();
+        data.add(BadgeEntry::new(
+            NaiveDate::from_ymd_opt(2025, 2, 3).unwrap(),
+            "Office",
+            false,
+        ));
+        save_json_to(&scenarios_dir(tmp.path()), "busy.json", &data, 0).unwrap();
+        let loaded = load_scenario(tmp.path(), "busy").unwrap();
+        assert_eq!(loaded.len(), 1);
+        assert!(load_scenario(tmp.path(), "missing").is_err());
+    }
+
+    #[test]
+    fn test_save_scenario_roundtrip_and_overwrite() {
+        let tmp = TempDir::new().unwrap();
+        let mut mondays = BadgeEntryData::default();
+        for day in [3, 10, 17] {
+            mondays.add(
+                BadgeEntry::new(NaiveDate::from_ymd_opt(2025, 2, day).unwrap(), "HQ", false)
+                    .with_note("planned"),
+            );
+        }
+        let path = save_scenario(tmp.path(), "mondays", &mondays, 0).unwrap();
+        assert_eq!(path, scenarios_dir(tmp.path()).join("mondays.json"));
+
+        let loaded = load_scenario(tmp.path(), "mondays").unwrap();
+        assert_eq!(loaded.len(), 3);
+        let entry = loaded.get("2025-02-10").unwrap();
+        assert_eq!(entry.office, "HQ");
+        assert_eq!(entry.note.as_deref(), Some("planned"));
+        assert_eq!(list_scenarios(tmp.path()).unwrap(), vec!["mondays"]);
+
+        save_scenario(tmp.path(), "mondays", &BadgeEntryData::default(), 0).unwrap();
+        assert!(load_scenario(tmp.path(), "mondays").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_save_scenario_rejects_unsafe_names() {
+        let tmp = TempDir::new().unwrap();
+        for bad in ["", "../escape", "two words", "a.json"] {
+            assert!(save_scenario(tmp.path(), bad, &BadgeEntryData::default(), 0).is_err());
+        }
+        assert!(list_scenarios(tmp.path()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_load_scenario_rejects_unsafe_names() {
+        let tmp = TempDir::new().unwrap();
+        save_json_to(tmp.path(), "escape.json", &BadgeEntryData::default(), 0).unwrap();
+        assert!(load_scenario(tmp.path(), "../escape").is_err());
+    }
+}
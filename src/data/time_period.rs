@@ -1,4 +1,4 @@
-use crate::data::persistence::{get_data_dir, load_yaml_from, save_yaml_to};
+use crate::data::persistence::{get_data_dir, load_file_from, save_file_to};
 use anyhow::{Context, Result, bail};
 use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
@@ -15,10 +15,28 @@ pub struct TimePeriod {
     pub start_date_raw: String,
     #[serde(rename = "end_date")]
     pub end_date_raw: String,
+    /// Optional onboarding date. Workdays before it do not count toward the period.
+    #[serde(
+        rename = "effective_start",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub effective_start_raw: Option<String>,
+    /// Overrides `AppSettings::default_office` for badges created in this period.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_office: Option<String>,
+    /// Overrides `AppSettings::flex_credit` for badges created in this period.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub flex_credit: Option<String>,
+    /// Leaves the period out of the year stats, e.g. a quarter before a mid-year start.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub exclude_from_year: bool,
     #[serde(skip)]
     pub start_date: Option<NaiveDate>,
     #[serde(skip)]
     pub end_date: Option<NaiveDate>,
+    #[serde(skip)]
+    pub effective_start: Option<NaiveDate>,
 }
 
 impl TimePeriod {
@@ -36,6 +54,14 @@ impl TimePeriod {
                 format!("parsing end_date {:?} for {}", self.end_date_raw, self.key)
             })?,
         );
+        self.effective_start = match &self.effective_start_raw {
+            Some(raw) => {
+                Some(NaiveDate::parse_from_str(raw, "%Y-%m-%d").with_context(|| {
+                    format!("parsing effective_start {:?} for {}", raw, self.key)
+                })?)
+            }
+            None => None,
+        };
         Ok(())
     }
 
@@ -111,43 +137,49 @@ impl TimePeriodData {
     }
 
     pub fn load_from(dir: &Path, filename: &str) -> Result<Self> {
+        let mut data = Self::load_raw_from(dir, filename)?;
+        for tp in &mut data.periods {
+            tp.parse_dates()?;
+        }
+        Ok(data)
+    }
+
+    /// Loads the file without parsing period dates: `start_date`/`end_date`
+    /// stay `None` and only the raw strings are set. Used by `rto doctor` to
+    /// report bad dates instead of failing on the first one.
+    pub fn load_raw_from(dir: &Path, filename: &str) -> Result<Self> {
         let filename = if filename.is_empty() {
             DEFAULT_TIME_PERIODS_FILENAME
         } else {
             filename
         };
 
-        let file: Option<TimePeriodDataFile> = load_yaml_from(dir, filename)?;
+        let file: Option<TimePeriodDataFile> = load_file_from(dir, filename)?;
         let file = file.unwrap_or_default();
 
-        let mut periods = file.timeperiods;
-        for tp in &mut periods {
-            tp.parse_dates()?;
-        }
-
         let cols = file
             .calendar_display_columns
             .filter(|&c| c > 0)
             .unwrap_or(DEFAULT_CALENDAR_DISPLAY_COLUMNS);
 
         Ok(TimePeriodData {
-            periods,
+            periods: file.timeperiods,
             filename: filename.to_string(),
             calendar_display_columns: cols,
         })
     }
 
-    pub fn save(&self) -> Result<()> {
+    pub fn save(&self, backup_count: usize) -> Result<()> {
         let dir = get_data_dir()?;
-        self.save_to(&dir)
+        self.save_to(&dir, backup_count)
     }
 
-    pub fn save_to(&self, dir: &Path) -> Result<()> {
+    pub fn save_to(&self, dir: &Path, backup_count: usize) -> Result<()> {
         let file = TimePeriodDataFile {
             calendar_display_columns: Some(self.calendar_display_columns),
             timeperiods: self.periods.clone(),
         };
-        save_yaml_to(dir, &self.filename, &file)
+        save_file_to(dir, &self.filename, &file, backup_count)
     }
 
     pub fn filename(&self) -> &str {
@@ -182,8 +214,23 @@ impl TimePeriodData {
         self.periods.push(tp);
     }
 
+    pub fn periods(&self) -> &[TimePeriod] {
+        &self.periods
+    }
+
+    /// Replaces the period at `idx`; out-of-range indexes are ignored.
+    pub fn replace(&mut self, idx: usize, tp: TimePeriod) {
+        if let Some(slot) = self.periods.get_mut(idx) {
+            *slot = tp;
+        }
+    }
+
+    pub fn remove(&mut self, idx: usize) -> TimePeriod {
+        self.periods.remove(idx)
+    }
+
     pub fn get_current_period(&self) -> Option<&TimePeriod> {
-        let today = chrono::Local::now().date_naive();
+        let today = crate::calc::clock::today_local();
         self.get_period_by_date(today)
     }
 
@@ -195,6 +242,27 @@ impl TimePeriodData {
         self.periods.iter().find(|tp| tp.key == key)
     }
 
+    /// The period that starts latest before the period `key` starts.
+    pub fn previous_period(&self, key: &str) -> Option<&TimePeriod> {
+        let start = self.get_period_by_key(key)?.start_date?;
+        self.periods
+            .iter()
+            .filter(|tp| tp.start_date.is_some_and(|s| s < start))
+            .max_by_key(|tp| tp.start_date)
+    }
+
+    /// Periods that start in `year`, earliest first. These are the periods
+    /// aggregated into that year's stats.
+    pub fn periods_in_year(&self, year: i32) -> Vec<&TimePeriod> {
+        let mut periods: Vec<&TimePeriod> = self
+            .periods
+            .iter()
+            .filter(|tp| tp.start_date.map(|d| d.year()) == Some(year))
+            .collect();
+        periods.sort_by_key(|tp| tp.start_date);
+        periods
+    }
+
     pub fn nearest_period(&self, date: NaiveDate) -> Result<&TimePeriod> {
         if self.periods.is_empty() {
             bail!("no time periods configured");
@@ -233,6 +301,7 @@ mod tests {
             end_date_raw: end.to_string(),
             start_date: None,
             end_date: None,
+            ..Default::default()
         };
         tp.parse_dates().unwrap();
         tp
@@ -242,6 +311,34 @@ mod tests {
         NaiveDate::from_ymd_opt(y, m, d).unwrap()
     }
 
+    #[test]
+    fn test_previous_period_by_start_date() {
+        let mut data = TimePeriodData::new();
+        data.add(make_period("Q2_2025", "Q2", "2025-04-01", "2025-06-30"));
+        data.add(make_period("Q4_2024", "Q4", "2024-10-01", "2024-12-31"));
+        data.add(make_period("Q1_2025", "Q1", "2025-01-01", "2025-03-31"));
+        let prev = |key| data.previous_period(key).map(|tp| tp.key.as_str());
+        assert_eq!(prev("Q2_2025"), Some("Q1_2025"));
+        assert_eq!(prev("Q1_2025"), Some("Q4_2024"));
+        assert_eq!(prev("Q4_2024"), None);
+        assert_eq!(prev("Q9_2099"), None);
+    }
+
+    #[test]
+    fn test_periods_in_year_sorted_by_start() {
+        let mut data = TimePeriodData::new();
+        data.add(make_period("Q2_2025", "Q2", "2025-04-01", "2025-06-30"));
+        data.add(make_period("Q4_2024", "Q4", "2024-10-01", "2024-12-31"));
+        data.add(make_period("Q1_2025", "Q1", "2025-01-01", "2025-03-31"));
+        let keys: Vec<_> = data
+            .periods_in_year(2025)
+            .iter()
+            .map(|tp| tp.key.as_str())
+            .collect();
+        assert_eq!(keys, vec!["Q1_2025", "Q2_2025"]);
+        assert!(data.periods_in_year(2023).is_empty());
+    }
+
     #[test]
     fn test_parse_dates_populates_fields() {
         let mut tp = TimePeriod {
@@ -251,6 +348,7 @@ mod tests {
             end_date_raw: "2025-04-30".to_string(),
             start_date: None,
             end_date: None,
+            ..Default::default()
         };
         tp.parse_dates().unwrap();
         assert_eq!(tp.start_date.unwrap(), date(2025, 2, 1));
@@ -266,6 +364,7 @@ mod tests {
             end_date_raw: "2025-04-30".to_string(),
             start_date: None,
             end_date: None,
+            ..Default::default()
         };
         assert!(tp.parse_dates().is_err());
     }
@@ -301,6 +400,26 @@ mod tests {
         assert!(!tp.is_date_in_range(date(2025, 3, 1)));
     }
 
+    #[test]
+    fn test_exclude_from_year_defaults_off_and_is_only_written_when_set() {
+        let mut tp: TimePeriod = serde_norway::from_str(
+            "key: Q1\nname: Q1\nstart_date: 2025-01-01\nend_date: 2025-03-31\n",
+        )
+        .unwrap();
+        assert!(!tp.exclude_from_year);
+        assert!(
+            !serde_norway::to_string(&tp)
+                .unwrap()
+                .contains("exclude_from_year")
+        );
+
+        tp.exclude_from_year = true;
+        let yaml = serde_norway::to_string(&tp).unwrap();
+        assert!(yaml.contains("exclude_from_year: true"), "{yaml}");
+        let back: TimePeriod = serde_norway::from_str(&yaml).unwrap();
+        assert!(back.exclude_from_year);
+    }
+
     #[test]
     fn test_month_span_quarter() {
         let tp = make_period("Q1", "Q1", "2025-01-01", "2025-03-31");
@@ -395,14 +514,42 @@ mod tests {
             end_date_raw: "2025-03-31".to_string(),
             start_date: None,
             end_date: None,
+            ..Default::default()
         });
-        data.save_to(tmp.path()).unwrap();
+        data.save_to(tmp.path(), 0).unwrap();
         let loaded = TimePeriodData::load_from(tmp.path(), DEFAULT_TIME_PERIODS_FILENAME).unwrap();
         assert_eq!(loaded.len(), 1);
         assert_eq!(loaded.periods[0].key, "Q1_2025");
         assert!(loaded.periods[0].start_date.is_some());
     }
 
+    #[test]
+    fn test_toml_save_and_load_roundtrip() {
+        let tmp = TempDir::new().unwrap();
+        let mut data = TimePeriodData::new_with_file("quarters.toml");
+        data.set_calendar_display_columns(4);
+        data.add(make_period("Q1_2025", "Q1", "2025-01-01", "2025-03-31"));
+        data.add(TimePeriod {
+            default_office: Some("Reston, VA".to_string()),
+            ..make_period("Q2_2025", "Q2", "2025-04-01", "2025-06-30")
+        });
+        data.save_to(tmp.path(), 0).unwrap();
+
+        let raw = std::fs::read_to_string(tmp.path().join("quarters.toml")).unwrap();
+        assert!(raw.contains("[[timeperiods]]"));
+        assert!(raw.contains("calendar_display_columns = 4"));
+
+        let loaded = TimePeriodData::load_from(tmp.path(), "quarters.toml").unwrap();
+        assert_eq!(loaded.len(), 2);
+        assert_eq!(loaded.calendar_display_columns(), 4);
+        assert_eq!(loaded.periods[1].key, "Q2_2025");
+        assert_eq!(loaded.periods[1].start_date, Some(date(2025, 4, 1)));
+        assert_eq!(
+            loaded.periods[1].default_office.as_deref(),
+            Some("Reston, VA")
+        );
+    }
+
     #[test]
     fn test_calendar_display_columns_default() {
         let data = TimePeriodData::new();
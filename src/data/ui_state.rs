@@ -0,0 +1,41 @@
+use crate::data::persistence::{Format, Persistable};
+use serde::{Deserialize, Serialize};
+
+/// TUI state remembered between sessions.
+#[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq)]
+pub struct UiState {
+    /// Key of the period the calendar was showing on exit
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub period_key: Option<String>,
+}
+
+impl Persistable for UiState {
+    fn filename() -> &'static str {
+        "ui_state.yaml"
+    }
+    fn format() -> Format {
+        Format::Yaml
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_ui_state_roundtrip() {
+        let tmp = TempDir::new().unwrap();
+        let state = UiState {
+            period_key: Some("Q2_2025".to_string()),
+        };
+        state.save_to(tmp.path(), 0).unwrap();
+        assert_eq!(UiState::load_from(tmp.path()).unwrap(), state);
+    }
+
+    #[test]
+    fn test_ui_state_missing_file_is_default() {
+        let tmp = TempDir::new().unwrap();
+        assert_eq!(UiState::load_from(tmp.path()).unwrap(), UiState::default());
+    }
+}
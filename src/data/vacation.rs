@@ -1,5 +1,6 @@
-use crate::data::persistence::Persistable;
-use chrono::{Datelike, NaiveDate, Weekday};
+use crate::data::app_settings::WorkWeek;
+use crate::data::persistence::{Format, Persistable};
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -9,6 +10,18 @@ pub struct Vacation {
     pub start_date: String,
     pub end_date: String,
     pub approved: bool,
+    /// A sick day or other excused absence: removed from the required days
+    /// like a vacation, but not counted as PTO.
+    #[serde(default)]
+    pub is_excused: bool,
+    /// False for a trip you still work through (e.g. working from the beach):
+    /// its days stay in the period's working days instead of being taken off.
+    #[serde(default = "default_counts_as_off")]
+    pub counts_as_off: bool,
+}
+
+fn default_counts_as_off() -> bool {
+    true
 }
 
 impl Vacation {
@@ -18,6 +31,26 @@ impl Vacation {
             start_date: start_date.to_string(),
             end_date: end_date.to_string(),
             approved,
+            is_excused: false,
+            counts_as_off: true,
+        }
+    }
+
+    /// An approved excused absence (e.g. a sick day) covering `start_date..=end_date`.
+    pub fn excused(reason: &str, start_date: &str, end_date: &str) -> Self {
+        Vacation {
+            is_excused: true,
+            ..Vacation::new(reason, start_date, end_date, true)
+        }
+    }
+
+    /// True when this vacation's date range shares at least one day with
+    /// `start..=end`. Vacations with unparseable dates never overlap.
+    pub fn overlaps(&self, start: NaiveDate, end: NaiveDate) -> bool {
+        let parse = |s: &str| NaiveDate::parse_from_str(s, "%Y-%m-%d").ok();
+        match (parse(&self.start_date), parse(&self.end_date)) {
+            (Some(s), Some(e)) => s <= end && start <= e,
+            _ => false,
         }
     }
 }
@@ -31,14 +64,24 @@ impl Persistable for VacationData {
     fn filename() -> &'static str {
         "vacations.yaml"
     }
-    fn is_json() -> bool {
-        false
+    fn format() -> Format {
+        Format::Yaml
+    }
+    fn after_load(&mut self) {
+        self.sort();
     }
 }
 
 impl VacationData {
+    /// Adds `vacation`, keeping the list ordered by start date.
     pub fn add(&mut self, vacation: Vacation) {
         self.vacations.push(vacation);
+        self.sort();
+    }
+
+    fn sort(&mut self) {
+        self.vacations
+            .sort_by(|a, b| a.start_date.cmp(&b.start_date));
     }
 
     #[allow(dead_code)]
@@ -51,7 +94,6 @@ impl VacationData {
         self.vacations.clone()
     }
 
-    #[allow(dead_code)]
     pub fn len(&self) -> usize {
         self.vacations.len()
     }
@@ -61,9 +103,26 @@ impl VacationData {
         self.vacations.is_empty()
     }
 
-    /// Expands all vacation date ranges into individual weekday entries.
-    /// Only weekdays (Mon-Fri) are included; holidays are NOT excluded here.
-    pub fn get_vacation_map(&self) -> HashMap<String, Vacation> {
+    /// Index pairs `(i, j)` with `i < j` whose date ranges share at least one
+    /// day. Vacations with unparseable dates are ignored.
+    pub fn find_overlaps(&self) -> Vec<(usize, usize)> {
+        let parse = |s: &str| NaiveDate::parse_from_str(s, "%Y-%m-%d").ok();
+        let mut pairs = Vec::new();
+        for (i, a) in self.vacations.iter().enumerate() {
+            for (j, b) in self.vacations.iter().enumerate().skip(i + 1) {
+                if let (Some(start), Some(end)) = (parse(&b.start_date), parse(&b.end_date))
+                    && a.overlaps(start, end)
+                {
+                    pairs.push((i, j));
+                }
+            }
+        }
+        pairs
+    }
+
+    /// Expands all vacation date ranges into individual work day entries.
+    /// Only days in `week` are included; holidays are NOT excluded here.
+    pub fn get_vacation_map(&self, week: WorkWeek) -> HashMap<String, Vacation> {
         let mut map = HashMap::new();
         for v in &self.vacations {
             let start = match NaiveDate::parse_from_str(&v.start_date, "%Y-%m-%d") {
@@ -76,7 +135,7 @@ impl VacationData {
             };
             let mut current = start;
             while current <= end {
-                if !matches!(current.weekday(), Weekday::Sat | Weekday::Sun) {
+                if week.is_workday(current) {
                     map.insert(current.format("%Y-%m-%d").to_string(), v.clone());
                 }
                 current = current.succ_opt().unwrap_or(current);
@@ -130,12 +189,63 @@ mod tests {
         assert_eq!(data.len(), 1);
     }
 
+    #[test]
+    fn test_find_overlaps_adjacent_ranges_do_not_overlap() {
+        let mut data = VacationData::default();
+        data.add(Vacation::new("A", "2025-05-05", "2025-05-09", true));
+        data.add(Vacation::new("B", "2025-05-10", "2025-05-16", true));
+        assert!(data.find_overlaps().is_empty());
+    }
+
+    #[test]
+    fn test_add_keeps_vacations_sorted_by_start_date() {
+        let mut data = VacationData::default();
+        data.add(Vacation::new("Fall", "2025-10-06", "2025-10-10", true));
+        data.add(Vacation::new("Spring", "2025-03-17", "2025-03-21", true));
+        data.add(Vacation::new("Summer", "2025-07-01", "2025-07-04", true));
+        let starts: Vec<&str> = data
+            .vacations
+            .iter()
+            .map(|v| v.start_date.as_str())
+            .collect();
+        assert_eq!(starts, ["2025-03-17", "2025-07-01", "2025-10-06"]);
+    }
+
+    #[test]
+    fn test_load_sorts_hand_edited_vacations() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let yaml = "vacations:\n- destination: Late\n  start_date: 2025-09-01\n  end_date: 2025-09-05\n  approved: true\n- destination: Early\n  start_date: 2025-02-03\n  end_date: 2025-02-07\n  approved: true\n";
+        std::fs::write(tmp.path().join("vacations.yaml"), yaml).unwrap();
+        let data = VacationData::load_from(tmp.path()).unwrap();
+        assert_eq!(data.vacations[0].destination, "Early");
+        assert_eq!(data.vacations[1].destination, "Late");
+    }
+
+    #[test]
+    fn test_find_overlaps_fully_contained() {
+        let mut data = VacationData::default();
+        data.add(Vacation::new("Long", "2025-05-01", "2025-05-31", true));
+        data.add(Vacation::new("Other", "2025-07-01", "2025-07-04", true));
+        data.add(Vacation::new("Inner", "2025-05-12", "2025-05-14", true));
+        // Sorted by start date, so Inner sits between Long and Other
+        assert_eq!(data.find_overlaps(), vec![(0, 1)]);
+    }
+
+    #[test]
+    fn test_find_overlaps_partial_and_invalid_dates() {
+        let mut data = VacationData::default();
+        data.add(Vacation::new("A", "2025-05-05", "2025-05-09", true));
+        data.add(Vacation::new("B", "2025-05-09", "2025-05-12", false));
+        data.add(Vacation::new("Bad", "not-a-date", "2025-05-30", true));
+        assert_eq!(data.find_overlaps(), vec![(0, 1)]);
+    }
+
     #[test]
     fn test_get_vacation_map_expands_weekdays_only() {
         let mut data = VacationData::default();
         // Mon 2025-01-06 through Sun 2025-01-12 → only 5 weekdays
         data.add(Vacation::new("Beach", "2025-01-06", "2025-01-12", true));
-        let map = data.get_vacation_map();
+        let map = data.get_vacation_map(WorkWeek::default());
         assert_eq!(map.len(), 5);
         assert!(map.contains_key("2025-01-06")); // Monday
         assert!(map.contains_key("2025-01-10")); // Friday
@@ -147,7 +257,7 @@ mod tests {
     fn test_get_vacation_map_single_day() {
         let mut data = VacationData::default();
         data.add(Vacation::new("Day off", "2025-01-06", "2025-01-06", true)); // Monday
-        let map = data.get_vacation_map();
+        let map = data.get_vacation_map(WorkWeek::default());
         assert_eq!(map.len(), 1);
         assert!(map.contains_key("2025-01-06"));
     }
@@ -160,8 +270,10 @@ mod tests {
             start_date: "not-a-date".to_string(),
             end_date: "2025-01-10".to_string(),
             approved: true,
+            is_excused: false,
+            counts_as_off: true,
         });
-        let map = data.get_vacation_map();
+        let map = data.get_vacation_map(WorkWeek::default());
         assert!(map.is_empty());
     }
 
@@ -169,18 +281,37 @@ mod tests {
     fn test_get_vacation_map_destination_preserved() {
         let mut data = VacationData::default();
         data.add(Vacation::new("Hawaii", "2025-01-06", "2025-01-08", true)); // Mon-Wed
-        let map = data.get_vacation_map();
+        let map = data.get_vacation_map(WorkWeek::default());
         assert_eq!(map["2025-01-06"].destination, "Hawaii");
         assert_eq!(map["2025-01-07"].destination, "Hawaii");
         assert_eq!(map["2025-01-08"].destination, "Hawaii");
     }
 
+    #[test]
+    fn test_get_vacation_map_carries_counts_as_off() {
+        let mut data = VacationData::default();
+        let mut working = Vacation::new("Beach", "2025-01-06", "2025-01-07", true);
+        working.counts_as_off = false;
+        data.add(working);
+        let map = data.get_vacation_map(WorkWeek::default());
+        assert!(!map["2025-01-06"].counts_as_off);
+        assert!(!map["2025-01-07"].counts_as_off);
+    }
+
+    #[test]
+    fn test_counts_as_off_defaults_to_true_when_omitted() {
+        let yaml = "vacations:\n- destination: Paris\n  start_date: \"2025-07-14\"\n  end_date: \"2025-07-18\"\n  approved: true\n";
+        let data: VacationData = serde_norway::from_str(yaml).unwrap();
+        assert!(data.vacations[0].counts_as_off);
+        assert!(Vacation::new("Paris", "2025-07-14", "2025-07-18", true).counts_as_off);
+    }
+
     #[test]
     fn test_get_vacation_map_multiple_vacations() {
         let mut data = VacationData::default();
         data.add(Vacation::new("Trip A", "2025-01-06", "2025-01-07", true)); // Mon-Tue
         data.add(Vacation::new("Trip B", "2025-01-13", "2025-01-14", true)); // Mon-Tue
-        let map = data.get_vacation_map();
+        let map = data.get_vacation_map(WorkWeek::default());
         assert_eq!(map.len(), 4);
         assert_eq!(map["2025-01-06"].destination, "Trip A");
         assert_eq!(map["2025-01-13"].destination, "Trip B");
@@ -198,7 +329,7 @@ mod tests {
     fn test_default_vacation_data_is_empty() {
         let data = VacationData::default();
         assert!(data.is_empty());
-        assert!(data.get_vacation_map().is_empty());
+        assert!(data.get_vacation_map(WorkWeek::default()).is_empty());
     }
 
     #[test]
@@ -208,10 +339,22 @@ mod tests {
         let mut data = VacationData::default();
         data.add(Vacation::new("Paris", "2025-07-14", "2025-07-18", true));
         data.add(Vacation::new("London", "2025-08-04", "2025-08-08", false));
-        data.save_to(tmp.path()).unwrap();
+        data.save_to(tmp.path(), 0).unwrap();
         let loaded = VacationData::load_from(tmp.path()).unwrap();
         assert_eq!(loaded.len(), 2);
         assert_eq!(loaded.vacations[0].destination, "Paris");
         assert!(!loaded.vacations[1].approved);
     }
+
+    #[test]
+    fn test_overlaps() {
+        let v = Vacation::new("Hawaii", "2025-05-10", "2025-05-17", true);
+        let d = |s: &str| NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap();
+        assert!(v.overlaps(d("2025-05-17"), d("2025-05-20")));
+        assert!(v.overlaps(d("2025-05-01"), d("2025-05-31")));
+        assert!(!v.overlaps(d("2025-05-18"), d("2025-05-20")));
+        assert!(!v.overlaps(d("2025-05-01"), d("2025-05-09")));
+        let bad = Vacation::new("?", "soon", "2025-05-17", true);
+        assert!(!bad.overlaps(d("2025-05-01"), d("2025-05-31")));
+    }
 }
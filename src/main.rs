@@ -3,15 +3,32 @@ mod cmd;
 mod data;
 mod ui;
 
-use clap::{Parser, Subcommand};
+use clap::{Args, Parser, Subcommand};
+use log::LevelFilter;
+use std::io::Write;
 use std::path::PathBuf;
 
+const DATA_DIR_ENV: &str = "RUSTRTO_DATA_DIR";
+const DEFAULT_DATA_DIR: &str = "./config";
+
 #[derive(Parser)]
 #[command(name = "rto", about = "Return-to-Office tracker")]
 struct Cli {
-    /// Path to the data directory (default: ./config)
-    #[arg(short = 'd', long, default_value = "./config")]
-    data_dir: PathBuf,
+    /// Path to the data directory (default: $RUSTRTO_DATA_DIR, then ./config)
+    #[arg(short = 'd', long)]
+    data_dir: Option<PathBuf>,
+
+    /// Keep a separate set of data files in the same directory (e.g. badge_data.<name>.json)
+    #[arg(long, global = true, value_parser = parse_profile)]
+    profile: Option<String>,
+
+    /// Also print diagnostic messages, e.g. which data directory is used
+    #[arg(short, long, global = true, conflicts_with = "quiet")]
+    verbose: bool,
+
+    /// Print only errors; command output such as reports is unaffected
+    #[arg(short, long, global = true)]
+    quiet: bool,
 
     #[command(subcommand)]
     command: Option<Commands>,
@@ -25,6 +42,93 @@ enum Commands {
     Stats {
         /// Period key (e.g. Q1_2025). Uses the current period if not specified.
         period_key: Option<String>,
+        /// Also print the distribution of remote-day gaps between office visits
+        #[arg(long)]
+        gaps: bool,
+        /// Print a one-line summary for every configured period
+        #[arg(long, conflicts_with_all = ["period_key", "gaps"])]
+        all: bool,
+        /// Print the period's statistics as JSON instead of the text report
+        #[arg(long, conflicts_with_all = ["gaps", "all"])]
+        json: bool,
+        /// Print only the period's status; exit 0 when achieved or on track,
+        /// 1 when at risk, 2 when impossible
+        #[arg(long, conflicts_with_all = ["all", "json", "gaps", "format"])]
+        check: bool,
+        /// Compute stats as if today were this date (YYYY-MM-DD)
+        #[arg(long, alias = "today", value_name = "DATE")]
+        as_of: Option<chrono::NaiveDate>,
+        /// Column layout of the text report
+        #[arg(
+            long,
+            value_enum,
+            default_value_t = cmd::stats::StatsFormat::Standard,
+            conflicts_with_all = ["all", "json"]
+        )]
+        format: cmd::stats::StatsFormat,
+    },
+    /// Mark a date as badged in, flex credit, or cleared without opening the TUI
+    Badge {
+        /// Date to update (YYYY-MM-DD)
+        date: String,
+        /// Office to badge in at (default: the `default_office` setting)
+        #[arg(long, conflicts_with_all = ["flex", "remove", "out"])]
+        office: Option<String>,
+        /// Record a flex credit instead of an office badge
+        #[arg(long, conflicts_with_all = ["remove", "out"])]
+        flex: bool,
+        /// Clear the date's badge entry
+        #[arg(long, conflicts_with = "out")]
+        remove: bool,
+        /// Print whether the date is currently badged
+        #[arg(long)]
+        out: bool,
+        /// Allow badging a weekend date
+        #[arg(long)]
+        force: bool,
+        /// Fraction of a day to credit, e.g. 0.5 for a half day
+        #[arg(long, default_value_t = 1.0)]
+        credit: f64,
+    },
+    /// Relabel existing flex entries with the current flex credit label
+    RelabelFlex {
+        /// Report how many entries would change without writing
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Remove every badge entry in a time period
+    ClearBadges {
+        /// Period key (e.g., Q1_2025)
+        period: String,
+        /// Skip the confirmation prompt
+        #[arg(short, long)]
+        yes: bool,
+    },
+    /// Export badge history as CSV or JSON, or everything as an iCalendar file
+    Export {
+        /// Output format
+        #[arg(long, value_enum, default_value_t = cmd::export::ExportFormat::Csv)]
+        format: cmd::export::ExportFormat,
+        /// File to write (default: stdout)
+        #[arg(long)]
+        out: Option<PathBuf>,
+    },
+    /// Summarize a year: per-period compliance plus aggregate year stats
+    Report {
+        /// Calendar year to report on (default: the current year)
+        #[arg(long)]
+        year: Option<i32>,
+        /// Output format
+        #[arg(long, value_enum, default_value_t = cmd::report::ReportFormat::Md)]
+        format: cmd::report::ReportFormat,
+    },
+    /// Import badge entries from a CSV of date,office,is_flex rows
+    Import {
+        /// CSV file to read
+        file: PathBuf,
+        /// Report what would change without writing
+        #[arg(long)]
+        dry_run: bool,
     },
     /// Backup data directory to git
     Backup {
@@ -35,43 +139,233 @@ enum Commands {
         #[arg(long)]
         dir: Option<String>,
     },
-    /// List all vacations
-    Vacations,
-    /// List all holidays
-    Holidays,
+    /// Restore data files from the git backup
+    Restore {
+        /// Commit or branch to restore from (default: the latest commit)
+        #[arg(long = "ref")]
+        git_ref: Option<String>,
+        /// Skip the confirmation prompt
+        #[arg(short, long)]
+        yes: bool,
+    },
+    /// List all vacations, or add one with `vacation add`
+    #[command(alias = "vacation")]
+    Vacations {
+        #[command(subcommand)]
+        action: Option<VacationAction>,
+    },
+    /// Shorthand for `vacation add`: `add-vacation "Europe Trip" 2025-09-01 2025-09-14 --approved`
+    AddVacation(AddVacationArgs),
+    /// Remove a vacation by its number in `rto vacations`
+    RemoveVacation {
+        /// 1-based position in the `rto vacations` listing
+        #[arg(long)]
+        index: usize,
+    },
+    /// Merge the events of an iCalendar (.ics) file or URL into holidays
+    ImportHolidays {
+        /// Path to an .ics file, or an http(s)/webcal URL (needs the `http` feature)
+        source: String,
+    },
+    /// Remove every holiday on a date
+    RemoveHoliday {
+        /// Date of the holiday(s) to remove (YYYY-MM-DD)
+        #[arg(long)]
+        date: String,
+    },
+    /// List all holidays, or generate a country's public holidays
+    Holidays {
+        #[command(subcommand)]
+        action: Option<HolidayAction>,
+    },
+    /// Validate every data file and report bad dates, overlaps, and duplicates
+    Doctor,
+    /// Append a year's four quarters to the active time period file
+    AddYear {
+        /// Year to add (e.g. 2027)
+        year: i32,
+    },
+}
+
+#[derive(Subcommand)]
+enum VacationAction {
+    /// Add a vacation, refusing ranges that overlap an existing one
+    Add(AddVacationArgs),
+}
+
+/// The vacation to add, for `vacation add` and its `add-vacation` shorthand.
+#[derive(Args)]
+struct AddVacationArgs {
+    /// Where you're going
+    destination: String,
+    /// First day (YYYY-MM-DD)
+    from: String,
+    /// Last day (YYYY-MM-DD)
+    to: String,
+    /// Mark the vacation as approved
+    #[arg(long, conflicts_with = "pending")]
+    approved: bool,
+    /// Mark the vacation as not yet approved (the default)
+    #[arg(long)]
+    pending: bool,
+    /// Add even if it overlaps an existing vacation
+    #[arg(long)]
+    force: bool,
+}
+
+impl AddVacationArgs {
+    /// Approved only with `--approved`; `--pending` and no flag both add an
+    /// unapproved vacation.
+    fn approved(&self) -> bool {
+        self.approved && !self.pending
+    }
+}
+
+#[derive(Subcommand)]
+enum HolidayAction {
+    /// Generate a country's public holidays for a year and merge them in
+    Generate {
+        /// Country code: US, UK, or CA (default: the `country` setting)
+        #[arg(long)]
+        country: Option<String>,
+        /// Year to generate
+        #[arg(long)]
+        year: i32,
+    },
 }
 
 fn main() -> anyhow::Result<()> {
     let cli = Cli::parse();
+    init_logging(log_level(cli.verbose, cli.quiet));
 
-    let data_dir = if cli.data_dir.is_absolute() {
-        cli.data_dir.clone()
+    let data_dir = resolve_data_dir(cli.data_dir.clone(), std::env::var(DATA_DIR_ENV).ok());
+    let data_dir = if data_dir.is_absolute() {
+        data_dir
     } else {
-        std::env::current_dir()?.join(&cli.data_dir)
+        std::env::current_dir()?.join(&data_dir)
     };
+    log::debug!("data directory: {}", data_dir.display());
     data::persistence::set_data_dir(data_dir.clone());
+    if let Some(profile) = cli.profile.clone() {
+        log::debug!("profile: {}", profile);
+        data::persistence::set_profile(profile);
+    }
 
-    let is_init_command = matches!(cli.command, Some(Commands::Init));
-    if !is_init_command && dir_needs_init(&data_dir) {
-        eprintln!("Data directory not initialized. Running 'rto init'...",);
+    if needs_auto_init(cli.command.as_ref(), &data_dir) {
+        log::info!(
+            "Data directory {} not initialized. Running 'rto init'...",
+            data_dir.display()
+        );
         cmd::init::run()?;
     }
 
     match cli.command {
         None => cmd::root::run(),
         Some(Commands::Init) => cmd::init::run(),
-        Some(Commands::Stats { period_key }) => cmd::stats::run(period_key.as_deref()),
+        Some(Commands::Stats {
+            period_key,
+            gaps,
+            all,
+            json,
+            check,
+            as_of,
+            format,
+        }) => {
+            let code =
+                cmd::stats::run(period_key.as_deref(), gaps, all, json, check, as_of, format)?;
+            if code != 0 {
+                std::process::exit(code);
+            }
+            Ok(())
+        }
+        Some(Commands::Badge {
+            date,
+            office,
+            flex,
+            remove,
+            out,
+            force,
+            credit,
+        }) => cmd::badge::run(&date, office.as_deref(), flex, remove, out, force, credit),
+        Some(Commands::Export { format, out }) => cmd::export::run(format, out.as_deref()),
+        Some(Commands::RelabelFlex { dry_run }) => cmd::badge::run_relabel_flex(dry_run),
+        Some(Commands::ClearBadges { period, yes }) => cmd::badge::run_clear(&period, yes),
+        Some(Commands::Report { year, format }) => cmd::report::run(year, format),
+        Some(Commands::Import { file, dry_run }) => cmd::import::run(&file, dry_run),
         Some(Commands::Backup { remote, dir }) => {
             let target = dir.unwrap_or_else(|| data_dir.to_string_lossy().to_string());
             cmd::backup::run(remote.as_deref(), &target)
         }
-        Some(Commands::Vacations) => cmd::vacations::run(),
-        Some(Commands::Holidays) => cmd::holidays::run(),
+        Some(Commands::Restore { git_ref, yes }) => cmd::restore::run(git_ref.as_deref(), yes),
+        Some(Commands::Vacations { action: None }) => cmd::vacations::run(),
+        Some(Commands::Vacations {
+            action: Some(VacationAction::Add(args)),
+        })
+        | Some(Commands::AddVacation(args)) => cmd::vacations::run_add(
+            &args.destination,
+            &args.from,
+            &args.to,
+            args.approved(),
+            args.force,
+        ),
+        Some(Commands::RemoveVacation { index }) => cmd::vacations::run_remove(index),
+        Some(Commands::ImportHolidays { source }) => cmd::holidays::run_import(&source),
+        Some(Commands::RemoveHoliday { date }) => cmd::holidays::run_remove(&date),
+        Some(Commands::Holidays { action: None }) => cmd::holidays::run(),
+        Some(Commands::Holidays {
+            action: Some(HolidayAction::Generate { country, year }),
+        }) => cmd::holidays::run_generate(country.as_deref(), year),
+        Some(Commands::Doctor) => cmd::doctor::run(),
+        Some(Commands::AddYear { year }) => cmd::periods::run_add_year(year),
+    }
+}
+
+/// Picks the data directory: the `--data-dir` flag, then `$RUSTRTO_DATA_DIR`,
+/// then `./config`. An empty environment value is ignored.
+fn resolve_data_dir(flag: Option<PathBuf>, env: Option<String>) -> PathBuf {
+    flag.or_else(|| env.filter(|v| !v.is_empty()).map(PathBuf::from))
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_DATA_DIR))
+}
+
+fn parse_profile(name: &str) -> Result<String, String> {
+    data::persistence::validate_profile(name)
+        .map(|_| name.to_string())
+        .map_err(|e| e.to_string())
+}
+
+/// Level for the status messages written to stderr: `--quiet` keeps only
+/// errors, `--verbose` adds debug detail.
+fn log_level(verbose: bool, quiet: bool) -> LevelFilter {
+    if quiet {
+        LevelFilter::Error
+    } else if verbose {
+        LevelFilter::Debug
+    } else {
+        LevelFilter::Info
     }
 }
 
+/// Sends log records to stderr. Info messages print bare, as the plain
+/// `eprintln!` status lines they replaced did; other levels are prefixed.
+fn init_logging(level: LevelFilter) {
+    env_logger::Builder::new()
+        .filter_level(level)
+        .format(|buf, record| match record.level() {
+            log::Level::Info => writeln!(buf, "{}", record.args()),
+            level => writeln!(buf, "{}: {}", level.as_str().to_lowercase(), record.args()),
+        })
+        .init();
+}
+
+/// Whether `rto` should run `init` before `command`: every command except
+/// `init` itself does so on an uninitialized data directory.
+fn needs_auto_init(command: Option<&Commands>, dir: &std::path::Path) -> bool {
+    !matches!(command, Some(Commands::Init)) && dir_needs_init(dir)
+}
+
 /// Returns true when the data directory has never been initialized.
-/// Checks for settings.yaml as the canonical marker of initialization.
+/// Checks for settings.yaml (or settings.toml) as the canonical marker of
+/// initialization.
 fn dir_needs_init(dir: &std::path::Path) -> bool {
     if !dir.exists() {
         return true;
@@ -79,8 +373,7 @@ fn dir_needs_init(dir: &std::path::Path) -> bool {
     if !dir.is_dir() {
         return true;
     }
-    let settings_path = dir.join("settings.yaml");
-    !settings_path.exists()
+    !dir.join("settings.yaml").exists() && !dir.join("settings.toml").exists()
 }
 
 #[cfg(test)]
@@ -115,4 +408,93 @@ mod tests {
         fs::write(tmp.path().join("settings.yaml"), "goal: 50").unwrap();
         assert!(!dir_needs_init(tmp.path()));
     }
+
+    #[test]
+    fn test_log_level_flags() {
+        let level = |args: &[&str]| {
+            let cli = Cli::try_parse_from(args).unwrap();
+            log_level(cli.verbose, cli.quiet)
+        };
+        assert_eq!(level(&["rto", "stats"]), LevelFilter::Info);
+        assert_eq!(level(&["rto", "stats", "--quiet"]), LevelFilter::Error);
+        assert_eq!(level(&["rto", "-v", "stats"]), LevelFilter::Debug);
+        assert!(Cli::try_parse_from(["rto", "-q", "-v"]).is_err());
+    }
+
+    #[test]
+    fn test_auto_init_detection_ignores_log_level() {
+        let tmp = TempDir::new().unwrap();
+        for flag in ["--quiet", "--verbose", "--profile=alex"] {
+            let stats = Cli::try_parse_from(["rto", flag, "stats"]).unwrap();
+            assert!(needs_auto_init(stats.command.as_ref(), tmp.path()));
+            let tui = Cli::try_parse_from(["rto", flag]).unwrap();
+            assert!(needs_auto_init(tui.command.as_ref(), tmp.path()));
+            let init = Cli::try_parse_from(["rto", flag, "init"]).unwrap();
+            assert!(!needs_auto_init(init.command.as_ref(), tmp.path()));
+        }
+        fs::write(tmp.path().join("settings.yaml"), "goal: 50").unwrap();
+        let stats = Cli::try_parse_from(["rto", "--quiet", "stats"]).unwrap();
+        assert!(!needs_auto_init(stats.command.as_ref(), tmp.path()));
+    }
+
+    fn parse_add_vacation(command: &[&str], flags: &[&str]) -> clap::error::Result<bool> {
+        let range = ["Europe Trip", "2025-09-01", "2025-09-14"];
+        let args = ["rto"].iter().chain(command).chain(&range).chain(flags);
+        let cli = Cli::try_parse_from(args)?;
+        match cli.command {
+            Some(Commands::AddVacation(args))
+            | Some(Commands::Vacations {
+                action: Some(VacationAction::Add(args)),
+            }) => {
+                assert_eq!(
+                    (
+                        args.destination.as_str(),
+                        args.from.as_str(),
+                        args.to.as_str()
+                    ),
+                    (range[0], range[1], range[2])
+                );
+                Ok(args.approved())
+            }
+            _ => panic!("expected a vacation add command"),
+        }
+    }
+
+    #[test]
+    fn test_add_vacation_approved_and_pending_flags() {
+        for command in [
+            &["add-vacation"][..],
+            &["vacation", "add"],
+            &["vacations", "add"],
+        ] {
+            assert!(parse_add_vacation(command, &["--approved"]).unwrap());
+            assert!(!parse_add_vacation(command, &["--pending"]).unwrap());
+            assert!(!parse_add_vacation(command, &[]).unwrap());
+            assert!(parse_add_vacation(command, &["--approved", "--pending"]).is_err());
+        }
+    }
+
+    #[test]
+    fn test_profile_flag_is_global_and_validated() {
+        let cli = Cli::try_parse_from(["rto", "stats", "--profile", "alex"]).unwrap();
+        assert_eq!(cli.profile.as_deref(), Some("alex"));
+        assert!(Cli::try_parse_from(["rto", "--profile", "a/b"]).is_err());
+        assert!(Cli::try_parse_from(["rto"]).unwrap().profile.is_none());
+    }
+
+    #[test]
+    fn test_resolve_data_dir_precedence() {
+        let flag = Some(PathBuf::from("/from/flag"));
+        let env = Some("/from/env".to_string());
+        assert_eq!(
+            resolve_data_dir(flag, env.clone()),
+            PathBuf::from("/from/flag")
+        );
+        assert_eq!(resolve_data_dir(None, env), PathBuf::from("/from/env"));
+        assert_eq!(resolve_data_dir(None, None), PathBuf::from("./config"));
+        assert_eq!(
+            resolve_data_dir(None, Some(String::new())),
+            PathBuf::from("./config")
+        );
+    }
 }
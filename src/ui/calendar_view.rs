@@ -1,36 +1,80 @@
-use crate::calc::{QuarterStats, calculate_quarter_stats, calculate_year_stats};
+use crate::calc::clock::today_local;
+use crate::calc::plan::{PlanStrategy, suggest_office_days};
+use crate::calc::pto::projected_pto_remaining;
+use crate::calc::weekly::{WeeklyProgress, weekly_breakdown, weekly_progress};
+use crate::calc::workday::Workday;
+use crate::calc::{
+    QuarterStats, StatsOptions, apply_carryover, calculate_quarter_stats, calculate_year_stats,
+    carryover,
+};
 use crate::data::{
     AppSettings, BadgeEntry, BadgeEntryData, Event, EventData, Holiday, HolidayData, Persistable,
-    TimePeriod, TimePeriodData, Vacation, VacationData,
+    Recurrence, TimePeriod, TimePeriodData, Vacation, VacationData, WorkWeek, scenario,
 };
+use crate::ui::theme::Theme;
 use anyhow::Result;
-use chrono::{Datelike, Duration, Local, NaiveDate};
+use chrono::{Datelike, Duration, Local, NaiveDate, NaiveTime, Timelike, Weekday};
 use crossterm::event::{self, Event as CEvent, KeyCode, KeyModifiers};
 use ratatui::{
     Frame, Terminal,
     backend::CrosstermBackend,
-    layout::{Constraint, Direction, Layout},
+    layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+    widgets::{Block, Borders, Cell, Clear, Gauge, Paragraph, Row, Sparkline, Table, TableState},
 };
+use std::collections::BTreeSet;
 use std::io::Stdout;
 use std::path::PathBuf;
 use std::process::{Command, Stdio};
-use std::time::Duration as StdDuration;
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::time::{Duration as StdDuration, Instant};
+
+// Longest an event description may wrap before it is cut with an ellipsis
+const MAX_EVENT_LINES: usize = 3;
+
+// Most edits `u` can step back through
+const UNDO_LIMIT: usize = 50;
+
+// Frames of the spinner shown while a backup runs, advanced every 100ms
+const SPINNER: [char; 10] = ['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
 
-// Calendar cell colors
-const FLEX_COLOR: Color = Color::Indexed(208); // reddish-orange
+// Columns one month of the calendar takes, and the space between two months
+const MONTH_WIDTH: u16 = 24;
+const GAP_WIDTH: u16 = 2;
 
-// Stats section header style
-const SECTION_BG: Color = Color::Rgb(40, 44, 52);
+// Rows a row of months takes, including the blank line after it
+const MONTH_HEIGHT: u16 = 10;
+
+// Rows the year stats (or week breakdown) get below the period stats when
+// the terminal is tall enough for both
+const YEAR_STATS_MIN_HEIGHT: u16 = 12;
+
+/// A copy of the one collection an edit is about to change, for undo/redo.
+enum Snapshot {
+    Badges(BadgeEntryData),
+    Events(EventData),
+    Vacations(VacationData),
+    Holidays(HolidayData),
+    TimePeriods(TimePeriodData),
+}
 
 #[derive(PartialEq)]
 enum Mode {
     Normal,
     Add,
     Delete,
+    /// Editing the description of the event under `cursor_index`
+    Edit,
+    /// Editing the note on the selected day's badge entry
+    Note,
+    /// Naming the what-if badge data to save as a scenario
+    SaveScenario,
+    /// Naming a saved scenario to load into what-if mode
+    LoadScenario,
     Search,
+    ConfirmReload,
+    JumpToDate,
 }
 
 #[derive(PartialEq, Default)]
@@ -39,10 +83,14 @@ enum ViewState {
     Calendar,
     Vacations,
     Holidays,
+    TimePeriods,
     Settings,
+    Compare,
 }
 
 pub struct App<'a> {
+    /// Owned rather than borrowed like the other data: the Time Periods view
+    /// edits it in place, and switching period files replaces it wholesale.
     time_period_data: TimePeriodData,
     badge_data: &'a mut BadgeEntryData,
     holiday_data: &'a mut HolidayData,
@@ -53,21 +101,49 @@ pub struct App<'a> {
     nav_date: NaiveDate,
     mode: Mode,
     input_buffer: String,
+    /// Repeat pattern for the event being added; Tab cycles it
+    add_recurrence: Option<Recurrence>,
     cursor_index: usize,
     active_stats: Option<QuarterStats>,
     year_stats: Option<QuarterStats>,
     table_state: TableState,
+    /// Rows in the period stats table and how many of them fit, as of the
+    /// last draw; PgUp/PgDn scroll within these bounds
+    stats_rows: usize,
+    stats_visible: usize,
     pub settings: AppSettings,
     what_if_snapshot: Option<BadgeEntryData>,
     data_dirty: bool,
     data_dir: PathBuf,
     active_time_period_idx: usize,
     git_status: Option<(String, Color)>,
+    /// The running `g` backup: where its result will arrive, and when it began
+    backup: Option<(Receiver<(String, Color)>, Instant)>,
     view_state: ViewState,
     list_cursor: usize,
+    list_offset: usize,
     list_add_stage: u8,
     list_field_bufs: Vec<String>,
     list_edit_index: Option<usize>,
+    /// Set after `x`/Delete in a list view until `y` confirms or `n`/Esc cancels
+    list_confirm_delete: bool,
+    /// Year the vacation or holiday list is narrowed to with `[`/`]`; `None` shows every year
+    list_year: Option<String>,
+    /// Full-screen key reference toggled with `?`, drawn over the active view
+    show_help: bool,
+    compare_names: Vec<String>,
+    compare_sel: [usize; 2],
+    compare_stats: [Option<QuarterStats>; 2],
+    show_full_stats: bool,
+    theme: Theme,
+    /// Show the per-week breakdown in place of year stats
+    show_week_breakdown: bool,
+    /// Highlight suggested office days with this strategy (`P` cycles)
+    plan_strategy: Option<PlanStrategy>,
+    undo_stack: Vec<Snapshot>,
+    redo_stack: Vec<Snapshot>,
+    /// Set when the last jump-to-date entry failed to parse
+    jump_invalid: bool,
 }
 
 impl<'a> App<'a> {
@@ -75,6 +151,42 @@ impl<'a> App<'a> {
         self.time_period_data.get_period_by_date(self.nav_date)
     }
 
+    /// Office label for new badges: the current period's override, else the global setting.
+    fn office_label(&self) -> String {
+        self.current_period()
+            .and_then(|p| p.default_office.clone())
+            .unwrap_or_else(|| self.settings.default_office.clone())
+    }
+
+    /// Flex label for new badges: the current period's override, else the global setting.
+    fn flex_label(&self) -> String {
+        self.current_period()
+            .and_then(|p| p.flex_credit.clone())
+            .unwrap_or_else(|| self.settings.flex_credit.clone())
+    }
+
+    /// Scrolls the period stats table by `delta` rows, stopping once its last
+    /// row is in view.
+    fn scroll_stats(&mut self, delta: isize) {
+        let max = self.stats_rows.saturating_sub(self.stats_visible);
+        let offset = self.table_state.offset().saturating_add_signed(delta);
+        *self.table_state.offset_mut() = offset.min(max);
+    }
+
+    /// An office badge for `day`: stamped with the wall-clock time when it is
+    /// today, at midnight when it is logged after the fact.
+    fn office_badge(&self, day: NaiveDate, office: &str) -> BadgeEntry {
+        if day == self.today {
+            let now = Local::now().time();
+            let time =
+                NaiveTime::from_num_seconds_from_midnight_opt(now.num_seconds_from_midnight(), 0)
+                    .unwrap_or(NaiveTime::MIN);
+            BadgeEntry::new_at(day, office, false, time)
+        } else {
+            BadgeEntry::new(day, office, false)
+        }
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn new(
         time_period_data: TimePeriodData,
@@ -89,6 +201,7 @@ impl<'a> App<'a> {
         let period = time_period_data.get_period_by_date(today);
         let selected_date = today;
         let nav_date = period.and_then(|q| q.start_date).unwrap_or(today);
+        let theme = Theme::named(&settings.theme);
         let mut app = App {
             time_period_data,
             badge_data,
@@ -100,21 +213,39 @@ impl<'a> App<'a> {
             nav_date,
             mode: Mode::Normal,
             input_buffer: String::new(),
+            add_recurrence: None,
             cursor_index: 0,
             active_stats: None,
             year_stats: None,
             table_state: TableState::default(),
+            stats_rows: 0,
+            stats_visible: 0,
             settings,
             what_if_snapshot: None,
             data_dirty: false,
             data_dir,
             active_time_period_idx: 0,
             git_status: None,
+            backup: None,
             view_state: ViewState::Calendar,
             list_cursor: 0,
+            list_offset: 0,
             list_add_stage: 0,
             list_field_bufs: Vec::new(),
             list_edit_index: None,
+            list_confirm_delete: false,
+            list_year: None,
+            show_help: false,
+            compare_names: Vec::new(),
+            compare_sel: [0, 0],
+            compare_stats: [None, None],
+            show_full_stats: false,
+            theme,
+            show_week_breakdown: false,
+            plan_strategy: None,
+            undo_stack: Vec::new(),
+            redo_stack: Vec::new(),
+            jump_invalid: false,
         };
         app.update_stats();
         app
@@ -127,10 +258,16 @@ impl<'a> App<'a> {
                 self.badge_data,
                 self.holiday_data,
                 self.vacation_data,
-                self.settings.goal,
-                None,
+                &StatsOptions::from(&self.settings),
+                Some(self.today),
             ) {
-                Ok(stats) => self.active_stats = Some(stats),
+                Ok(mut stats) => {
+                    if let Some(surplus) = self.previous_period_surplus(&q.key, stats.days_required)
+                    {
+                        apply_carryover(&mut stats, surplus);
+                    }
+                    self.active_stats = Some(stats);
+                }
                 Err(e) => {
                     self.active_stats = None;
                     eprintln!("Error calculating stats: {e}");
@@ -142,6 +279,49 @@ impl<'a> App<'a> {
         self.update_year_stats();
     }
 
+    /// This week's progress toward `min_days_per_week`, counted by the stats
+    /// of the period containing today (which need not be the one shown).
+    /// `None` when the target is off or no period contains today.
+    fn this_week_progress(&self) -> Option<WeeklyProgress> {
+        if self.settings.min_days_per_week <= 0 {
+            return None;
+        }
+        let period = self.time_period_data.get_period_by_date(self.today)?;
+        let stats = calculate_quarter_stats(
+            period,
+            self.badge_data,
+            self.holiday_data,
+            self.vacation_data,
+            &StatsOptions::from(&self.settings),
+            Some(self.today),
+        )
+        .ok()?;
+        Some(weekly_progress(
+            &stats,
+            self.today,
+            self.settings.min_days_per_week,
+        ))
+    }
+
+    /// Days the previous period's surplus takes off a requirement of
+    /// `required`, when `allow_carryover` is on.
+    fn previous_period_surplus(&self, key: &str, required: i32) -> Option<i32> {
+        if !self.settings.allow_carryover {
+            return None;
+        }
+        let prev = self.time_period_data.previous_period(key)?;
+        let prev_stats = calculate_quarter_stats(
+            prev,
+            self.badge_data,
+            self.holiday_data,
+            self.vacation_data,
+            &StatsOptions::from(&self.settings),
+            Some(self.today),
+        )
+        .ok()?;
+        Some(carryover(&prev_stats, required))
+    }
+
     fn update_year_stats(&mut self) {
         let year = match self.current_period() {
             Some(q) => q.start_date.map(|d| d.year()).unwrap_or(self.today.year()),
@@ -151,12 +331,7 @@ impl<'a> App<'a> {
             }
         };
 
-        let all = self.time_period_data.all();
-        let year_periods: Vec<&TimePeriod> = all
-            .iter()
-            .filter(|tp| tp.start_date.map(|d| d.year()) == Some(year))
-            .collect();
-
+        let year_periods = self.time_period_data.periods_in_year(year);
         if year_periods.is_empty() {
             self.year_stats = None;
             return;
@@ -167,8 +342,8 @@ impl<'a> App<'a> {
             self.badge_data,
             self.holiday_data,
             self.vacation_data,
-            self.settings.goal,
-            None,
+            &StatsOptions::from(&self.settings),
+            Some(self.today),
         ) {
             Ok(Some(stats)) => self.year_stats = Some(stats),
             _ => self.year_stats = None,
@@ -206,25 +381,188 @@ impl<'a> App<'a> {
         }
     }
 
+    /// Moves to the previous/next configured period ordered by start date, so
+    /// uneven period lengths never skip or land between periods. Outside every
+    /// configured range, falls back to a three-month jump.
     fn navigate_to_adjacent_period(&mut self, dir: i32) {
-        let current = match self.current_period() {
-            Some(p) => p.key.clone(),
-            None => return,
+        let Some(current) = self.current_period().map(|p| p.key.clone()) else {
+            self.nav_date = add_months(self.nav_date, 3 * dir);
+            self.selected_date = self.nav_date;
+            self.update_stats();
+            return;
         };
-        let all = self.time_period_data.all();
-        for (i, tp) in all.iter().enumerate() {
-            if tp.key == current {
-                let next = i as i32 + dir;
-                if next >= 0 && (next as usize) < all.len() {
-                    let np = &all[next as usize];
-                    if let Some(start) = np.start_date {
-                        self.selected_date = start;
-                        self.nav_date = start;
-                        self.update_stats();
-                    }
+        let mut all: Vec<TimePeriod> = self
+            .time_period_data
+            .all()
+            .into_iter()
+            .filter(|tp| tp.start_date.is_some())
+            .collect();
+        all.sort_by_key(|tp| tp.start_date);
+        let Some(i) = all.iter().position(|tp| tp.key == current) else {
+            return;
+        };
+        let next = i as i32 + dir;
+        if next >= 0
+            && (next as usize) < all.len()
+            && let Some(start) = all[next as usize].start_date
+        {
+            self.selected_date = start;
+            self.nav_date = start;
+            self.update_stats();
+        }
+    }
+
+    /// Moves `today` to `now` after midnight passes, recomputing stats.
+    /// Returns whether the date changed.
+    pub fn roll_over_to(&mut self, now: NaiveDate) -> bool {
+        if now == self.today {
+            return false;
+        }
+        self.today = now;
+        self.update_stats();
+        true
+    }
+
+    /// Key of the period the calendar is showing, if any.
+    pub fn current_period_key(&self) -> Option<String> {
+        self.current_period().map(|p| p.key.clone())
+    }
+
+    /// Shows the period with `key`, selecting today if it falls inside it and
+    /// the period's first day otherwise. Returns false, leaving the view
+    /// unchanged, when no such period exists.
+    pub fn show_period(&mut self, key: &str) -> bool {
+        let Some(period) = self.time_period_data.get_period_by_key(key) else {
+            return false;
+        };
+        let Some(start) = period.start_date else {
+            return false;
+        };
+        self.selected_date = if period.is_date_in_range(self.today) {
+            self.today
+        } else {
+            start
+        };
+        self.nav_date = start;
+        self.update_stats();
+        true
+    }
+
+    /// Selects `date` and shows the period containing it; outside every
+    /// period, the calendar starts at `date` itself.
+    fn jump_to_date(&mut self, date: NaiveDate) {
+        self.selected_date = date;
+        self.nav_date = self
+            .time_period_data
+            .get_period_by_date(date)
+            .and_then(|p| p.start_date)
+            .unwrap_or(date);
+        self.update_stats();
+    }
+
+    /// Marks the selected date as an excused absence (sick day), or clears
+    /// the one-day excused entry already on it.
+    fn toggle_excused(&mut self) {
+        let key = self.selected_date.format("%Y-%m-%d").to_string();
+        self.record_undo(Snapshot::Vacations(self.vacation_data.clone()));
+        let existing = self
+            .vacation_data
+            .vacations
+            .iter()
+            .position(|v| v.is_excused && v.start_date == key && v.end_date == key);
+        match existing {
+            Some(idx) => {
+                self.vacation_data.vacations.remove(idx);
+            }
+            None => self
+                .vacation_data
+                .add(Vacation::excused("Sick day", &key, &key)),
+        }
+        self.data_dirty = true;
+        let _ = self
+            .vacation_data
+            .save_to(&self.data_dir, self.settings.backup_count);
+        self.update_stats();
+    }
+
+    /// Badges every open workday in the selected date's Monday–Sunday week,
+    /// or clears the week's badges when the selected date is already badged.
+    /// Holidays, vacation days, and days outside the current period are left
+    /// alone, and days that already have a badge keep it.
+    fn toggle_week(&mut self) {
+        let Some((start, end)) = self
+            .current_period()
+            .and_then(|p| Some((p.start_date?, p.end_date?)))
+        else {
+            return;
+        };
+        let work_week = self.settings.work_week();
+        let holiday_map = self
+            .holiday_data
+            .get_holiday_map(work_week, self.settings.observe_weekend_holidays);
+        let vacation_map = self.vacation_data.get_vacation_map(work_week);
+        let monday = self.selected_date
+            - Duration::days(self.selected_date.weekday().num_days_from_monday() as i64);
+        let days: Vec<NaiveDate> = (0..7)
+            .map(|i| monday + Duration::days(i))
+            .filter(|d| *d >= start && *d <= end && work_week.is_workday(*d))
+            .filter(|d| {
+                let key = d.format("%Y-%m-%d").to_string();
+                !holiday_map.contains_key(key.as_str())
+                    && !vacation_map
+                        .get(key.as_str())
+                        .is_some_and(|v| v.counts_as_off)
+            })
+            .collect();
+
+        let selected_key = self.selected_date.format("%Y-%m-%d").to_string();
+        let clear = self.badge_data.has(&selected_key);
+        self.record_undo(Snapshot::Badges(self.badge_data.clone()));
+        let office = self.office_label();
+        for day in days {
+            let key = day.format("%Y-%m-%d").to_string();
+            if clear {
+                self.badge_data.remove(&key);
+            } else if !self.badge_data.has(&key) {
+                self.badge_data.add(self.office_badge(day, &office));
+            }
+        }
+        if !self.is_what_if() {
+            self.data_dirty = true;
+            let _ = self
+                .badge_data
+                .save_to(&self.data_dir, self.settings.backup_count);
+        }
+        self.update_stats();
+    }
+
+    /// Extra stats-panel rows for the "By Office" section, shown in the full
+    /// stats view once badges span more than one office.
+    fn office_breakdown_rows(&self) -> u16 {
+        match &self.active_stats {
+            Some(stats) if stats.days_by_office.len() > 1 => {
+                let collapsed = stats.compliance_status == "Achieved" && !self.show_full_stats;
+                if collapsed {
+                    0
+                } else {
+                    stats.days_by_office.len() as u16 + 2
                 }
-                return;
             }
+            _ => 0,
+        }
+    }
+
+    /// Extra stats-panel rows for the "Looking Ahead" section, shown in the
+    /// full stats view while holidays or vacation days remain in the period.
+    fn looking_ahead_rows(&self) -> u16 {
+        match &self.active_stats {
+            Some(stats)
+                if (stats.future_holidays > 0 || stats.future_vacation_days > 0)
+                    && (stats.compliance_status != "Achieved" || self.show_full_stats) =>
+            {
+                4
+            }
+            _ => 0,
         }
     }
 
@@ -232,123 +570,229 @@ impl<'a> App<'a> {
         self.what_if_snapshot.is_some()
     }
 
+    /// Undo history is cleared on entering and leaving what-if mode so a
+    /// simulated badge state can never be undone into real data.
     fn enter_what_if(&mut self) {
         self.what_if_snapshot = Some(self.badge_data.clone());
+        self.clear_undo();
     }
 
     fn exit_what_if(&mut self) {
         if let Some(original) = self.what_if_snapshot.take() {
             *self.badge_data = original;
+            self.clear_undo();
             self.update_stats();
         }
     }
 
-    /// Git-add, commit, and optionally push the data directory.
-    /// Sets self.git_status with a result message. Never panics.
-    fn git_backup(&mut self) {
-        let dir = self.data_dir.to_string_lossy().to_string();
+    /// Saves the what-if badge data as `scenarios/<name>.json`.
+    fn save_scenario(&mut self, name: &str) {
+        self.git_status = Some(
+            match scenario::save_scenario(
+                &self.data_dir,
+                name,
+                self.badge_data,
+                self.settings.backup_count,
+            ) {
+                Ok(_) => (format!("Saved scenario '{}'", name), Color::Green),
+                Err(e) => (e.to_string(), Color::Red),
+            },
+        );
+    }
 
-        // 1. Confirm it's a git repo
-        let is_repo = Command::new("git")
-            .args(["-C", &dir, "rev-parse", "--is-inside-work-tree"])
-            .stdout(Stdio::null())
-            .stderr(Stdio::null())
-            .status()
-            .map(|s| s.success())
-            .unwrap_or(false);
-
-        if !is_repo {
-            self.git_status = Some((
-                format!("'{}' is not a git repo — backup skipped", dir),
-                Color::DarkGray,
-            ));
-            return;
+    /// Replaces the badge data with a saved scenario, entering what-if mode
+    /// first so real data is restored on `w`. Undo steps back to the data
+    /// shown before loading.
+    fn load_scenario(&mut self, name: &str) {
+        let loaded = match scenario::load_scenario(&self.data_dir, name) {
+            Ok(data) => data,
+            Err(e) => {
+                let saved = scenario::list_scenarios(&self.data_dir).unwrap_or_default();
+                let hint = if saved.is_empty() {
+                    "no scenarios saved yet".to_string()
+                } else {
+                    format!("saved: {}", saved.join(", "))
+                };
+                self.git_status = Some((format!("{} ({})", e, hint), Color::Red));
+                return;
+            }
+        };
+        if !self.is_what_if() {
+            self.enter_what_if();
         }
+        let old = std::mem::replace(self.badge_data, loaded);
+        self.record_undo(Snapshot::Badges(old));
+        self.update_stats();
+        self.git_status = Some((
+            format!("Loaded scenario '{}' — w discards it", name),
+            Color::Green,
+        ));
+    }
 
-        // 2. git add .
-        let add_ok = Command::new("git")
-            .args(["-C", &dir, "add", "."])
-            .stdout(Stdio::null())
-            .stderr(Stdio::null())
-            .status()
-            .map(|s| s.success())
-            .unwrap_or(false);
-
-        if !add_ok {
-            self.git_status = Some(("git add failed".to_string(), Color::Red));
-            return;
+    /// Records the state of a collection just before it is edited.
+    fn record_undo(&mut self, snapshot: Snapshot) {
+        self.undo_stack.push(snapshot);
+        if self.undo_stack.len() > UNDO_LIMIT {
+            self.undo_stack.remove(0);
         }
+        self.redo_stack.clear();
+    }
 
-        // 3. git commit with a unique timestamp
-        let now = Local::now();
-        let timestamp = now.format("%Y-%m-%d-%H-%M-%S-%3f").to_string();
-        let msg = format!("backup {}", timestamp);
+    fn clear_undo(&mut self) {
+        self.undo_stack.clear();
+        self.redo_stack.clear();
+    }
+
+    fn undo(&mut self) {
+        match self.undo_stack.pop() {
+            Some(snapshot) => {
+                let replaced = self.restore_snapshot(snapshot);
+                self.redo_stack.push(replaced);
+                self.git_status = Some(("Undone".to_string(), Color::Green));
+            }
+            None => self.git_status = Some(("Nothing to undo".to_string(), Color::DarkGray)),
+        }
+    }
 
-        let commit_out = Command::new("git")
-            .args(["-C", &dir, "commit", "-m", &msg])
-            .output();
+    fn redo(&mut self) {
+        match self.redo_stack.pop() {
+            Some(snapshot) => {
+                let replaced = self.restore_snapshot(snapshot);
+                self.undo_stack.push(replaced);
+                self.git_status = Some(("Redone".to_string(), Color::Green));
+            }
+            None => self.git_status = Some(("Nothing to redo".to_string(), Color::DarkGray)),
+        }
+    }
 
-        match &commit_out {
-            Err(e) => {
-                self.git_status = Some((format!("git commit error: {}", e), Color::Red));
-                return;
+    /// Puts `snapshot` back in place, saves it (badges only outside what-if
+    /// mode), and returns the state it replaced.
+    fn restore_snapshot(&mut self, snapshot: Snapshot) -> Snapshot {
+        let replaced = match snapshot {
+            Snapshot::Badges(data) => {
+                let old = std::mem::replace(&mut *self.badge_data, data);
+                if !self.is_what_if() {
+                    self.data_dirty = true;
+                    let _ = self
+                        .badge_data
+                        .save_to(&self.data_dir, self.settings.backup_count);
+                }
+                Snapshot::Badges(old)
             }
-            Ok(out) => {
-                let stdout = String::from_utf8_lossy(&out.stdout);
-                let stderr = String::from_utf8_lossy(&out.stderr);
-                if stdout.contains("nothing to commit")
-                    || stderr.contains("nothing to commit")
-                    || stdout.contains("nothing added")
-                {
-                    self.git_status = Some((
-                        "Nothing to commit — already up to date".to_string(),
-                        Color::Yellow,
-                    ));
-                    return;
-                }
-                if !out.status.success() {
-                    let detail = stdout.trim().to_string();
-                    self.git_status = Some((format!("git commit failed: {}", detail), Color::Red));
-                    return;
-                }
-            }
-        }
-
-        // 4. Check for a remote named "origin"
-        let has_remote = Command::new("git")
-            .args(["-C", &dir, "remote", "get-url", "origin"])
-            .stdout(Stdio::null())
-            .stderr(Stdio::null())
-            .status()
-            .map(|s| s.success())
-            .unwrap_or(false);
-
-        if has_remote {
-            // 5. git push
-            let push_ok = Command::new("git")
-                .args(["-C", &dir, "push"])
-                .stdout(Stdio::null())
-                .stderr(Stdio::null())
-                .status()
-                .map(|s| s.success())
-                .unwrap_or(false);
-
-            if push_ok {
-                self.git_status =
-                    Some((format!("Backed up & pushed — {}", timestamp), Color::Green));
-            } else {
-                self.git_status = Some((
-                    format!("Committed locally (push failed) — {}", timestamp),
-                    Color::Yellow,
-                ));
+            Snapshot::Events(data) => {
+                let old = std::mem::replace(&mut *self.event_data, data);
+                self.data_dirty = true;
+                let _ = self
+                    .event_data
+                    .save_to(&self.data_dir, self.settings.backup_count);
+                Snapshot::Events(old)
             }
-        } else {
-            self.git_status = Some((format!("Backed up locally — {}", timestamp), Color::Cyan));
+            Snapshot::Vacations(data) => {
+                let old = std::mem::replace(&mut *self.vacation_data, data);
+                self.data_dirty = true;
+                let _ = self
+                    .vacation_data
+                    .save_to(&self.data_dir, self.settings.backup_count);
+                Snapshot::Vacations(old)
+            }
+            Snapshot::Holidays(data) => {
+                let old = std::mem::replace(&mut *self.holiday_data, data);
+                self.data_dirty = true;
+                let _ = self
+                    .holiday_data
+                    .save_to(&self.data_dir, self.settings.backup_count);
+                Snapshot::Holidays(old)
+            }
+            Snapshot::TimePeriods(data) => {
+                let old = std::mem::replace(&mut self.time_period_data, data);
+                self.data_dirty = true;
+                let _ = self
+                    .time_period_data
+                    .save_to(&self.data_dir, self.settings.backup_count);
+                Snapshot::TimePeriods(old)
+            }
+        };
+        self.update_stats();
+        replaced
+    }
+
+    /// Re-reads every data file from `data_dir` into the app, discarding any
+    /// in-memory state (including a what-if snapshot), then recomputes stats.
+    fn reload_from_disk(&mut self) -> Result<()> {
+        let dir = self.data_dir.clone();
+        let settings = AppSettings::load_from(&dir)?;
+        let tp_file = settings.active_time_period_file(self.active_time_period_idx);
+        let time_period_data = TimePeriodData::load_from(&dir, tp_file)?;
+        let badge_data = BadgeEntryData::load_from(&dir)?;
+        let holiday_data = HolidayData::load_from(&dir)?;
+        let vacation_data = VacationData::load_from(&dir)?;
+        let event_data = EventData::load_from(&dir)?;
+
+        self.theme = Theme::named(&settings.theme);
+        self.settings = settings;
+        self.time_period_data = time_period_data;
+        *self.badge_data = badge_data;
+        *self.holiday_data = holiday_data;
+        *self.vacation_data = vacation_data;
+        *self.event_data = event_data;
+        self.what_if_snapshot = None;
+        self.clear_undo();
+        self.data_dirty = false;
+        self.update_stats();
+        Ok(())
+    }
+
+    /// Starts a git backup of the data directory on a background thread, so
+    /// a slow push doesn't freeze the UI. [`App::poll_backup`] picks up the result.
+    fn git_backup(&mut self) {
+        if self.backup.is_some() {
+            self.git_status = Some(("Backup already in progress".to_string(), Color::Yellow));
+            return;
+        }
+        let dir = self.data_dir.to_string_lossy().to_string();
+        let (tx, rx) = mpsc::channel();
+        std::thread::spawn(move || {
+            let _ = tx.send(run_git_backup(&dir));
+        });
+        self.backup = Some((rx, Instant::now()));
+        self.git_status = Some(("Backing up…".to_string(), Color::Yellow));
+    }
+
+    /// Shows the result of a finished backup. Returns true while a backup is
+    /// running or has just finished, when the footer needs redrawing.
+    pub fn poll_backup(&mut self) -> bool {
+        let Some((rx, _)) = &self.backup else {
+            return false;
+        };
+        if let Some(status) = backup_update(rx.try_recv()) {
+            self.git_status = Some(status);
+            self.backup = None;
+        }
+        true
+    }
+
+    /// Blocks until a running backup finishes, so quitting never cuts off a
+    /// commit or push halfway.
+    fn wait_for_backup(&mut self) {
+        if let Some((rx, _)) = self.backup.take() {
+            let _ = rx.recv();
         }
     }
 
     /// Returns true if the app should quit.
     pub fn handle_key(&mut self, code: KeyCode, modifiers: KeyModifiers) -> bool {
+        // The help overlay swallows every key until it is dismissed
+        if self.show_help {
+            if matches!(code, KeyCode::Char('?') | KeyCode::Esc) {
+                self.show_help = false;
+            }
+            return false;
+        }
+        if code == KeyCode::Char('?') && !self.is_typing() {
+            self.show_help = true;
+            return false;
+        }
+
         // Dispatch to view-specific handlers when not in Calendar view
         match self.view_state {
             ViewState::Vacations => {
@@ -359,15 +803,26 @@ impl<'a> App<'a> {
                 self.handle_holiday_key(code);
                 return false;
             }
+            ViewState::TimePeriods => {
+                self.handle_time_period_key(code);
+                return false;
+            }
             ViewState::Settings => {
                 self.handle_settings_key(code);
                 return false;
             }
+            ViewState::Compare => {
+                self.handle_compare_key(code);
+                return false;
+            }
             ViewState::Calendar => {}
         }
 
-        // Clear the git status message on every keypress
-        self.git_status = None;
+        // Clear the git status message on every keypress, unless a backup
+        // is still running and will replace it
+        if self.backup.is_none() {
+            self.git_status = None;
+        }
 
         match self.mode {
             Mode::Add => {
@@ -376,12 +831,15 @@ impl<'a> App<'a> {
                         if !self.input_buffer.is_empty() {
                             let date_key = self.selected_date.format("%Y-%m-%d").to_string();
                             let event = Event {
-                                date: date_key,
-                                description: self.input_buffer.clone(),
+                                recurrence: self.add_recurrence,
+                                ..Event::new(&date_key, &self.input_buffer)
                             };
+                            self.record_undo(Snapshot::Events(self.event_data.clone()));
                             self.event_data.add(event);
                             self.data_dirty = true;
-                            let _ = self.event_data.save_to(&self.data_dir);
+                            let _ = self
+                                .event_data
+                                .save_to(&self.data_dir, self.settings.backup_count);
                         }
                         self.input_buffer.clear();
                         self.mode = Mode::Normal;
@@ -390,6 +848,10 @@ impl<'a> App<'a> {
                         self.input_buffer.clear();
                         self.mode = Mode::Normal;
                     }
+                    KeyCode::Tab => {
+                        self.add_recurrence =
+                            next_recurrence(self.add_recurrence, self.selected_date);
+                    }
                     KeyCode::Backspace => {
                         self.input_buffer.pop();
                     }
@@ -402,21 +864,31 @@ impl<'a> App<'a> {
             }
 
             Mode::Delete => {
-                let date_key = self.selected_date.format("%Y-%m-%d").to_string();
-                let events: Vec<_> = self
+                let events: Vec<Event> = self
                     .event_data
-                    .events
-                    .iter()
-                    .filter(|e| e.date == date_key)
+                    .events_on(self.selected_date)
+                    .into_iter()
                     .cloned()
                     .collect();
                 match code {
-                    KeyCode::Enter => {
+                    // Enter drops one occurrence of a recurring event; D drops the series
+                    KeyCode::Enter | KeyCode::Char('D') => {
                         if !events.is_empty() && self.cursor_index < events.len() {
-                            let desc = events[self.cursor_index].description.clone();
-                            self.event_data.remove(&date_key, &desc);
+                            let event = &events[self.cursor_index];
+                            self.record_undo(Snapshot::Events(self.event_data.clone()));
+                            if event.recurrence.is_some() && code == KeyCode::Enter {
+                                self.event_data.skip_occurrence(
+                                    &event.date,
+                                    &event.description,
+                                    self.selected_date,
+                                );
+                            } else {
+                                self.event_data.remove(&event.date, &event.description);
+                            }
                             self.data_dirty = true;
-                            let _ = self.event_data.save_to(&self.data_dir);
+                            let _ = self
+                                .event_data
+                                .save_to(&self.data_dir, self.settings.backup_count);
                             let new_len = events.len() - 1;
                             if self.cursor_index > 0 && self.cursor_index >= new_len {
                                 self.cursor_index -= 1;
@@ -427,15 +899,152 @@ impl<'a> App<'a> {
                     KeyCode::Esc => {
                         self.mode = Mode::Normal;
                     }
-                    KeyCode::Up => {
-                        if self.cursor_index > 0 {
-                            self.cursor_index -= 1;
+                    KeyCode::Up if self.cursor_index > 0 => {
+                        self.cursor_index -= 1;
+                    }
+                    KeyCode::Down if !events.is_empty() && self.cursor_index < events.len() - 1 => {
+                        self.cursor_index += 1;
+                    }
+                    KeyCode::Char('e') if self.cursor_index < events.len() => {
+                        self.input_buffer = events[self.cursor_index].description.clone();
+                        self.mode = Mode::Edit;
+                    }
+                    _ => {}
+                }
+                false
+            }
+
+            Mode::Edit => {
+                match code {
+                    KeyCode::Enter => {
+                        // Editing a recurring event renames the whole series
+                        let old = self
+                            .event_data
+                            .events_on(self.selected_date)
+                            .get(self.cursor_index)
+                            .map(|e| (e.date.clone(), e.description.clone()));
+                        if let Some((date, old)) = old
+                            && !self.input_buffer.is_empty()
+                            && self.input_buffer != old
+                        {
+                            self.record_undo(Snapshot::Events(self.event_data.clone()));
+                            self.event_data.update(&date, &old, &self.input_buffer);
+                            self.data_dirty = true;
+                            let _ = self
+                                .event_data
+                                .save_to(&self.data_dir, self.settings.backup_count);
                         }
+                        self.input_buffer.clear();
+                        self.mode = Mode::Normal;
                     }
-                    KeyCode::Down => {
-                        if !events.is_empty() && self.cursor_index < events.len() - 1 {
-                            self.cursor_index += 1;
+                    KeyCode::Esc => {
+                        self.input_buffer.clear();
+                        self.mode = Mode::Normal;
+                    }
+                    KeyCode::Backspace => {
+                        self.input_buffer.pop();
+                    }
+                    KeyCode::Char(c) => {
+                        self.input_buffer.push(c);
+                    }
+                    _ => {}
+                }
+                false
+            }
+
+            Mode::ConfirmReload => {
+                if let KeyCode::Char('y') | KeyCode::Char('Y') = code {
+                    self.git_status = Some(match self.reload_from_disk() {
+                        Ok(()) => ("Reloaded data from disk".to_string(), Color::Green),
+                        Err(e) => (format!("Reload failed: {}", e), Color::Red),
+                    });
+                }
+                self.mode = Mode::Normal;
+                false
+            }
+
+            Mode::JumpToDate => {
+                match code {
+                    KeyCode::Enter => {
+                        match NaiveDate::parse_from_str(self.input_buffer.trim(), "%Y-%m-%d") {
+                            Ok(date) => {
+                                self.jump_to_date(date);
+                                self.input_buffer.clear();
+                                self.mode = Mode::Normal;
+                            }
+                            Err(_) => self.jump_invalid = true,
+                        }
+                    }
+                    KeyCode::Esc => {
+                        self.input_buffer.clear();
+                        self.mode = Mode::Normal;
+                    }
+                    KeyCode::Backspace => {
+                        self.input_buffer.pop();
+                        self.jump_invalid = false;
+                    }
+                    KeyCode::Char(c) => {
+                        self.input_buffer.push(c);
+                        self.jump_invalid = false;
+                    }
+                    _ => {}
+                }
+                false
+            }
+
+            Mode::Note => {
+                match code {
+                    KeyCode::Enter => {
+                        let date_key = self.selected_date.format("%Y-%m-%d").to_string();
+                        let old = self.badge_data.clone();
+                        if self.badge_data.set_note(&date_key, &self.input_buffer) {
+                            self.record_undo(Snapshot::Badges(old));
+                            if !self.is_what_if() {
+                                self.data_dirty = true;
+                                let _ = self
+                                    .badge_data
+                                    .save_to(&self.data_dir, self.settings.backup_count);
+                            }
+                        }
+                        self.input_buffer.clear();
+                        self.mode = Mode::Normal;
+                    }
+                    KeyCode::Esc => {
+                        self.input_buffer.clear();
+                        self.mode = Mode::Normal;
+                    }
+                    KeyCode::Backspace => {
+                        self.input_buffer.pop();
+                    }
+                    KeyCode::Char(c) => {
+                        self.input_buffer.push(c);
+                    }
+                    _ => {}
+                }
+                false
+            }
+
+            Mode::SaveScenario | Mode::LoadScenario => {
+                match code {
+                    KeyCode::Enter => {
+                        let name = self.input_buffer.trim().to_string();
+                        if self.mode == Mode::SaveScenario {
+                            self.save_scenario(&name);
+                        } else {
+                            self.load_scenario(&name);
                         }
+                        self.input_buffer.clear();
+                        self.mode = Mode::Normal;
+                    }
+                    KeyCode::Esc => {
+                        self.input_buffer.clear();
+                        self.mode = Mode::Normal;
+                    }
+                    KeyCode::Backspace => {
+                        self.input_buffer.pop();
+                    }
+                    KeyCode::Char(c) => {
+                        self.input_buffer.push(c);
                     }
                     _ => {}
                 }
@@ -487,43 +1096,96 @@ impl<'a> App<'a> {
                             self.selected_date = d;
                         }
                     }
+                    KeyCode::Char(' ') if modifiers.contains(KeyModifiers::SHIFT) => {
+                        self.toggle_week();
+                    }
+                    KeyCode::Char('B') => {
+                        self.toggle_week();
+                    }
                     KeyCode::Char(' ') => {
                         self.switch_time_period_view(1);
                     }
-                    KeyCode::Char('b') => {
-                        if self.current_period().is_some() {
-                            let date_key = self.selected_date.format("%Y-%m-%d").to_string();
-                            if self.badge_data.has(&date_key) {
-                                self.badge_data.remove(&date_key);
-                            } else {
-                                let office = self.settings.default_office.clone();
-                                let entry = BadgeEntry::new(self.selected_date, &office, false);
-                                self.badge_data.add(entry);
-                            }
-                            if !self.is_what_if() {
-                                self.data_dirty = true;
-                                let _ = self.badge_data.save_to(&self.data_dir);
-                            }
-                            self.update_stats();
+                    KeyCode::Char('b') if self.current_period().is_some() => {
+                        let date_key = self.selected_date.format("%Y-%m-%d").to_string();
+                        self.record_undo(Snapshot::Badges(self.badge_data.clone()));
+                        if self.badge_data.has(&date_key) {
+                            self.badge_data.remove(&date_key);
+                        } else {
+                            let office = self.office_label();
+                            let entry = self.office_badge(self.selected_date, &office);
+                            self.badge_data.add(entry);
+                        }
+                        if !self.is_what_if() {
+                            self.data_dirty = true;
+                            let _ = self
+                                .badge_data
+                                .save_to(&self.data_dir, self.settings.backup_count);
                         }
+                        self.update_stats();
                     }
-                    KeyCode::Char('f') => {
-                        if self.current_period().is_some() {
-                            let date_key = self.selected_date.format("%Y-%m-%d").to_string();
-                            if self.badge_data.has(&date_key) {
-                                self.badge_data.remove(&date_key);
-                            } else {
-                                let flex = self.settings.flex_credit.clone();
-                                let entry = BadgeEntry::new(self.selected_date, &flex, true);
-                                self.badge_data.add(entry);
-                            }
-                            if !self.is_what_if() {
-                                self.data_dirty = true;
-                                let _ = self.badge_data.save_to(&self.data_dir);
-                            }
-                            self.update_stats();
+                    KeyCode::Char('f') if self.current_period().is_some() => {
+                        let date_key = self.selected_date.format("%Y-%m-%d").to_string();
+                        self.record_undo(Snapshot::Badges(self.badge_data.clone()));
+                        if self.badge_data.has(&date_key) {
+                            self.badge_data.remove(&date_key);
+                        } else {
+                            let flex = self.flex_label();
+                            let entry = BadgeEntry::new(self.selected_date, &flex, true);
+                            self.badge_data.add(entry);
+                        }
+                        if !self.is_what_if() {
+                            self.data_dirty = true;
+                            let _ = self
+                                .badge_data
+                                .save_to(&self.data_dir, self.settings.backup_count);
+                        }
+                        self.update_stats();
+                    }
+                    KeyCode::Char('S') => {
+                        if self.is_what_if() {
+                            self.mode = Mode::SaveScenario;
+                        } else {
+                            self.git_status = Some((
+                                "Enter what-if mode (w) to save a scenario".to_string(),
+                                Color::DarkGray,
+                            ));
                         }
                     }
+                    KeyCode::Char('L') => {
+                        self.mode = Mode::LoadScenario;
+                    }
+                    KeyCode::Char('N') => {
+                        let date_key = self.selected_date.format("%Y-%m-%d").to_string();
+                        if let Some(entry) = self.badge_data.get(&date_key) {
+                            self.input_buffer = entry.note.clone().unwrap_or_default();
+                            self.mode = Mode::Note;
+                        }
+                    }
+                    KeyCode::Char('u') => {
+                        self.undo();
+                    }
+                    KeyCode::Char('r') if modifiers.contains(KeyModifiers::CONTROL) => {
+                        self.redo();
+                    }
+                    KeyCode::Char('R') => {
+                        self.mode = Mode::ConfirmReload;
+                    }
+                    KeyCode::Char('D') => {
+                        self.show_full_stats = !self.show_full_stats;
+                    }
+                    KeyCode::Char('W') => {
+                        self.show_week_breakdown = !self.show_week_breakdown;
+                    }
+                    KeyCode::Char('P') => {
+                        self.plan_strategy = match self.plan_strategy {
+                            None => Some(PlanStrategy::EarliestFirst),
+                            Some(PlanStrategy::EarliestFirst) => Some(PlanStrategy::EvenlySpaced),
+                            Some(PlanStrategy::EvenlySpaced) => None,
+                        };
+                    }
+                    KeyCode::Char('x') => {
+                        self.toggle_excused();
+                    }
                     KeyCode::Char('g') => {
                         self.git_backup();
                         // Don't clear git_status — we just set it
@@ -544,6 +1206,7 @@ impl<'a> App<'a> {
                     KeyCode::Char('a') => {
                         self.mode = Mode::Add;
                         self.input_buffer.clear();
+                        self.add_recurrence = None;
                     }
                     KeyCode::Char('d') => {
                         self.mode = Mode::Delete;
@@ -553,9 +1216,31 @@ impl<'a> App<'a> {
                         self.mode = Mode::Search;
                         self.input_buffer.clear();
                     }
+                    KeyCode::PageDown => {
+                        self.scroll_stats(self.stats_visible.max(1) as isize);
+                    }
+                    KeyCode::PageUp => {
+                        self.scroll_stats(-(self.stats_visible.max(1) as isize));
+                    }
+                    KeyCode::End if modifiers.contains(KeyModifiers::CONTROL) => {
+                        self.scroll_stats(isize::MAX);
+                    }
+                    KeyCode::Home if modifiers.contains(KeyModifiers::CONTROL) => {
+                        self.scroll_stats(isize::MIN);
+                    }
+                    KeyCode::Char('t') | KeyCode::Home => {
+                        self.jump_to_date(self.today);
+                    }
+                    KeyCode::Char('J') | KeyCode::Char(':') => {
+                        self.mode = Mode::JumpToDate;
+                        self.input_buffer.clear();
+                        self.jump_invalid = false;
+                    }
                     KeyCode::Char('v') => {
                         self.view_state = ViewState::Vacations;
+                        self.list_year = None;
                         self.list_cursor = 0;
+                        self.list_offset = 0;
                         self.list_add_stage = 0;
                         self.list_field_bufs.clear();
                         self.list_edit_index = None;
@@ -563,7 +1248,18 @@ impl<'a> App<'a> {
                     }
                     KeyCode::Char('h') => {
                         self.view_state = ViewState::Holidays;
+                        self.list_year = None;
+                        self.list_cursor = 0;
+                        self.list_offset = 0;
+                        self.list_add_stage = 0;
+                        self.list_field_bufs.clear();
+                        self.list_edit_index = None;
+                        self.input_buffer.clear();
+                    }
+                    KeyCode::Char('T') => {
+                        self.view_state = ViewState::TimePeriods;
                         self.list_cursor = 0;
+                        self.list_offset = 0;
                         self.list_add_stage = 0;
                         self.list_field_bufs.clear();
                         self.list_edit_index = None;
@@ -572,6 +1268,7 @@ impl<'a> App<'a> {
                     KeyCode::Char('o') => {
                         self.view_state = ViewState::Settings;
                         self.list_cursor = 0;
+                        self.list_offset = 0;
                         self.list_add_stage = 0;
                         self.input_buffer.clear();
                     }
@@ -588,6 +1285,9 @@ impl<'a> App<'a> {
                         }
                         return true;
                     }
+                    KeyCode::Char('c') => {
+                        self.enter_compare_view();
+                    }
                     _ => {}
                 }
                 false
@@ -595,7 +1295,80 @@ impl<'a> App<'a> {
         }
     }
 
+    /// Whether keys are going into a text field, where `?` is just a character.
+    fn is_typing(&self) -> bool {
+        self.list_add_stage > 0
+            || (self.view_state == ViewState::Calendar
+                && !matches!(self.mode, Mode::Normal | Mode::Delete))
+    }
+
     pub fn render(&mut self, f: &mut Frame) {
+        self.render_view(f);
+        if self.show_help {
+            let area = f.area();
+            Self::render_help_overlay(f, area);
+        }
+    }
+
+    /// Draws [`HELP_SECTIONS`] in a box over the whole screen: the calendar
+    /// keys in one column and the other views beside them when there is room.
+    fn render_help_overlay(f: &mut Frame, area: ratatui::layout::Rect) {
+        let key_style = Style::default()
+            .fg(Color::Indexed(51))
+            .add_modifier(Modifier::BOLD);
+        let section_lines = |sections: &[(&'static str, &[(&'static str, &'static str)])]| {
+            let mut lines: Vec<Line> = Vec::new();
+            for (section, bindings) in sections {
+                if !lines.is_empty() {
+                    lines.push(Line::from(""));
+                }
+                lines.push(Line::from(Span::styled(
+                    *section,
+                    Style::default().add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+                )));
+                for (key, action) in *bindings {
+                    lines.push(Line::from(vec![
+                        Span::styled(format!("  {:<18}", key), key_style),
+                        Span::raw(*action),
+                    ]));
+                }
+            }
+            lines
+        };
+
+        let two_columns = area.width >= 120;
+        let width = area
+            .width
+            .saturating_sub(4)
+            .min(if two_columns { 140 } else { 76 });
+        let height = area.height.saturating_sub(2);
+        let popup = ratatui::layout::Rect {
+            x: area.x + (area.width - width) / 2,
+            y: area.y + (area.height - height) / 2,
+            width,
+            height,
+        };
+        f.render_widget(Clear, popup);
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(" Key Bindings ")
+            .title_bottom(" ? or Esc to close ");
+        let inner = block.inner(popup);
+        f.render_widget(block, popup);
+
+        if two_columns {
+            let cols = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+                .split(inner);
+            f.render_widget(Paragraph::new(section_lines(&HELP_SECTIONS[..1])), cols[0]);
+            f.render_widget(Paragraph::new(section_lines(&HELP_SECTIONS[1..])), cols[1]);
+        } else {
+            f.render_widget(Paragraph::new(section_lines(HELP_SECTIONS)), inner);
+        }
+    }
+
+    fn render_view(&mut self, f: &mut Frame) {
         match self.view_state {
             ViewState::Vacations => {
                 let area = f.area();
@@ -605,73 +1378,79 @@ impl<'a> App<'a> {
                 let area = f.area();
                 self.render_holiday_view(f, area);
             }
+            ViewState::TimePeriods => {
+                let area = f.area();
+                self.render_time_period_view(f, area);
+            }
             ViewState::Settings => {
                 let area = f.area();
                 self.render_settings_view(f, area);
             }
+            ViewState::Compare => {
+                let area = f.area();
+                self.render_compare_view(f, area);
+            }
             ViewState::Calendar => {
-                let size = f.area();
-
-                // Horizontal split: left (calendar + events/help), right (stats panels)
-                let h_chunks = Layout::default()
-                    .direction(Direction::Horizontal)
-                    .constraints([
-                        Constraint::Min(30),    // left: calendar + events
-                        Constraint::Length(68), // right: stats panels (40+14+8 cols + borders)
-                    ])
-                    .split(size);
+                let plan = calendar_layout(f.area());
 
                 // Left panel: calendar on top, events+help below
                 let months = self.period_months();
-                let cols = self.time_period_data.calendar_display_columns() as usize;
-                let month_rows = if cols > 0 {
-                    months.len().div_ceil(cols)
-                } else {
-                    1
-                };
-                let cal_height = (month_rows as u16 * 10) + 1;
+                let cols =
+                    plan.month_columns(self.time_period_data.calendar_display_columns() as usize);
+                let cal_height = months.len().div_ceil(cols) as u16 * MONTH_HEIGHT + 1;
 
                 let left_chunks = Layout::default()
                     .direction(Direction::Vertical)
-                    .constraints([Constraint::Length(cal_height), Constraint::Min(10)])
-                    .split(h_chunks[0]);
+                    .constraints([
+                        Constraint::Length(1), // timeline of every period
+                        Constraint::Length(cal_height),
+                        Constraint::Min(10),
+                    ])
+                    .split(plan.left);
 
-                self.render_calendar(f, left_chunks[0]);
-                self.render_events_and_help(f, left_chunks[1]);
+                self.render_timeline(f, left_chunks[0]);
+                self.render_calendar(f, left_chunks[1], cols);
+                self.render_events_and_help(f, left_chunks[2]);
 
-                // Right panel: period stats on top, year stats below
+                // Right panel: period stats on top, year stats (or weeks) below
                 let right_chunks = Layout::default()
                     .direction(Direction::Vertical)
-                    .constraints([Constraint::Length(18), Constraint::Min(12)])
-                    .split(h_chunks[1]);
+                    .constraints(plan.stats_constraints(
+                        26 + self.looking_ahead_rows() + self.office_breakdown_rows(),
+                    ))
+                    .split(plan.right);
 
                 self.render_stats(f, right_chunks[0]);
-                self.render_year_stats(f, right_chunks[1]);
+                if self.show_week_breakdown {
+                    self.render_week_breakdown(f, right_chunks[1]);
+                } else {
+                    self.render_year_stats(f, right_chunks[1]);
+                }
             }
         }
     }
 
+    /// First day of each month the calendar shows: every month the current
+    /// period touches, or three months from `nav_date` outside any period.
     fn period_months(&self) -> Vec<NaiveDate> {
         if let Some(period) = self.current_period()
             && let (Some(start), Some(end)) = (period.start_date, period.end_date)
         {
-            let start_month = NaiveDate::from_ymd_opt(start.year(), start.month(), 1).unwrap();
-            let end_month = NaiveDate::from_ymd_opt(end.year(), end.month(), 1).unwrap();
+            let end_month = first_of_month_after(end, 0);
             let mut months = Vec::new();
-            let mut mo = start_month;
+            let mut mo = first_of_month_after(start, 0);
             while mo <= end_month {
                 months.push(mo);
-                mo = add_months(mo, 1);
+                mo = first_of_month_after(mo, 1);
             }
             return months;
         }
-        vec![
-            self.nav_date,
-            add_months(self.nav_date, 1),
-            add_months(self.nav_date, 2),
-        ]
+        (0..3)
+            .map(|n| first_of_month_after(self.nav_date, n))
+            .collect()
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn render_single_month(
         &self,
         month_date: NaiveDate,
@@ -679,29 +1458,31 @@ impl<'a> App<'a> {
         event_map: &std::collections::HashMap<String, Vec<&Event>>,
         holiday_map: &std::collections::HashMap<String, &Holiday>,
         vacation_map: &std::collections::HashMap<String, Vacation>,
+        planned: &[NaiveDate],
         today: NaiveDate,
     ) -> Vec<Line<'static>> {
         let year = month_date.year();
         let month = month_date.month();
         let title = format!("{} {}", month_name(month), year);
-        let header_str = " Su Mo Tu We Th Fr Sa   ";
+        let header_str = weekday_header(self.settings.week_start);
 
         let mut lines: Vec<Line<'static>> = vec![
             Line::from(Span::styled(
-                format!("{:^24}", title),
+                format!("{:^width$}", title, width = MONTH_WIDTH as usize),
                 Style::default()
                     .fg(Color::White)
                     .add_modifier(Modifier::BOLD),
             )),
             Line::from(Span::styled(
-                header_str.to_string(),
+                header_str,
                 Style::default().fg(Color::DarkGray),
             )),
         ];
 
         let first_of_month = NaiveDate::from_ymd_opt(year, month, 1).unwrap();
         let dim = days_in_month(year, month);
-        let start_dow = first_of_month.weekday().num_days_from_sunday() as usize;
+        let start_dow = weekday_offset(first_of_month, self.settings.week_start);
+        let work_week = self.settings.work_week();
 
         let mut day_cells: Vec<Span<'static>> = Vec::new();
         for _ in 0..start_dow {
@@ -714,39 +1495,70 @@ impl<'a> App<'a> {
 
             let is_selected = date == self.selected_date;
             let is_today = date == today;
-            let is_weekend =
-                date.weekday() == chrono::Weekday::Sat || date.weekday() == chrono::Weekday::Sun;
+            let is_non_workday = !work_week.is_workday(date);
 
-            let (is_badged, is_flex) = if let Some(s) = stats {
+            let (is_badged, is_flex, is_partial) = if let Some(s) = stats {
                 let w = s.workday_stats.get(&date_key);
                 (
                     w.map(|wd| wd.is_badged_in).unwrap_or(false),
                     w.map(|wd| wd.is_flex_credit).unwrap_or(false),
+                    w.map(|wd| wd.is_badged_in && wd.credit < 1.0)
+                        .unwrap_or(false),
                 )
             } else {
-                (false, false)
+                (false, false, false)
             };
 
             let is_holiday_or_vacation = if let Some(s) = stats {
                 s.workday_stats
                     .get(&date_key)
-                    .map(|w| w.is_holiday || w.is_vacation)
+                    .map(|w| w.is_holiday || w.is_vacation || w.is_pending)
                     .unwrap_or(false)
             } else {
-                holiday_map.contains_key(&date_key) || vacation_map.contains_key(&date_key)
+                holiday_map.contains_key(&date_key)
+                    || vacation_map.get(&date_key).is_some_and(|v| v.counts_as_off)
+            };
+
+            let is_pending_vacation = if let Some(s) = stats {
+                s.workday_stats.get(&date_key).is_some_and(|w| w.is_pending)
+            } else {
+                !holiday_map.contains_key(&date_key)
+                    && vacation_map
+                        .get(&date_key)
+                        .is_some_and(|v| v.counts_as_off && !v.approved)
             };
 
             let has_event = event_map.contains_key(&date_key);
+            let is_pace_miss = stats
+                .as_ref()
+                .is_some_and(|s| s.pace_misses.contains(&date_key));
 
             let style = calendar_day_style(
+                &self.theme,
                 is_selected,
                 is_badged,
                 is_flex,
                 is_holiday_or_vacation,
                 is_today,
-                is_weekend,
+                is_non_workday,
                 has_event,
+                is_pace_miss,
+                is_pending_vacation,
             );
+            // Partial-credit days: a dimmed, non-underlined version of the badge color
+            let style = if is_partial && !is_selected {
+                style
+                    .add_modifier(Modifier::DIM)
+                    .remove_modifier(Modifier::UNDERLINED)
+            } else {
+                style
+            };
+            // Suggested plan days: the planning color, never an actual badge
+            let style = if planned.contains(&date) && !is_selected {
+                style.fg(self.theme.plan).add_modifier(Modifier::BOLD)
+            } else {
+                style
+            };
             day_cells.push(Span::styled(format!("{:2}", d), style));
         }
 
@@ -773,15 +1585,84 @@ impl<'a> App<'a> {
         lines
     }
 
-    fn render_calendar(&self, f: &mut Frame, area: ratatui::layout::Rect) {
+    /// One line across the top of the calendar: every period as a segment
+    /// sized by its length, today's period highlighted, the period on screen
+    /// underlined, and a marker at today.
+    fn render_timeline(&self, f: &mut Frame, area: ratatui::layout::Rect) {
+        let segments = quarter_timeline(self.time_period_data.periods(), self.today, area.width);
+        let shown = self.current_period().map(|p| p.key.as_str());
+        let mut spans = Vec::new();
+        for (i, seg) in segments.iter().enumerate() {
+            let width = seg.width as usize;
+            if width == 0 {
+                continue;
+            }
+            let mut style = if seg.is_current {
+                Style::default()
+                    .fg(self.theme.selected_fg)
+                    .bg(self.theme.event)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(Color::Gray).bg(if i % 2 == 0 {
+                    Color::Indexed(237)
+                } else {
+                    Color::Indexed(239)
+                })
+            };
+            if shown == Some(seg.key.as_str()) {
+                style = style.add_modifier(Modifier::UNDERLINED);
+            }
+            let label: Vec<char> = seg.key.chars().take(width).collect();
+            // Centre the key, moving it to the roomier side if the marker hits it
+            let mut pos = (width - label.len()) / 2;
+            if let Some(at) = seg.today_at.map(|at| at as usize)
+                && (pos..pos + label.len()).contains(&at)
+            {
+                pos = if at < width / 2 {
+                    (width - label.len()).min(at + 2)
+                } else {
+                    at.saturating_sub(label.len() + 1)
+                };
+            }
+            let mut text = vec![' '; width];
+            text[pos..pos + label.len()].copy_from_slice(&label);
+            match seg.today_at {
+                Some(at) => {
+                    let at = at as usize;
+                    let after: String = text.split_off(at + 1).into_iter().collect();
+                    let before: String = text[..at].iter().collect();
+                    spans.push(Span::styled(before, style));
+                    spans.push(Span::styled(
+                        "▼",
+                        style.fg(Color::Yellow).add_modifier(Modifier::BOLD),
+                    ));
+                    spans.push(Span::styled(after, style));
+                }
+                None => spans.push(Span::styled(text.into_iter().collect::<String>(), style)),
+            }
+        }
+        f.render_widget(Paragraph::new(Line::from(spans)), area);
+    }
+
+    fn render_calendar(&self, f: &mut Frame, area: ratatui::layout::Rect, cols: usize) {
         let stats = &self.active_stats;
         let today = self.today;
-        let event_map = self.event_data.get_event_map();
-        let holiday_map = self.holiday_data.get_holiday_map();
-        let vacation_map = self.vacation_data.get_vacation_map();
+        let holiday_map = self.holiday_data.get_holiday_map(
+            self.settings.work_week(),
+            self.settings.observe_weekend_holidays,
+        );
+        let vacation_map = self
+            .vacation_data
+            .get_vacation_map(self.settings.work_week());
 
         let months = self.period_months();
-        let cols = self.time_period_data.calendar_display_columns() as usize;
+        let first = months[0];
+        let last = first_of_month_after(months[months.len() - 1], 1) - Duration::days(1);
+        let event_map = self.event_data.get_event_map(first, last);
+        let planned = match (self.plan_strategy, stats) {
+            (Some(strategy), Some(s)) => suggest_office_days(s, strategy),
+            _ => Vec::new(),
+        };
 
         let mut all_lines: Vec<Line> = Vec::new();
 
@@ -812,7 +1693,20 @@ impl<'a> App<'a> {
             all_lines.push(Line::from(""));
         }
 
-        let cols = if cols == 0 { 3 } else { cols };
+        if let Some(strategy) = self.plan_strategy {
+            all_lines.push(Line::from(Span::styled(
+                format!(
+                    " PLAN: {} suggested day(s), {}  (P to change) ",
+                    planned.len(),
+                    strategy.label()
+                ),
+                Style::default()
+                    .fg(self.theme.plan)
+                    .add_modifier(Modifier::BOLD),
+            )));
+            all_lines.push(Line::from(""));
+        }
+
         for chunk_start in (0..months.len()).step_by(cols) {
             let chunk_end = (chunk_start + cols).min(months.len());
             let row_months = &months[chunk_start..chunk_end];
@@ -826,6 +1720,7 @@ impl<'a> App<'a> {
                         &event_map,
                         &holiday_map,
                         &vacation_map,
+                        &planned,
                         today,
                     )
                 })
@@ -837,12 +1732,12 @@ impl<'a> App<'a> {
                 let mut spans: Vec<Span> = Vec::new();
                 for (m_idx, month_lines) in month_renders.iter().enumerate() {
                     if m_idx > 0 {
-                        spans.push(Span::raw("  "));
+                        spans.push(Span::raw(" ".repeat(GAP_WIDTH as usize)));
                     }
                     if line_idx < month_lines.len() {
                         spans.extend(month_lines[line_idx].spans.clone());
                     } else {
-                        spans.push(Span::raw("                        "));
+                        spans.push(Span::raw(" ".repeat(MONTH_WIDTH as usize)));
                     }
                 }
                 all_lines.push(Line::from(spans));
@@ -860,17 +1755,7 @@ impl<'a> App<'a> {
             None => return,
         };
 
-        let status_style = match stats.compliance_status.as_str() {
-            "Achieved" => Style::default()
-                .fg(Color::Indexed(46))
-                .add_modifier(Modifier::BOLD),
-            "On Track" => Style::default().fg(Color::Indexed(40)),
-            "At Risk" => Style::default().fg(Color::Indexed(208)),
-            "Impossible" => Style::default()
-                .fg(Color::Indexed(196))
-                .add_modifier(Modifier::BOLD),
-            _ => Style::default(),
-        };
+        let status_style = compliance_style(&stats.compliance_status);
 
         let pace_str = format!("{:+} days", stats.days_ahead_of_pace);
         let pace_str = if stats.days_ahead_of_pace > 0 {
@@ -879,7 +1764,7 @@ impl<'a> App<'a> {
             pace_str
         };
 
-        let office_days = stats.days_badged_in - stats.flex_days;
+        let office_days = stats.office_days();
         let goal_pct = if stats.total_days > 0 {
             format!(
                 "{:.1}%",
@@ -891,20 +1776,17 @@ impl<'a> App<'a> {
         let office_pct = if stats.days_required > 0 {
             format!(
                 "{:.1}%",
-                stats.days_badged_in as f64 / stats.days_required as f64 * 100.0
+                stats.days_badged_in / stats.days_required as f64 * 100.0
             )
         } else {
             String::new()
         };
-        let (badge_pct, flex_pct) = if stats.days_badged_in > 0 {
+        let (badge_pct, flex_pct) = if stats.days_badged_in > 0.0 {
             (
+                format!("{:.1}%", office_days / stats.days_badged_in * 100.0),
                 format!(
                     "{:.1}%",
-                    office_days as f64 / stats.days_badged_in as f64 * 100.0
-                ),
-                format!(
-                    "{:.1}%",
-                    stats.flex_days as f64 / stats.days_badged_in as f64 * 100.0
+                    (stats.days_badged_in - office_days) / stats.days_badged_in * 100.0
                 ),
             )
         } else {
@@ -919,73 +1801,203 @@ impl<'a> App<'a> {
             String::new()
         };
 
+        let still_needed_label = if stats.carryover_days > 0 {
+            format!("Still Needed ({} carried over)", stats.carryover_days)
+        } else {
+            "Still Needed".to_string()
+        };
         let skippable_label = format!(
             "Skippable Days ({} left - {} needed)",
             stats.days_left, stats.days_still_needed
         );
 
-        let rows: Vec<Row> = vec![
-            section_header("STATUS"),
-            data_row(
-                "Status",
-                Cell::from(stats.compliance_status.clone()).style(status_style),
-                plain(""),
-            ),
-            data_row("Days Ahead of Pace", plain(pace_str), plain("")),
-            data_row(
-                &skippable_label,
-                plain(format!("{}", stats.remaining_missable_days)),
-                plain(""),
-            ),
-            spacer(),
-            section_header("PROGRESS"),
-            data_row(
-                "Total Days",
-                plain(format!("{}", stats.total_calendar_days)),
-                plain(""),
-            ),
-            data_row(
-                "Total Working Days",
-                plain(format!("{}", stats.available_workdays - stats.holidays)),
-                plain(""),
-            ),
-            data_row(
-                "Available Working Days",
-                plain(format!("{}", stats.total_days)),
+        let week_row = if let Some(week) = self.this_week_progress() {
+            let week_style = if week.remaining_to_target() == 0.0 {
+                compliance_style("Achieved")
+            } else if week.remaining_to_target() > week.days_left as f64 {
+                compliance_style("At Risk")
+            } else {
+                Style::default()
+            };
+            Some(data_row(
+                format!("This Week ({} workdays left)", week.days_left),
+                Cell::from(format!("{} / {}", week.badged, week.target)).style(week_style),
                 plain(""),
-            ),
-            data_row(
-                format!("Goal ({}% Required)", self.settings.goal),
-                plain(format!("{} / {}", stats.days_required, stats.total_days)),
-                plain(goal_pct),
-            ),
-            data_row(
-                "Office Days",
-                plain(format!(
-                    "{} / {}",
-                    stats.days_badged_in, stats.days_required
-                )),
-                plain(office_pct),
-            ),
-            data_row(
-                " Badge-In Days",
-                plain(format!("{}", office_days)),
-                plain(badge_pct),
-            ),
-            data_row(
-                " Flex Credits",
-                plain(format!("{}", stats.flex_days)),
-                plain(flex_pct),
-            ),
-            data_row(
-                "Still Needed",
-                plain(format!(
-                    "{} / {}",
-                    stats.days_still_needed, stats.days_required
-                )),
-                plain(needed_pct),
-            ),
-        ];
+            ))
+        } else {
+            None
+        };
+
+        let collapsed = stats.compliance_status == "Achieved" && !self.show_full_stats;
+        let rows: Vec<Row> = if collapsed {
+            let final_rate = if stats.total_days > 0 {
+                format!(
+                    "{:.1}%",
+                    stats.days_badged_in / stats.total_days as f64 * 100.0
+                )
+            } else {
+                String::new()
+            };
+            vec![
+                section_header("DONE", &self.theme),
+                data_row(
+                    "Status",
+                    Cell::from(stats.compliance_status.clone()).style(status_style),
+                    plain(""),
+                ),
+                data_row(
+                    "Office Days",
+                    plain(format!(
+                        "{} / {}",
+                        stats.days_badged_in, stats.days_required
+                    )),
+                    plain(office_pct),
+                ),
+                data_row(
+                    "Banked Days",
+                    plain(format!(
+                        "{}",
+                        (stats.days_badged_in - stats.days_required as f64).max(0.0)
+                    )),
+                    plain(""),
+                ),
+                data_row(
+                    format!("Final Rate ({} Goal)", self.settings.target()),
+                    plain(final_rate),
+                    plain(""),
+                ),
+                spacer(),
+                data_row("Goal met — press D for full detail", plain(""), plain("")),
+            ]
+        } else {
+            let mut rows = vec![
+                section_header("STATUS", &self.theme),
+                data_row(
+                    "Status",
+                    Cell::from(stats.compliance_status.clone()).style(status_style),
+                    plain(""),
+                ),
+                data_row(
+                    "Period Elapsed",
+                    plain(format!("{} days to go", stats.days_until_end)),
+                    plain(format!("{:.0}%", stats.quarter_elapsed_pct)),
+                ),
+                data_row("Days Ahead of Pace", plain(pace_str), plain("")),
+                data_row(
+                    "Buffer Used",
+                    plain(format!("{} days beyond pace", stats.buffer_days_used)),
+                    plain(""),
+                ),
+                data_row(
+                    &skippable_label,
+                    plain(format!("{}", stats.remaining_missable_days)),
+                    plain(""),
+                ),
+            ];
+            rows.extend(week_row);
+            rows.extend([
+                spacer(),
+                section_header("PROGRESS", &self.theme),
+                data_row(
+                    "Total Days",
+                    plain(format!("{}", stats.total_calendar_days)),
+                    plain(""),
+                ),
+                data_row(
+                    "Total Working Days",
+                    plain(format!("{}", stats.available_workdays - stats.holidays)),
+                    plain(""),
+                ),
+                data_row(
+                    "Available Working Days",
+                    plain(format!("{}", stats.total_days)),
+                    plain(""),
+                ),
+                data_row(
+                    format!("Goal ({} Required)", self.settings.target()),
+                    plain(format!("{} / {}", stats.days_required, stats.total_days)),
+                    plain(goal_pct),
+                ),
+                data_row(
+                    "Office Days",
+                    plain(format!(
+                        "{} / {}",
+                        stats.days_badged_in, stats.days_required
+                    )),
+                    plain(office_pct),
+                ),
+                data_row(
+                    badge_in_label(&stats),
+                    plain(format!("{}", office_days)),
+                    plain(badge_pct),
+                ),
+                data_row(
+                    flex_credits_label(&stats),
+                    plain(format!("{}", stats.flex_days)),
+                    plain(flex_pct),
+                ),
+                data_row(
+                    still_needed_label,
+                    plain(format!(
+                        "{} / {}",
+                        stats.days_still_needed, stats.days_required
+                    )),
+                    plain(needed_pct),
+                ),
+                {
+                    let (rate, color) = rate_needed_style(&stats);
+                    data_row(
+                        "Rate Needed (Remaining)",
+                        Cell::from(rate).style(Style::default().fg(color)),
+                        plain(""),
+                    )
+                },
+                data_row(
+                    "Projected Final",
+                    plain(format!(
+                        "{:.1}%–{:.1}%",
+                        stats.worst_case_average * 100.0,
+                        stats.best_case_average * 100.0
+                    )),
+                    plain(""),
+                ),
+                data_row(
+                    "Streak (Current / Longest)",
+                    plain(format!(
+                        "{} / {}",
+                        stats.current_streak, stats.longest_streak
+                    )),
+                    plain(""),
+                ),
+            ]);
+            if self.looking_ahead_rows() > 0 {
+                rows.push(spacer());
+                rows.push(section_header("LOOKING AHEAD", &self.theme));
+                rows.push(data_row(
+                    "Holidays Ahead",
+                    plain(format!("{}", stats.future_holidays)),
+                    plain(""),
+                ));
+                rows.push(data_row(
+                    "Vacation Days Ahead",
+                    plain(format!("{}", stats.future_vacation_days)),
+                    plain(""),
+                ));
+            }
+            if self.office_breakdown_rows() > 0 {
+                let total: i32 = stats.days_by_office.values().sum();
+                rows.push(spacer());
+                rows.push(section_header("BY OFFICE", &self.theme));
+                for (office, days) in stats.offices_by_days() {
+                    rows.push(data_row(
+                        office,
+                        plain(format!("{}", days)),
+                        plain(format!("{:.1}%", days as f64 / total as f64 * 100.0)),
+                    ));
+                }
+            }
+            rows
+        };
 
         let quarter_key = self
             .current_period()
@@ -997,12 +2009,95 @@ impl<'a> App<'a> {
         let (title_text, title_style) = if self.is_what_if() {
             (
                 format!(" Period Stats: {} [What-If Mode] ", quarter_key),
-                Style::default().fg(FLEX_COLOR).add_modifier(Modifier::BOLD),
+                Style::default()
+                    .fg(self.theme.flex)
+                    .add_modifier(Modifier::BOLD),
             )
         } else {
             (format!(" Period Stats: {} ", quarter_key), bold_white)
         };
 
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(bold_white)
+            .title(title_text)
+            .title_style(title_style);
+        let inner = block.inner(area);
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(1),
+                Constraint::Length(2),
+                Constraint::Min(0),
+            ])
+            .split(inner);
+
+        // Keep the scroll position valid after a resize or a shorter table
+        self.stats_rows = rows.len();
+        self.stats_visible = chunks[2].height as usize;
+        let offset = self
+            .table_state
+            .offset()
+            .min(self.stats_rows.saturating_sub(self.stats_visible));
+        *self.table_state.offset_mut() = offset;
+        let block = block.title_bottom(more_rows_indicator(
+            offset,
+            self.stats_visible,
+            self.stats_rows,
+        ));
+        f.render_widget(block, area);
+
+        // Goal progress bar above the metrics
+        let ratio = goal_progress(&stats);
+        let gauge_color = compliance_style(&stats.compliance_status)
+            .fg
+            .unwrap_or(Color::White);
+        let gauge = Gauge::default()
+            .gauge_style(Style::default().fg(gauge_color).bg(Color::DarkGray))
+            .ratio(ratio)
+            .label(format!(
+                "{} / {} required ({:.0}%)",
+                stats.days_badged_in,
+                stats.days_required,
+                ratio * 100.0
+            ));
+        f.render_widget(gauge, chunks[0]);
+
+        // Cumulative badge-ins against the ideal pace, on a shared scale
+        let (actual, pace) = cumulative_series(&stats);
+        let max = pace
+            .last()
+            .copied()
+            .max(actual.last().copied())
+            .unwrap_or(0)
+            .max(1);
+        let series = [
+            ("Actual", actual, gauge_color),
+            ("Pace", pace, Color::DarkGray),
+        ];
+        for (i, (label, data, color)) in series.into_iter().enumerate() {
+            let row = ratatui::layout::Rect {
+                y: chunks[1].y + i as u16,
+                height: 1,
+                ..chunks[1]
+            };
+            let cols = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Length(8), Constraint::Min(0)])
+                .split(row);
+            f.render_widget(
+                Paragraph::new(Span::styled(label, Style::default().fg(Color::DarkGray))),
+                cols[0],
+            );
+            f.render_widget(
+                Sparkline::default()
+                    .data(&data)
+                    .max(max)
+                    .style(Style::default().fg(color)),
+                cols[1],
+            );
+        }
+
         let table = Table::new(
             rows,
             [
@@ -1010,16 +2105,9 @@ impl<'a> App<'a> {
                 Constraint::Length(14),
                 Constraint::Length(8),
             ],
-        )
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .border_style(bold_white)
-                .title(title_text)
-                .title_style(title_style),
         );
 
-        f.render_stateful_widget(table, area, &mut self.table_state);
+        f.render_stateful_widget(table, chunks[2], &mut self.table_state);
     }
 
     fn render_year_stats(&self, f: &mut Frame, area: ratatui::layout::Rect) {
@@ -1028,30 +2116,24 @@ impl<'a> App<'a> {
             None => return,
         };
         let year = match self.current_period() {
-            Some(q) => q
-                .start_date
-                .map(|d| d.year().to_string())
-                .unwrap_or_default(),
+            Some(q) => q.start_date.map(|d| d.year()).unwrap_or(self.today.year()),
             None => return,
         };
 
-        let office_days = stats.days_badged_in - stats.flex_days;
-        let (badge_pct, flex_pct) = if stats.days_badged_in > 0 {
+        let office_days = stats.office_days();
+        let (badge_pct, flex_pct) = if stats.days_badged_in > 0.0 {
             (
+                format!("{:.1}%", office_days / stats.days_badged_in * 100.0),
                 format!(
                     "{:.1}%",
-                    office_days as f64 / stats.days_badged_in as f64 * 100.0
-                ),
-                format!(
-                    "{:.1}%",
-                    stats.flex_days as f64 / stats.days_badged_in as f64 * 100.0
+                    (stats.days_badged_in - office_days) / stats.days_badged_in * 100.0
                 ),
             )
         } else {
             (String::new(), String::new())
         };
 
-        let rows = vec![
+        let mut rows = vec![
             data_row(
                 "Total Calendar Days",
                 plain(format!("{}", stats.total_calendar_days)),
@@ -1073,22 +2155,47 @@ impl<'a> App<'a> {
                 plain(format!("{}", stats.vacation_days)),
                 plain(""),
             ),
+            data_row(
+                "Excused Days",
+                plain(format!("{}", stats.excused_days)),
+                plain(""),
+            ),
             data_row(
                 "Office Days",
                 plain(format!("{}", stats.days_badged_in)),
                 plain(""),
             ),
             data_row(
-                " Badge-In Days",
+                badge_in_label(&stats),
                 plain(format!("{}", office_days)),
                 plain(badge_pct),
             ),
             data_row(
-                " Flex Credits",
+                flex_credits_label(&stats),
                 plain(format!("{}", stats.flex_days)),
                 plain(flex_pct),
             ),
         ];
+        if self.settings.pto_days_per_year > 0 {
+            let remaining = projected_pto_remaining(
+                self.settings.pto_days_per_year,
+                self.vacation_data,
+                self.holiday_data,
+                year,
+                self.settings.work_week(),
+                self.settings.observe_weekend_holidays,
+            );
+            let style = if remaining < 0 {
+                compliance_style("Impossible")
+            } else {
+                Style::default()
+            };
+            rows.push(data_row(
+                "Projected PTO Left at Year End",
+                Cell::from(format!("{} days", remaining)).style(style),
+                plain(""),
+            ));
+        }
 
         let bold_white = Style::default()
             .fg(Color::Indexed(231))
@@ -1112,30 +2219,89 @@ impl<'a> App<'a> {
         f.render_widget(table, area);
     }
 
-    fn render_events_and_help(&self, f: &mut Frame, area: ratatui::layout::Rect) {
-        let event_map = self.event_data.get_event_map();
-        let date_key = self.selected_date.format("%Y-%m-%d").to_string();
-        let events: Vec<_> = event_map
-            .get(&date_key)
-            .map(|v| v.as_slice())
-            .unwrap_or(&[])
-            .to_vec();
-
-        let mut lines: Vec<Line> = Vec::new();
+    fn render_week_breakdown(&self, f: &mut Frame, area: ratatui::layout::Rect) {
+        let Some(stats) = &self.active_stats else {
+            return;
+        };
+        let target = self.settings.target();
+        const BAR_WIDTH: usize = 20;
 
-        if let Some((msg, color)) = &self.git_status {
-            lines.push(Line::from(vec![
-                Span::styled(
-                    "[ git ] ",
+        let rows: Vec<Row> = weekly_breakdown(stats)
+            .iter()
+            .map(|w| {
+                let filled = if w.working_days > 0 {
+                    ((w.badged / w.working_days as f64) * BAR_WIDTH as f64).round() as usize
+                } else {
+                    0
+                };
+                let filled = filled.min(BAR_WIDTH);
+                let bar = format!("{}{}", "█".repeat(filled), "░".repeat(BAR_WIDTH - filled));
+                let style = if w.missed(target, self.today) {
+                    compliance_style("Impossible")
+                } else {
                     Style::default()
-                        .fg(Color::DarkGray)
-                        .add_modifier(Modifier::BOLD),
-                ),
-                Span::styled(
-                    msg.clone(),
-                    Style::default().fg(*color).add_modifier(Modifier::BOLD),
-                ),
-            ]));
+                };
+                Row::new(vec![
+                    Cell::from(format!(
+                        "  W{:02}  {}",
+                        w.iso_week,
+                        w.first_day.format("%b %e")
+                    )),
+                    Cell::from(format!("{} / {}", w.badged, w.working_days)),
+                    Cell::from(bar),
+                ])
+                .style(style)
+            })
+            .collect();
+
+        let bold_white = Style::default()
+            .fg(Color::Indexed(231))
+            .add_modifier(Modifier::BOLD);
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(16),
+                Constraint::Length(10),
+                Constraint::Length(BAR_WIDTH as u16),
+            ],
+        )
+        .header(Row::new(vec!["  Week", "Badged", ""]).style(Style::default().fg(Color::DarkGray)))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(bold_white)
+                .title(format!(" Weeks: {} ", stats.name))
+                .title_style(bold_white),
+        );
+
+        f.render_widget(table, area);
+    }
+
+    fn render_events_and_help(&self, f: &mut Frame, area: ratatui::layout::Rect) {
+        let events = self.event_data.events_on(self.selected_date);
+
+        let mut lines: Vec<Line> = Vec::new();
+
+        if let Some((msg, color)) = &self.git_status {
+            let msg = match &self.backup {
+                Some((_, started)) => {
+                    let frame = started.elapsed().as_millis() as usize / 100 % SPINNER.len();
+                    format!("{} {}", SPINNER[frame], msg)
+                }
+                None => msg.clone(),
+            };
+            lines.push(Line::from(vec![
+                Span::styled(
+                    "[ git ] ",
+                    Style::default()
+                        .fg(Color::DarkGray)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    msg,
+                    Style::default().fg(*color).add_modifier(Modifier::BOLD),
+                ),
+            ]));
             lines.push(Line::from(""));
         }
 
@@ -1147,13 +2313,57 @@ impl<'a> App<'a> {
             ),
             event_style.add_modifier(Modifier::BOLD),
         )));
+        for note in day_notes(
+            self.selected_date,
+            self.holiday_data,
+            self.vacation_data,
+            self.settings.work_week(),
+            self.settings.observe_weekend_holidays,
+        ) {
+            lines.push(Line::from(Span::styled(
+                format!("  {}", note),
+                Style::default().fg(self.theme.holiday),
+            )));
+        }
+        // The label stored on the entry, which may predate a settings rename
+        let selected_key = self.selected_date.format("%Y-%m-%d").to_string();
+        if let Some(entry) = self.badge_data.get(&selected_key)
+            && entry.is_badged_in
+        {
+            let (kind, color) = if entry.is_flex_credit {
+                ("Flex", self.theme.flex)
+            } else {
+                ("Office", self.theme.office)
+            };
+            let logged = entry
+                .logged_time()
+                .map(|t| format!("  (badged at {})", t.format("%H:%M")))
+                .unwrap_or_default();
+            lines.push(Line::from(Span::styled(
+                format!("  {}: {}{}", kind, entry.office, logged),
+                Style::default().fg(color),
+            )));
+        }
+        if let Some(note) = self
+            .badge_data
+            .get(&selected_key)
+            .and_then(|e| e.note.as_deref())
+            && self.mode != Mode::Note
+        {
+            lines.push(Line::from(format!("  Note: {}", note)));
+        }
 
         match self.mode {
             Mode::Add => {
+                let repeat = self
+                    .add_recurrence
+                    .map(|r| format!(" ({})", r.label()))
+                    .unwrap_or_default();
                 lines.push(Line::from(Span::styled(
-                    format!(" Add event: {}_", self.input_buffer),
+                    format!(" Add event{}: {}_", repeat, self.input_buffer),
                     event_style,
                 )));
+                lines.push(Line::from("  Tab=repeat weekly/monthly"));
             }
             Mode::Delete => {
                 lines.push(Line::from("  Select event to delete:"));
@@ -1166,9 +2376,72 @@ impl<'a> App<'a> {
                         } else {
                             "    "
                         };
-                        lines.push(Line::from(format!("{}{}", prefix, e.description)));
+                        let repeat = e
+                            .recurrence
+                            .map(|r| format!(" ({})", r.label()))
+                            .unwrap_or_default();
+                        lines.push(Line::from(format!("{}{}{}", prefix, e.description, repeat)));
                     }
-                    lines.push(Line::from("  Enter=delete  Esc=cancel  ↑↓=move"));
+                    let series = events
+                        .get(self.cursor_index)
+                        .is_some_and(|e| e.recurrence.is_some());
+                    lines.push(Line::from(if series {
+                        "  Enter=delete this day  D=delete series  e=edit  Esc=cancel"
+                    } else {
+                        "  Enter=delete  e=edit  Esc=cancel  ↑↓=move"
+                    }));
+                }
+            }
+            Mode::Edit => {
+                lines.push(Line::from(Span::styled(
+                    format!(" Edit event: {}_", self.input_buffer),
+                    event_style,
+                )));
+            }
+            Mode::Note => {
+                lines.push(Line::from(Span::styled(
+                    format!(" Day note: {}_", self.input_buffer),
+                    event_style,
+                )));
+                lines.push(Line::from("  Enter=save (empty clears)  Esc=cancel"));
+            }
+            Mode::SaveScenario => {
+                lines.push(Line::from(Span::styled(
+                    format!(" Save scenario as: {}_", self.input_buffer),
+                    event_style,
+                )));
+                lines.push(Line::from(
+                    "  Letters, digits, - and _  Enter=save (replaces same name)  Esc=cancel",
+                ));
+            }
+            Mode::LoadScenario => {
+                lines.push(Line::from(Span::styled(
+                    format!(" Load scenario: {}_", self.input_buffer),
+                    event_style,
+                )));
+                let saved = scenario::list_scenarios(&self.data_dir).unwrap_or_default();
+                lines.push(Line::from(if saved.is_empty() {
+                    "  (no scenarios saved yet)".to_string()
+                } else {
+                    format!("  Saved: {}", saved.join(", "))
+                }));
+            }
+            Mode::ConfirmReload => {
+                lines.push(Line::from(Span::styled(
+                    " Reload all files from disk and discard unsaved changes? (y/n)",
+                    event_style,
+                )));
+            }
+            Mode::JumpToDate => {
+                lines.push(Line::from(Span::styled(
+                    format!(" Go to date (YYYY-MM-DD): {}_", self.input_buffer),
+                    event_style,
+                )));
+                if self.jump_invalid {
+                    lines.push(Line::from(Span::styled(
+                        "  Invalid date",
+                        Style::default().fg(Color::Red),
+                    )));
                 }
             }
             Mode::Search => {
@@ -1190,8 +2463,13 @@ impl<'a> App<'a> {
                         Style::default().fg(Color::DarkGray),
                     )));
                 } else {
+                    let width = (area.width as usize).saturating_sub(4);
                     for e in &events {
-                        lines.push(Line::from(format!("  • {}", e.description)));
+                        let wrapped = wrap_truncated(&e.description, width, MAX_EVENT_LINES);
+                        for (i, text) in wrapped.into_iter().enumerate() {
+                            let prefix = if i == 0 { "  • " } else { "    " };
+                            lines.push(Line::from(format!("{}{}", prefix, text)));
+                        }
                     }
                 }
             }
@@ -1221,17 +2499,31 @@ impl<'a> App<'a> {
 
         let bindings: Vec<(&str, String)> = vec![
             ("←→↑↓", "Navigate".to_string()),
-            ("b", self.settings.default_office.clone()),
-            ("f", self.settings.flex_credit.clone()),
+            ("b", self.office_label()),
+            ("f", self.flex_label()),
+            ("N", "Day note".to_string()),
             ("n/p", "Next/Prev period".to_string()),
             ("a", "Add event".to_string()),
-            ("d", "Delete event".to_string()),
+            ("d", "Delete/edit event".to_string()),
             ("s", "Search".to_string()),
+            ("J", "Jump to date".to_string()),
+            ("t", "Today".to_string()),
             ("w", "What-if".to_string()),
+            ("S/L", "Save/load scenario".to_string()),
             ("g", "Git backup".to_string()),
+            ("u/^R", "Undo/Redo".to_string()),
+            ("R", "Reload".to_string()),
+            ("x", "Excused/sick day".to_string()),
+            ("B", "Badge whole week".to_string()),
+            ("D", "Full/done stats".to_string()),
+            ("W", "Week breakdown".to_string()),
+            ("P", "Plan days".to_string()),
             ("v", "Vacations".to_string()),
             ("h", "Holidays".to_string()),
+            ("T", "Time periods".to_string()),
             ("o", "Settings".to_string()),
+            ("c", "Compare".to_string()),
+            ("?", "All keys".to_string()),
             ("q", "Quit".to_string()),
         ];
 
@@ -1307,7 +2599,91 @@ impl<'a> App<'a> {
 
     // ── Vacation View ─────────────────────────────────────────────────────────
 
-    fn render_vacation_view(&self, f: &mut Frame, area: ratatui::layout::Rect) {
+    /// Scrolls the list viewport so the cursor stays visible, keeping the
+    /// previous offset when possible so the view doesn't jump between redraws.
+    fn scroll_list(&mut self, len: usize, visible: usize) -> TableState {
+        self.list_offset = scroll_offset(self.list_offset, self.list_cursor, visible, len);
+        let mut state = TableState::default().with_offset(self.list_offset);
+        if len > 0 {
+            state.select(Some(self.list_cursor));
+        }
+        state
+    }
+
+    /// Indices of the vacations or holidays shown in the current list view,
+    /// narrowed to `list_year` when set. A vacation shows under both years
+    /// it touches.
+    fn list_rows(&self) -> Vec<usize> {
+        let year = self.list_year.as_deref();
+        let shown = |dates: &[&str]| year.is_none_or(|y| dates.iter().any(|d| date_in_year(d, y)));
+        match self.view_state {
+            ViewState::Vacations => self
+                .vacation_data
+                .vacations
+                .iter()
+                .enumerate()
+                .filter(|(_, v)| shown(&[&v.start_date, &v.end_date]))
+                .map(|(i, _)| i)
+                .collect(),
+            ViewState::Holidays => self
+                .holiday_data
+                .holidays
+                .iter()
+                .enumerate()
+                .filter(|(_, h)| shown(&[&h.date]))
+                .map(|(i, _)| i)
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Index into the underlying vacation or holiday list of the row under
+    /// `list_cursor`.
+    fn selected_list_index(&self) -> Option<usize> {
+        self.list_rows().get(self.list_cursor).copied()
+    }
+
+    /// Years with at least one entry in the current list view, ascending.
+    fn list_years(&self) -> Vec<String> {
+        let dates: Vec<&str> = match self.view_state {
+            ViewState::Vacations => self
+                .vacation_data
+                .vacations
+                .iter()
+                .flat_map(|v| [v.start_date.as_str(), v.end_date.as_str()])
+                .collect(),
+            ViewState::Holidays => self
+                .holiday_data
+                .holidays
+                .iter()
+                .map(|h| h.date.as_str())
+                .collect(),
+            _ => Vec::new(),
+        };
+        let years: BTreeSet<String> = dates
+            .iter()
+            .filter_map(|d| d.get(..4))
+            .filter(|y| y.chars().all(|c| c.is_ascii_digit()))
+            .map(String::from)
+            .collect();
+        years.into_iter().collect()
+    }
+
+    fn cycle_list_year(&mut self, forward: bool) {
+        self.list_year = cycle_year(&self.list_years(), self.list_year.as_deref(), forward);
+        self.list_cursor = 0;
+        self.list_offset = 0;
+    }
+
+    /// Suffix for a list view's title naming the year filter, if any.
+    fn list_year_label(&self) -> String {
+        self.list_year
+            .as_ref()
+            .map(|y| format!(" — {}", y))
+            .unwrap_or_default()
+    }
+
+    fn render_vacation_view(&mut self, f: &mut Frame, area: ratatui::layout::Rect) {
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
@@ -1323,28 +2699,40 @@ impl<'a> App<'a> {
             Cell::from("Start").style(Style::default().add_modifier(Modifier::BOLD)),
             Cell::from("End").style(Style::default().add_modifier(Modifier::BOLD)),
             Cell::from("Approved").style(Style::default().add_modifier(Modifier::BOLD)),
+            Cell::from("Day off").style(Style::default().add_modifier(Modifier::BOLD)),
         ]);
 
-        let rows: Vec<Row> = self
-            .vacation_data
-            .vacations
+        let overlaps = self.vacation_data.find_overlaps();
+        let shown = self.list_rows();
+        let rows: Vec<Row> = shown
             .iter()
-            .enumerate()
-            .map(|(i, v)| {
-                Row::new(vec![
+            .map(|&i| {
+                let v = &self.vacation_data.vacations[i];
+                let row = Row::new(vec![
                     Cell::from(format!("{}", i + 1)),
                     Cell::from(v.destination.clone()),
                     Cell::from(v.start_date.clone()),
                     Cell::from(v.end_date.clone()),
-                    Cell::from(if v.approved { "Yes" } else { "No" }),
-                ])
+                    Cell::from(if v.is_excused {
+                        "Excused"
+                    } else if v.approved {
+                        "Yes"
+                    } else {
+                        "No"
+                    }),
+                    Cell::from(if v.counts_as_off { "Yes" } else { "Working" }),
+                ]);
+                if overlaps.iter().any(|&(a, b)| a == i || b == i) {
+                    row.style(Style::default().fg(Color::Red))
+                } else {
+                    row
+                }
             })
             .collect();
 
-        let mut table_state = TableState::default();
-        if !self.vacation_data.vacations.is_empty() {
-            table_state.select(Some(self.list_cursor));
-        }
+        let len = shown.len();
+        let visible = list_visible_rows(chunks[0]);
+        let mut table_state = self.scroll_list(len, visible);
 
         let table = Table::new(
             rows,
@@ -1354,13 +2742,18 @@ impl<'a> App<'a> {
                 Constraint::Length(12),
                 Constraint::Length(12),
                 Constraint::Length(10),
+                Constraint::Length(9),
             ],
         )
         .header(header)
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .title(" Vacations  (a=add  Enter/e=edit  Del/x=delete  Esc=back) "),
+                .title(format!(
+                    " Vacations{}  (a=add  Enter/e=edit  w=working  Del/x=delete  [/]=year  Esc=back) ",
+                    self.list_year_label()
+                ))
+                .title_bottom(more_rows_indicator(self.list_offset, visible, len)),
         )
         .row_highlight_style(
             Style::default()
@@ -1400,7 +2793,13 @@ impl<'a> App<'a> {
                 };
                 form_lines.push(Line::from(format!("{}: {}", label, value)));
             }
-            form_lines.push(Line::from(""));
+            match self.form_date_warning(&[2, 3]) {
+                Some(warning) => form_lines.push(Line::from(Span::styled(
+                    warning,
+                    Style::default().fg(Color::Yellow),
+                ))),
+                None => form_lines.push(Line::from("")),
+            }
             form_lines.push(Line::from(Span::styled(
                 "Enter=confirm  Esc=cancel",
                 Style::default().fg(Color::DarkGray),
@@ -1408,62 +2807,140 @@ impl<'a> App<'a> {
             let p = Paragraph::new(form_lines).block(Block::default().borders(Borders::NONE));
             f.render_widget(p, bottom);
         } else {
-            let hints = Paragraph::new(vec![Line::from(Span::styled(
-                "↑↓=move  a=add  Enter/e=edit  Del/x=delete  Esc=back",
-                Style::default().fg(Color::DarkGray),
-            ))])
-            .block(Block::default().borders(Borders::NONE));
+            let selected = self
+                .selected_list_index()
+                .and_then(|i| self.vacation_data.vacations.get(i));
+            let mut hint_lines = match selected {
+                Some(v) if self.list_confirm_delete => {
+                    vec![delete_prompt_line(&v.destination)]
+                }
+                _ => vec![Line::from(Span::styled(
+                    "↑↓=move  a=add  Enter/e=edit  w=working trip  Del/x=delete  [/]=year  Esc=back",
+                    Style::default().fg(Color::DarkGray),
+                ))],
+            };
+            for (a, b) in &overlaps {
+                hint_lines.push(Line::from(Span::styled(
+                    format!("⚠ Vacations #{} and #{} overlap", a + 1, b + 1),
+                    Style::default().fg(Color::Red),
+                )));
+            }
+            let hints = Paragraph::new(hint_lines).block(Block::default().borders(Borders::NONE));
             f.render_widget(hints, bottom);
         }
     }
 
+    /// Warning for the list add/edit form when any filled-in date field (by
+    /// 1-based stage) parses but lies outside every period.
+    fn form_date_warning(&self, date_stages: &[u8]) -> Option<String> {
+        let outside = date_stages.iter().any(|&stage| {
+            let value = if stage < self.list_add_stage {
+                self.list_field_bufs
+                    .get(stage as usize - 1)
+                    .map(String::as_str)
+            } else if stage == self.list_add_stage {
+                Some(self.input_buffer.as_str())
+            } else {
+                None
+            };
+            value
+                .and_then(|v| NaiveDate::parse_from_str(v, "%Y-%m-%d").ok())
+                .is_some_and(|date| !date_in_any_period(&self.time_period_data, date))
+        });
+        outside.then(|| "⚠ Date is outside every configured period".to_string())
+    }
+
+    /// Handles the `y`/`n` answer to a pending list deletion. Returns true
+    /// when the deletion was confirmed.
+    fn answer_delete_prompt(&mut self, code: KeyCode) -> bool {
+        match code {
+            KeyCode::Char('y') | KeyCode::Char('Y') => {
+                self.list_confirm_delete = false;
+                true
+            }
+            KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                self.list_confirm_delete = false;
+                false
+            }
+            _ => false,
+        }
+    }
+
+    fn delete_selected_vacation(&mut self) {
+        let Some(idx) = self.selected_list_index() else {
+            return;
+        };
+        self.record_undo(Snapshot::Vacations(self.vacation_data.clone()));
+        self.vacation_data.vacations.remove(idx);
+        self.data_dirty = true;
+        let _ = self
+            .vacation_data
+            .save_to(&self.data_dir, self.settings.backup_count);
+        if self.list_cursor > 0 && self.list_cursor >= self.list_rows().len() {
+            self.list_cursor -= 1;
+        }
+    }
+
+    /// Flips whether the selected vacation takes its days off the period.
+    fn toggle_selected_counts_as_off(&mut self) {
+        let Some(idx) = self.selected_list_index() else {
+            return;
+        };
+        self.record_undo(Snapshot::Vacations(self.vacation_data.clone()));
+        let v = &mut self.vacation_data.vacations[idx];
+        v.counts_as_off = !v.counts_as_off;
+        self.data_dirty = true;
+        let _ = self
+            .vacation_data
+            .save_to(&self.data_dir, self.settings.backup_count);
+        self.update_stats();
+    }
+
     fn handle_vacation_key(&mut self, code: KeyCode) {
         use crate::data::vacation::Vacation;
+        if self.list_confirm_delete {
+            if self.answer_delete_prompt(code) {
+                self.delete_selected_vacation();
+            }
+            return;
+        }
         if self.list_add_stage == 0 {
             // ── Browse mode ───────────────────────────────────────────────────
             match code {
-                KeyCode::Up => {
-                    if self.list_cursor > 0 {
-                        self.list_cursor -= 1;
-                    }
+                KeyCode::Up if self.list_cursor > 0 => {
+                    self.list_cursor -= 1;
                 }
-                KeyCode::Down => {
-                    if self.list_cursor + 1 < self.vacation_data.vacations.len() {
-                        self.list_cursor += 1;
-                    }
+                KeyCode::Down if self.list_cursor + 1 < self.list_rows().len() => {
+                    self.list_cursor += 1;
                 }
+                KeyCode::Char('[') => self.cycle_list_year(false),
+                KeyCode::Char(']') => self.cycle_list_year(true),
                 KeyCode::Char('a') => {
                     self.list_edit_index = None;
                     self.list_field_bufs.clear();
                     self.input_buffer.clear();
                     self.list_add_stage = 1;
                 }
+                // Edit the selected vacation
                 KeyCode::Char('e') | KeyCode::Enter => {
-                    // Edit the selected vacation
-                    if !self.vacation_data.vacations.is_empty()
-                        && self.list_cursor < self.vacation_data.vacations.len()
-                    {
-                        let v = &self.vacation_data.vacations[self.list_cursor];
+                    if let Some(idx) = self.selected_list_index() {
+                        let v = &self.vacation_data.vacations[idx];
                         self.input_buffer = v.destination.clone();
                         self.list_field_bufs.clear();
-                        self.list_edit_index = Some(self.list_cursor);
+                        self.list_edit_index = Some(idx);
                         self.list_add_stage = 1;
                     }
                 }
-                KeyCode::Delete | KeyCode::Char('x') => {
-                    if !self.vacation_data.vacations.is_empty()
-                        && self.list_cursor < self.vacation_data.vacations.len()
-                    {
-                        self.vacation_data.vacations.remove(self.list_cursor);
-                        self.data_dirty = true;
-                        let _ = self.vacation_data.save_to(&self.data_dir);
-                        if self.list_cursor > 0
-                            && self.list_cursor >= self.vacation_data.vacations.len()
-                        {
-                            self.list_cursor -= 1;
-                        }
+                KeyCode::Delete | KeyCode::Char('x') if self.selected_list_index().is_some() => {
+                    if self.settings.quick_delete {
+                        self.delete_selected_vacation();
+                    } else {
+                        self.list_confirm_delete = true;
                     }
                 }
+                KeyCode::Char('w') => {
+                    self.toggle_selected_counts_as_off();
+                }
                 KeyCode::Esc | KeyCode::Char('q') => {
                     self.view_state = ViewState::Calendar;
                 }
@@ -1495,7 +2972,7 @@ impl<'a> App<'a> {
                             .get(3)
                             .map(|s| s.to_lowercase().starts_with('y'))
                             .unwrap_or(false);
-                        let v = Vacation::new(
+                        let mut v = Vacation::new(
                             self.list_field_bufs
                                 .first()
                                 .map(String::as_str)
@@ -1510,15 +2987,20 @@ impl<'a> App<'a> {
                                 .unwrap_or(""),
                             approved,
                         );
+                        self.record_undo(Snapshot::Vacations(self.vacation_data.clone()));
                         if let Some(idx) = self.list_edit_index {
                             if idx < self.vacation_data.vacations.len() {
+                                v.is_excused = self.vacation_data.vacations[idx].is_excused;
+                                v.counts_as_off = self.vacation_data.vacations[idx].counts_as_off;
                                 self.vacation_data.vacations[idx] = v;
                             }
                         } else {
                             self.vacation_data.add(v);
                         }
                         self.data_dirty = true;
-                        let _ = self.vacation_data.save_to(&self.data_dir);
+                        let _ = self
+                            .vacation_data
+                            .save_to(&self.data_dir, self.settings.backup_count);
                         self.list_add_stage = 0;
                         self.list_edit_index = None;
                         self.list_field_bufs.clear();
@@ -1562,7 +3044,7 @@ impl<'a> App<'a> {
 
     // ── Holiday View ──────────────────────────────────────────────────────────
 
-    fn render_holiday_view(&self, f: &mut Frame, area: ratatui::layout::Rect) {
+    fn render_holiday_view(&mut self, f: &mut Frame, area: ratatui::layout::Rect) {
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
@@ -1577,9 +3059,11 @@ impl<'a> App<'a> {
             Cell::from("Name").style(Style::default().add_modifier(Modifier::BOLD)),
         ]);
 
-        let rows: Vec<Row> = self
-            .holiday_data
-            .holidays
+        let shown = match &self.list_year {
+            Some(year) => filter_by_year(&self.holiday_data.holidays, year),
+            None => self.holiday_data.holidays.iter().collect(),
+        };
+        let rows: Vec<Row> = shown
             .iter()
             .enumerate()
             .map(|(i, h)| {
@@ -1591,10 +3075,9 @@ impl<'a> App<'a> {
             })
             .collect();
 
-        let mut table_state = TableState::default();
-        if !self.holiday_data.holidays.is_empty() {
-            table_state.select(Some(self.list_cursor));
-        }
+        let len = shown.len();
+        let visible = list_visible_rows(chunks[0]);
+        let mut table_state = self.scroll_list(len, visible);
 
         let table = Table::new(
             rows,
@@ -1608,7 +3091,11 @@ impl<'a> App<'a> {
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .title(" Holidays  (a=add  Enter/e=edit  Del/x=delete  Esc=back) "),
+                .title(format!(
+                    " Holidays{}  (a=add  Enter/e=edit  Del/x=delete  [/]=year  Esc=back) ",
+                    self.list_year_label()
+                ))
+                .title_bottom(more_rows_indicator(self.list_offset, visible, len)),
         )
         .row_highlight_style(
             Style::default()
@@ -1642,7 +3129,13 @@ impl<'a> App<'a> {
                 };
                 form_lines.push(Line::from(format!("{}: {}", label, value)));
             }
-            form_lines.push(Line::from(""));
+            match self.form_date_warning(&[1]) {
+                Some(warning) => form_lines.push(Line::from(Span::styled(
+                    warning,
+                    Style::default().fg(Color::Yellow),
+                ))),
+                None => form_lines.push(Line::from("")),
+            }
             form_lines.push(Line::from(Span::styled(
                 "Enter=confirm  Esc=cancel",
                 Style::default().fg(Color::DarkGray),
@@ -1650,60 +3143,76 @@ impl<'a> App<'a> {
             let p = Paragraph::new(form_lines).block(Block::default().borders(Borders::NONE));
             f.render_widget(p, bottom);
         } else {
-            let hints = Paragraph::new(vec![Line::from(Span::styled(
-                "↑↓=move  a=add  Enter/e=edit  Del/x=delete  Esc=back",
-                Style::default().fg(Color::DarkGray),
-            ))])
-            .block(Block::default().borders(Borders::NONE));
+            let selected = self
+                .selected_list_index()
+                .and_then(|i| self.holiday_data.holidays.get(i));
+            let hint = match selected {
+                Some(h) if self.list_confirm_delete => delete_prompt_line(&h.name),
+                _ => Line::from(Span::styled(
+                    "↑↓=move  a=add  Enter/e=edit  Del/x=delete  [/]=year  Esc=back",
+                    Style::default().fg(Color::DarkGray),
+                )),
+            };
+            let hints = Paragraph::new(vec![hint]).block(Block::default().borders(Borders::NONE));
             f.render_widget(hints, bottom);
         }
     }
 
+    fn delete_selected_holiday(&mut self) {
+        let Some(idx) = self.selected_list_index() else {
+            return;
+        };
+        self.record_undo(Snapshot::Holidays(self.holiday_data.clone()));
+        self.holiday_data.holidays.remove(idx);
+        self.data_dirty = true;
+        let _ = self
+            .holiday_data
+            .save_to(&self.data_dir, self.settings.backup_count);
+        if self.list_cursor > 0 && self.list_cursor >= self.list_rows().len() {
+            self.list_cursor -= 1;
+        }
+    }
+
     fn handle_holiday_key(&mut self, code: KeyCode) {
         use crate::data::holiday::Holiday;
+        if self.list_confirm_delete {
+            if self.answer_delete_prompt(code) {
+                self.delete_selected_holiday();
+            }
+            return;
+        }
         if self.list_add_stage == 0 {
             // ── Browse mode ───────────────────────────────────────────────────
             match code {
-                KeyCode::Up => {
-                    if self.list_cursor > 0 {
-                        self.list_cursor -= 1;
-                    }
+                KeyCode::Up if self.list_cursor > 0 => {
+                    self.list_cursor -= 1;
                 }
-                KeyCode::Down => {
-                    if self.list_cursor + 1 < self.holiday_data.holidays.len() {
-                        self.list_cursor += 1;
-                    }
+                KeyCode::Down if self.list_cursor + 1 < self.list_rows().len() => {
+                    self.list_cursor += 1;
                 }
+                KeyCode::Char('[') => self.cycle_list_year(false),
+                KeyCode::Char(']') => self.cycle_list_year(true),
                 KeyCode::Char('a') => {
                     self.list_edit_index = None;
                     self.list_field_bufs.clear();
                     self.input_buffer.clear();
                     self.list_add_stage = 1;
                 }
+                // Edit the selected holiday
                 KeyCode::Char('e') | KeyCode::Enter => {
-                    // Edit the selected holiday
-                    if !self.holiday_data.holidays.is_empty()
-                        && self.list_cursor < self.holiday_data.holidays.len()
-                    {
-                        let h = &self.holiday_data.holidays[self.list_cursor];
+                    if let Some(idx) = self.selected_list_index() {
+                        let h = &self.holiday_data.holidays[idx];
                         self.input_buffer = h.date.clone();
                         self.list_field_bufs.clear();
-                        self.list_edit_index = Some(self.list_cursor);
+                        self.list_edit_index = Some(idx);
                         self.list_add_stage = 1;
                     }
                 }
-                KeyCode::Delete | KeyCode::Char('x') => {
-                    if !self.holiday_data.holidays.is_empty()
-                        && self.list_cursor < self.holiday_data.holidays.len()
-                    {
-                        self.holiday_data.holidays.remove(self.list_cursor);
-                        self.data_dirty = true;
-                        let _ = self.holiday_data.save_to(&self.data_dir);
-                        if self.list_cursor > 0
-                            && self.list_cursor >= self.holiday_data.holidays.len()
-                        {
-                            self.list_cursor -= 1;
-                        }
+                KeyCode::Delete | KeyCode::Char('x') if self.selected_list_index().is_some() => {
+                    if self.settings.quick_delete {
+                        self.delete_selected_holiday();
+                    } else {
+                        self.list_confirm_delete = true;
                     }
                 }
                 KeyCode::Esc | KeyCode::Char('q') => {
@@ -1742,6 +3251,7 @@ impl<'a> App<'a> {
                                 .map(String::as_str)
                                 .unwrap_or(""),
                         );
+                        self.record_undo(Snapshot::Holidays(self.holiday_data.clone()));
                         if let Some(idx) = self.list_edit_index {
                             if idx < self.holiday_data.holidays.len() {
                                 self.holiday_data.holidays[idx] = h;
@@ -1750,7 +3260,9 @@ impl<'a> App<'a> {
                             self.holiday_data.add(h);
                         }
                         self.data_dirty = true;
-                        let _ = self.holiday_data.save_to(&self.data_dir);
+                        let _ = self
+                            .holiday_data
+                            .save_to(&self.data_dir, self.settings.backup_count);
                         self.list_add_stage = 0;
                         self.list_edit_index = None;
                         self.list_field_bufs.clear();
@@ -1780,66 +3292,350 @@ impl<'a> App<'a> {
         }
     }
 
-    // ── Settings View ─────────────────────────────────────────────────────────
+    // ── Time Period View ──────────────────────────────────────────────────────
 
-    fn render_settings_view(&self, f: &mut Frame, area: ratatui::layout::Rect) {
+    fn render_time_period_view(&mut self, f: &mut Frame, area: ratatui::layout::Rect) {
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
-                Constraint::Min(5),    // settings table
-                Constraint::Length(3), // hints
+                Constraint::Min(5),    // period list table
+                Constraint::Length(8), // add form or key hints
             ])
             .split(area);
 
         let header = Row::new(vec![
-            Cell::from("Setting").style(Style::default().add_modifier(Modifier::BOLD)),
-            Cell::from("Value").style(Style::default().add_modifier(Modifier::BOLD)),
+            Cell::from("#").style(Style::default().add_modifier(Modifier::BOLD)),
+            Cell::from("Key").style(Style::default().add_modifier(Modifier::BOLD)),
+            Cell::from("Name").style(Style::default().add_modifier(Modifier::BOLD)),
+            Cell::from("Start").style(Style::default().add_modifier(Modifier::BOLD)),
+            Cell::from("End").style(Style::default().add_modifier(Modifier::BOLD)),
         ]);
 
-        let fields = [
-            ("Default Office", self.settings.default_office.as_str()),
-            ("Flex Credit Label", self.settings.flex_credit.as_str()),
-        ];
-
-        let rows: Vec<Row> = fields
+        let rows: Vec<Row> = self
+            .time_period_data
+            .periods()
             .iter()
             .enumerate()
-            .map(|(i, (label, current_val))| {
-                let value = if self.list_add_stage == 1 && self.list_cursor == i {
-                    format!("{}_", self.input_buffer)
-                } else {
-                    current_val.to_string()
-                };
-                Row::new(vec![Cell::from(format!("  {}", label)), Cell::from(value)])
+            .map(|(i, tp)| {
+                Row::new(vec![
+                    Cell::from(format!("{}", i + 1)),
+                    Cell::from(tp.key.clone()),
+                    Cell::from(tp.name.clone()),
+                    Cell::from(tp.start_date_raw.clone()),
+                    Cell::from(tp.end_date_raw.clone()),
+                ])
             })
             .collect();
 
-        let mut table_state = TableState::default();
-        table_state.select(Some(self.list_cursor));
+        let len = self.time_period_data.len();
+        let visible = list_visible_rows(chunks[0]);
+        let mut table_state = self.scroll_list(len, visible);
 
-        let table = Table::new(rows, [Constraint::Length(22), Constraint::Min(30)])
-            .header(header)
-            .block(
-                Block::default()
-                    .borders(Borders::ALL)
-                    .title(" Settings  (↑↓=select  Enter/e=edit  Esc=back) "),
-            )
-            .row_highlight_style(
-                Style::default()
-                    .fg(Color::Yellow)
-                    .bg(Color::DarkGray)
-                    .add_modifier(Modifier::BOLD),
-            );
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(3),
+                Constraint::Length(16),
+                Constraint::Length(16),
+                Constraint::Length(12),
+                Constraint::Length(12),
+            ],
+        )
+        .header(header)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!(
+                    " Time Periods: {}  (a=add  Enter/e=edit  Del/x=delete  Esc=back) ",
+                    self.time_period_data.filename()
+                ))
+                .title_bottom(more_rows_indicator(self.list_offset, visible, len)),
+        )
+        .row_highlight_style(
+            Style::default()
+                .fg(Color::Yellow)
+                .bg(Color::DarkGray)
+                .add_modifier(Modifier::BOLD),
+        );
 
         f.render_stateful_widget(table, chunks[0], &mut table_state);
 
-        let hint_text = if self.list_add_stage == 1 {
-            "Type new value  Enter=save  Esc=cancel"
-        } else {
-            "↑↓=select  Enter/e=edit  Esc=back to calendar"
-        };
-        let hints = Paragraph::new(Line::from(Span::styled(
-            hint_text,
+        let bottom = chunks[1];
+        if self.list_add_stage > 0 {
+            let labels = [
+                "Key (e.g. Q1_2027)",
+                "Name",
+                "Start date (YYYY-MM-DD)",
+                "End date (YYYY-MM-DD)",
+            ];
+            let form_title = if self.list_edit_index.is_some() {
+                "── Edit Time Period ─────────────────────────────"
+            } else {
+                "── Add Time Period ──────────────────────────────"
+            };
+            let mut form_lines: Vec<Line> = vec![Line::from(Span::styled(
+                form_title,
+                Style::default().add_modifier(Modifier::BOLD),
+            ))];
+            for (i, label) in labels.iter().enumerate() {
+                let field_num = (i + 1) as u8;
+                let value = if field_num < self.list_add_stage {
+                    self.list_field_bufs.get(i).cloned().unwrap_or_default()
+                } else if field_num == self.list_add_stage {
+                    format!("{}_", self.input_buffer)
+                } else {
+                    String::new()
+                };
+                form_lines.push(Line::from(format!("{}: {}", label, value)));
+            }
+            form_lines.push(Line::from(""));
+            form_lines.push(Line::from(Span::styled(
+                "Enter=confirm  Esc=cancel",
+                Style::default().fg(Color::DarkGray),
+            )));
+            let p = Paragraph::new(form_lines).block(Block::default().borders(Borders::NONE));
+            f.render_widget(p, bottom);
+        } else {
+            let hint = match self.time_period_data.periods().get(self.list_cursor) {
+                Some(tp) if self.list_confirm_delete => delete_prompt_line(&tp.key),
+                _ => Line::from(Span::styled(
+                    "↑↓=move  a=add  Enter/e=edit  Del/x=delete  Esc=back",
+                    Style::default().fg(Color::DarkGray),
+                )),
+            };
+            let hints = Paragraph::new(vec![hint]).block(Block::default().borders(Borders::NONE));
+            f.render_widget(hints, bottom);
+        }
+    }
+
+    fn delete_selected_time_period(&mut self) {
+        if self.list_cursor >= self.time_period_data.len() {
+            return;
+        }
+        self.record_undo(Snapshot::TimePeriods(self.time_period_data.clone()));
+        self.time_period_data.remove(self.list_cursor);
+        self.data_dirty = true;
+        let _ = self
+            .time_period_data
+            .save_to(&self.data_dir, self.settings.backup_count);
+        if self.list_cursor > 0 && self.list_cursor >= self.time_period_data.len() {
+            self.list_cursor -= 1;
+        }
+        self.update_stats();
+    }
+
+    /// Checks the field being confirmed in the time period form, returning
+    /// the message to show in its place when it is rejected.
+    fn time_period_field_error(&self) -> Option<&'static str> {
+        let value = self.input_buffer.trim();
+        match self.list_add_stage {
+            1 if value.is_empty() => Some("Key is required"),
+            1 if self
+                .time_period_data
+                .periods()
+                .iter()
+                .enumerate()
+                .any(|(i, tp)| tp.key == value && Some(i) != self.list_edit_index) =>
+            {
+                Some("Key already exists")
+            }
+            3 | 4 if NaiveDate::parse_from_str(value, "%Y-%m-%d").is_err() => {
+                Some("Invalid date — use YYYY-MM-DD")
+            }
+            4 if self
+                .list_field_bufs
+                .get(2)
+                .is_some_and(|start| start.as_str() > value) =>
+            {
+                Some("End date is before the start date")
+            }
+            _ => None,
+        }
+    }
+
+    fn handle_time_period_key(&mut self, code: KeyCode) {
+        if self.list_confirm_delete {
+            if self.answer_delete_prompt(code) {
+                self.delete_selected_time_period();
+            }
+            return;
+        }
+        if self.list_add_stage == 0 {
+            // ── Browse mode ───────────────────────────────────────────────────
+            match code {
+                KeyCode::Up if self.list_cursor > 0 => {
+                    self.list_cursor -= 1;
+                }
+                KeyCode::Down if self.list_cursor + 1 < self.time_period_data.len() => {
+                    self.list_cursor += 1;
+                }
+                KeyCode::Char('a') => {
+                    self.list_edit_index = None;
+                    self.list_field_bufs.clear();
+                    self.input_buffer.clear();
+                    self.list_add_stage = 1;
+                }
+                // Edit the selected period
+                KeyCode::Char('e') | KeyCode::Enter
+                    if self.list_cursor < self.time_period_data.len() =>
+                {
+                    self.input_buffer = self.time_period_data.periods()[self.list_cursor]
+                        .key
+                        .clone();
+                    self.list_field_bufs.clear();
+                    self.list_edit_index = Some(self.list_cursor);
+                    self.list_add_stage = 1;
+                }
+                KeyCode::Delete | KeyCode::Char('x')
+                    if self.list_cursor < self.time_period_data.len() =>
+                {
+                    if self.settings.quick_delete {
+                        self.delete_selected_time_period();
+                    } else {
+                        self.list_confirm_delete = true;
+                    }
+                }
+                KeyCode::Esc | KeyCode::Char('q') => {
+                    self.view_state = ViewState::Calendar;
+                }
+                _ => {}
+            }
+        } else {
+            // ── Add/Edit mode: field entry ─────────────────────────────────────
+            match code {
+                KeyCode::Char(c) => {
+                    self.input_buffer.push(c);
+                }
+                KeyCode::Backspace => {
+                    self.input_buffer.pop();
+                }
+                KeyCode::Enter => {
+                    if let Some(err) = self.time_period_field_error() {
+                        self.input_buffer = err.to_string();
+                        return;
+                    }
+                    self.list_field_bufs
+                        .push(self.input_buffer.trim().to_string());
+
+                    if self.list_add_stage == 4 {
+                        // field_bufs: [0]=key, [1]=name, [2]=start, [3]=end.
+                        // Editing keeps the period's other fields.
+                        let mut tp = self
+                            .list_edit_index
+                            .and_then(|idx| self.time_period_data.periods().get(idx).cloned())
+                            .unwrap_or_default();
+                        tp.key = self.list_field_bufs[0].clone();
+                        tp.name = self.list_field_bufs[1].clone();
+                        tp.start_date_raw = self.list_field_bufs[2].clone();
+                        tp.end_date_raw = self.list_field_bufs[3].clone();
+                        if tp.parse_dates().is_err() {
+                            self.list_field_bufs.pop();
+                            self.input_buffer = "Invalid date — use YYYY-MM-DD".to_string();
+                            return;
+                        }
+                        self.record_undo(Snapshot::TimePeriods(self.time_period_data.clone()));
+                        match self.list_edit_index {
+                            Some(idx) => self.time_period_data.replace(idx, tp),
+                            None => self.time_period_data.add(tp),
+                        }
+                        self.data_dirty = true;
+                        let _ = self
+                            .time_period_data
+                            .save_to(&self.data_dir, self.settings.backup_count);
+                        self.list_add_stage = 0;
+                        self.list_edit_index = None;
+                        self.list_field_bufs.clear();
+                        self.input_buffer.clear();
+                        self.update_stats();
+                    } else {
+                        // Advance to next field, pre-fill from existing data when editing
+                        let next_stage = self.list_add_stage + 1;
+                        self.input_buffer = match self
+                            .list_edit_index
+                            .and_then(|idx| self.time_period_data.periods().get(idx))
+                        {
+                            Some(tp) => match next_stage {
+                                2 => tp.name.clone(),
+                                3 => tp.start_date_raw.clone(),
+                                4 => tp.end_date_raw.clone(),
+                                _ => String::new(),
+                            },
+                            None => String::new(),
+                        };
+                        self.list_add_stage = next_stage;
+                    }
+                }
+                KeyCode::Esc => {
+                    self.list_add_stage = 0;
+                    self.list_edit_index = None;
+                    self.input_buffer.clear();
+                    self.list_field_bufs.clear();
+                }
+                _ => {}
+            }
+        }
+    }
+
+    // ── Settings View ─────────────────────────────────────────────────────────
+
+    fn render_settings_view(&self, f: &mut Frame, area: ratatui::layout::Rect) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Min(5),    // settings table
+                Constraint::Length(3), // hints
+            ])
+            .split(area);
+
+        let header = Row::new(vec![
+            Cell::from("Setting").style(Style::default().add_modifier(Modifier::BOLD)),
+            Cell::from("Value").style(Style::default().add_modifier(Modifier::BOLD)),
+        ]);
+
+        let fields = [
+            ("Default Office", self.settings.default_office.as_str()),
+            ("Flex Credit Label", self.settings.flex_credit.as_str()),
+        ];
+
+        let rows: Vec<Row> = fields
+            .iter()
+            .enumerate()
+            .map(|(i, (label, current_val))| {
+                let value = if self.list_add_stage == 1 && self.list_cursor == i {
+                    format!("{}_", self.input_buffer)
+                } else {
+                    current_val.to_string()
+                };
+                Row::new(vec![Cell::from(format!("  {}", label)), Cell::from(value)])
+            })
+            .collect();
+
+        let mut table_state = TableState::default();
+        table_state.select(Some(self.list_cursor));
+
+        let table = Table::new(rows, [Constraint::Length(22), Constraint::Min(30)])
+            .header(header)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(" Settings  (↑↓=select  Enter/e=edit  Esc=back) "),
+            )
+            .row_highlight_style(
+                Style::default()
+                    .fg(Color::Yellow)
+                    .bg(Color::DarkGray)
+                    .add_modifier(Modifier::BOLD),
+            );
+
+        f.render_stateful_widget(table, chunks[0], &mut table_state);
+
+        let hint_text = if self.list_add_stage == 1 {
+            "Type new value  Enter=save  Esc=cancel"
+        } else {
+            "↑↓=select  Enter/e=edit  Esc=back to calendar"
+        };
+        let hints = Paragraph::new(Line::from(Span::styled(
+            hint_text,
             Style::default().fg(Color::DarkGray),
         )));
         f.render_widget(hints, chunks[1]);
@@ -1849,15 +3645,11 @@ impl<'a> App<'a> {
         if self.list_add_stage == 0 {
             // ── Browse mode ───────────────────────────────────────────────────
             match code {
-                KeyCode::Up => {
-                    if self.list_cursor > 0 {
-                        self.list_cursor -= 1;
-                    }
+                KeyCode::Up if self.list_cursor > 0 => {
+                    self.list_cursor -= 1;
                 }
-                KeyCode::Down => {
-                    if self.list_cursor < 1 {
-                        self.list_cursor += 1;
-                    }
+                KeyCode::Down if self.list_cursor < 1 => {
+                    self.list_cursor += 1;
                 }
                 KeyCode::Enter | KeyCode::Char('e') => {
                     // Pre-fill input_buffer with current value
@@ -1900,22 +3692,225 @@ impl<'a> App<'a> {
             }
         }
     }
+
+    // ── Compare View ──────────────────────────────────────────────────────────
+
+    /// Stats for the current period computed against an arbitrary badge dataset.
+    fn stats_for_badges(&self, badges: &BadgeEntryData) -> Option<QuarterStats> {
+        let q = self.current_period()?;
+        calculate_quarter_stats(
+            q,
+            badges,
+            self.holiday_data,
+            self.vacation_data,
+            &StatsOptions::from(&self.settings),
+            Some(self.today),
+        )
+        .ok()
+    }
+
+    /// Column label: index 0 is the live badge data, the rest are saved scenarios.
+    fn compare_label(&self, idx: usize) -> String {
+        match idx {
+            0 => "Current".to_string(),
+            i => self.compare_names.get(i - 1).cloned().unwrap_or_default(),
+        }
+    }
+
+    fn enter_compare_view(&mut self) {
+        self.compare_names = scenario::list_scenarios(&self.data_dir).unwrap_or_default();
+        self.compare_sel = [0, usize::from(!self.compare_names.is_empty())];
+        self.view_state = ViewState::Compare;
+        self.update_comparison();
+    }
+
+    fn update_comparison(&mut self) {
+        for col in 0..2 {
+            self.compare_stats[col] = match self.compare_sel[col] {
+                0 => self.stats_for_badges(self.badge_data),
+                i => scenario::load_scenario(&self.data_dir, &self.compare_names[i - 1])
+                    .ok()
+                    .and_then(|badges| self.stats_for_badges(&badges)),
+            };
+        }
+    }
+
+    fn render_compare_view(&self, f: &mut Frame, area: ratatui::layout::Rect) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Min(5),    // comparison table
+                Constraint::Length(3), // hints
+            ])
+            .split(area);
+
+        let bold = Style::default().add_modifier(Modifier::BOLD);
+        let header = Row::new(vec![
+            Cell::from("Metric").style(bold),
+            Cell::from(self.compare_label(self.compare_sel[0])).style(bold),
+            Cell::from(self.compare_label(self.compare_sel[1])).style(bold),
+        ]);
+
+        let rows: Vec<Row> = compare_rows(&self.compare_stats)
+            .into_iter()
+            .map(|(label, values)| {
+                let cells: Vec<Cell> = values
+                    .into_iter()
+                    .map(|v| {
+                        let style = if label == "Status" {
+                            compliance_style(&v)
+                        } else {
+                            Style::default()
+                        };
+                        Cell::from(v).style(style)
+                    })
+                    .collect();
+                Row::new(vec![
+                    Cell::from(format!("  {}", label)),
+                    cells[0].clone(),
+                    cells[1].clone(),
+                ])
+            })
+            .collect();
+
+        let quarter_key = self
+            .current_period()
+            .map(|q| q.key.as_str())
+            .unwrap_or("N/A");
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(24),
+                Constraint::Length(22),
+                Constraint::Length(22),
+            ],
+        )
+        .header(header)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!(" Compare Scenarios: {} ", quarter_key)),
+        );
+        f.render_widget(table, chunks[0]);
+
+        let hint_text = if self.compare_names.is_empty() {
+            "No saved scenarios in scenarios/  Esc=back to calendar"
+        } else {
+            "←→=left column  ↑↓=right column  Esc=back to calendar"
+        };
+        let hints = Paragraph::new(Line::from(Span::styled(
+            hint_text,
+            Style::default().fg(Color::DarkGray),
+        )));
+        f.render_widget(hints, chunks[1]);
+    }
+
+    fn handle_compare_key(&mut self, code: KeyCode) {
+        let n = self.compare_names.len() + 1;
+        match code {
+            KeyCode::Left => {
+                self.compare_sel[0] = (self.compare_sel[0] + n - 1) % n;
+                self.update_comparison();
+            }
+            KeyCode::Right => {
+                self.compare_sel[0] = (self.compare_sel[0] + 1) % n;
+                self.update_comparison();
+            }
+            KeyCode::Up => {
+                self.compare_sel[1] = (self.compare_sel[1] + n - 1) % n;
+                self.update_comparison();
+            }
+            KeyCode::Down => {
+                self.compare_sel[1] = (self.compare_sel[1] + 1) % n;
+                self.update_comparison();
+            }
+            KeyCode::Esc | KeyCode::Char('q') => {
+                self.view_state = ViewState::Calendar;
+            }
+            _ => {}
+        }
+    }
 }
 
+/// Every key binding, grouped by view, for the `?` overlay.
+const HELP_SECTIONS: &[(&str, &[(&str, &str)])] = &[
+    (
+        "Calendar",
+        &[
+            ("←→↑↓", "Move by day or week"),
+            ("Space, Shift+←→", "Switch time period view"),
+            ("n / p", "Next / previous period"),
+            ("t / Home", "Go to today"),
+            ("J / :", "Jump to a date"),
+            ("b / f", "Toggle office badge / flex credit"),
+            ("B / Shift+Space", "Badge the whole week"),
+            ("N", "Note on the day's badge entry"),
+            ("x", "Excused/sick day"),
+            ("a", "Add event (Tab repeats it)"),
+            ("d", "Delete or edit events"),
+            ("s", "Search events"),
+            ("w", "What-if mode"),
+            ("S / L", "Save what-if as a scenario / load one"),
+            ("P", "Plan days to go in"),
+            ("D / W", "Full stats / week breakdown"),
+            (
+                "PgUp / PgDn",
+                "Scroll period stats (Ctrl+Home/End: top/end)",
+            ),
+            ("u / Ctrl+R", "Undo / redo"),
+            ("R", "Reload files from disk"),
+            ("g", "Git backup"),
+            (
+                "v h T o c",
+                "Vacations, holidays, periods, settings, compare",
+            ),
+            ("q / Ctrl+C", "Quit / force quit"),
+        ],
+    ),
+    (
+        "Vacations, Holidays, Time Periods",
+        &[
+            ("↑ / ↓", "Select an entry"),
+            ("a / e, Enter", "Add / edit an entry"),
+            ("x, Delete", "Delete the selected entry"),
+            ("w", "Vacations: day off or working trip"),
+            ("[ / ]", "Vacations and holidays: filter by year"),
+            ("Esc / q", "Back to the calendar"),
+        ],
+    ),
+    (
+        "Settings",
+        &[
+            ("↑ / ↓", "Select a setting"),
+            ("e, Enter", "Edit the selected value"),
+            ("Esc / q", "Cancel editing / back to the calendar"),
+        ],
+    ),
+    (
+        "Compare",
+        &[
+            ("← / →", "Choose the left scenario"),
+            ("↑ / ↓", "Choose the right scenario"),
+            ("Esc / q", "Back to the calendar"),
+        ],
+    ),
+    ("Anywhere", &[("?", "Show or hide this help")]),
+];
+
 // ── Row construction helpers ──────────────────────────────────────────────────
 
-/// A section header row with a dark background and bold text.
-fn section_header(title: &str) -> Row<'static> {
+/// A section header row with the theme's section background and bold text.
+fn section_header(title: &str, theme: &Theme) -> Row<'static> {
     Row::new(vec![
         Cell::from(title.to_string()).style(
             Style::default()
-                .fg(Color::White)
+                .fg(theme.section_fg)
                 .add_modifier(Modifier::BOLD),
         ),
         Cell::from(""),
         Cell::from(""),
     ])
-    .style(Style::default().bg(SECTION_BG))
+    .style(Style::default().bg(theme.section_bg))
 }
 
 /// An empty spacer row for visual breathing room between sections.
@@ -1928,26 +3923,339 @@ fn data_row(metric: impl Into<String>, value: Cell<'static>, pct: Cell<'static>)
     Row::new(vec![Cell::from(format!("  {}", metric.into())), value, pct])
 }
 
-/// Plain (unstyled) cell.
-fn plain(s: impl Into<String>) -> Cell<'static> {
-    Cell::from(s.into())
+/// Label for the badge-in days row, noting office badges that don't count
+/// because the office isn't one of the `qualifying_offices`.
+fn badge_in_label(stats: &QuarterStats) -> String {
+    if stats.non_qualifying_days > 0.0 {
+        format!(
+            " Badge-In Days ({} not qualifying)",
+            stats.non_qualifying_days
+        )
+    } else {
+        " Badge-In Days".to_string()
+    }
+}
+
+/// Label for the flex credits row: the label stored on the period's flex
+/// entries (all of them, if they differ), noting any credits over the cap.
+fn flex_credits_label(stats: &QuarterStats) -> String {
+    let name = match stats.flex_labels.as_slice() {
+        [label] => label.clone(),
+        [] => "Flex Credits".to_string(),
+        labels => labels.join(" / "),
+    };
+    if stats.excess_flex_days > 0.0 {
+        format!(" {} ({} excess)", name, stats.excess_flex_days)
+    } else {
+        format!(" {}", name)
+    }
+}
+
+/// Plain (unstyled) cell.
+fn plain(s: impl Into<String>) -> Cell<'static> {
+    Cell::from(s.into())
+}
+
+/// Data rows that fit in a bordered list table with a one-line header.
+fn list_visible_rows(area: ratatui::layout::Rect) -> usize {
+    area.height.saturating_sub(3) as usize
+}
+
+/// Returns the first visible row so that `selected` lies within
+/// `[offset, offset + visible)`, moving as little as possible from `offset`.
+fn scroll_offset(offset: usize, selected: usize, visible: usize, len: usize) -> usize {
+    if visible == 0 || len == 0 {
+        return 0;
+    }
+    let offset = offset.min(len.saturating_sub(visible));
+    if selected < offset {
+        selected
+    } else if selected >= offset + visible {
+        selected + 1 - visible
+    } else {
+        offset
+    }
+}
+
+/// "▲ N more" / "▼ N more" hint for rows scrolled out of a list viewport.
+fn more_rows_indicator(offset: usize, visible: usize, len: usize) -> Line<'static> {
+    let below = len.saturating_sub(offset + visible);
+    let mut parts = Vec::new();
+    if offset > 0 {
+        parts.push(format!("▲ {} more", offset));
+    }
+    if below > 0 {
+        parts.push(format!("▼ {} more", below));
+    }
+    if parts.is_empty() {
+        return Line::default();
+    }
+    Line::from(Span::styled(
+        format!(" {} ", parts.join("  ")),
+        Style::default().fg(Color::DarkGray),
+    ))
+}
+
+/// Share of the required office days already badged, clamped to 0–1. A
+/// period with nothing required counts as complete.
+pub(crate) fn goal_progress(stats: &QuarterStats) -> f64 {
+    if stats.days_required <= 0 {
+        return 1.0;
+    }
+    (stats.days_badged_in / stats.days_required as f64).clamp(0.0, 1.0)
+}
+
+/// Cumulative badge-ins and the ideal pace, one point per available workday
+/// (holidays and vacation days skipped) in date order. The pace series spans
+/// the whole period and ends at `days_required`; the actual series covers the
+/// days worked so far, plus the next day once it is badged.
+pub(crate) fn cumulative_series(stats: &QuarterStats) -> (Vec<u64>, Vec<u64>) {
+    let mut days: Vec<&Workday> = stats
+        .workday_stats
+        .values()
+        .filter(|w| !w.is_holiday && !w.is_vacation)
+        .collect();
+    days.sort_by_key(|w| w.date);
+
+    let n = days.len();
+    let pace = (1..=n)
+        .map(|i| (stats.days_required as f64 * i as f64 / n as f64).round() as u64)
+        .collect();
+
+    let past = (stats.days_thus_far.max(0) as usize).min(n);
+    let shown = if days.get(past).is_some_and(|w| w.is_badged_in) {
+        past + 1
+    } else {
+        past
+    };
+    let mut total = 0.0f64;
+    let actual = days[..shown]
+        .iter()
+        .map(|w| {
+            if w.is_badged_in {
+                total += w.credit;
+            }
+            total.round() as u64
+        })
+        .collect();
+    (actual, pace)
+}
+
+/// Color for a compliance status string.
+fn compliance_style(status: &str) -> Style {
+    match status {
+        "Achieved" => Style::default()
+            .fg(Color::Indexed(46))
+            .add_modifier(Modifier::BOLD),
+        "On Track" => Style::default().fg(Color::Indexed(40)),
+        "At Risk" => Style::default().fg(Color::Indexed(208)),
+        "Impossible" => Style::default()
+            .fg(Color::Indexed(196))
+            .add_modifier(Modifier::BOLD),
+        _ => Style::default(),
+    }
+}
+
+/// The rate still needed on the remaining workdays, colored by how hard it
+/// is to reach: green below 50%, yellow to 70%, orange above that, and red
+/// "Unreachable" once more days are needed than remain.
+pub(crate) fn rate_needed_style(stats: &QuarterStats) -> (String, Color) {
+    if stats.days_still_needed > stats.days_left.max(0) {
+        return ("Unreachable".to_string(), Color::Indexed(196));
+    }
+    let rate = stats.required_future_average;
+    let color = if rate > 0.7 {
+        Color::Indexed(208)
+    } else if rate > 0.5 {
+        Color::Indexed(220)
+    } else {
+        Color::Indexed(40)
+    };
+    (format!("{:.1}%", rate * 100.0), color)
+}
+
+/// Key metrics for two scenarios side by side. Missing stats render as "—".
+pub(crate) fn compare_rows(stats: &[Option<QuarterStats>; 2]) -> Vec<(&'static str, [String; 2])> {
+    type Metric = fn(&QuarterStats) -> String;
+    let metrics: [(&'static str, Metric); 6] = [
+        ("Status", |s| s.compliance_status.clone()),
+        ("Badged / Required", |s| {
+            format!("{} / {}", s.days_badged_in, s.days_required)
+        }),
+        ("Still Needed", |s| format!("{}", s.days_still_needed)),
+        ("Rate Needed", |s| rate_needed_style(s).0),
+        ("Days Ahead of Pace", |s| {
+            format!("{:+}", s.days_ahead_of_pace)
+        }),
+        ("Skippable Days", |s| {
+            format!("{}", s.remaining_missable_days)
+        }),
+    ];
+    metrics
+        .iter()
+        .map(|(label, f)| {
+            let value = |col: &Option<QuarterStats>| col.as_ref().map(f).unwrap_or("—".into());
+            (*label, [value(&stats[0]), value(&stats[1])])
+        })
+        .collect()
+}
+
+/// Git-adds, commits, and (when an `origin` remote exists) pushes `dir`.
+/// Returns the footer message and its color. Never panics.
+fn run_git_backup(dir: &str) -> (String, Color) {
+    // 1. Confirm it's a git repo
+    let is_repo = Command::new("git")
+        .args(["-C", dir, "rev-parse", "--is-inside-work-tree"])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .map(|s| s.success())
+        .unwrap_or(false);
+
+    if !is_repo {
+        return (
+            format!("'{}' is not a git repo — backup skipped", dir),
+            Color::DarkGray,
+        );
+    }
+
+    // 2. git add .
+    let add_ok = Command::new("git")
+        .args(["-C", dir, "add", "."])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .map(|s| s.success())
+        .unwrap_or(false);
+
+    if !add_ok {
+        return ("git add failed".to_string(), Color::Red);
+    }
+
+    // 3. git commit with a unique timestamp
+    let now = Local::now();
+    let timestamp = now.format("%Y-%m-%d-%H-%M-%S-%3f").to_string();
+    let msg = format!("backup {}", timestamp);
+
+    let commit_out = Command::new("git")
+        .args(["-C", dir, "commit", "-m", &msg])
+        .output();
+
+    match &commit_out {
+        Err(e) => {
+            return (format!("git commit error: {}", e), Color::Red);
+        }
+        Ok(out) => {
+            let stdout = String::from_utf8_lossy(&out.stdout);
+            let stderr = String::from_utf8_lossy(&out.stderr);
+            if stdout.contains("nothing to commit")
+                || stderr.contains("nothing to commit")
+                || stdout.contains("nothing added")
+            {
+                return (
+                    "Nothing to commit — already up to date".to_string(),
+                    Color::Yellow,
+                );
+            }
+            if !out.status.success() {
+                let detail = stdout.trim().to_string();
+                return (format!("git commit failed: {}", detail), Color::Red);
+            }
+        }
+    }
+
+    // 4. Check for a remote named "origin"
+    let has_remote = Command::new("git")
+        .args(["-C", dir, "remote", "get-url", "origin"])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .map(|s| s.success())
+        .unwrap_or(false);
+
+    if !has_remote {
+        return (format!("Backed up locally — {}", timestamp), Color::Cyan);
+    }
+
+    // 5. git push
+    let push_ok = Command::new("git")
+        .args(["-C", dir, "push"])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .map(|s| s.success())
+        .unwrap_or(false);
+
+    if push_ok {
+        (format!("Backed up & pushed — {}", timestamp), Color::Green)
+    } else {
+        (
+            format!("Committed locally (push failed) — {}", timestamp),
+            Color::Yellow,
+        )
+    }
+}
+
+/// The footer message once a backup's result is in, or `None` while it is
+/// still running. A worker that died without reporting counts as a failure.
+pub(crate) fn backup_update(
+    received: Result<(String, Color), TryRecvError>,
+) -> Option<(String, Color)> {
+    match received {
+        Ok(status) => Some(status),
+        Err(TryRecvError::Empty) => None,
+        Err(TryRecvError::Disconnected) => Some((
+            "Backup failed — the git worker stopped unexpectedly".to_string(),
+            Color::Red,
+        )),
+    }
 }
 
 // ── App event loop ────────────────────────────────────────────────────────────
 
 pub fn run_app(terminal: &mut Terminal<CrosstermBackend<Stdout>>, app: &mut App) -> Result<()> {
+    let mut redraw = true;
     loop {
-        terminal.draw(|f| app.render(f))?;
-        if event::poll(StdDuration::from_millis(16))?
-            && let CEvent::Key(key) = event::read()?
+        if redraw {
+            terminal.draw(|f| app.render(f))?;
+        }
+        let poll = StdDuration::from_millis(app.settings.poll_ms.max(1));
+        let event = if event::poll(poll)? {
+            Some(event::read()?)
+        } else {
+            None
+        };
+        if let Some(CEvent::Key(key)) = &event
             && app.handle_key(key.code, key.modifiers)
         {
             break;
         }
+        let rolled_over = app.roll_over_to(today_local());
+        // A running backup redraws every poll to animate its spinner
+        let backing_up = app.poll_backup();
+        redraw = needs_redraw(event.as_ref(), rolled_over) || backing_up;
+    }
+    if app.backup.is_some() {
+        app.git_status = Some((
+            "Finishing backup before quitting…".to_string(),
+            Color::Yellow,
+        ));
+        terminal.draw(|f| app.render(f))?;
+        app.wait_for_backup();
     }
     Ok(())
 }
 
+/// Whether the screen must be redrawn after a poll: on a key press, a resize
+/// or paste, or a change of date. An idle poll skips the redraw.
+pub(crate) fn needs_redraw(event: Option<&CEvent>, date_rolled_over: bool) -> bool {
+    date_rolled_over
+        || matches!(
+            event,
+            Some(CEvent::Key(_) | CEvent::Resize(..) | CEvent::Paste(_))
+        )
+}
+
 // ── Calendar helpers ──────────────────────────────────────────────────────────
 
 pub(crate) fn month_name(month: u32) -> &'static str {
@@ -1989,32 +4297,59 @@ pub(crate) fn add_months(date: NaiveDate, months: i32) -> NaiveDate {
     NaiveDate::from_ymd_opt(new_year, new_month, new_day).unwrap_or(date)
 }
 
+/// The first day of the month `months` after `date`'s month. Unlike
+/// [`add_months`] it never depends on `date`'s day, so calendar month
+/// headers stay stable whatever day a period starts on.
+pub(crate) fn first_of_month_after(date: NaiveDate, months: i32) -> NaiveDate {
+    let first = NaiveDate::from_ymd_opt(date.year(), date.month(), 1).unwrap();
+    add_months(first, months)
+}
+
+/// Blank cells before `first_of_month` in a calendar whose weeks start on
+/// `week_start`.
+pub(crate) fn weekday_offset(first_of_month: NaiveDate, week_start: Weekday) -> usize {
+    first_of_month.weekday().days_since(week_start) as usize
+}
+
+/// The " Su Mo Tu … " column header for weeks starting on `week_start`.
+fn weekday_header(week_start: Weekday) -> String {
+    let names: Vec<String> = std::iter::successors(Some(week_start), |d| Some(d.succ()))
+        .take(7)
+        .map(|d| d.to_string()[..2].to_string())
+        .collect();
+    format!(" {}   ", names.join(" "))
+}
+
 /// Determines the ratatui `Style` for a calendar day cell based on its state.
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn calendar_day_style(
+    theme: &Theme,
     is_selected: bool,
     is_badged: bool,
     is_flex: bool,
     is_holiday_or_vacation: bool,
     is_today: bool,
-    is_weekend: bool,
+    is_non_workday: bool,
     has_event: bool,
+    is_pace_miss: bool,
+    is_pending_vacation: bool,
 ) -> Style {
     if is_selected {
         let bg = if is_badged && is_flex {
-            FLEX_COLOR
+            theme.flex
         } else if is_badged {
-            Color::Yellow
+            theme.office
         } else if is_holiday_or_vacation {
-            Color::Green
+            theme.holiday
         } else {
-            Color::White
+            theme.selected_bg
         };
         Style::default()
-            .fg(Color::Black)
+            .fg(theme.selected_fg)
             .bg(bg)
             .add_modifier(Modifier::BOLD)
     } else if is_badged {
-        let color = if is_flex { FLEX_COLOR } else { Color::Yellow };
+        let color = if is_flex { theme.flex } else { theme.office };
         let mut s = Style::default()
             .fg(color)
             .add_modifier(Modifier::BOLD | Modifier::UNDERLINED);
@@ -2023,28 +4358,304 @@ pub(crate) fn calendar_day_style(
         }
         s
     } else if is_holiday_or_vacation {
-        let mut s = Style::default().fg(Color::Green);
+        let mut s = Style::default().fg(theme.holiday);
+        if is_pending_vacation {
+            s = s.add_modifier(Modifier::ITALIC | Modifier::DIM);
+        }
         if is_today {
             s = s.add_modifier(Modifier::REVERSED);
         }
         s
     } else if is_today {
         Style::default().add_modifier(Modifier::REVERSED | Modifier::BOLD)
-    } else if is_weekend {
+    } else if is_non_workday {
         Style::default().add_modifier(Modifier::DIM)
-    } else if has_event {
-        Style::default().fg(Color::Cyan)
     } else {
+        let mut s = if has_event {
+            Style::default().fg(theme.event)
+        } else {
+            Style::default()
+        };
+        if is_pace_miss {
+            s = s
+                .add_modifier(Modifier::UNDERLINED)
+                .underline_color(Color::Red);
+        }
+        s
+    }
+}
+
+/// The "Delete <name>? (y/n)" prompt shown under a list view.
+fn delete_prompt_line(name: &str) -> Line<'static> {
+    Line::from(Span::styled(
+        format!("Delete {}? (y/n)", name),
         Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD),
+    ))
+}
+
+/// Word-wraps `text` into lines of at most `width` characters. Words longer
+/// than `width` are split across lines.
+pub(crate) fn wrap_text(text: &str, width: usize) -> Vec<String> {
+    let width = width.max(1);
+    let mut lines = Vec::new();
+    let mut current: Vec<char> = Vec::new();
+    for word in text.split_whitespace() {
+        let mut word: Vec<char> = word.chars().collect();
+        if !current.is_empty() && current.len() + 1 + word.len() > width {
+            lines.push(current.iter().collect());
+            current.clear();
+        }
+        if !current.is_empty() {
+            current.push(' ');
+        }
+        while current.len() + word.len() > width {
+            let take = width - current.len();
+            current.extend(word.drain(..take));
+            lines.push(current.iter().collect());
+            current.clear();
+        }
+        current.extend(word);
+    }
+    if !current.is_empty() || lines.is_empty() {
+        lines.push(current.iter().collect());
+    }
+    lines
+}
+
+/// Wraps like [`wrap_text`] but keeps at most `max_lines`, ending the last
+/// kept line with an ellipsis when anything was dropped.
+pub(crate) fn wrap_truncated(text: &str, width: usize, max_lines: usize) -> Vec<String> {
+    let mut lines = wrap_text(text, width);
+    if lines.len() > max_lines {
+        lines.truncate(max_lines.max(1));
+        if let Some(last) = lines.last_mut() {
+            let mut chars: Vec<char> = last.chars().collect();
+            chars.truncate(width.max(1) - 1);
+            *last = chars.into_iter().collect::<String>() + "…";
+        }
+    }
+    lines
+}
+
+/// "Holiday: …" / "Vacation: …" / "Excused: …" lines for the day panel,
+/// naming whatever keeps `date` out of the office.
+pub(crate) fn day_notes(
+    date: NaiveDate,
+    holidays: &HolidayData,
+    vacations: &VacationData,
+    week: WorkWeek,
+    observe_weekends: bool,
+) -> Vec<String> {
+    let key = date.format("%Y-%m-%d").to_string();
+    let mut notes = Vec::new();
+    if let Some(h) = holidays.get_holiday_map(week, observe_weekends).get(&key) {
+        notes.push(format!("Holiday: {}", h.name));
+    }
+    if let Some(v) = vacations.get_vacation_map(week).get(&key) {
+        let label = if v.is_excused {
+            "Excused"
+        } else if !v.counts_as_off {
+            "Working trip"
+        } else {
+            "Vacation"
+        };
+        notes.push(format!("{}: {}", label, v.destination));
+    }
+    notes
+}
+
+/// Whether a `YYYY-MM-DD` date string falls in `year` (e.g. "2026").
+fn date_in_year(date: &str, year: &str) -> bool {
+    date.strip_prefix(year)
+        .is_some_and(|rest| rest.starts_with('-'))
+}
+
+/// The holidays dated in `year`, in list order.
+pub(crate) fn filter_by_year<'a>(holidays: &'a [Holiday], year: &str) -> Vec<&'a Holiday> {
+    holidays
+        .iter()
+        .filter(|h| date_in_year(&h.date, year))
+        .collect()
+}
+
+/// `]` (forward) and `[` in a list view: all years → each of `years` in
+/// turn → all years again.
+fn cycle_year(years: &[String], current: Option<&str>, forward: bool) -> Option<String> {
+    let pos = current
+        .and_then(|y| years.iter().position(|c| c == y))
+        .map_or(0, |i| i + 1);
+    let count = years.len() + 1;
+    let next = if forward {
+        (pos + 1) % count
+    } else {
+        (pos + count - 1) % count
+    };
+    next.checked_sub(1).map(|i| years[i].clone())
+}
+
+/// One period's share of the timeline bar.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct TimelineSegment {
+    pub key: String,
+    /// Columns, proportional to the period's length in days
+    pub width: u16,
+    /// Whether the period contains today
+    pub is_current: bool,
+    /// Column within the segment where today falls
+    pub today_at: Option<u16>,
+}
+
+/// Splits `width` columns among the periods with valid dates, in date order
+/// and in proportion to their lengths. Widths always add up to `width`; a
+/// period too short for a column when space is tight gets zero.
+pub(crate) fn quarter_timeline(
+    periods: &[TimePeriod],
+    today: NaiveDate,
+    width: u16,
+) -> Vec<TimelineSegment> {
+    let mut spans: Vec<(&str, NaiveDate, i64)> = periods
+        .iter()
+        .filter_map(|p| match (p.start_date, p.end_date) {
+            (Some(start), Some(end)) if end >= start => {
+                Some((p.key.as_str(), start, (end - start).num_days() + 1))
+            }
+            _ => None,
+        })
+        .collect();
+    spans.sort_by_key(|&(_, start, _)| start);
+    let total: i64 = spans.iter().map(|&(_, _, days)| days).sum();
+
+    // Rounding cumulative boundaries rather than each width keeps the sum exact
+    let mut elapsed = 0;
+    let mut left = 0;
+    spans
+        .into_iter()
+        .map(|(key, start, days)| {
+            elapsed += days;
+            let right = ((elapsed * width as i64 + total / 2) / total) as u16;
+            let seg_width = right - left;
+            left = right;
+            let offset = (today - start).num_days();
+            let is_current = (0..days).contains(&offset);
+            TimelineSegment {
+                key: key.to_string(),
+                width: seg_width,
+                is_current,
+                today_at: (is_current && seg_width > 0)
+                    .then(|| (offset * seg_width as i64 / days) as u16),
+            }
+        })
+        .collect()
+}
+
+/// How the months of the calendar are laid out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum MonthArrangement {
+    /// Side by side, with room for at most this many months per row.
+    Horizontal(usize),
+    /// Stacked one month per row.
+    Vertical,
+}
+
+/// How the calendar screen divides the terminal, from [`calendar_layout`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct LayoutPlan {
+    /// Timeline, calendar and events
+    pub left: Rect,
+    /// Period stats above year stats or the week breakdown
+    pub right: Rect,
+    pub months: MonthArrangement,
+}
+
+impl LayoutPlan {
+    /// Months per row: the `calendar_display_columns` setting (3 when unset),
+    /// cut down to what fits.
+    pub fn month_columns(&self, configured: usize) -> usize {
+        let configured = if configured == 0 { 3 } else { configured };
+        match self.months {
+            MonthArrangement::Horizontal(fit) => configured.min(fit),
+            MonthArrangement::Vertical => 1,
+        }
+    }
+
+    /// Splits the right panel between period stats that want `wanted` rows
+    /// and the year stats. When both don't fit, the year stats give up rows
+    /// first so the period stats aren't clipped.
+    pub fn stats_constraints(&self, wanted: u16) -> [Constraint; 2] {
+        if self.right.height >= wanted + YEAR_STATS_MIN_HEIGHT {
+            [
+                Constraint::Length(wanted),
+                Constraint::Min(YEAR_STATS_MIN_HEIGHT),
+            ]
+        } else {
+            [Constraint::Length(wanted), Constraint::Min(0)]
+        }
+    }
+}
+
+/// Splits the calendar screen into the left panel and the 68-column stats
+/// panel, and stacks the months vertically once the left panel is too narrow
+/// for two of them side by side.
+pub(crate) fn calendar_layout(area: Rect) -> LayoutPlan {
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Min(30),    // left: calendar + events
+            Constraint::Length(68), // right: stats panels (40+14+8 cols + borders)
+        ])
+        .split(area);
+    let fit = ((chunks[0].width + GAP_WIDTH) / (MONTH_WIDTH + GAP_WIDTH)) as usize;
+    LayoutPlan {
+        left: chunks[0],
+        right: chunks[1],
+        months: if fit >= 2 {
+            MonthArrangement::Horizontal(fit)
+        } else {
+            MonthArrangement::Vertical
+        },
+    }
+}
+
+/// Whether `date` falls inside any configured period. Holidays and vacations
+/// outside every period never show up in stats.
+pub(crate) fn date_in_any_period(td: &TimePeriodData, date: NaiveDate) -> bool {
+    td.get_period_by_date(date).is_some()
+}
+
+/// Tab in add mode: no repeat → weekly on `date`'s weekday → monthly on its day.
+fn next_recurrence(current: Option<Recurrence>, date: NaiveDate) -> Option<Recurrence> {
+    match current {
+        None => Some(Recurrence::Weekly {
+            weekday: date.weekday(),
+            until: None,
+        }),
+        Some(Recurrence::Weekly { .. }) => Some(Recurrence::Monthly {
+            day: date.day(),
+            until: None,
+        }),
+        Some(Recurrence::Monthly { .. }) => None,
     }
 }
 
-/// Filters events by a search query (case-insensitive description, case-sensitive date).
+/// Case-insensitive substring search over events. A `date:` or `desc:` prefix
+/// limits the match to that field; a bare query matches either.
 pub(crate) fn search_events<'a>(events: &'a [Event], query: &str) -> Vec<&'a Event> {
     let q = query.to_lowercase();
+    let (in_date, in_desc, q) = if let Some(rest) = q.strip_prefix("date:") {
+        (true, false, rest.trim())
+    } else if let Some(rest) = q.strip_prefix("desc:") {
+        (false, true, rest.trim())
+    } else {
+        (true, true, q.as_str())
+    };
     events
         .iter()
-        .filter(|e| e.description.to_lowercase().contains(&q) || e.date.contains(query))
+        .filter(|e| {
+            (in_date && e.date.to_lowercase().contains(q))
+                || (in_desc && e.description.to_lowercase().contains(q))
+        })
         .collect()
 }
 
@@ -2071,6 +4682,7 @@ mod tests {
             end_date_raw: "2025-03-31".to_string(),
             start_date: None,
             end_date: None,
+            ..Default::default()
         };
         q1.parse_dates().unwrap();
         let mut q2 = TimePeriod {
@@ -2080,6 +4692,7 @@ mod tests {
             end_date_raw: "2025-06-30".to_string(),
             start_date: None,
             end_date: None,
+            ..Default::default()
         };
         q2.parse_dates().unwrap();
         let mut data = TimePeriodData::new();
@@ -2108,11 +4721,119 @@ mod tests {
         )
     }
 
+    // ── weekday_offset tests ──────────────────────────────────────────────────
+
+    #[test]
+    fn test_weekday_offset_sunday_start() {
+        // March 2025 starts on a Saturday, June 2025 on a Sunday
+        assert_eq!(weekday_offset(d(2025, 3, 1), Weekday::Sun), 6);
+        assert_eq!(weekday_offset(d(2025, 6, 1), Weekday::Sun), 0);
+        assert_eq!(weekday_offset(d(2025, 1, 1), Weekday::Sun), 3);
+    }
+
+    #[test]
+    fn test_weekday_offset_monday_start() {
+        assert_eq!(weekday_offset(d(2025, 3, 1), Weekday::Mon), 5);
+        assert_eq!(weekday_offset(d(2025, 6, 1), Weekday::Mon), 6);
+        assert_eq!(weekday_offset(d(2025, 9, 1), Weekday::Mon), 0);
+    }
+
+    #[test]
+    fn test_weekday_header_follows_week_start() {
+        assert_eq!(weekday_header(Weekday::Sun), " Su Mo Tu We Th Fr Sa   ");
+        assert_eq!(weekday_header(Weekday::Mon), " Mo Tu We Th Fr Sa Su   ");
+    }
+
+    #[test]
+    fn test_monday_start_renders_monday_first_header() {
+        let qd = make_quarter_data();
+        let mut bd = BadgeEntryData::default();
+        let mut hd = HolidayData::default();
+        let mut vd = VacationData::default();
+        let mut ed = EventData::default();
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, d(2025, 1, 22));
+        assert!(render_app_text(&mut app).contains("Su Mo Tu We Th Fr Sa"));
+        app.settings.week_start = Weekday::Mon;
+        let text = render_app_text(&mut app);
+        assert!(text.contains("Mo Tu We Th Fr Sa Su"));
+        assert!(!text.contains("Su Mo Tu"));
+    }
+
     // ── calendar_day_style tests ──────────────────────────────────────────────
 
+    #[test]
+    fn test_style_pace_miss_red_underline() {
+        let s = calendar_day_style(
+            &Theme::dark(),
+            false,
+            false,
+            false,
+            false,
+            false,
+            false,
+            false,
+            true,
+            false,
+        );
+        assert_eq!(
+            s,
+            Style::default()
+                .add_modifier(Modifier::UNDERLINED)
+                .underline_color(Color::Red)
+        );
+        // Selection still wins
+        let s = calendar_day_style(
+            &Theme::dark(),
+            true,
+            false,
+            false,
+            false,
+            false,
+            false,
+            false,
+            true,
+            false,
+        );
+        assert_eq!(s.bg, Some(Theme::dark().selected_bg));
+    }
+
+    #[test]
+    fn test_style_pending_vacation_differs_from_approved() {
+        let style = |pending| {
+            calendar_day_style(
+                &Theme::dark(),
+                false,
+                false,
+                false,
+                true,
+                false,
+                false,
+                false,
+                false,
+                pending,
+            )
+        };
+        let approved = style(false);
+        let pending = style(true);
+        assert_ne!(approved, pending);
+        assert_eq!(pending.fg, Some(Theme::dark().holiday));
+        assert!(pending.add_modifier.contains(Modifier::ITALIC));
+    }
+
     #[test]
     fn test_style_selected_badged_office() {
-        let s = calendar_day_style(true, true, false, false, false, false, false);
+        let s = calendar_day_style(
+            &Theme::dark(),
+            true,
+            true,
+            false,
+            false,
+            false,
+            false,
+            false,
+            false,
+            false,
+        );
         assert_eq!(
             s,
             Style::default()
@@ -2124,19 +4845,41 @@ mod tests {
 
     #[test]
     fn test_style_selected_badged_flex() {
-        let s = calendar_day_style(true, true, true, false, false, false, false);
+        let s = calendar_day_style(
+            &Theme::dark(),
+            true,
+            true,
+            true,
+            false,
+            false,
+            false,
+            false,
+            false,
+            false,
+        );
         assert_eq!(
             s,
             Style::default()
                 .fg(Color::Black)
-                .bg(FLEX_COLOR)
+                .bg(Theme::dark().flex)
                 .add_modifier(Modifier::BOLD)
         );
     }
 
     #[test]
     fn test_style_selected_holiday() {
-        let s = calendar_day_style(true, false, false, true, false, false, false);
+        let s = calendar_day_style(
+            &Theme::dark(),
+            true,
+            false,
+            false,
+            true,
+            false,
+            false,
+            false,
+            false,
+            false,
+        );
         assert_eq!(
             s,
             Style::default()
@@ -2146,9 +4889,76 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_light_theme_changes_badged_foreground() {
+        let dark = calendar_day_style(
+            &Theme::dark(),
+            false,
+            true,
+            false,
+            false,
+            false,
+            false,
+            false,
+            false,
+            false,
+        );
+        let light = calendar_day_style(
+            &Theme::light(),
+            false,
+            true,
+            false,
+            false,
+            false,
+            false,
+            false,
+            false,
+            false,
+        );
+        assert_ne!(dark.fg, light.fg);
+        assert_eq!(light.fg, Some(Theme::light().office));
+
+        let dark = calendar_day_style(
+            &Theme::dark(),
+            false,
+            true,
+            true,
+            false,
+            false,
+            false,
+            false,
+            false,
+            false,
+        );
+        let light = calendar_day_style(
+            &Theme::light(),
+            false,
+            true,
+            true,
+            false,
+            false,
+            false,
+            false,
+            false,
+            false,
+        );
+        assert_ne!(dark.fg, light.fg);
+    }
+
     #[test]
     fn test_style_selected_plain() {
-        let s = calendar_day_style(true, false, false, false, false, false, false);
+        let s = calendar_day_style(
+            &Theme::dark(),
+            true,
+            false,
+            false,
+            false,
+            false,
+            false,
+            false,
+            false,
+            false,
+        );
         assert_eq!(
             s,
             Style::default()
@@ -2160,7 +4970,18 @@ mod tests {
 
     #[test]
     fn test_style_badged_office_not_selected() {
-        let s = calendar_day_style(false, true, false, false, false, false, false);
+        let s = calendar_day_style(
+            &Theme::dark(),
+            false,
+            true,
+            false,
+            false,
+            false,
+            false,
+            false,
+            false,
+            false,
+        );
         assert_eq!(
             s,
             Style::default()
@@ -2171,18 +4992,40 @@ mod tests {
 
     #[test]
     fn test_style_badged_flex_not_selected() {
-        let s = calendar_day_style(false, true, true, false, false, false, false);
+        let s = calendar_day_style(
+            &Theme::dark(),
+            false,
+            true,
+            true,
+            false,
+            false,
+            false,
+            false,
+            false,
+            false,
+        );
         assert_eq!(
             s,
             Style::default()
-                .fg(FLEX_COLOR)
+                .fg(Theme::dark().flex)
                 .add_modifier(Modifier::BOLD | Modifier::UNDERLINED)
         );
     }
 
     #[test]
     fn test_style_badged_today() {
-        let s = calendar_day_style(false, true, false, false, true, false, false);
+        let s = calendar_day_style(
+            &Theme::dark(),
+            false,
+            true,
+            false,
+            false,
+            true,
+            false,
+            false,
+            false,
+            false,
+        );
         assert_eq!(
             s,
             Style::default()
@@ -2193,13 +5036,35 @@ mod tests {
 
     #[test]
     fn test_style_holiday_not_selected() {
-        let s = calendar_day_style(false, false, false, true, false, false, false);
+        let s = calendar_day_style(
+            &Theme::dark(),
+            false,
+            false,
+            false,
+            true,
+            false,
+            false,
+            false,
+            false,
+            false,
+        );
         assert_eq!(s, Style::default().fg(Color::Green));
     }
 
     #[test]
     fn test_style_today_plain() {
-        let s = calendar_day_style(false, false, false, false, true, false, false);
+        let s = calendar_day_style(
+            &Theme::dark(),
+            false,
+            false,
+            false,
+            false,
+            true,
+            false,
+            false,
+            false,
+            false,
+        );
         assert_eq!(
             s,
             Style::default().add_modifier(Modifier::REVERSED | Modifier::BOLD)
@@ -2208,598 +5073,2608 @@ mod tests {
 
     #[test]
     fn test_style_weekend() {
-        let s = calendar_day_style(false, false, false, false, false, true, false);
+        let s = calendar_day_style(
+            &Theme::dark(),
+            false,
+            false,
+            false,
+            false,
+            false,
+            true,
+            false,
+            false,
+            false,
+        );
         assert_eq!(s, Style::default().add_modifier(Modifier::DIM));
     }
 
     #[test]
     fn test_style_has_event() {
-        let s = calendar_day_style(false, false, false, false, false, false, true);
+        let s = calendar_day_style(
+            &Theme::dark(),
+            false,
+            false,
+            false,
+            false,
+            false,
+            false,
+            true,
+            false,
+            false,
+        );
         assert_eq!(s, Style::default().fg(Color::Cyan));
     }
 
     #[test]
     fn test_style_plain_workday() {
-        let s = calendar_day_style(false, false, false, false, false, false, false);
+        let s = calendar_day_style(
+            &Theme::dark(),
+            false,
+            false,
+            false,
+            false,
+            false,
+            false,
+            false,
+            false,
+            false,
+        );
         assert_eq!(s, Style::default());
     }
 
-    // ── search_events tests ───────────────────────────────────────────────────
-
-    fn ev(date: &str, desc: &str) -> Event {
-        Event {
-            date: date.to_string(),
-            description: desc.to_string(),
-        }
+    #[test]
+    fn test_day_notes_name_holiday_and_vacation() {
+        let mut holidays = HolidayData::default();
+        holidays.add(Holiday::new("Memorial Day", "2025-05-26"));
+        let mut vacations = VacationData::default();
+        vacations.add(Vacation::new("Hawaii", "2025-06-02", "2025-06-06", true));
+        vacations.add(Vacation::excused("Flu", "2025-06-10", "2025-06-10"));
+        let notes = |date| day_notes(date, &holidays, &vacations, WorkWeek::default(), false);
+
+        assert_eq!(notes(d(2025, 5, 26)), vec!["Holiday: Memorial Day"]);
+        assert_eq!(notes(d(2025, 6, 4)), vec!["Vacation: Hawaii"]);
+        assert_eq!(notes(d(2025, 6, 10)), vec!["Excused: Flu"]);
+        assert!(notes(d(2025, 5, 27)).is_empty());
     }
 
     #[test]
-    fn test_search_empty_query_returns_all() {
-        let events = vec![ev("2025-01-01", "Alpha"), ev("2025-01-02", "Beta")];
-        let result = search_events(&events, "");
-        assert_eq!(result.len(), 2);
+    fn test_day_panel_shows_holiday_name() {
+        let qd = make_quarter_data();
+        let mut bd = BadgeEntryData::default();
+        let mut hd = HolidayData::default();
+        hd.add(Holiday::new("Presidents Day", "2025-02-17"));
+        let mut vd = VacationData::default();
+        let mut ed = EventData::default();
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, d(2025, 2, 17));
+        let text = render_app_text_sized(&mut app, 160, 60);
+        assert!(text.contains("Holiday: Presidents Day"));
     }
 
     #[test]
-    fn test_search_case_insensitive_description() {
-        let events = vec![ev("2025-01-01", "Team Lunch"), ev("2025-01-02", "Meeting")];
-        let result = search_events(&events, "lunch");
-        assert_eq!(result.len(), 1);
-        assert_eq!(result[0].description, "Team Lunch");
+    fn test_goal_progress_clamps_and_handles_zero_required() {
+        let qd = make_quarter_data();
+        let mut bd = BadgeEntryData::default();
+        let mut hd = HolidayData::default();
+        let mut vd = VacationData::default();
+        let mut ed = EventData::default();
+        let app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, d(2025, 2, 3));
+        let mut stats = app.active_stats.clone().unwrap();
+
+        stats.days_required = 30;
+        stats.days_badged_in = 12.0;
+        assert!((goal_progress(&stats) - 0.4).abs() < 1e-9);
+        stats.days_badged_in = 45.0;
+        assert_eq!(goal_progress(&stats), 1.0);
+        stats.days_required = 0;
+        stats.days_badged_in = 0.0;
+        assert_eq!(goal_progress(&stats), 1.0);
     }
 
     #[test]
-    fn test_search_no_match_returns_empty() {
-        let events = vec![ev("2025-01-01", "Alpha"), ev("2025-01-02", "Beta")];
-        let result = search_events(&events, "zzz");
-        assert!(result.is_empty());
+    fn test_rate_needed_style_by_achievability() {
+        let qd = make_quarter_data();
+        let mut bd = BadgeEntryData::default();
+        let mut hd = HolidayData::default();
+        let mut vd = VacationData::default();
+        let mut ed = EventData::default();
+        let app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, d(2025, 2, 3));
+        let mut stats = app.active_stats.clone().unwrap();
+        let mut style = |needed: i32, left: i32| {
+            stats.days_still_needed = needed;
+            stats.days_left = left;
+            stats.required_future_average = if left > 0 {
+                needed as f64 / left as f64
+            } else {
+                0.0
+            };
+            rate_needed_style(&stats)
+        };
+
+        assert_eq!(style(4, 10), ("40.0%".to_string(), Color::Indexed(40)));
+        assert_eq!(style(6, 10), ("60.0%".to_string(), Color::Indexed(220)));
+        assert_eq!(style(9, 10), ("90.0%".to_string(), Color::Indexed(208)));
+        // Every remaining day is still high, not unreachable
+        assert_eq!(style(10, 10), ("100.0%".to_string(), Color::Indexed(208)));
+        assert_eq!(
+            style(11, 10),
+            ("Unreachable".to_string(), Color::Indexed(196))
+        );
+        assert_eq!(
+            style(2, 0),
+            ("Unreachable".to_string(), Color::Indexed(196))
+        );
+        assert_eq!(style(0, 0), ("0.0%".to_string(), Color::Indexed(40)));
     }
 
     #[test]
-    fn test_search_partial_match() {
-        let events = vec![
-            ev("2025-01-01", "Team Lunch"),
-            ev("2025-01-02", "Team Meeting"),
-        ];
-        let result = search_events(&events, "team");
-        assert_eq!(result.len(), 2);
+    fn test_stats_panel_shows_rate_needed_row() {
+        let qd = make_quarter_data();
+        let mut bd = BadgeEntryData::default();
+        let mut hd = HolidayData::default();
+        let mut vd = VacationData::default();
+        let mut ed = EventData::default();
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, d(2025, 3, 24));
+        let text = render_app_text_sized(&mut app, 160, 60);
+        let row = text
+            .lines()
+            .find(|line| line.contains("Rate Needed (Remaining)"))
+            .unwrap_or_else(|| panic!("no rate row:\n{text}"));
+        assert!(row.contains("Unreachable"), "{row}");
+        // The whole full-stats panel still fits without scrolling
+        assert!(app.stats_rows <= app.stats_visible);
     }
 
-    #[test]
-    fn test_search_by_date() {
-        let events = vec![ev("2025-03-15", "Review"), ev("2025-04-01", "Launch")];
-        let result = search_events(&events, "2025-03");
-        assert_eq!(result.len(), 1);
-        assert_eq!(result[0].date, "2025-03-15");
+    fn series_stats(badged: &[u32], holidays: &[&str], today: NaiveDate) -> QuarterStats {
+        // Two weeks: Jan 6–17, 2025 (10 workdays)
+        let mut period = TimePeriod {
+            key: "T".to_string(),
+            name: "T".to_string(),
+            start_date_raw: "2025-01-06".to_string(),
+            end_date_raw: "2025-01-17".to_string(),
+            ..Default::default()
+        };
+        period.parse_dates().unwrap();
+        let mut bd = BadgeEntryData::default();
+        for &day in badged {
+            bd.add(BadgeEntry::new(d(2025, 1, day), "HQ", false));
+        }
+        let mut hd = HolidayData::default();
+        for date in holidays {
+            hd.add(Holiday::new("Off", date));
+        }
+        calculate_quarter_stats(
+            &period,
+            &bd,
+            &hd,
+            &VacationData::default(),
+            &StatsOptions::default(),
+            Some(today),
+        )
+        .unwrap()
     }
 
-    // ── add_months tests ──────────────────────────────────────────────────────
-
     #[test]
-    fn test_add_months_forward() {
-        assert_eq!(add_months(d(2025, 1, 15), 1), d(2025, 2, 15));
+    fn test_cumulative_series_actual_vs_pace() {
+        let stats = series_stats(&[6, 8, 9], &[], d(2025, 1, 10));
+        let (actual, pace) = cumulative_series(&stats);
+        // Mon–Thu are past; Friday (today) isn't badged yet
+        assert_eq!(actual, vec![1, 1, 2, 3]);
+        assert_eq!(pace, vec![1, 1, 2, 2, 3, 3, 4, 4, 5, 5]);
+
+        // Badging today extends the actual series by one day
+        let stats = series_stats(&[6, 8, 9, 10], &[], d(2025, 1, 10));
+        assert_eq!(cumulative_series(&stats).0, vec![1, 1, 2, 3, 4]);
     }
 
     #[test]
-    fn test_add_months_across_year() {
-        assert_eq!(add_months(d(2025, 11, 15), 2), d(2026, 1, 15));
+    fn test_cumulative_series_skips_holidays() {
+        let stats = series_stats(&[6, 8], &["2025-01-07"], d(2025, 1, 9));
+        let (actual, pace) = cumulative_series(&stats);
+        assert_eq!(pace.len(), 9);
+        assert_eq!(pace.last(), Some(&(stats.days_required as u64)));
+        assert_eq!(actual, vec![1, 2]);
     }
 
     #[test]
-    fn test_add_months_backward() {
-        assert_eq!(add_months(d(2025, 3, 10), -2), d(2025, 1, 10));
+    fn test_stats_panel_shows_goal_gauge() {
+        let qd = make_quarter_data();
+        let mut bd = BadgeEntryData::default();
+        bd.add(BadgeEntry::new(d(2025, 1, 6), "Office", false));
+        let mut hd = HolidayData::default();
+        let mut vd = VacationData::default();
+        let mut ed = EventData::default();
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, d(2025, 2, 3));
+        let text = render_app_text_sized(&mut app, 160, 60);
+        assert!(text.contains("1 / 32 required (3%)"));
+        assert!(text.contains("Actual"));
+        assert!(text.contains("Pace"));
     }
 
     #[test]
-    fn test_add_months_backward_across_year() {
-        assert_eq!(add_months(d(2025, 1, 10), -1), d(2024, 12, 10));
+    fn test_flex_day_shows_stored_label() {
+        let qd = make_quarter_data();
+        let mut bd = BadgeEntryData::default();
+        bd.add(BadgeEntry::new(d(2025, 2, 3), "Remote Day", true));
+        bd.add(BadgeEntry::new(d(2025, 2, 4), "Flex Credit", true));
+        let mut hd = HolidayData::default();
+        let mut vd = VacationData::default();
+        let mut ed = EventData::default();
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, d(2025, 2, 3));
+        let text = render_app_text_sized(&mut app, 160, 60);
+        assert!(text.contains("Flex: Remote Day"));
+        assert!(text.contains("Flex Credit / Remote Day"));
     }
 
     #[test]
-    fn test_add_months_clamps_month_end() {
-        // Jan 31 + 1 month = Feb 28 (2025 is not a leap year)
-        assert_eq!(add_months(d(2025, 1, 31), 1), d(2025, 2, 28));
+    fn test_date_in_any_period() {
+        let qd = make_quarter_data();
+        assert!(date_in_any_period(&qd, d(2025, 1, 1)));
+        assert!(date_in_any_period(&qd, d(2025, 6, 30)));
+        assert!(!date_in_any_period(&qd, d(2024, 12, 31)));
+        assert!(!date_in_any_period(&qd, d(2025, 7, 4)));
     }
 
-    // ── days_in_month tests ───────────────────────────────────────────────────
+    #[test]
+    fn test_question_mark_toggles_help_over_current_view() {
+        let qd = make_quarter_data();
+        let mut bd = BadgeEntryData::default();
+        let mut hd = HolidayData::default();
+        let mut vd = VacationData::default();
+        let mut ed = EventData::default();
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, d(2025, 2, 3));
+
+        app.handle_key(KeyCode::Char('h'), KeyModifiers::empty());
+        app.handle_key(KeyCode::Char('?'), KeyModifiers::empty());
+        assert!(app.show_help);
+        let text = render_app_text_sized(&mut app, 160, 60);
+        assert!(text.contains("Key Bindings"));
+        assert!(text.contains("Vacations and holidays: filter by year"));
+
+        // Keys don't reach the view underneath while help is open
+        app.handle_key(KeyCode::Char('a'), KeyModifiers::empty());
+        assert_eq!(app.list_add_stage, 0);
+        app.handle_key(KeyCode::Esc, KeyModifiers::empty());
+        assert!(!app.show_help);
+        assert!(app.view_state == ViewState::Holidays);
+
+        app.handle_key(KeyCode::Char('?'), KeyModifiers::empty());
+        app.handle_key(KeyCode::Char('?'), KeyModifiers::empty());
+        assert!(!app.show_help);
+        assert!(app.view_state == ViewState::Holidays);
+    }
 
     #[test]
-    fn test_days_in_month_january() {
-        assert_eq!(days_in_month(2025, 1), 31);
+    fn test_question_mark_is_typed_in_text_fields() {
+        let qd = make_quarter_data();
+        let mut bd = BadgeEntryData::default();
+        let mut hd = HolidayData::default();
+        let mut vd = VacationData::default();
+        let mut ed = EventData::default();
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, d(2025, 2, 3));
+
+        app.handle_key(KeyCode::Char('a'), KeyModifiers::empty());
+        app.handle_key(KeyCode::Char('?'), KeyModifiers::empty());
+        assert!(!app.show_help);
+        assert_eq!(app.input_buffer, "?");
     }
 
     #[test]
-    fn test_days_in_month_february_non_leap() {
-        assert_eq!(days_in_month(2025, 2), 28);
+    fn test_filter_by_year_matches_date_prefix() {
+        let holidays = vec![
+            Holiday::new("New Year's Day", "2025-01-01"),
+            Holiday::new("Christmas Day", "2025-12-25"),
+            Holiday::new("New Year's Day", "2026-01-01"),
+            Holiday::new("Bad", "20251-01-01"),
+        ];
+        let names = |year| -> Vec<&str> {
+            filter_by_year(&holidays, year)
+                .iter()
+                .map(|h| h.date.as_str())
+                .collect()
+        };
+        assert_eq!(names("2025"), ["2025-01-01", "2025-12-25"]);
+        assert_eq!(names("2026"), ["2026-01-01"]);
+        assert!(names("2027").is_empty());
     }
 
     #[test]
-    fn test_days_in_month_february_leap() {
-        assert_eq!(days_in_month(2024, 2), 29);
+    fn test_quarter_timeline_widths_follow_period_lengths() {
+        // Q1_2025 is 90 days, Q2_2025 91
+        let qd = make_quarter_data();
+        let widths = |width| -> Vec<u16> {
+            quarter_timeline(qd.periods(), d(2025, 2, 14), width)
+                .iter()
+                .map(|s| s.width)
+                .collect()
+        };
+        assert_eq!(widths(181), [90, 91]);
+        assert_eq!(widths(60), [30, 30]);
+        assert_eq!(widths(31), [15, 16]);
+        assert_eq!(widths(0), [0, 0]);
+
+        // Date order, whatever the file order; periods without dates are left out
+        let mut data = TimePeriodData::new();
+        let mut q2 = qd.get_period_by_key("Q2_2025").unwrap().clone();
+        let mut short = TimePeriod {
+            key: "JUL".to_string(),
+            start_date_raw: "2025-07-01".to_string(),
+            end_date_raw: "2025-07-31".to_string(),
+            ..Default::default()
+        };
+        short.parse_dates().unwrap();
+        data.add(short);
+        data.add(q2.clone());
+        q2.key = "BROKEN".to_string();
+        q2.start_date = None;
+        data.add(q2);
+        let segs = quarter_timeline(data.periods(), d(2025, 2, 14), 122);
+        let keys: Vec<&str> = segs.iter().map(|s| s.key.as_str()).collect();
+        assert_eq!(keys, ["Q2_2025", "JUL"]);
+        assert_eq!(segs[0].width + segs[1].width, 122);
+        assert_eq!(segs[1].width, 31);
     }
 
     #[test]
-    fn test_days_in_month_april() {
-        assert_eq!(days_in_month(2025, 4), 30);
+    fn test_quarter_timeline_marks_today() {
+        let qd = make_quarter_data();
+        let at = |today| {
+            quarter_timeline(qd.periods(), today, 60)
+                .into_iter()
+                .map(|s| (s.is_current, s.today_at))
+                .collect::<Vec<_>>()
+        };
+        // Day 45 of 90 sits halfway across Q1's 30 columns
+        assert_eq!(at(d(2025, 2, 15)), [(true, Some(15)), (false, None)]);
+        assert_eq!(at(d(2025, 4, 1)), [(false, None), (true, Some(0))]);
+        assert_eq!(at(d(2025, 6, 30)), [(false, None), (true, Some(29))]);
+        assert_eq!(at(d(2025, 7, 1)), [(false, None), (false, None)]);
     }
 
     #[test]
-    fn test_days_in_month_december() {
-        assert_eq!(days_in_month(2025, 12), 31);
+    fn test_timeline_renders_above_calendar() {
+        let qd = make_quarter_data();
+        let mut bd = BadgeEntryData::default();
+        let mut hd = HolidayData::default();
+        let mut vd = VacationData::default();
+        let mut ed = EventData::default();
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, d(2025, 2, 14));
+        let text = render_app_text_sized(&mut app, 160, 60);
+        let top = text.lines().next().unwrap();
+        assert!(top.contains("Q1_2025"), "{top}");
+        assert!(top.contains("Q2_2025"), "{top}");
+        assert_eq!(top.matches('▼').count(), 1);
+        assert!(top.find('▼') < top.find("Q2_2025"));
     }
 
-    // ── month_name tests ──────────────────────────────────────────────────────
+    #[test]
+    fn test_calendar_layout_stacks_months_when_narrow() {
+        let area = |width, height| Rect::new(0, 0, width, height);
+        let wide = calendar_layout(area(160, 60));
+        assert_eq!(wide.left.width, 92);
+        assert_eq!(wide.right.width, 68);
+        assert_eq!(wide.months, MonthArrangement::Horizontal(3));
+        assert_eq!(wide.month_columns(0), 3);
+        assert_eq!(wide.month_columns(2), 2);
+
+        // 52 columns: two months and a gap fit, a third doesn't
+        let medium = calendar_layout(area(120, 60));
+        assert_eq!(medium.months, MonthArrangement::Horizontal(2));
+        assert_eq!(medium.month_columns(3), 2);
+
+        let narrow = calendar_layout(area(100, 60));
+        assert_eq!(narrow.months, MonthArrangement::Vertical);
+        assert_eq!(narrow.month_columns(3), 1);
+    }
 
     #[test]
-    fn test_month_name_known_values() {
-        assert_eq!(month_name(1), "January");
-        assert_eq!(month_name(6), "June");
-        assert_eq!(month_name(12), "December");
+    fn test_stats_constraints_shrink_year_stats_when_short() {
+        let tall = calendar_layout(Rect::new(0, 0, 160, 60));
+        assert_eq!(
+            tall.stats_constraints(25),
+            [Constraint::Length(25), Constraint::Min(12)]
+        );
+        let short = calendar_layout(Rect::new(0, 0, 160, 30));
+        assert_eq!(
+            short.stats_constraints(25),
+            [Constraint::Length(25), Constraint::Min(0)]
+        );
     }
 
     #[test]
-    fn test_month_name_unknown() {
-        assert_eq!(month_name(0), "Unknown");
-        assert_eq!(month_name(13), "Unknown");
+    fn test_narrow_terminal_renders_months_stacked() {
+        let qd = make_quarter_data();
+        let mut bd = BadgeEntryData::default();
+        let mut hd = HolidayData::default();
+        let mut vd = VacationData::default();
+        let mut ed = EventData::default();
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, d(2025, 2, 14));
+        let row_of = |text: &str, title: &str| {
+            text.lines()
+                .position(|line| line.contains(title))
+                .unwrap_or_else(|| panic!("{title} missing:\n{text}"))
+        };
+
+        let wide = render_app_text_sized(&mut app, 160, 60);
+        assert_eq!(
+            row_of(&wide, "January 2025"),
+            row_of(&wide, "February 2025")
+        );
+
+        let narrow = render_app_text_sized(&mut app, 100, 60);
+        let (jan, feb) = (
+            row_of(&narrow, "January 2025"),
+            row_of(&narrow, "February 2025"),
+        );
+        assert!(feb > jan);
     }
 
-    // ── handle_key tests ──────────────────────────────────────────────────────
+    #[test]
+    fn test_badging_today_records_time_but_past_days_stay_midnight() {
+        let qd = make_quarter_data();
+        let mut bd = BadgeEntryData::default();
+        let mut hd = HolidayData::default();
+        let mut vd = VacationData::default();
+        let mut ed = EventData::default();
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, d(2025, 2, 14));
+        let tmp = tempfile::TempDir::new().unwrap();
+        app.data_dir = tmp.path().to_path_buf();
+
+        let before = Local::now().time().with_nanosecond(0).unwrap();
+        app.selected_date = d(2025, 2, 14);
+        app.handle_key(KeyCode::Char('b'), KeyModifiers::empty());
+        let after = Local::now().time();
+        let logged = app.badge_data.get("2025-02-14").unwrap().logged_time();
+        // Skip the range check if the clock passed midnight mid-test
+        if before <= after {
+            let logged = logged.expect("same-day badge should carry its time");
+            assert!(before <= logged && logged <= after, "{logged}");
+            let text = render_app_text_sized(&mut app, 160, 60);
+            assert!(
+                text.contains(&format!("(badged at {})", logged.format("%H:%M"))),
+                "{text}"
+            );
+        }
+
+        app.selected_date = d(2025, 2, 13);
+        app.handle_key(KeyCode::Char('b'), KeyModifiers::empty());
+        let past = app.badge_data.get("2025-02-13").unwrap();
+        assert_eq!(past.date_time.to_string(), "2025-02-13T00:00:00");
+        assert_eq!(past.logged_time(), None);
+        let text = render_app_text_sized(&mut app, 160, 60);
+        assert!(!text.contains("badged at"), "{text}");
+    }
 
     #[test]
-    fn test_arrow_keys_move_selected_date() {
+    fn test_page_keys_scroll_stats_and_clamp_at_the_end() {
         let qd = make_quarter_data();
         let mut bd = BadgeEntryData::default();
         let mut hd = HolidayData::default();
         let mut vd = VacationData::default();
         let mut ed = EventData::default();
-        let today = d(2025, 2, 10);
-        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, today);
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, d(2025, 2, 14));
+        let press = |app: &mut App, code, modifiers| {
+            app.handle_key(code, modifiers);
+            render_app_text_sized(app, 160, 20)
+        };
 
-        app.handle_key(KeyCode::Right, KeyModifiers::empty());
-        assert_eq!(app.selected_date, d(2025, 2, 11));
+        let text = render_app_text_sized(&mut app, 160, 20);
+        let (rows, visible) = (app.stats_rows, app.stats_visible);
+        assert!(
+            rows > visible && visible > 0,
+            "{rows} rows, {visible} visible"
+        );
+        assert!(
+            text.contains(&format!("▼ {} more", rows - visible)),
+            "{text}"
+        );
 
-        app.handle_key(KeyCode::Left, KeyModifiers::empty());
-        assert_eq!(app.selected_date, d(2025, 2, 10));
+        press(&mut app, KeyCode::PageDown, KeyModifiers::empty());
+        assert_eq!(app.table_state.offset(), visible.min(rows - visible));
+        for _ in 0..5 {
+            press(&mut app, KeyCode::PageDown, KeyModifiers::empty());
+        }
+        assert_eq!(app.table_state.offset(), rows - visible);
+        let text = press(&mut app, KeyCode::PageDown, KeyModifiers::empty());
+        assert_eq!(app.table_state.offset(), rows - visible);
+        assert!(
+            text.contains(&format!("▲ {} more", rows - visible)),
+            "{text}"
+        );
+        assert_eq!(text.matches(" more").count(), 1, "{text}");
 
-        app.handle_key(KeyCode::Down, KeyModifiers::empty());
-        assert_eq!(app.selected_date, d(2025, 2, 17));
+        press(&mut app, KeyCode::PageUp, KeyModifiers::empty());
+        assert_eq!(
+            app.table_state.offset(),
+            (rows - visible).saturating_sub(visible)
+        );
+        press(&mut app, KeyCode::End, KeyModifiers::CONTROL);
+        assert_eq!(app.table_state.offset(), rows - visible);
+        press(&mut app, KeyCode::Home, KeyModifiers::CONTROL);
+        assert_eq!(app.table_state.offset(), 0);
+        press(&mut app, KeyCode::PageUp, KeyModifiers::empty());
+        assert_eq!(app.table_state.offset(), 0);
+        // Plain Home still goes to today rather than scrolling
+        assert_eq!(app.selected_date, d(2025, 2, 14));
+    }
 
-        app.handle_key(KeyCode::Up, KeyModifiers::empty());
-        assert_eq!(app.selected_date, d(2025, 2, 10));
+    #[test]
+    fn test_stats_offset_shrinks_when_the_terminal_grows() {
+        let qd = make_quarter_data();
+        let mut bd = BadgeEntryData::default();
+        let mut hd = HolidayData::default();
+        let mut vd = VacationData::default();
+        let mut ed = EventData::default();
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, d(2025, 2, 14));
+        render_app_text_sized(&mut app, 160, 20);
+        app.handle_key(KeyCode::End, KeyModifiers::CONTROL);
+        assert!(app.table_state.offset() > 0);
+
+        // Everything fits on a tall terminal, so the table snaps back to the top
+        render_app_text_sized(&mut app, 160, 80);
+        assert_eq!(app.table_state.offset(), 0);
+        app.handle_key(KeyCode::PageDown, KeyModifiers::empty());
+        assert_eq!(app.table_state.offset(), 0);
     }
 
     #[test]
-    fn test_b_toggles_office_badge() {
+    fn test_cycle_year_wraps_through_all_years() {
+        let years = vec!["2025".to_string(), "2026".to_string()];
+        let fwd = |cur: Option<&str>| cycle_year(&years, cur, true);
+        let back = |cur: Option<&str>| cycle_year(&years, cur, false);
+        assert_eq!(fwd(None).as_deref(), Some("2025"));
+        assert_eq!(fwd(Some("2025")).as_deref(), Some("2026"));
+        assert_eq!(fwd(Some("2026")), None);
+        assert_eq!(back(None).as_deref(), Some("2026"));
+        assert_eq!(back(Some("2025")), None);
+        // A year that no longer has entries restarts the cycle
+        assert_eq!(fwd(Some("2019")).as_deref(), Some("2025"));
+        assert_eq!(cycle_year(&[], None, true), None);
+    }
+
+    #[test]
+    fn test_holiday_year_filter_limits_rows_and_actions() {
         let qd = make_quarter_data();
         let mut bd = BadgeEntryData::default();
         let mut hd = HolidayData::default();
+        hd.add(Holiday::new("New Year's Day", "2025-01-01"));
+        hd.add(Holiday::new("Labor Day", "2025-09-01"));
+        hd.add(Holiday::new("New Year's Day", "2026-01-01"));
+        hd.add(Holiday::new("Labor Day", "2026-09-07"));
         let mut vd = VacationData::default();
         let mut ed = EventData::default();
-        let today = d(2025, 2, 10);
-        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, today);
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, d(2025, 2, 3));
 
-        let key = today.format("%Y-%m-%d").to_string();
-        assert!(!app.badge_data.has(&key));
+        app.handle_key(KeyCode::Char('h'), KeyModifiers::empty());
+        app.handle_key(KeyCode::Char('['), KeyModifiers::empty());
+        assert_eq!(app.list_year.as_deref(), Some("2026"));
+        let text = render_app_text_sized(&mut app, 160, 60);
+        assert!(text.contains("Holidays — 2026"));
+        assert!(text.contains("2026-09-07"));
+        assert!(!text.contains("2025-09-01"));
 
-        app.handle_key(KeyCode::Char('b'), KeyModifiers::empty());
-        assert!(app.badge_data.has(&key));
+        // The cursor stops at the last shown row, and actions hit that row
+        app.handle_key(KeyCode::Down, KeyModifiers::empty());
+        app.handle_key(KeyCode::Down, KeyModifiers::empty());
+        assert_eq!(app.list_cursor, 1);
+        app.handle_key(KeyCode::Char('e'), KeyModifiers::empty());
+        assert_eq!(app.list_edit_index, Some(3));
+        app.handle_key(KeyCode::Esc, KeyModifiers::empty());
+        app.settings.quick_delete = true;
+        app.handle_key(KeyCode::Char('x'), KeyModifiers::empty());
+        assert_eq!(app.holiday_data.holidays.len(), 3);
+        assert_eq!(app.holiday_data.holidays[2].date, "2026-01-01");
+        assert_eq!(app.list_cursor, 0);
 
-        app.handle_key(KeyCode::Char('b'), KeyModifiers::empty());
-        assert!(!app.badge_data.has(&key));
+        app.handle_key(KeyCode::Char(']'), KeyModifiers::empty());
+        assert_eq!(app.list_year, None);
+        assert_eq!(app.list_rows().len(), 3);
     }
 
     #[test]
-    fn test_f_toggles_flex_badge() {
+    fn test_vacation_year_filter_includes_trips_spanning_new_year() {
         let qd = make_quarter_data();
         let mut bd = BadgeEntryData::default();
         let mut hd = HolidayData::default();
         let mut vd = VacationData::default();
+        vd.add(Vacation::new("Ski", "2025-02-10", "2025-02-14", true));
+        vd.add(Vacation::new("Holidays", "2025-12-29", "2026-01-02", true));
+        vd.add(Vacation::new("Beach", "2026-07-06", "2026-07-10", true));
         let mut ed = EventData::default();
-        let today = d(2025, 2, 10);
-        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, today);
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, d(2025, 2, 3));
 
-        let key = today.format("%Y-%m-%d").to_string();
-        app.handle_key(KeyCode::Char('f'), KeyModifiers::empty());
-        assert!(app.badge_data.has(&key));
-        let entry = app.badge_data.data.iter().find(|e| e.key == key).unwrap();
-        assert_eq!(entry.office, "Flex Credit");
+        app.handle_key(KeyCode::Char('v'), KeyModifiers::empty());
+        app.handle_key(KeyCode::Char(']'), KeyModifiers::empty());
+        assert_eq!(app.list_rows(), [0, 1]);
+        app.handle_key(KeyCode::Char(']'), KeyModifiers::empty());
+        assert_eq!(app.list_year.as_deref(), Some("2026"));
+        assert_eq!(app.list_rows(), [1, 2]);
+
+        app.handle_key(KeyCode::Down, KeyModifiers::empty());
+        app.handle_key(KeyCode::Char('w'), KeyModifiers::empty());
+        assert!(!app.vacation_data.vacations[2].counts_as_off);
+        assert!(app.vacation_data.vacations[1].counts_as_off);
     }
 
     #[test]
-    fn test_b_does_nothing_outside_quarter() {
-        let qd = TimePeriodData::new();
+    fn test_holiday_form_warns_outside_periods_but_still_adds() {
+        let qd = make_quarter_data();
         let mut bd = BadgeEntryData::default();
         let mut hd = HolidayData::default();
         let mut vd = VacationData::default();
         let mut ed = EventData::default();
-        let today = d(2025, 2, 10);
-        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, today);
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, d(2025, 2, 3));
+        app.handle_key(KeyCode::Char('h'), KeyModifiers::empty());
+        app.handle_key(KeyCode::Char('a'), KeyModifiers::empty());
+        for c in "2025-02-17".chars() {
+            app.handle_key(KeyCode::Char(c), KeyModifiers::empty());
+        }
+        let text = render_app_text_sized(&mut app, 160, 60);
+        assert!(!text.contains("outside every configured period"));
+
+        app.input_buffer = "2025-07-04".to_string();
+        let text = render_app_text_sized(&mut app, 160, 60);
+        assert!(text.contains("outside every configured period"));
+
+        app.handle_key(KeyCode::Enter, KeyModifiers::empty());
+        for c in "Independence Day".chars() {
+            app.handle_key(KeyCode::Char(c), KeyModifiers::empty());
+        }
+        app.handle_key(KeyCode::Enter, KeyModifiers::empty());
+        assert_eq!(app.holiday_data.holidays.len(), 1);
+        assert_eq!(app.holiday_data.holidays[0].date, "2025-07-04");
+    }
+
+    // ── search_events tests ───────────────────────────────────────────────────
+
+    fn ev(date: &str, desc: &str) -> Event {
+        Event::new(date, desc)
+    }
+
+    #[test]
+    fn test_search_empty_query_returns_all() {
+        let events = vec![ev("2025-01-01", "Alpha"), ev("2025-01-02", "Beta")];
+        let result = search_events(&events, "");
+        assert_eq!(result.len(), 2);
+    }
+
+    #[test]
+    fn test_search_case_insensitive_description() {
+        let events = vec![ev("2025-01-01", "Team Lunch"), ev("2025-01-02", "Meeting")];
+        let result = search_events(&events, "lunch");
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].description, "Team Lunch");
+    }
+
+    #[test]
+    fn test_search_no_match_returns_empty() {
+        let events = vec![ev("2025-01-01", "Alpha"), ev("2025-01-02", "Beta")];
+        let result = search_events(&events, "zzz");
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn test_search_partial_match() {
+        let events = vec![
+            ev("2025-01-01", "Team Lunch"),
+            ev("2025-01-02", "Team Meeting"),
+        ];
+        let result = search_events(&events, "team");
+        assert_eq!(result.len(), 2);
+    }
+
+    #[test]
+    fn test_search_by_date() {
+        let events = vec![ev("2025-03-15", "Review"), ev("2025-04-01", "Launch")];
+        let result = search_events(&events, "2025-03");
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].date, "2025-03-15");
+    }
+
+    #[test]
+    fn test_search_date_prefix_matches_only_dates() {
+        let events = vec![
+            ev("2025-03-15", "Review"),
+            ev("2025-04-01", "Plan 2025-03 offsite"),
+        ];
+        let result = search_events(&events, "date:2025-03");
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].date, "2025-03-15");
+        // The bare query matches either field
+        assert_eq!(search_events(&events, "2025-03").len(), 2);
+    }
+
+    #[test]
+    fn test_search_desc_prefix_matches_only_descriptions() {
+        let events = vec![ev("2025-03-15", "Team Lunch"), ev("2025-04-15", "Review")];
+        let result = search_events(&events, "DESC: lunch");
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].description, "Team Lunch");
+        assert!(search_events(&events, "desc:15").is_empty());
+    }
+
+    // ── wrap_text tests ───────────────────────────────────────────────────────
+
+    #[test]
+    fn test_wrap_text_splits_on_words() {
+        let lines = wrap_text("the quick brown fox jumps over the lazy dog", 10);
+        assert_eq!(
+            lines,
+            vec!["the quick", "brown fox", "jumps over", "the lazy", "dog"]
+        );
+    }
+
+    #[test]
+    fn test_wrap_text_short_string_single_line() {
+        assert_eq!(wrap_text("Standup", 20), vec!["Standup"]);
+        assert_eq!(wrap_text("", 20), vec![""]);
+    }
+
+    #[test]
+    fn test_wrap_text_splits_long_word() {
+        assert_eq!(wrap_text("abcdefghij", 4), vec!["abcd", "efgh", "ij"]);
+    }
+
+    #[test]
+    fn test_wrap_truncated_adds_ellipsis_past_max_lines() {
+        let lines = wrap_truncated("the quick brown fox jumps over the lazy dog", 10, 2);
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0], "the quick");
+        assert!(lines[1].ends_with('…'));
+        assert!(lines[1].chars().count() <= 10);
+    }
+
+    // ── add_months tests ──────────────────────────────────────────────────────
+
+    #[test]
+    fn test_add_months_forward() {
+        assert_eq!(add_months(d(2025, 1, 15), 1), d(2025, 2, 15));
+    }
+
+    #[test]
+    fn test_add_months_across_year() {
+        assert_eq!(add_months(d(2025, 11, 15), 2), d(2026, 1, 15));
+    }
+
+    #[test]
+    fn test_add_months_backward() {
+        assert_eq!(add_months(d(2025, 3, 10), -2), d(2025, 1, 10));
+    }
+
+    #[test]
+    fn test_add_months_backward_across_year() {
+        assert_eq!(add_months(d(2025, 1, 10), -1), d(2024, 12, 10));
+    }
+
+    #[test]
+    fn test_add_months_clamps_month_end() {
+        // Jan 31 + 1 month = Feb 28 (2025 is not a leap year)
+        assert_eq!(add_months(d(2025, 1, 31), 1), d(2025, 2, 28));
+    }
+
+    #[test]
+    fn test_first_of_month_after_matches_add_months_month() {
+        for start in [
+            d(2025, 1, 31),
+            d(2025, 3, 31),
+            d(2024, 1, 30),
+            d(2025, 12, 31),
+        ] {
+            for n in -13..=13 {
+                let first = first_of_month_after(start, n);
+                let shifted = add_months(start, n);
+                assert_eq!(first.day(), 1);
+                assert_eq!(
+                    (first.year(), first.month()),
+                    (shifted.year(), shifted.month())
+                );
+            }
+        }
+        // add_months clamps the day; chaining it drifts off the month end
+        assert_eq!(add_months(add_months(d(2025, 1, 31), 1), 1), d(2025, 3, 28));
+        assert_eq!(first_of_month_after(d(2025, 1, 31), 2), d(2025, 3, 1));
+        assert_eq!(first_of_month_after(d(2024, 2, 29), 12), d(2025, 2, 1));
+    }
+
+    #[test]
+    fn test_period_months_from_month_end_start() {
+        let mut td = TimePeriodData::new();
+        let mut p = TimePeriod {
+            key: "FY_Q".to_string(),
+            name: "FYQ".to_string(),
+            start_date_raw: "2025-01-31".to_string(),
+            end_date_raw: "2025-04-29".to_string(),
+            ..Default::default()
+        };
+        p.parse_dates().unwrap();
+        td.add(p);
+        let mut bd = BadgeEntryData::default();
+        let mut hd = HolidayData::default();
+        let mut vd = VacationData::default();
+        let mut ed = EventData::default();
+        let app = make_test_app(td, &mut bd, &mut hd, &mut vd, &mut ed, d(2025, 2, 10));
+        assert_eq!(
+            app.period_months(),
+            vec![d(2025, 1, 1), d(2025, 2, 1), d(2025, 3, 1), d(2025, 4, 1)]
+        );
+    }
+
+    // ── days_in_month tests ───────────────────────────────────────────────────
+
+    #[test]
+    fn test_days_in_month_january() {
+        assert_eq!(days_in_month(2025, 1), 31);
+    }
+
+    #[test]
+    fn test_days_in_month_february_non_leap() {
+        assert_eq!(days_in_month(2025, 2), 28);
+    }
+
+    #[test]
+    fn test_days_in_month_february_leap() {
+        assert_eq!(days_in_month(2024, 2), 29);
+    }
+
+    #[test]
+    fn test_days_in_month_april() {
+        assert_eq!(days_in_month(2025, 4), 30);
+    }
+
+    #[test]
+    fn test_days_in_month_december() {
+        assert_eq!(days_in_month(2025, 12), 31);
+    }
+
+    // ── month_name tests ──────────────────────────────────────────────────────
+
+    #[test]
+    fn test_month_name_known_values() {
+        assert_eq!(month_name(1), "January");
+        assert_eq!(month_name(6), "June");
+        assert_eq!(month_name(12), "December");
+    }
+
+    #[test]
+    fn test_month_name_unknown() {
+        assert_eq!(month_name(0), "Unknown");
+        assert_eq!(month_name(13), "Unknown");
+    }
+
+    // ── handle_key tests ──────────────────────────────────────────────────────
+
+    #[test]
+    fn test_arrow_keys_move_selected_date() {
+        let qd = make_quarter_data();
+        let mut bd = BadgeEntryData::default();
+        let mut hd = HolidayData::default();
+        let mut vd = VacationData::default();
+        let mut ed = EventData::default();
+        let today = d(2025, 2, 10);
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, today);
+
+        app.handle_key(KeyCode::Right, KeyModifiers::empty());
+        assert_eq!(app.selected_date, d(2025, 2, 11));
+
+        app.handle_key(KeyCode::Left, KeyModifiers::empty());
+        assert_eq!(app.selected_date, d(2025, 2, 10));
+
+        app.handle_key(KeyCode::Down, KeyModifiers::empty());
+        assert_eq!(app.selected_date, d(2025, 2, 17));
+
+        app.handle_key(KeyCode::Up, KeyModifiers::empty());
+        assert_eq!(app.selected_date, d(2025, 2, 10));
+    }
+
+    #[test]
+    fn test_b_toggles_office_badge() {
+        let qd = make_quarter_data();
+        let mut bd = BadgeEntryData::default();
+        let mut hd = HolidayData::default();
+        let mut vd = VacationData::default();
+        let mut ed = EventData::default();
+        let today = d(2025, 2, 10);
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, today);
+
+        let key = today.format("%Y-%m-%d").to_string();
+        assert!(!app.badge_data.has(&key));
+
+        app.handle_key(KeyCode::Char('b'), KeyModifiers::empty());
+        assert!(app.badge_data.has(&key));
+
+        app.handle_key(KeyCode::Char('b'), KeyModifiers::empty());
+        assert!(!app.badge_data.has(&key));
+    }
+
+    #[test]
+    fn test_f_toggles_flex_badge() {
+        let qd = make_quarter_data();
+        let mut bd = BadgeEntryData::default();
+        let mut hd = HolidayData::default();
+        let mut vd = VacationData::default();
+        let mut ed = EventData::default();
+        let today = d(2025, 2, 10);
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, today);
+
+        let key = today.format("%Y-%m-%d").to_string();
+        app.handle_key(KeyCode::Char('f'), KeyModifiers::empty());
+        assert!(app.badge_data.has(&key));
+        let entry = app.badge_data.data.iter().find(|e| e.key == key).unwrap();
+        assert_eq!(entry.office, "Flex Credit");
+    }
+
+    #[test]
+    fn test_shift_space_badges_whole_week() {
+        let qd = make_quarter_data();
+        let mut bd = BadgeEntryData::default();
+        let mut hd = HolidayData::default();
+        let mut vd = VacationData::default();
+        let mut ed = EventData::default();
+        let today = d(2025, 2, 12); // Wednesday
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, today);
+
+        app.handle_key(KeyCode::Char(' '), KeyModifiers::SHIFT);
+        let mut keys: Vec<_> = app.badge_data.data.iter().map(|e| e.key.clone()).collect();
+        keys.sort();
+        assert_eq!(
+            keys,
+            vec![
+                "2025-02-10",
+                "2025-02-11",
+                "2025-02-12",
+                "2025-02-13",
+                "2025-02-14"
+            ]
+        );
+        assert!(!app.badge_data.has("2025-02-15"));
+        assert!(!app.badge_data.has("2025-02-16"));
+
+        // Pressed again on a badged day, it clears the week
+        app.handle_key(KeyCode::Char('B'), KeyModifiers::SHIFT);
+        assert!(app.badge_data.is_empty());
+    }
+
+    #[test]
+    fn test_week_toggle_skips_holidays_and_vacations() {
+        let qd = make_quarter_data();
+        let mut bd = BadgeEntryData::default();
+        let mut hd = HolidayData::default();
+        hd.add(Holiday::new("Presidents Day", "2025-02-17"));
+        let mut vd = VacationData::default();
+        vd.add(Vacation::new("Trip", "2025-02-20", "2025-02-21", true));
+        let mut ed = EventData::default();
+        let today = d(2025, 2, 18);
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, today);
+
+        app.handle_key(KeyCode::Char('B'), KeyModifiers::SHIFT);
+        let mut keys: Vec<_> = app.badge_data.data.iter().map(|e| e.key.clone()).collect();
+        keys.sort();
+        assert_eq!(keys, vec!["2025-02-18", "2025-02-19"]);
+    }
+
+    #[test]
+    fn test_show_period_restores_saved_key_or_keeps_today() {
+        let qd = make_quarter_data();
+        let mut bd = BadgeEntryData::default();
+        let mut hd = HolidayData::default();
+        let mut vd = VacationData::default();
+        let mut ed = EventData::default();
+        let today = d(2025, 2, 10);
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, today);
+        assert_eq!(app.current_period_key().as_deref(), Some("Q1_2025"));
+
+        assert!(app.show_period("Q2_2025"));
+        assert_eq!(app.current_period_key().as_deref(), Some("Q2_2025"));
+        assert_eq!(app.selected_date, d(2025, 4, 1));
+
+        // A key that no longer exists leaves the view alone
+        assert!(!app.show_period("Q9_2031"));
+        assert_eq!(app.current_period_key().as_deref(), Some("Q2_2025"));
+    }
+
+    #[test]
+    fn test_b_does_nothing_outside_quarter() {
+        let qd = TimePeriodData::new();
+        let mut bd = BadgeEntryData::default();
+        let mut hd = HolidayData::default();
+        let mut vd = VacationData::default();
+        let mut ed = EventData::default();
+        let today = d(2025, 2, 10);
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, today);
+
+        app.handle_key(KeyCode::Char('b'), KeyModifiers::empty());
+        assert_eq!(app.badge_data.data.len(), 0);
+    }
+
+    #[test]
+    fn test_backup_update_transitions() {
+        let done = ("Backed up locally — now".to_string(), Color::Cyan);
+        assert_eq!(backup_update(Ok(done.clone())), Some(done));
+        assert_eq!(backup_update(Err(TryRecvError::Empty)), None);
+        let (msg, color) = backup_update(Err(TryRecvError::Disconnected)).unwrap();
+        assert!(msg.starts_with("Backup failed"));
+        assert_eq!(color, Color::Red);
+    }
+
+    #[test]
+    fn test_poll_backup_keeps_pending_status_until_result() {
+        let qd = make_quarter_data();
+        let mut bd = BadgeEntryData::default();
+        let mut hd = HolidayData::default();
+        let mut vd = VacationData::default();
+        let mut ed = EventData::default();
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, d(2025, 2, 14));
+        assert!(!app.poll_backup());
+
+        let (tx, rx) = mpsc::channel();
+        app.backup = Some((rx, Instant::now()));
+        app.git_status = Some(("Backing up…".to_string(), Color::Yellow));
+        assert!(app.poll_backup());
+        // Moving around doesn't clear the pending message
+        app.handle_key(KeyCode::Right, KeyModifiers::empty());
+        assert_eq!(app.git_status.as_ref().unwrap().0, "Backing up…");
+        let text = render_app_text_sized(&mut app, 160, 60);
+        assert!(
+            SPINNER
+                .iter()
+                .any(|c| text.contains(&format!("{c} Backing up…")))
+        );
+
+        tx.send(("Backed up & pushed — now".to_string(), Color::Green))
+            .unwrap();
+        assert!(app.poll_backup());
+        assert_eq!(
+            app.git_status.as_ref().unwrap().0,
+            "Backed up & pushed — now"
+        );
+        assert!(app.backup.is_none());
+        assert!(!app.poll_backup());
+    }
+
+    #[test]
+    fn test_git_backup_runs_off_the_ui_thread() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let qd = make_quarter_data();
+        let mut bd = BadgeEntryData::default();
+        let mut hd = HolidayData::default();
+        let mut vd = VacationData::default();
+        let mut ed = EventData::default();
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, d(2025, 2, 14));
+        app.data_dir = tmp.path().to_path_buf();
+
+        app.handle_key(KeyCode::Char('g'), KeyModifiers::empty());
+        assert_eq!(app.git_status.as_ref().unwrap().0, "Backing up…");
+        app.handle_key(KeyCode::Char('g'), KeyModifiers::empty());
+        assert_eq!(
+            app.git_status.as_ref().unwrap().0,
+            "Backup already in progress"
+        );
+
+        app.wait_for_backup();
+        let started = Instant::now();
+        app.handle_key(KeyCode::Char('g'), KeyModifiers::empty());
+        while app.backup.is_some() && started.elapsed() < StdDuration::from_secs(10) {
+            app.poll_backup();
+            std::thread::sleep(StdDuration::from_millis(10));
+        }
+        let (msg, _) = app.git_status.as_ref().unwrap();
+        assert!(msg.contains("not a git repo"), "{msg}");
+    }
+
+    #[test]
+    fn test_needs_redraw_only_when_state_changes() {
+        use crossterm::event::KeyEvent;
+        let key = CEvent::Key(KeyEvent::new(KeyCode::Char('n'), KeyModifiers::empty()));
+        assert!(!needs_redraw(None, false));
+        assert!(!needs_redraw(Some(&CEvent::FocusGained), false));
+        assert!(needs_redraw(Some(&key), false));
+        assert!(needs_redraw(Some(&CEvent::Resize(80, 24)), false));
+        assert!(needs_redraw(None, true));
+    }
+
+    #[test]
+    fn test_roll_over_to_updates_today_once() {
+        let qd = make_quarter_data();
+        let mut bd = BadgeEntryData::default();
+        let mut hd = HolidayData::default();
+        let mut vd = VacationData::default();
+        let mut ed = EventData::default();
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, d(2025, 2, 10));
+        assert!(!app.roll_over_to(d(2025, 2, 10)));
+        assert!(app.roll_over_to(d(2025, 2, 11)));
+        assert_eq!(app.today, d(2025, 2, 11));
+        assert!(!app.roll_over_to(d(2025, 2, 11)));
+    }
+
+    #[test]
+    fn test_t_returns_to_today() {
+        let qd = make_quarter_data();
+        let mut bd = BadgeEntryData::default();
+        let mut hd = HolidayData::default();
+        let mut vd = VacationData::default();
+        let mut ed = EventData::default();
+        let today = d(2025, 2, 10);
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, today);
+
+        app.handle_key(KeyCode::Char('n'), KeyModifiers::empty());
+        assert_eq!(app.current_period_key().as_deref(), Some("Q2_2025"));
+        app.handle_key(KeyCode::Char('t'), KeyModifiers::empty());
+        assert_eq!(app.selected_date, today);
+        assert_eq!(app.current_period_key().as_deref(), Some("Q1_2025"));
+
+        // Also works from a date outside every period
+        app.jump_to_date(d(2026, 5, 1));
+        assert!(app.current_period_key().is_none());
+        app.handle_key(KeyCode::Home, KeyModifiers::empty());
+        assert_eq!(app.selected_date, today);
+        assert_eq!(app.current_period_key().as_deref(), Some("Q1_2025"));
+    }
+
+    #[test]
+    fn test_add_mode_enter_saves_event() {
+        let qd = make_quarter_data();
+        let mut bd = BadgeEntryData::default();
+        let mut hd = HolidayData::default();
+        let mut vd = VacationData::default();
+        let mut ed = EventData::default();
+        let today = d(2025, 2, 10);
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, today);
+
+        app.handle_key(KeyCode::Char('a'), KeyModifiers::empty());
+        app.handle_key(KeyCode::Char('T'), KeyModifiers::empty());
+        app.handle_key(KeyCode::Char('e'), KeyModifiers::empty());
+        app.handle_key(KeyCode::Char('s'), KeyModifiers::empty());
+        app.handle_key(KeyCode::Char('t'), KeyModifiers::empty());
+        app.handle_key(KeyCode::Enter, KeyModifiers::empty());
+
+        assert_eq!(app.event_data.events.len(), 1);
+        assert_eq!(app.event_data.events[0].description, "Test");
+        assert_eq!(app.event_data.events[0].date, "2025-02-10");
+    }
+
+    #[test]
+    fn test_add_recurring_event_and_delete_one_occurrence_or_series() {
+        let qd = make_quarter_data();
+        let mut bd = BadgeEntryData::default();
+        let mut hd = HolidayData::default();
+        let mut vd = VacationData::default();
+        let mut ed = EventData::default();
+        let today = d(2025, 2, 10); // Monday
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, today);
+
+        app.handle_key(KeyCode::Char('a'), KeyModifiers::empty());
+        app.handle_key(KeyCode::Tab, KeyModifiers::empty());
+        let text = render_app_text_sized(&mut app, 160, 60);
+        assert!(text.contains("Add event (weekly on Mon): _"));
+        for c in "Standup".chars() {
+            app.handle_key(KeyCode::Char(c), KeyModifiers::empty());
+        }
+        app.handle_key(KeyCode::Enter, KeyModifiers::empty());
+        assert_eq!(app.event_data.len(), 1);
+        assert_eq!(app.event_data.events_on(d(2025, 3, 3)).len(), 1);
+
+        // Enter on a recurring event removes only that day
+        app.selected_date = d(2025, 2, 17);
+        app.handle_key(KeyCode::Char('d'), KeyModifiers::empty());
+        app.handle_key(KeyCode::Enter, KeyModifiers::empty());
+        assert!(app.event_data.events_on(d(2025, 2, 17)).is_empty());
+        assert_eq!(app.event_data.events_on(d(2025, 2, 24)).len(), 1);
+
+        // D removes the whole series
+        app.selected_date = d(2025, 2, 24);
+        app.handle_key(KeyCode::Char('d'), KeyModifiers::empty());
+        app.handle_key(KeyCode::Char('D'), KeyModifiers::SHIFT);
+        assert!(app.event_data.is_empty());
+    }
+
+    #[test]
+    fn test_tab_cycles_add_recurrence() {
+        let date = d(2025, 2, 10);
+        let weekly = next_recurrence(None, date);
+        assert_eq!(weekly.unwrap().label(), "weekly on Mon");
+        let monthly = next_recurrence(weekly, date);
+        assert_eq!(monthly.unwrap().label(), "monthly on day 10");
+        assert_eq!(next_recurrence(monthly, date), None);
+    }
+
+    #[test]
+    fn test_add_mode_esc_discards() {
+        let qd = make_quarter_data();
+        let mut bd = BadgeEntryData::default();
+        let mut hd = HolidayData::default();
+        let mut vd = VacationData::default();
+        let mut ed = EventData::default();
+        let today = d(2025, 2, 10);
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, today);
+
+        app.handle_key(KeyCode::Char('a'), KeyModifiers::empty());
+        app.handle_key(KeyCode::Char('x'), KeyModifiers::empty());
+        app.handle_key(KeyCode::Esc, KeyModifiers::empty());
+
+        assert!(app.event_data.events.is_empty());
+    }
+
+    #[test]
+    fn test_add_mode_empty_buffer_no_event() {
+        let qd = make_quarter_data();
+        let mut bd = BadgeEntryData::default();
+        let mut hd = HolidayData::default();
+        let mut vd = VacationData::default();
+        let mut ed = EventData::default();
+        let today = d(2025, 2, 10);
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, today);
+
+        app.handle_key(KeyCode::Char('a'), KeyModifiers::empty());
+        app.handle_key(KeyCode::Enter, KeyModifiers::empty());
+
+        assert!(app.event_data.events.is_empty());
+    }
+
+    #[test]
+    fn test_add_mode_backspace_in_add_mode() {
+        let qd = make_quarter_data();
+        let mut bd = BadgeEntryData::default();
+        let mut hd = HolidayData::default();
+        let mut vd = VacationData::default();
+        let mut ed = EventData::default();
+        let today = d(2025, 2, 10);
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, today);
+
+        app.handle_key(KeyCode::Char('a'), KeyModifiers::empty());
+        app.handle_key(KeyCode::Char('x'), KeyModifiers::empty());
+        app.handle_key(KeyCode::Backspace, KeyModifiers::empty());
+        app.handle_key(KeyCode::Enter, KeyModifiers::empty());
+
+        // buffer was "x", backspace removed it, enter on empty buffer → no event
+        assert!(app.event_data.events.is_empty());
+    }
+
+    #[test]
+    fn test_delete_mode_enter_removes_event() {
+        let qd = make_quarter_data();
+        let mut bd = BadgeEntryData::default();
+        let mut hd = HolidayData::default();
+        let mut vd = VacationData::default();
+        let mut ed = EventData::default();
+        ed.add(Event::new("2025-02-10", "To remove"));
+        let today = d(2025, 2, 10);
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, today);
+
+        app.handle_key(KeyCode::Char('d'), KeyModifiers::empty());
+        app.handle_key(KeyCode::Enter, KeyModifiers::empty());
+
+        assert!(app.event_data.events.is_empty());
+    }
+
+    #[test]
+    fn test_edit_event_replaces_description() {
+        let qd = make_quarter_data();
+        let mut bd = BadgeEntryData::default();
+        let mut hd = HolidayData::default();
+        let mut vd = VacationData::default();
+        let mut ed = EventData::default();
+        ed.add(Event::new("2025-02-10", "Standup"));
+        ed.add(Event::new("2025-02-10", "Tema lunch"));
+        let today = d(2025, 2, 10);
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, today);
+
+        app.handle_key(KeyCode::Char('d'), KeyModifiers::empty());
+        app.handle_key(KeyCode::Down, KeyModifiers::empty());
+        app.handle_key(KeyCode::Char('e'), KeyModifiers::empty());
+        assert!(app.mode == Mode::Edit);
+        assert_eq!(app.input_buffer, "Tema lunch");
+
+        for _ in 0.."Tema lunch".len() {
+            app.handle_key(KeyCode::Backspace, KeyModifiers::empty());
+        }
+        for c in "Team lunch".chars() {
+            app.handle_key(KeyCode::Char(c), KeyModifiers::empty());
+        }
+        app.handle_key(KeyCode::Enter, KeyModifiers::empty());
+
+        assert!(app.mode == Mode::Normal);
+        let descs: Vec<_> = app
+            .event_data
+            .events
+            .iter()
+            .map(|e| e.description.as_str())
+            .collect();
+        assert_eq!(descs, vec!["Standup", "Team lunch"]);
+        assert!(!descs.contains(&"Tema lunch"));
+
+        // u restores the original text
+        app.handle_key(KeyCode::Char('u'), KeyModifiers::empty());
+        assert_eq!(app.event_data.events[1].description, "Tema lunch");
+    }
+
+    #[test]
+    fn test_edit_event_esc_keeps_original() {
+        let qd = make_quarter_data();
+        let mut bd = BadgeEntryData::default();
+        let mut hd = HolidayData::default();
+        let mut vd = VacationData::default();
+        let mut ed = EventData::default();
+        ed.add(Event::new("2025-02-10", "Standup"));
+        let today = d(2025, 2, 10);
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, today);
+
+        app.handle_key(KeyCode::Char('d'), KeyModifiers::empty());
+        app.handle_key(KeyCode::Char('e'), KeyModifiers::empty());
+        app.handle_key(KeyCode::Char('!'), KeyModifiers::empty());
+        app.handle_key(KeyCode::Esc, KeyModifiers::empty());
+
+        assert!(app.mode == Mode::Normal);
+        assert!(app.input_buffer.is_empty());
+        assert_eq!(app.event_data.events[0].description, "Standup");
+    }
+
+    #[test]
+    fn test_delete_mode_cursor_navigation() {
+        let qd = make_quarter_data();
+        let mut bd = BadgeEntryData::default();
+        let mut hd = HolidayData::default();
+        let mut vd = VacationData::default();
+        let mut ed = EventData::default();
+        ed.add(Event::new("2025-02-10", "First"));
+        ed.add(Event::new("2025-02-10", "Second"));
+        let today = d(2025, 2, 10);
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, today);
+
+        app.handle_key(KeyCode::Char('d'), KeyModifiers::empty());
+        assert_eq!(app.cursor_index, 0);
+
+        app.handle_key(KeyCode::Down, KeyModifiers::empty());
+        assert_eq!(app.cursor_index, 1);
+
+        app.handle_key(KeyCode::Up, KeyModifiers::empty());
+        assert_eq!(app.cursor_index, 0);
+    }
+
+    #[test]
+    fn test_search_mode_char_fills_buffer() {
+        let qd = make_quarter_data();
+        let mut bd = BadgeEntryData::default();
+        let mut hd = HolidayData::default();
+        let mut vd = VacationData::default();
+        let mut ed = EventData::default();
+        let today = d(2025, 2, 10);
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, today);
+
+        app.handle_key(KeyCode::Char('s'), KeyModifiers::empty());
+        app.handle_key(KeyCode::Char('q'), KeyModifiers::empty());
+        app.handle_key(KeyCode::Char('1'), KeyModifiers::empty());
+
+        assert_eq!(app.input_buffer, "q1");
+    }
+
+    #[test]
+    fn test_what_if_toggle_w() {
+        let qd = make_quarter_data();
+        let mut bd = BadgeEntryData::default();
+        let mut hd = HolidayData::default();
+        let mut vd = VacationData::default();
+        let mut ed = EventData::default();
+        let today = d(2025, 2, 10);
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, today);
+
+        assert!(!app.is_what_if());
+
+        // Enter what-if, add a badge
+        app.handle_key(KeyCode::Char('w'), KeyModifiers::empty());
+        assert!(app.is_what_if());
+        app.handle_key(KeyCode::Char('b'), KeyModifiers::empty());
+        assert_eq!(app.badge_data.data.len(), 1);
+
+        // Exit what-if — badge changes discarded
+        app.handle_key(KeyCode::Char('w'), KeyModifiers::empty());
+        assert!(!app.is_what_if());
+        assert_eq!(app.badge_data.data.len(), 0);
+    }
+
+    #[test]
+    fn test_n_advances_quarter() {
+        let qd = make_quarter_data();
+        let mut bd = BadgeEntryData::default();
+        let mut hd = HolidayData::default();
+        let mut vd = VacationData::default();
+        let mut ed = EventData::default();
+        let today = d(2025, 2, 10);
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, today);
+
+        assert_eq!(
+            app.current_period().map(|q| q.key.as_str()),
+            Some("Q1_2025")
+        );
+        app.handle_key(KeyCode::Char('n'), KeyModifiers::empty());
+        assert_eq!(
+            app.current_period().map(|q| q.key.as_str()),
+            Some("Q2_2025")
+        );
+    }
+
+    #[test]
+    fn test_p_retreats_quarter() {
+        let qd = make_quarter_data();
+        let mut bd = BadgeEntryData::default();
+        let mut hd = HolidayData::default();
+        let mut vd = VacationData::default();
+        let mut ed = EventData::default();
+        // Start in Q2
+        let today = d(2025, 5, 10);
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, today);
+
+        assert_eq!(
+            app.current_period().map(|q| q.key.as_str()),
+            Some("Q2_2025")
+        );
+        app.handle_key(KeyCode::Char('p'), KeyModifiers::empty());
+        assert_eq!(
+            app.current_period().map(|q| q.key.as_str()),
+            Some("Q1_2025")
+        );
+    }
+
+    /// Uneven periods listed out of order: Q4_2025 runs Nov–Jan.
+    fn make_uneven_quarter_data() -> TimePeriodData {
+        let mut data = TimePeriodData::new();
+        for (key, start, end) in [
+            ("Q1_2026", "2026-02-01", "2026-04-30"),
+            ("Q3_2025", "2025-08-01", "2025-10-31"),
+            ("Q4_2025", "2025-11-01", "2026-01-31"),
+        ] {
+            let mut tp = TimePeriod {
+                key: key.to_string(),
+                name: key.to_string(),
+                start_date_raw: start.to_string(),
+                end_date_raw: end.to_string(),
+                start_date: None,
+                end_date: None,
+                ..Default::default()
+            };
+            tp.parse_dates().unwrap();
+            data.add(tp);
+        }
+        data
+    }
+
+    #[test]
+    fn test_n_reaches_adjacent_uneven_quarter() {
+        let mut bd = BadgeEntryData::default();
+        let mut hd = HolidayData::default();
+        let mut vd = VacationData::default();
+        let mut ed = EventData::default();
+        // Late in Q3: a fixed three-month jump would land in Q1_2026
+        let today = d(2025, 10, 20);
+        let mut app = make_test_app(
+            make_uneven_quarter_data(),
+            &mut bd,
+            &mut hd,
+            &mut vd,
+            &mut ed,
+            today,
+        );
+        app.nav_date = today;
+        app.handle_key(KeyCode::Char('n'), KeyModifiers::empty());
+        assert_eq!(
+            app.current_period().map(|q| q.key.as_str()),
+            Some("Q4_2025")
+        );
+        assert_eq!(app.selected_date, d(2025, 11, 1));
+    }
+
+    #[test]
+    fn test_p_reaches_adjacent_uneven_quarter() {
+        let mut bd = BadgeEntryData::default();
+        let mut hd = HolidayData::default();
+        let mut vd = VacationData::default();
+        let mut ed = EventData::default();
+        let today = d(2026, 2, 10);
+        let mut app = make_test_app(
+            make_uneven_quarter_data(),
+            &mut bd,
+            &mut hd,
+            &mut vd,
+            &mut ed,
+            today,
+        );
+        app.handle_key(KeyCode::Char('p'), KeyModifiers::empty());
+        assert_eq!(
+            app.current_period().map(|q| q.key.as_str()),
+            Some("Q4_2025")
+        );
+    }
+
+    #[test]
+    fn test_n_outside_periods_falls_back_to_month_jump() {
+        let mut bd = BadgeEntryData::default();
+        let mut hd = HolidayData::default();
+        let mut vd = VacationData::default();
+        let mut ed = EventData::default();
+        let today = d(2025, 5, 10);
+        let mut app = make_test_app(
+            make_uneven_quarter_data(),
+            &mut bd,
+            &mut hd,
+            &mut vd,
+            &mut ed,
+            today,
+        );
+        app.nav_date = today;
+        app.handle_key(KeyCode::Char('n'), KeyModifiers::empty());
+        assert_eq!(app.nav_date, d(2025, 8, 10));
+        assert_eq!(
+            app.current_period().map(|q| q.key.as_str()),
+            Some("Q3_2025")
+        );
+    }
+
+    #[test]
+    fn test_jump_to_date_from_february_to_may() {
+        let qd = make_quarter_data();
+        let mut bd = BadgeEntryData::default();
+        let mut hd = HolidayData::default();
+        let mut vd = VacationData::default();
+        let mut ed = EventData::default();
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, d(2025, 2, 10));
+
+        app.handle_key(KeyCode::Char('J'), KeyModifiers::empty());
+        for c in "2025-05-2x".chars() {
+            app.handle_key(KeyCode::Char(c), KeyModifiers::empty());
+        }
+        app.handle_key(KeyCode::Enter, KeyModifiers::empty());
+        assert!(app.mode == Mode::JumpToDate);
+        assert!(render_app_text(&mut app).contains("Invalid date"));
+
+        app.handle_key(KeyCode::Backspace, KeyModifiers::empty());
+        app.handle_key(KeyCode::Char('0'), KeyModifiers::empty());
+        assert!(render_app_text(&mut app).contains("Go to date (YYYY-MM-DD): 2025-05-20_"));
+        app.handle_key(KeyCode::Enter, KeyModifiers::empty());
+        assert!(app.mode == Mode::Normal);
+        assert_eq!(app.selected_date, d(2025, 5, 20));
+        assert_eq!(
+            app.current_period().map(|q| q.key.as_str()),
+            Some("Q2_2025")
+        );
+    }
+
+    #[test]
+    fn test_q_returns_true() {
+        let qd = make_quarter_data();
+        let mut bd = BadgeEntryData::default();
+        let mut hd = HolidayData::default();
+        let mut vd = VacationData::default();
+        let mut ed = EventData::default();
+        let today = d(2025, 2, 10);
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, today);
+
+        let quit = app.handle_key(KeyCode::Char('q'), KeyModifiers::empty());
+        assert!(quit);
+    }
+
+    #[test]
+    fn test_ctrl_c_returns_true() {
+        let qd = make_quarter_data();
+        let mut bd = BadgeEntryData::default();
+        let mut hd = HolidayData::default();
+        let mut vd = VacationData::default();
+        let mut ed = EventData::default();
+        let today = d(2025, 2, 10);
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, today);
+
+        let quit = app.handle_key(KeyCode::Char('c'), KeyModifiers::CONTROL);
+        assert!(quit);
+    }
+
+    #[test]
+    fn test_q_in_what_if_restores_data() {
+        let qd = make_quarter_data();
+        let mut bd = BadgeEntryData::default();
+        let mut hd = HolidayData::default();
+        let mut vd = VacationData::default();
+        let mut ed = EventData::default();
+        let today = d(2025, 2, 10);
+        // Pre-badge a date
+        bd.add(BadgeEntry::new(today, "McLean, VA", false));
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, today);
+
+        // Enter what-if, remove the badge
+        app.handle_key(KeyCode::Char('w'), KeyModifiers::empty());
+        app.handle_key(KeyCode::Char('b'), KeyModifiers::empty()); // toggle off
+        assert_eq!(app.badge_data.data.len(), 0);
+
+        // Quit — should restore original data
+        let quit = app.handle_key(KeyCode::Char('q'), KeyModifiers::empty());
+        assert!(quit);
+        assert_eq!(app.badge_data.data.len(), 1);
+    }
+
+    #[test]
+    fn test_n_at_last_quarter_stays() {
+        // make_quarter_data has Q1 (Jan-Mar) and Q2 (Apr-Jun) 2025 only.
+        // At last quarter, n keeps us there (Go behavior: adjacent in list).
+        let qd = make_quarter_data();
+        let mut bd = BadgeEntryData::default();
+        let mut hd = HolidayData::default();
+        let mut vd = VacationData::default();
+        let mut ed = EventData::default();
+        let today = d(2025, 5, 10);
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, today);
+
+        assert_eq!(
+            app.current_period().map(|q| q.key.as_str()),
+            Some("Q2_2025")
+        );
+        app.handle_key(KeyCode::Char('n'), KeyModifiers::empty());
+        assert_eq!(
+            app.current_period().map(|q| q.key.as_str()),
+            Some("Q2_2025"),
+            "n at last quarter keeps us there"
+        );
+    }
+
+    #[test]
+    fn test_p_at_first_quarter_stays() {
+        // At first quarter, p keeps us there (Go behavior: adjacent in list).
+        let qd = make_quarter_data();
+        let mut bd = BadgeEntryData::default();
+        let mut hd = HolidayData::default();
+        let mut vd = VacationData::default();
+        let mut ed = EventData::default();
+        let today = d(2025, 2, 10);
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, today);
+
+        assert_eq!(
+            app.current_period().map(|q| q.key.as_str()),
+            Some("Q1_2025")
+        );
+        app.handle_key(KeyCode::Char('p'), KeyModifiers::empty());
+        assert_eq!(
+            app.current_period().map(|q| q.key.as_str()),
+            Some("Q1_2025"),
+            "p at first quarter keeps us there"
+        );
+    }
+
+    #[test]
+    fn test_year_stats_computed_for_current_year() {
+        let qd = make_quarter_data();
+        let mut bd = BadgeEntryData::default();
+        let mut hd = HolidayData::default();
+        let mut vd = VacationData::default();
+        let mut ed = EventData::default();
+        let today = d(2025, 2, 10);
+        let app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, today);
+
+        assert!(app.year_stats.is_some(), "year_stats should be populated");
+    }
+
+    #[test]
+    fn test_year_stats_cleared_when_no_quarter() {
+        let qd = TimePeriodData::new();
+        let mut bd = BadgeEntryData::default();
+        let mut hd = HolidayData::default();
+        let mut vd = VacationData::default();
+        let mut ed = EventData::default();
+        let today = d(2025, 5, 10);
+        let app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, today);
+
+        assert!(app.current_period().is_none());
+        assert!(
+            app.year_stats.is_none(),
+            "year_stats should be None when no quarter"
+        );
+    }
+
+    #[test]
+    fn test_b_sets_data_dirty() {
+        let qd = make_quarter_data();
+        let mut bd = BadgeEntryData::default();
+        let mut hd = HolidayData::default();
+        let mut vd = VacationData::default();
+        let mut ed = EventData::default();
+        let today = d(2025, 2, 10);
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, today);
+
+        assert!(!app.data_dirty);
+        app.handle_key(KeyCode::Char('b'), KeyModifiers::empty());
+        assert!(
+            app.data_dirty,
+            "data_dirty should be true after badge toggle"
+        );
+    }
+
+    #[test]
+    fn test_f_sets_data_dirty() {
+        let qd = make_quarter_data();
+        let mut bd = BadgeEntryData::default();
+        let mut hd = HolidayData::default();
+        let mut vd = VacationData::default();
+        let mut ed = EventData::default();
+        let today = d(2025, 2, 10);
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, today);
+
+        assert!(!app.data_dirty);
+        app.handle_key(KeyCode::Char('f'), KeyModifiers::empty());
+        assert!(
+            app.data_dirty,
+            "data_dirty should be true after flex toggle"
+        );
+    }
+
+    #[test]
+    fn test_what_if_badge_does_not_set_dirty() {
+        let qd = make_quarter_data();
+        let mut bd = BadgeEntryData::default();
+        let mut hd = HolidayData::default();
+        let mut vd = VacationData::default();
+        let mut ed = EventData::default();
+        let today = d(2025, 2, 10);
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, today);
+
+        app.handle_key(KeyCode::Char('w'), KeyModifiers::empty());
+        assert!(app.is_what_if());
+
+        app.handle_key(KeyCode::Char('b'), KeyModifiers::empty());
+        assert!(
+            !app.data_dirty,
+            "data_dirty should remain false in what-if mode"
+        );
+    }
+
+    #[test]
+    fn test_compare_view_reflects_scenario_badge_counts() {
+        use crate::data::persistence::save_json_to;
+        let tmp = tempfile::TempDir::new().unwrap();
+        let sdir = scenario::scenarios_dir(tmp.path());
+        let mut light = BadgeEntryData::default();
+        light.add(BadgeEntry::new(d(2025, 1, 6), "Office", false));
+        let mut heavy = BadgeEntryData::default();
+        for day in 6..=8 {
+            heavy.add(BadgeEntry::new(d(2025, 1, day), "Office", false));
+        }
+        save_json_to(&sdir, "a-light.json", &light, 0).unwrap();
+        save_json_to(&sdir, "b-heavy.json", &heavy, 0).unwrap();
+
+        let qd = make_quarter_data();
+        let mut bd = BadgeEntryData::default();
+        let mut hd = HolidayData::default();
+        let mut vd = VacationData::default();
+        let mut ed = EventData::default();
+        let mut app = App::new(
+            qd,
+            &mut bd,
+            &mut hd,
+            &mut vd,
+            &mut ed,
+            AppSettings::default(),
+            d(2025, 2, 10),
+            tmp.path().to_path_buf(),
+        );
+
+        app.handle_key(KeyCode::Char('c'), KeyModifiers::empty());
+        assert!(app.view_state == ViewState::Compare);
+        assert_eq!(app.compare_names, vec!["a-light", "b-heavy"]);
+
+        // Left column → a-light, right column → b-heavy
+        app.handle_key(KeyCode::Right, KeyModifiers::empty());
+        app.handle_key(KeyCode::Down, KeyModifiers::empty());
+        let left = app.compare_stats[0].as_ref().unwrap();
+        let right = app.compare_stats[1].as_ref().unwrap();
+        assert_eq!(left.days_badged_in, 1.0);
+        assert_eq!(right.days_badged_in, 3.0);
+
+        let rows = compare_rows(&app.compare_stats);
+        let badged = rows
+            .iter()
+            .find(|(l, _)| *l == "Badged / Required")
+            .unwrap();
+        assert!(badged.1[0].starts_with("1 / "));
+        assert!(badged.1[1].starts_with("3 / "));
+    }
+
+    #[test]
+    fn test_undo_and_redo_badge_toggle() {
+        let qd = make_quarter_data();
+        let mut bd = BadgeEntryData::default();
+        bd.add(BadgeEntry::new(d(2025, 2, 7), "HQ", false));
+        let mut hd = HolidayData::default();
+        let mut vd = VacationData::default();
+        let mut ed = EventData::default();
+        let dir = tempfile::tempdir().unwrap();
+        let mut app = App::new(
+            qd,
+            &mut bd,
+            &mut hd,
+            &mut vd,
+            &mut ed,
+            AppSettings::default(),
+            d(2025, 2, 10),
+            dir.path().to_path_buf(),
+        );
+        let badge_keys = |app: &App| {
+            let mut keys: Vec<String> = app
+                .badge_data
+                .get_badge_map(d(2025, 1, 1), d(2025, 3, 31))
+                .into_keys()
+                .collect();
+            keys.sort();
+            keys
+        };
+        let before = badge_keys(&app);
+
+        app.handle_key(KeyCode::Char('b'), KeyModifiers::empty());
+        assert!(app.badge_data.has("2025-02-10"));
+        app.handle_key(KeyCode::Char('u'), KeyModifiers::empty());
+        assert_eq!(badge_keys(&app), before);
+        assert!(!app.badge_data.has("2025-02-10"));
+        let saved = BadgeEntryData::load_from(dir.path()).unwrap();
+        assert!(!saved.has("2025-02-10"));
+
+        app.handle_key(KeyCode::Char('r'), KeyModifiers::CONTROL);
+        assert!(app.badge_data.has("2025-02-10"));
+
+        // A fresh edit clears the redo history
+        app.handle_key(KeyCode::Char('u'), KeyModifiers::empty());
+        app.handle_key(KeyCode::Right, KeyModifiers::empty());
+        app.handle_key(KeyCode::Char('f'), KeyModifiers::empty());
+        app.handle_key(KeyCode::Char('r'), KeyModifiers::CONTROL);
+        assert!(!app.badge_data.has("2025-02-10"));
+        assert!(app.badge_data.has("2025-02-11"));
+    }
+
+    #[test]
+    fn test_undo_stays_inside_what_if() {
+        let qd = make_quarter_data();
+        let mut bd = BadgeEntryData::default();
+        let mut hd = HolidayData::default();
+        let mut vd = VacationData::default();
+        let mut ed = EventData::default();
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, d(2025, 2, 10));
+
+        app.handle_key(KeyCode::Char('b'), KeyModifiers::empty());
+        app.handle_key(KeyCode::Char('w'), KeyModifiers::empty());
+        // Undo history from before what-if isn't reachable inside it
+        app.handle_key(KeyCode::Char('u'), KeyModifiers::empty());
+        assert!(app.badge_data.has("2025-02-10"));
+
+        app.handle_key(KeyCode::Right, KeyModifiers::empty());
+        app.handle_key(KeyCode::Char('b'), KeyModifiers::empty());
+        assert!(app.badge_data.has("2025-02-11"));
+        app.handle_key(KeyCode::Char('u'), KeyModifiers::empty());
+        assert!(!app.badge_data.has("2025-02-11"));
+        assert!(app.is_what_if());
+
+        // Leaving what-if restores real data; simulated edits can't be redone into it
+        app.handle_key(KeyCode::Char('b'), KeyModifiers::empty());
+        app.handle_key(KeyCode::Char('w'), KeyModifiers::empty());
+        app.handle_key(KeyCode::Char('u'), KeyModifiers::empty());
+        app.handle_key(KeyCode::Char('r'), KeyModifiers::CONTROL);
+        assert_eq!(app.badge_data.data.len(), 1);
+        assert!(app.badge_data.has("2025-02-10"));
+    }
+
+    #[test]
+    fn test_undo_event_delete_and_vacation_delete() {
+        let qd = make_quarter_data();
+        let mut bd = BadgeEntryData::default();
+        let mut hd = HolidayData::default();
+        let mut vd = VacationData::default();
+        vd.add(Vacation::new("Hawaii", "2025-05-10", "2025-05-17", true));
+        let mut ed = EventData::default();
+        ed.add(Event::new("2025-02-10", "Offsite"));
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, d(2025, 2, 10));
+
+        app.handle_key(KeyCode::Char('d'), KeyModifiers::empty());
+        app.handle_key(KeyCode::Enter, KeyModifiers::empty());
+        assert!(app.event_data.events.is_empty());
+
+        app.handle_key(KeyCode::Char('v'), KeyModifiers::empty());
+        app.handle_key(KeyCode::Char('x'), KeyModifiers::empty());
+        app.handle_key(KeyCode::Char('y'), KeyModifiers::empty());
+        app.handle_key(KeyCode::Esc, KeyModifiers::empty());
+        assert!(app.vacation_data.vacations.is_empty());
+
+        app.handle_key(KeyCode::Char('u'), KeyModifiers::empty());
+        assert_eq!(app.vacation_data.vacations.len(), 1);
+        assert!(app.event_data.events.is_empty());
+        app.handle_key(KeyCode::Char('u'), KeyModifiers::empty());
+        assert_eq!(app.event_data.events[0].description, "Offsite");
+        app.handle_key(KeyCode::Char('u'), KeyModifiers::empty());
+        assert_eq!(app.git_status.as_ref().unwrap().0, "Nothing to undo");
+    }
+
+    #[test]
+    fn test_reload_repopulates_data_from_disk() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        crate::cmd::init::run_in_dir(tmp.path()).unwrap();
+        let mut on_disk = HolidayData::load_from(tmp.path()).unwrap();
+        on_disk.add(Holiday::new("Edited Elsewhere", "2025-02-14"));
+        on_disk.save_to(tmp.path(), 0).unwrap();
+        let mut settings = AppSettings::load_from(tmp.path()).unwrap();
+        settings.theme = "light".to_string();
+        settings.save_to(tmp.path()).unwrap();
+
+        let qd = make_quarter_data();
+        let mut bd = BadgeEntryData::default();
+        let mut hd = HolidayData::default();
+        let mut vd = VacationData::default();
+        let mut ed = EventData::default();
+        let mut app = App::new(
+            qd,
+            &mut bd,
+            &mut hd,
+            &mut vd,
+            &mut ed,
+            AppSettings::default(),
+            d(2025, 2, 10),
+            tmp.path().to_path_buf(),
+        );
+        assert!(app.holiday_data.is_empty());
+
+        // Anything but 'y' cancels
+        app.handle_key(KeyCode::Char('R'), KeyModifiers::SHIFT);
+        app.handle_key(KeyCode::Char('n'), KeyModifiers::empty());
+        assert!(app.holiday_data.is_empty());
+
+        app.handle_key(KeyCode::Char('R'), KeyModifiers::SHIFT);
+        app.handle_key(KeyCode::Char('y'), KeyModifiers::empty());
+        assert_eq!(app.holiday_data.len(), on_disk.len());
+        assert_eq!(app.vacation_data.len(), 1);
+        assert_eq!(app.event_data.len(), 1);
+        assert_eq!(app.time_period_data.len(), 8);
+        assert_eq!(app.settings.theme, "light");
+        assert_eq!(app.theme, Theme::light());
+        assert!(app.mode == Mode::Normal);
+    }
+
+    #[test]
+    fn test_badge_uses_period_office_override() {
+        let mut qd = make_quarter_data();
+        let mut q3 = TimePeriod {
+            key: "Q3_2025".to_string(),
+            name: "Q3".to_string(),
+            start_date_raw: "2025-07-01".to_string(),
+            end_date_raw: "2025-09-30".to_string(),
+            default_office: Some("New Job HQ".to_string()),
+            flex_credit: Some("New Job Flex".to_string()),
+            ..Default::default()
+        };
+        q3.parse_dates().unwrap();
+        qd.add(q3);
+        let mut bd = BadgeEntryData::default();
+        let mut hd = HolidayData::default();
+        let mut vd = VacationData::default();
+        let mut ed = EventData::default();
+        let today = d(2025, 8, 4);
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, today);
+
+        app.handle_key(KeyCode::Char('b'), KeyModifiers::empty());
+        assert_eq!(
+            app.badge_data.get("2025-08-04").unwrap().office,
+            "New Job HQ"
+        );
+
+        app.handle_key(KeyCode::Right, KeyModifiers::empty());
+        app.handle_key(KeyCode::Char('f'), KeyModifiers::empty());
+        assert_eq!(
+            app.badge_data.get("2025-08-05").unwrap().office,
+            "New Job Flex"
+        );
+
+        // Periods without an override fall back to the global settings
+        app.handle_key(KeyCode::Char('p'), KeyModifiers::empty());
+        app.handle_key(KeyCode::Char('b'), KeyModifiers::empty());
+        assert_eq!(
+            app.badge_data.get("2025-04-01").unwrap().office,
+            AppSettings::default().default_office
+        );
+    }
+
+    fn render_app_text(app: &mut App) -> String {
+        render_app_text_sized(app, 160, 60)
+    }
 
-        app.handle_key(KeyCode::Char('b'), KeyModifiers::empty());
-        assert_eq!(app.badge_data.data.len(), 0);
+    fn render_app_text_sized(app: &mut App, width: u16, height: u16) -> String {
+        use ratatui::{Terminal, backend::TestBackend};
+        let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+        terminal.draw(|f| app.render(f)).unwrap();
+        let buffer = terminal.backend().buffer();
+        buffer
+            .content()
+            .chunks(buffer.area.width as usize)
+            .map(|row| row.iter().map(|c| c.symbol()).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
     }
 
     #[test]
-    fn test_add_mode_enter_saves_event() {
+    fn test_achieved_period_collapses_stats() {
         let qd = make_quarter_data();
         let mut bd = BadgeEntryData::default();
+        let mut day = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+        while day <= NaiveDate::from_ymd_opt(2025, 3, 14).unwrap() {
+            if day.weekday().num_days_from_monday() < 5 {
+                bd.add(BadgeEntry::new(day, "McLean, VA", false));
+            }
+            day = day.succ_opt().unwrap();
+        }
         let mut hd = HolidayData::default();
         let mut vd = VacationData::default();
         let mut ed = EventData::default();
-        let today = d(2025, 2, 10);
+        let today = NaiveDate::from_ymd_opt(2025, 3, 17).unwrap();
         let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, today);
+        assert_eq!(
+            app.active_stats.as_ref().unwrap().compliance_status,
+            "Achieved"
+        );
 
-        app.handle_key(KeyCode::Char('a'), KeyModifiers::empty());
-        app.handle_key(KeyCode::Char('T'), KeyModifiers::empty());
-        app.handle_key(KeyCode::Char('e'), KeyModifiers::empty());
-        app.handle_key(KeyCode::Char('s'), KeyModifiers::empty());
-        app.handle_key(KeyCode::Char('t'), KeyModifiers::empty());
-        app.handle_key(KeyCode::Enter, KeyModifiers::empty());
-
-        assert_eq!(app.event_data.events.len(), 1);
-        assert_eq!(app.event_data.events[0].description, "Test");
-        assert_eq!(app.event_data.events[0].date, "2025-02-10");
+        let text = render_app_text(&mut app);
+        assert!(text.contains("DONE"));
+        assert!(text.contains("Banked Days"));
+        assert!(!text.contains("Days Ahead of Pace"));
+        assert!(!text.contains("Skippable Days"));
+        assert!(!text.contains("Still Needed"));
+
+        // D toggles back to the full detail view
+        app.handle_key(KeyCode::Char('D'), KeyModifiers::SHIFT);
+        let text = render_app_text(&mut app);
+        assert!(text.contains("Days Ahead of Pace"));
+        assert!(text.contains("Still Needed"));
+        assert!(!text.contains("Banked Days"));
+        assert!(text.contains("Projected Final"));
+        // Longest run: every weekday from Jan 1 through Fri Mar 14
+        let streak = text
+            .lines()
+            .find(|l| l.contains("Streak (Current / Longest)"))
+            .unwrap();
+        assert!(streak.contains("/ 53 "), "got: {}", streak);
     }
 
     #[test]
-    fn test_add_mode_esc_discards() {
+    fn test_w_toggles_week_breakdown_panel() {
         let qd = make_quarter_data();
         let mut bd = BadgeEntryData::default();
+        // Week of Jan 6: three office days
+        for day in 6..=8 {
+            bd.add(BadgeEntry::new(d(2025, 1, day), "HQ", false));
+        }
         let mut hd = HolidayData::default();
         let mut vd = VacationData::default();
         let mut ed = EventData::default();
-        let today = d(2025, 2, 10);
-        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, today);
-
-        app.handle_key(KeyCode::Char('a'), KeyModifiers::empty());
-        app.handle_key(KeyCode::Char('x'), KeyModifiers::empty());
-        app.handle_key(KeyCode::Esc, KeyModifiers::empty());
-
-        assert!(app.event_data.events.is_empty());
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, d(2025, 1, 22));
+
+        let text = render_app_text_sized(&mut app, 160, 60);
+        assert!(text.contains("Year Stats"));
+        assert!(!text.contains("Weeks:"));
+
+        app.handle_key(KeyCode::Char('W'), KeyModifiers::SHIFT);
+        let text = render_app_text_sized(&mut app, 160, 60);
+        assert!(text.contains("Weeks: Q1 "));
+        assert!(text.contains("W02  Jan  6"));
+        assert!(text.contains("3 / 5"));
+        assert!(!text.contains("Year Stats"));
+
+        app.handle_key(KeyCode::Char('W'), KeyModifiers::SHIFT);
+        let text = render_app_text_sized(&mut app, 160, 60);
+        assert!(text.contains("Year Stats"));
     }
 
     #[test]
-    fn test_add_mode_empty_buffer_no_event() {
+    fn test_vacation_delete_requires_confirmation() {
         let qd = make_quarter_data();
         let mut bd = BadgeEntryData::default();
         let mut hd = HolidayData::default();
         let mut vd = VacationData::default();
+        vd.add(Vacation::new("Hawaii", "2025-05-10", "2025-05-17", true));
         let mut ed = EventData::default();
-        let today = d(2025, 2, 10);
-        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, today);
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, d(2025, 2, 10));
 
-        app.handle_key(KeyCode::Char('a'), KeyModifiers::empty());
-        app.handle_key(KeyCode::Enter, KeyModifiers::empty());
+        app.handle_key(KeyCode::Char('v'), KeyModifiers::empty());
+        app.handle_key(KeyCode::Char('x'), KeyModifiers::empty());
+        let text = render_app_text_sized(&mut app, 160, 60);
+        assert!(text.contains("Delete Hawaii? (y/n)"));
+        app.handle_key(KeyCode::Char('n'), KeyModifiers::empty());
+        assert_eq!(app.vacation_data.vacations.len(), 1);
+        assert!(app.view_state == ViewState::Vacations);
 
-        assert!(app.event_data.events.is_empty());
+        app.handle_key(KeyCode::Delete, KeyModifiers::empty());
+        app.handle_key(KeyCode::Esc, KeyModifiers::empty());
+        assert_eq!(app.vacation_data.vacations.len(), 1);
+
+        app.handle_key(KeyCode::Char('x'), KeyModifiers::empty());
+        app.handle_key(KeyCode::Char('y'), KeyModifiers::empty());
+        assert!(app.vacation_data.vacations.is_empty());
     }
 
     #[test]
-    fn test_add_mode_backspace_in_add_mode() {
+    fn test_holiday_delete_confirmation_and_quick_delete() {
         let qd = make_quarter_data();
         let mut bd = BadgeEntryData::default();
         let mut hd = HolidayData::default();
+        hd.add(Holiday::new("Labor Day", "2025-09-01"));
+        hd.add(Holiday::new("Thanksgiving Day", "2025-11-27"));
         let mut vd = VacationData::default();
         let mut ed = EventData::default();
-        let today = d(2025, 2, 10);
-        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, today);
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, d(2025, 2, 10));
 
-        app.handle_key(KeyCode::Char('a'), KeyModifiers::empty());
+        app.handle_key(KeyCode::Char('h'), KeyModifiers::empty());
         app.handle_key(KeyCode::Char('x'), KeyModifiers::empty());
-        app.handle_key(KeyCode::Backspace, KeyModifiers::empty());
-        app.handle_key(KeyCode::Enter, KeyModifiers::empty());
+        let text = render_app_text_sized(&mut app, 160, 60);
+        assert!(text.contains("Delete Labor Day? (y/n)"));
+        app.handle_key(KeyCode::Char('n'), KeyModifiers::empty());
+        assert_eq!(app.holiday_data.holidays.len(), 2);
+        app.handle_key(KeyCode::Char('x'), KeyModifiers::empty());
+        app.handle_key(KeyCode::Char('y'), KeyModifiers::empty());
+        assert_eq!(app.holiday_data.holidays.len(), 1);
 
-        // buffer was "x", backspace removed it, enter on empty buffer → no event
-        assert!(app.event_data.events.is_empty());
+        app.settings.quick_delete = true;
+        app.handle_key(KeyCode::Char('x'), KeyModifiers::empty());
+        assert!(app.holiday_data.holidays.is_empty());
     }
 
     #[test]
-    fn test_delete_mode_enter_removes_event() {
+    fn test_note_key_sets_note_on_badged_day() {
         let qd = make_quarter_data();
         let mut bd = BadgeEntryData::default();
         let mut hd = HolidayData::default();
         let mut vd = VacationData::default();
         let mut ed = EventData::default();
-        ed.add(Event {
-            date: "2025-02-10".to_string(),
-            description: "To remove".to_string(),
-        });
-        let today = d(2025, 2, 10);
-        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, today);
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, d(2025, 2, 3));
 
-        app.handle_key(KeyCode::Char('d'), KeyModifiers::empty());
-        app.handle_key(KeyCode::Enter, KeyModifiers::empty());
+        // Nothing to annotate until the day is badged
+        app.handle_key(KeyCode::Char('N'), KeyModifiers::empty());
+        assert!(app.mode == Mode::Normal);
 
-        assert!(app.event_data.events.is_empty());
+        app.handle_key(KeyCode::Char('b'), KeyModifiers::empty());
+        app.handle_key(KeyCode::Char('N'), KeyModifiers::empty());
+        assert!(app.mode == Mode::Note);
+        type_and_enter(&mut app, "client on-site");
+        assert!(app.mode == Mode::Normal);
+        assert_eq!(
+            app.badge_data.get("2025-02-03").unwrap().note.as_deref(),
+            Some("client on-site")
+        );
+        let text = render_app_text_sized(&mut app, 160, 60);
+        assert!(text.contains("Note: client on-site"));
+
+        app.handle_key(KeyCode::Char('u'), KeyModifiers::empty());
+        assert_eq!(app.badge_data.get("2025-02-03").unwrap().note, None);
+    }
+
+    fn type_and_enter(app: &mut App, text: &str) {
+        for c in text.chars() {
+            app.handle_key(KeyCode::Char(c), KeyModifiers::empty());
+        }
+        app.handle_key(KeyCode::Enter, KeyModifiers::empty());
     }
 
     #[test]
-    fn test_delete_mode_cursor_navigation() {
+    fn test_scenario_save_and_load_round_trip() {
+        let tmp = tempfile::TempDir::new().unwrap();
         let qd = make_quarter_data();
         let mut bd = BadgeEntryData::default();
         let mut hd = HolidayData::default();
         let mut vd = VacationData::default();
         let mut ed = EventData::default();
-        ed.add(Event {
-            date: "2025-02-10".to_string(),
-            description: "First".to_string(),
-        });
-        ed.add(Event {
-            date: "2025-02-10".to_string(),
-            description: "Second".to_string(),
-        });
-        let today = d(2025, 2, 10);
-        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, today);
-
-        app.handle_key(KeyCode::Char('d'), KeyModifiers::empty());
-        assert_eq!(app.cursor_index, 0);
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, d(2025, 2, 14));
+        app.data_dir = tmp.path().to_path_buf();
+        let key = |app: &mut App, c| app.handle_key(KeyCode::Char(c), KeyModifiers::empty());
+
+        // Saving needs what-if mode
+        key(&mut app, 'S');
+        assert!(app.mode == Mode::Normal);
+        assert!(app.git_status.as_ref().unwrap().0.contains("what-if"));
+
+        key(&mut app, 'w');
+        key(&mut app, 'b');
+        assert!(app.badge_data.has("2025-02-14"));
+        key(&mut app, 'S');
+        assert!(app.mode == Mode::SaveScenario);
+        type_and_enter(&mut app, "fridays");
+        assert_eq!(
+            app.git_status.as_ref().unwrap().0,
+            "Saved scenario 'fridays'"
+        );
+        assert!(
+            scenario::scenarios_dir(tmp.path())
+                .join("fridays.json")
+                .exists()
+        );
 
-        app.handle_key(KeyCode::Down, KeyModifiers::empty());
-        assert_eq!(app.cursor_index, 1);
+        // Leaving what-if discards the simulated badge
+        key(&mut app, 'w');
+        assert!(!app.badge_data.has("2025-02-14"));
 
-        app.handle_key(KeyCode::Up, KeyModifiers::empty());
-        assert_eq!(app.cursor_index, 0);
+        // Loading overlays it in what-if mode, and w restores real data
+        key(&mut app, 'L');
+        type_and_enter(&mut app, "fridays");
+        assert!(app.is_what_if());
+        assert!(app.badge_data.has("2025-02-14"));
+        assert_eq!(app.active_stats.as_ref().unwrap().days_badged_in, 1.0);
+        key(&mut app, 'w');
+        assert!(!app.badge_data.has("2025-02-14"));
+        assert!(!app.data_dirty);
     }
 
     #[test]
-    fn test_search_mode_char_fills_buffer() {
+    fn test_scenario_load_missing_lists_saved_names() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        scenario::save_scenario(tmp.path(), "mondays", &BadgeEntryData::default(), 0).unwrap();
         let qd = make_quarter_data();
         let mut bd = BadgeEntryData::default();
         let mut hd = HolidayData::default();
         let mut vd = VacationData::default();
         let mut ed = EventData::default();
-        let today = d(2025, 2, 10);
-        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, today);
-
-        app.handle_key(KeyCode::Char('s'), KeyModifiers::empty());
-        app.handle_key(KeyCode::Char('q'), KeyModifiers::empty());
-        app.handle_key(KeyCode::Char('1'), KeyModifiers::empty());
-
-        assert_eq!(app.input_buffer, "q1");
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, d(2025, 2, 14));
+        app.data_dir = tmp.path().to_path_buf();
+
+        app.handle_key(KeyCode::Char('L'), KeyModifiers::empty());
+        let text = render_app_text_sized(&mut app, 160, 60);
+        assert!(text.contains("Saved: mondays"));
+        type_and_enter(&mut app, "tuesdays");
+        let (msg, color) = app.git_status.clone().unwrap();
+        assert!(msg.contains("scenario 'tuesdays' not found"), "{msg}");
+        assert!(msg.contains("saved: mondays"), "{msg}");
+        assert_eq!(color, Color::Red);
+        assert!(!app.is_what_if());
     }
 
     #[test]
-    fn test_what_if_toggle_w() {
+    fn test_time_period_add_flow_progresses_through_fields() {
         let qd = make_quarter_data();
         let mut bd = BadgeEntryData::default();
         let mut hd = HolidayData::default();
         let mut vd = VacationData::default();
         let mut ed = EventData::default();
-        let today = d(2025, 2, 10);
-        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, today);
-
-        assert!(!app.is_what_if());
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, d(2025, 2, 3));
 
-        // Enter what-if, add a badge
-        app.handle_key(KeyCode::Char('w'), KeyModifiers::empty());
-        assert!(app.is_what_if());
-        app.handle_key(KeyCode::Char('b'), KeyModifiers::empty());
-        assert_eq!(app.badge_data.data.len(), 1);
+        app.handle_key(KeyCode::Char('T'), KeyModifiers::empty());
+        assert!(app.view_state == ViewState::TimePeriods);
+        app.handle_key(KeyCode::Char('a'), KeyModifiers::empty());
+        assert_eq!(app.list_add_stage, 1);
 
-        // Exit what-if — badge changes discarded
-        app.handle_key(KeyCode::Char('w'), KeyModifiers::empty());
-        assert!(!app.is_what_if());
-        assert_eq!(app.badge_data.data.len(), 0);
+        // Empty and duplicate keys are rejected in place
+        app.handle_key(KeyCode::Enter, KeyModifiers::empty());
+        assert_eq!(app.list_add_stage, 1);
+        assert_eq!(app.input_buffer, "Key is required");
+        app.input_buffer.clear();
+        type_and_enter(&mut app, "Q1_2025");
+        assert_eq!(app.list_add_stage, 1);
+        assert_eq!(app.input_buffer, "Key already exists");
+        app.input_buffer.clear();
+
+        type_and_enter(&mut app, "Q3_2025");
+        assert_eq!(app.list_add_stage, 2);
+        type_and_enter(&mut app, "Q3 2025");
+        assert_eq!(app.list_add_stage, 3);
+
+        type_and_enter(&mut app, "2025-07-3x");
+        assert_eq!(app.list_add_stage, 3);
+        assert_eq!(app.input_buffer, "Invalid date — use YYYY-MM-DD");
+        app.input_buffer.clear();
+        type_and_enter(&mut app, "2025-07-01");
+        assert_eq!(app.list_add_stage, 4);
+        assert_eq!(app.list_field_bufs, ["Q3_2025", "Q3 2025", "2025-07-01"]);
+
+        type_and_enter(&mut app, "2025-06-30");
+        assert_eq!(app.list_add_stage, 4);
+        assert_eq!(app.input_buffer, "End date is before the start date");
+        app.input_buffer.clear();
+        type_and_enter(&mut app, "2025-09-30");
+
+        assert_eq!(app.list_add_stage, 0);
+        assert_eq!(app.time_period_data.len(), 3);
+        let added = app.time_period_data.get_period_by_key("Q3_2025").unwrap();
+        assert_eq!(added.name, "Q3 2025");
+        assert_eq!(added.end_date, Some(d(2025, 9, 30)));
     }
 
     #[test]
-    fn test_n_advances_quarter() {
+    fn test_time_period_edit_prefills_and_keeps_key() {
         let qd = make_quarter_data();
         let mut bd = BadgeEntryData::default();
         let mut hd = HolidayData::default();
         let mut vd = VacationData::default();
         let mut ed = EventData::default();
-        let today = d(2025, 2, 10);
-        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, today);
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, d(2025, 2, 3));
 
+        app.handle_key(KeyCode::Char('T'), KeyModifiers::empty());
+        app.handle_key(KeyCode::Char('e'), KeyModifiers::empty());
+        assert_eq!(app.input_buffer, "Q1_2025");
+        // Re-confirming the period's own key is not a duplicate
+        app.handle_key(KeyCode::Enter, KeyModifiers::empty());
+        assert_eq!(app.list_add_stage, 2);
+        app.handle_key(KeyCode::Enter, KeyModifiers::empty());
+        assert_eq!(app.input_buffer, "2025-01-01");
+        app.handle_key(KeyCode::Enter, KeyModifiers::empty());
+        app.input_buffer = "2025-03-28".to_string();
+        app.handle_key(KeyCode::Enter, KeyModifiers::empty());
+
+        assert_eq!(app.list_add_stage, 0);
+        assert_eq!(app.time_period_data.len(), 2);
         assert_eq!(
-            app.current_period().map(|q| q.key.as_str()),
-            Some("Q1_2025")
-        );
-        app.handle_key(KeyCode::Char('n'), KeyModifiers::empty());
-        assert_eq!(
-            app.current_period().map(|q| q.key.as_str()),
-            Some("Q2_2025")
+            app.time_period_data.periods()[0].end_date,
+            Some(d(2025, 3, 28))
         );
     }
 
     #[test]
-    fn test_p_retreats_quarter() {
+    fn test_time_period_delete_confirmation_and_undo() {
         let qd = make_quarter_data();
         let mut bd = BadgeEntryData::default();
         let mut hd = HolidayData::default();
         let mut vd = VacationData::default();
         let mut ed = EventData::default();
-        // Start in Q2
-        let today = d(2025, 5, 10);
-        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, today);
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, d(2025, 2, 3));
 
-        assert_eq!(
-            app.current_period().map(|q| q.key.as_str()),
-            Some("Q2_2025")
-        );
-        app.handle_key(KeyCode::Char('p'), KeyModifiers::empty());
-        assert_eq!(
-            app.current_period().map(|q| q.key.as_str()),
-            Some("Q1_2025")
-        );
+        app.handle_key(KeyCode::Char('T'), KeyModifiers::empty());
+        app.handle_key(KeyCode::Down, KeyModifiers::empty());
+        app.handle_key(KeyCode::Char('x'), KeyModifiers::empty());
+        let text = render_app_text_sized(&mut app, 160, 60);
+        assert!(text.contains("Delete Q2_2025? (y/n)"));
+        app.handle_key(KeyCode::Char('y'), KeyModifiers::empty());
+        assert_eq!(app.time_period_data.len(), 1);
+        assert_eq!(app.list_cursor, 0);
+
+        app.handle_key(KeyCode::Esc, KeyModifiers::empty());
+        app.handle_key(KeyCode::Char('u'), KeyModifiers::empty());
+        assert_eq!(app.time_period_data.len(), 2);
     }
 
     #[test]
-    fn test_q_returns_true() {
+    fn test_x_toggles_excused_day() {
         let qd = make_quarter_data();
         let mut bd = BadgeEntryData::default();
         let mut hd = HolidayData::default();
         let mut vd = VacationData::default();
         let mut ed = EventData::default();
-        let today = d(2025, 2, 10);
-        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, today);
-
-        let quit = app.handle_key(KeyCode::Char('q'), KeyModifiers::empty());
-        assert!(quit);
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, d(2025, 1, 22));
+        let total = app.active_stats.as_ref().unwrap().total_days;
+
+        app.handle_key(KeyCode::Char('x'), KeyModifiers::NONE);
+        let stats = app.active_stats.as_ref().unwrap();
+        assert_eq!(stats.excused_days, 1);
+        assert_eq!(stats.total_days, total - 1);
+        assert!(app.vacation_data.vacations[0].is_excused);
+        assert_eq!(app.vacation_data.vacations[0].start_date, "2025-01-22");
+
+        app.handle_key(KeyCode::Char('x'), KeyModifiers::NONE);
+        assert!(app.vacation_data.is_empty());
+        assert_eq!(app.active_stats.as_ref().unwrap().total_days, total);
     }
 
     #[test]
-    fn test_ctrl_c_returns_true() {
+    fn test_w_in_vacation_view_toggles_working_trip() {
         let qd = make_quarter_data();
         let mut bd = BadgeEntryData::default();
         let mut hd = HolidayData::default();
         let mut vd = VacationData::default();
+        vd.add(Vacation::new("Beach", "2025-01-20", "2025-01-24", true));
         let mut ed = EventData::default();
-        let today = d(2025, 2, 10);
-        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, today);
-
-        let quit = app.handle_key(KeyCode::Char('c'), KeyModifiers::CONTROL);
-        assert!(quit);
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, d(2025, 1, 22));
+        let off_total = app.active_stats.as_ref().unwrap().total_days;
+
+        app.handle_key(KeyCode::Char('v'), KeyModifiers::NONE);
+        app.handle_key(KeyCode::Char('w'), KeyModifiers::NONE);
+        assert!(!app.vacation_data.vacations[0].counts_as_off);
+        let stats = app.active_stats.as_ref().unwrap();
+        assert_eq!(stats.total_days, off_total + 5);
+        assert_eq!(stats.vacation_days, 0);
+        assert!(render_app_text(&mut app).contains("Working"));
+
+        app.handle_key(KeyCode::Char('w'), KeyModifiers::NONE);
+        assert!(app.vacation_data.vacations[0].counts_as_off);
+        assert_eq!(app.active_stats.as_ref().unwrap().total_days, off_total);
     }
 
     #[test]
-    fn test_q_in_what_if_restores_data() {
+    fn test_by_office_section_lists_each_office() {
         let qd = make_quarter_data();
         let mut bd = BadgeEntryData::default();
+        for day in [6, 7, 8] {
+            bd.add(BadgeEntry::new(d(2025, 1, day), "McLean, VA", false));
+        }
+        bd.add(BadgeEntry::new(d(2025, 1, 9), "Reston, VA", false));
         let mut hd = HolidayData::default();
         let mut vd = VacationData::default();
         let mut ed = EventData::default();
-        let today = d(2025, 2, 10);
-        // Pre-badge a date
-        bd.add(BadgeEntry::new(today, "McLean, VA", false));
-        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, today);
-
-        // Enter what-if, remove the badge
-        app.handle_key(KeyCode::Char('w'), KeyModifiers::empty());
-        app.handle_key(KeyCode::Char('b'), KeyModifiers::empty()); // toggle off
-        assert_eq!(app.badge_data.data.len(), 0);
-
-        // Quit — should restore original data
-        let quit = app.handle_key(KeyCode::Char('q'), KeyModifiers::empty());
-        assert!(quit);
-        assert_eq!(app.badge_data.data.len(), 1);
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, d(2025, 1, 22));
+
+        let text = render_app_text_sized(&mut app, 160, 60);
+        assert!(text.contains("BY OFFICE"));
+        assert!(text.contains("McLean, VA"));
+        assert!(text.contains("Reston, VA"));
+        assert!(text.contains("75.0%"));
+        assert!(text.contains("Year Stats"));
     }
 
     #[test]
-    fn test_n_at_last_quarter_stays() {
-        // make_quarter_data has Q1 (Jan-Mar) and Q2 (Apr-Jun) 2025 only.
-        // At last quarter, n keeps us there (Go behavior: adjacent in list).
+    fn test_status_section_shows_period_elapsed() {
         let qd = make_quarter_data();
         let mut bd = BadgeEntryData::default();
         let mut hd = HolidayData::default();
         let mut vd = VacationData::default();
         let mut ed = EventData::default();
-        let today = d(2025, 5, 10);
-        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, today);
-
-        assert_eq!(
-            app.current_period().map(|q| q.key.as_str()),
-            Some("Q2_2025")
-        );
-        app.handle_key(KeyCode::Char('n'), KeyModifiers::empty());
-        assert_eq!(
-            app.current_period().map(|q| q.key.as_str()),
-            Some("Q2_2025"),
-            "n at last quarter keeps us there"
-        );
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, d(2025, 2, 15));
+        let text = render_app_text_sized(&mut app, 160, 60);
+
+        assert!(text.contains("Period Elapsed"));
+        assert!(text.contains("44 days to go"));
+        assert!(text.contains("50%"));
+        // The extra row still leaves room for the last PROGRESS row
+        assert!(text.contains("Streak (Current / Longest)"));
     }
 
     #[test]
-    fn test_p_at_first_quarter_stays() {
-        // At first quarter, p keeps us there (Go behavior: adjacent in list).
+    fn test_looking_ahead_section_counts_future_days_off() {
         let qd = make_quarter_data();
         let mut bd = BadgeEntryData::default();
         let mut hd = HolidayData::default();
+        hd.add(Holiday::new("Presidents Day", "2025-02-17"));
         let mut vd = VacationData::default();
+        vd.add(Vacation::new("Ski Trip", "2025-03-03", "2025-03-05", true));
         let mut ed = EventData::default();
-        let today = d(2025, 2, 10);
-        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, today);
-
-        assert_eq!(
-            app.current_period().map(|q| q.key.as_str()),
-            Some("Q1_2025")
-        );
-        app.handle_key(KeyCode::Char('p'), KeyModifiers::empty());
-        assert_eq!(
-            app.current_period().map(|q| q.key.as_str()),
-            Some("Q1_2025"),
-            "p at first quarter keeps us there"
-        );
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, d(2025, 1, 22));
+        let text = render_app_text_sized(&mut app, 160, 60);
+
+        assert_eq!(app.looking_ahead_rows(), 4);
+        assert!(text.contains("LOOKING AHEAD"));
+        assert!(text.contains("Vacation Days Ahead"));
+        let stats = app.active_stats.as_ref().unwrap();
+        assert_eq!(stats.future_holidays, 1);
+        assert_eq!(stats.future_vacation_days, 3);
     }
 
     #[test]
-    fn test_year_stats_computed_for_current_year() {
+    fn test_this_week_row_shows_counted_credit() {
         let qd = make_quarter_data();
         let mut bd = BadgeEntryData::default();
+        // Only the first flex credit counts
+        bd.add(BadgeEntry::new(d(2025, 3, 10), "Flex Credit", true));
+        bd.add(BadgeEntry::new(d(2025, 3, 11), "Flex Credit", true));
         let mut hd = HolidayData::default();
         let mut vd = VacationData::default();
         let mut ed = EventData::default();
-        let today = d(2025, 2, 10);
-        let app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, today);
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, d(2025, 3, 12));
+        app.settings.min_days_per_week = 3;
+        app.settings.max_flex_credits = Some(1);
+        let text = render_app_text_sized(&mut app, 160, 60);
+        let row = text
+            .lines()
+            .find(|line| line.contains("This Week"))
+            .unwrap();
+        assert!(row.contains("This Week (3 workdays left)"));
+        assert!(row.contains("1 / 3"));
+    }
 
-        assert!(app.year_stats.is_some(), "year_stats should be populated");
+    #[test]
+    fn test_scroll_offset_keeps_selection_visible() {
+        // Within the window: unchanged
+        assert_eq!(scroll_offset(5, 8, 10, 100), 5);
+        // Below the window: scroll just enough
+        assert_eq!(scroll_offset(0, 12, 10, 100), 3);
+        // Above the window: snap to the selection
+        assert_eq!(scroll_offset(20, 4, 10, 100), 4);
+        // Offset clamped when the list shrinks
+        assert_eq!(scroll_offset(50, 5, 10, 8), 0);
+        assert_eq!(scroll_offset(3, 0, 0, 8), 0);
     }
 
     #[test]
-    fn test_year_stats_cleared_when_no_quarter() {
-        let qd = TimePeriodData::new();
+    fn test_more_rows_indicator() {
+        assert_eq!(more_rows_indicator(0, 10, 5).width(), 0);
+        let text = more_rows_indicator(12, 10, 30).to_string();
+        assert!(text.contains("▲ 12 more"));
+        assert!(text.contains("▼ 8 more"));
+    }
+
+    #[test]
+    fn test_vacation_view_warns_on_overlap() {
+        let qd = make_quarter_data();
         let mut bd = BadgeEntryData::default();
         let mut hd = HolidayData::default();
         let mut vd = VacationData::default();
+        vd.add(Vacation::new("Hawaii", "2025-05-10", "2025-05-17", true));
+        vd.add(Vacation::new("Paris", "2025-06-01", "2025-06-07", true));
+        vd.add(Vacation::new("Oops", "2025-05-16", "2025-05-20", true));
         let mut ed = EventData::default();
-        let today = d(2025, 5, 10);
-        let app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, today);
-
-        assert!(app.current_period().is_none());
-        assert!(
-            app.year_stats.is_none(),
-            "year_stats should be None when no quarter"
-        );
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, d(2025, 2, 15));
+        app.handle_key(KeyCode::Char('v'), KeyModifiers::empty());
+        let text = render_app_text_sized(&mut app, 100, 30);
+        assert!(text.contains("⚠ Vacations #1 and #2 overlap"));
     }
 
     #[test]
-    fn test_b_sets_data_dirty() {
+    fn test_long_holiday_list_scrolls_with_cursor() {
         let qd = make_quarter_data();
         let mut bd = BadgeEntryData::default();
         let mut hd = HolidayData::default();
+        let start = NaiveDate::from_ymd_opt(2020, 1, 1).unwrap();
+        for i in 0..120 {
+            let date = start + chrono::Duration::days(i * 7);
+            hd.add(Holiday::new(
+                &format!("Holiday {}", i + 1),
+                &date.to_string(),
+            ));
+        }
         let mut vd = VacationData::default();
         let mut ed = EventData::default();
-        let today = d(2025, 2, 10);
+        let today = NaiveDate::from_ymd_opt(2025, 2, 15).unwrap();
         let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, today);
+        app.handle_key(KeyCode::Char('h'), KeyModifiers::empty());
+
+        // 30-line terminal: 24-line table area → 21 visible data rows
+        let visible = 21;
+        for step in 0..80 {
+            app.handle_key(KeyCode::Down, KeyModifiers::empty());
+            let text = render_app_text_sized(&mut app, 100, 30);
+            assert!(app.list_offset <= app.list_cursor, "step {step}");
+            assert!(app.list_cursor < app.list_offset + visible, "step {step}");
+            assert!(text.contains(&format!("Holiday {}", app.list_cursor + 1)));
+        }
+        let text = render_app_text_sized(&mut app, 100, 30);
+        assert!(text.contains(&format!("▲ {} more", app.list_offset)));
+        assert!(text.contains("▼"));
 
-        assert!(!app.data_dirty);
-        app.handle_key(KeyCode::Char('b'), KeyModifiers::empty());
-        assert!(app.data_dirty, "data_dirty should be true after badge toggle");
+        // Moving back up inside the window doesn't jump the viewport
+        let offset = app.list_offset;
+        app.handle_key(KeyCode::Up, KeyModifiers::empty());
+        render_app_text_sized(&mut app, 100, 30);
+        assert_eq!(app.list_offset, offset);
     }
 
     #[test]
-    fn test_f_sets_data_dirty() {
+    fn test_plan_key_cycles_strategies() {
         let qd = make_quarter_data();
         let mut bd = BadgeEntryData::default();
         let mut hd = HolidayData::default();
         let mut vd = VacationData::default();
         let mut ed = EventData::default();
-        let today = d(2025, 2, 10);
-        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, today);
-
-        assert!(!app.data_dirty);
-        app.handle_key(KeyCode::Char('f'), KeyModifiers::empty());
-        assert!(app.data_dirty, "data_dirty should be true after flex toggle");
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, d(2025, 3, 24));
+        assert_eq!(app.plan_strategy, None);
+        app.handle_key(KeyCode::Char('P'), KeyModifiers::empty());
+        assert_eq!(app.plan_strategy, Some(PlanStrategy::EarliestFirst));
+        app.handle_key(KeyCode::Char('P'), KeyModifiers::empty());
+        assert_eq!(app.plan_strategy, Some(PlanStrategy::EvenlySpaced));
+        app.handle_key(KeyCode::Char('P'), KeyModifiers::empty());
+        assert_eq!(app.plan_strategy, None);
     }
 
     #[test]
-    fn test_what_if_badge_does_not_set_dirty() {
+    fn test_plan_mode_highlights_days_without_badging() {
+        use ratatui::{Terminal, backend::TestBackend};
         let qd = make_quarter_data();
         let mut bd = BadgeEntryData::default();
         let mut hd = HolidayData::default();
         let mut vd = VacationData::default();
         let mut ed = EventData::default();
-        let today = d(2025, 2, 10);
-        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, today);
-
-        app.handle_key(KeyCode::Char('w'), KeyModifiers::empty());
-        assert!(app.is_what_if());
-
-        app.handle_key(KeyCode::Char('b'), KeyModifiers::empty());
-        assert!(
-            !app.data_dirty,
-            "data_dirty should remain false in what-if mode"
-        );
+        let mut app = make_test_app(qd, &mut bd, &mut hd, &mut vd, &mut ed, d(2025, 3, 24));
+        app.handle_key(KeyCode::Char('P'), KeyModifiers::empty());
+        let mut terminal = Terminal::new(TestBackend::new(160, 60)).unwrap();
+        terminal.draw(|f| app.render(f)).unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let rows: Vec<&[ratatui::buffer::Cell]> = buffer
+            .content()
+            .chunks(buffer.area.width as usize)
+            .collect();
+        let banner = rows
+            .iter()
+            .position(|row| {
+                row.iter()
+                    .map(|c| c.symbol())
+                    .collect::<String>()
+                    .contains("PLAN:")
+            })
+            .expect("plan banner");
+        let plan_color = app.theme.plan;
+        let highlighted = rows
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| *i != banner)
+            .flat_map(|(_, row)| row.iter())
+            .filter(|c| c.fg == plan_color && c.symbol().chars().all(|ch| ch.is_ascii_digit()))
+            .count();
+        assert!(highlighted > 0);
+        assert!(app.badge_data.is_empty());
     }
 }
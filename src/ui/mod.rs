@@ -1,4 +1,5 @@
 pub mod calendar_view;
+pub mod theme;
 
 use anyhow::Result;
 use crossterm::{
@@ -0,0 +1,98 @@
+use ratatui::style::Color;
+
+/// Theme names accepted by the `theme` setting.
+pub const THEME_NAMES: &[&str] = &["dark", "light", "high-contrast"];
+
+/// Colors for calendar cells and stats section headers.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Theme {
+    pub office: Color,
+    pub flex: Color,
+    pub holiday: Color,
+    pub event: Color,
+    /// Suggested office days in plan mode
+    pub plan: Color,
+    /// Text of the selected day, drawn over its state color
+    pub selected_fg: Color,
+    /// Background of a selected day with nothing on it
+    pub selected_bg: Color,
+    pub section_fg: Color,
+    pub section_bg: Color,
+}
+
+impl Theme {
+    /// The default palette, tuned for dark terminal backgrounds.
+    pub fn dark() -> Self {
+        Theme {
+            office: Color::Yellow,
+            flex: Color::Indexed(208), // reddish-orange
+            holiday: Color::Green,
+            event: Color::Cyan,
+            plan: Color::Magenta,
+            selected_fg: Color::Black,
+            selected_bg: Color::White,
+            section_fg: Color::White,
+            section_bg: Color::Rgb(40, 44, 52),
+        }
+    }
+
+    /// Darker foregrounds that stay readable on a light background.
+    pub fn light() -> Self {
+        Theme {
+            office: Color::Indexed(136), // dark goldenrod
+            flex: Color::Indexed(166),   // dark orange
+            holiday: Color::Indexed(28), // dark green
+            event: Color::Blue,
+            plan: Color::Indexed(90), // dark magenta
+            selected_fg: Color::White,
+            selected_bg: Color::Black,
+            section_fg: Color::Black,
+            section_bg: Color::Indexed(252),
+        }
+    }
+
+    /// Bright, fully saturated colors on black.
+    pub fn high_contrast() -> Self {
+        Theme {
+            office: Color::LightYellow,
+            flex: Color::LightMagenta,
+            holiday: Color::LightGreen,
+            event: Color::LightCyan,
+            plan: Color::LightBlue,
+            selected_fg: Color::Black,
+            selected_bg: Color::White,
+            section_fg: Color::White,
+            section_bg: Color::Black,
+        }
+    }
+
+    /// Looks up a theme by name (case-insensitive); unknown names fall back to dark.
+    pub fn named(name: &str) -> Self {
+        match name.to_ascii_lowercase().as_str() {
+            "light" => Theme::light(),
+            "high-contrast" => Theme::high_contrast(),
+            _ => Theme::dark(),
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme::dark()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_named_themes_and_fallback() {
+        assert_eq!(Theme::named("Light"), Theme::light());
+        assert_eq!(Theme::named("high-contrast"), Theme::high_contrast());
+        assert_eq!(Theme::named("solarized"), Theme::dark());
+        for name in THEME_NAMES {
+            assert!(name == &"dark" || Theme::named(name) != Theme::dark());
+        }
+    }
+}